@@ -0,0 +1,3 @@
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "professional_id";
+
+DROP TABLE IF EXISTS "professionals";
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS "professionals" (
+  "id" serial PRIMARY KEY,
+  "name" varchar(255) NOT NULL,
+  "license_number" varchar(20) NOT NULL,
+  "council_state" varchar(2) NOT NULL,
+  "specialties" text[] NOT NULL DEFAULT '{}',
+  "active" boolean NOT NULL DEFAULT TRUE,
+  UNIQUE ("license_number", "council_state")
+);
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "professional_id" integer;
+
+ALTER TABLE IF EXISTS "appointments" ADD FOREIGN KEY ("professional_id") REFERENCES "professionals" ("id");
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS "schedule_exceptions";
+
+DROP TABLE IF EXISTS "professional_schedules";
@@ -0,0 +1,25 @@
+CREATE TABLE IF NOT EXISTS "professional_schedules" (
+  "id" serial PRIMARY KEY,
+  "professional_id" integer NOT NULL,
+  "weekday" smallint NOT NULL CHECK ("weekday" BETWEEN 1 AND 7),
+  "starts_at" time NOT NULL,
+  "ends_at" time NOT NULL,
+  CHECK ("starts_at" < "ends_at")
+);
+
+CREATE TABLE IF NOT EXISTS "schedule_exceptions" (
+  "id" serial PRIMARY KEY,
+  "professional_id" integer NOT NULL,
+  "starts_at" timestamp NOT NULL,
+  "ends_at" timestamp NOT NULL,
+  "reason" text,
+  CHECK ("starts_at" < "ends_at")
+);
+
+ALTER TABLE IF EXISTS "professional_schedules" ADD FOREIGN KEY ("professional_id") REFERENCES "professionals" ("id");
+
+ALTER TABLE IF EXISTS "schedule_exceptions" ADD FOREIGN KEY ("professional_id") REFERENCES "professionals" ("id");
+
+CREATE INDEX IF NOT EXISTS "professional_schedules_professional_id_idx" ON "professional_schedules" ("professional_id");
+
+CREATE INDEX IF NOT EXISTS "schedule_exceptions_professional_id_starts_at_idx" ON "schedule_exceptions" ("professional_id", "starts_at");
@@ -0,0 +1,5 @@
+ALTER TABLE IF EXISTS "appointments" DROP CONSTRAINT IF EXISTS "appointments_professional_overlap_excl";
+
+ALTER TABLE IF EXISTS "appointments" DROP CONSTRAINT IF EXISTS "appointments_patient_overlap_excl";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "ends_at";
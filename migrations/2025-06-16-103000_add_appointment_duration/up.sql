@@ -0,0 +1,19 @@
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "ends_at" timestamp;
+
+UPDATE "appointments" SET "ends_at" = "appointment_at" + interval '30 minutes';
+
+ALTER TABLE IF EXISTS "appointments" ALTER COLUMN "ends_at" SET NOT NULL;
+
+ALTER TABLE IF EXISTS "appointments" ADD CHECK ("appointment_at" < "ends_at");
+
+-- Non-canceled appointments that already overlap must be resolved by hand
+-- before these constraints can be created.
+CREATE EXTENSION IF NOT EXISTS btree_gist;
+
+ALTER TABLE IF EXISTS "appointments" ADD CONSTRAINT "appointments_patient_overlap_excl"
+  EXCLUDE USING gist ("patient_id" WITH =, tsrange("appointment_at", "ends_at") WITH &&)
+  WHERE (NOT "canceled");
+
+ALTER TABLE IF EXISTS "appointments" ADD CONSTRAINT "appointments_professional_overlap_excl"
+  EXCLUDE USING gist ("professional_id" WITH =, tsrange("appointment_at", "ends_at") WITH &&)
+  WHERE (NOT "canceled" AND "professional_id" IS NOT NULL);
@@ -0,0 +1,27 @@
+ALTER TABLE IF EXISTS "appointments" DROP CONSTRAINT IF EXISTS "appointments_patient_overlap_excl";
+
+ALTER TABLE IF EXISTS "appointments" DROP CONSTRAINT IF EXISTS "appointments_professional_overlap_excl";
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "canceled" boolean NOT NULL DEFAULT FALSE;
+
+UPDATE "appointments" SET "canceled" = TRUE WHERE "status" = 'canceled';
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "no_show_at";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "completed_at";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "started_at";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "checked_in_at";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "confirmed_at";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "status";
+
+ALTER TABLE IF EXISTS "appointments" ADD CONSTRAINT "appointments_patient_overlap_excl"
+  EXCLUDE USING gist ("patient_id" WITH =, tsrange("appointment_at", "ends_at") WITH &&)
+  WHERE (NOT "canceled");
+
+ALTER TABLE IF EXISTS "appointments" ADD CONSTRAINT "appointments_professional_overlap_excl"
+  EXCLUDE USING gist ("professional_id" WITH =, tsrange("appointment_at", "ends_at") WITH &&)
+  WHERE (NOT "canceled" AND "professional_id" IS NOT NULL);
@@ -0,0 +1,28 @@
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "status" varchar(20) NOT NULL DEFAULT 'scheduled'
+  CHECK ("status" IN ('scheduled', 'confirmed', 'checked_in', 'in_progress', 'completed', 'no_show', 'canceled'));
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "confirmed_at" timestamp;
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "checked_in_at" timestamp;
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "started_at" timestamp;
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "completed_at" timestamp;
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "no_show_at" timestamp;
+
+UPDATE "appointments" SET "status" = 'canceled' WHERE "canceled";
+
+ALTER TABLE IF EXISTS "appointments" DROP CONSTRAINT IF EXISTS "appointments_patient_overlap_excl";
+
+ALTER TABLE IF EXISTS "appointments" DROP CONSTRAINT IF EXISTS "appointments_professional_overlap_excl";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN "canceled";
+
+ALTER TABLE IF EXISTS "appointments" ADD CONSTRAINT "appointments_patient_overlap_excl"
+  EXCLUDE USING gist ("patient_id" WITH =, tsrange("appointment_at", "ends_at") WITH &&)
+  WHERE ("status" <> 'canceled');
+
+ALTER TABLE IF EXISTS "appointments" ADD CONSTRAINT "appointments_professional_overlap_excl"
+  EXCLUDE USING gist ("professional_id" WITH =, tsrange("appointment_at", "ends_at") WITH &&)
+  WHERE ("status" <> 'canceled' AND "professional_id" IS NOT NULL);
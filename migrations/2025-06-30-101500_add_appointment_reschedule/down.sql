@@ -0,0 +1,7 @@
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "reschedule_reason";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "rescheduled_by";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "rescheduled_at";
+
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "previous_appointment_at";
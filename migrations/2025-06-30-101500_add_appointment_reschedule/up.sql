@@ -0,0 +1,7 @@
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "previous_appointment_at" timestamp;
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "rescheduled_at" timestamp;
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "rescheduled_by" varchar(150);
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "reschedule_reason" text;
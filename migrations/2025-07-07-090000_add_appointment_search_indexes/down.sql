@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS "appointments_status_idx";
+
+DROP INDEX IF EXISTS "appointments_professional_id_appointment_at_idx";
+
+DROP INDEX IF EXISTS "appointments_appointment_at_idx";
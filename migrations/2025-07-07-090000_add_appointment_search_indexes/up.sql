@@ -0,0 +1,5 @@
+CREATE INDEX IF NOT EXISTS "appointments_appointment_at_idx" ON "appointments" ("appointment_at");
+
+CREATE INDEX IF NOT EXISTS "appointments_professional_id_appointment_at_idx" ON "appointments" ("professional_id", "appointment_at");
+
+CREATE INDEX IF NOT EXISTS "appointments_status_idx" ON "appointments" ("status");
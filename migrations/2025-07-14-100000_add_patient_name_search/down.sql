@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS "patients_name_trgm_idx";
+
+DROP FUNCTION IF EXISTS "immutable_unaccent"(text);
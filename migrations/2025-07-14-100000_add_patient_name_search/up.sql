@@ -0,0 +1,11 @@
+CREATE EXTENSION IF NOT EXISTS unaccent;
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- unaccent() is only STABLE, so it cannot back an index on its own.
+CREATE OR REPLACE FUNCTION "immutable_unaccent"(text) RETURNS text AS $$
+  SELECT public.unaccent('public.unaccent', $1)
+$$ LANGUAGE sql IMMUTABLE PARALLEL SAFE STRICT;
+
+CREATE INDEX IF NOT EXISTS "patients_name_trgm_idx" ON "patients"
+  USING gin (lower("immutable_unaccent"("name")) gin_trgm_ops);
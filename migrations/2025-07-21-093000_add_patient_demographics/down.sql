@@ -0,0 +1,29 @@
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "uf";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "city";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "neighborhood";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "address_complement";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "address_number";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "street";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "cep";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "phones";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "email";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "cns";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "mother_name";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "gender_identity";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "sex";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "birth_date";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "social_name";
@@ -0,0 +1,33 @@
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "social_name" varchar(255);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "birth_date" date;
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "sex" varchar(10)
+  CHECK ("sex" IN ('female', 'male', 'intersex'));
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "gender_identity" varchar(50);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "mother_name" varchar(255);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "cns" varchar(15) UNIQUE;
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "email" varchar(150);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "phones" text[] NOT NULL DEFAULT '{}';
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "cep" varchar(8);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "street" varchar(255);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "address_number" varchar(20);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "address_complement" varchar(100);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "neighborhood" varchar(100);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "city" varchar(100);
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "uf" varchar(2);
+
+-- Contact data used to be planned as a separate, never-migrated "users" table.
+DROP TABLE IF EXISTS "users";
@@ -0,0 +1,24 @@
+-- Soft-deleted patients are records that must be kept, and they may share a
+-- CPF or CNS with an active patient, so the old unique constraints cannot
+-- come back while any exist. Refuse instead of destroying them.
+DO $$
+BEGIN
+  IF EXISTS (SELECT 1 FROM "patients" WHERE "deleted_at" IS NOT NULL) THEN
+    RAISE EXCEPTION 'cannot revert: soft-deleted patients exist and would be lost';
+  END IF;
+END;
+$$;
+
+DROP TABLE IF EXISTS "audit_events";
+
+DROP INDEX IF EXISTS "patients_cns_active_key";
+
+DROP INDEX IF EXISTS "patients_cpf_active_key";
+
+ALTER TABLE IF EXISTS "patients" ADD CONSTRAINT "patients_cns_key" UNIQUE ("cns");
+
+ALTER TABLE IF EXISTS "patients" ADD CONSTRAINT "patients_cpf_key" UNIQUE ("cpf");
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "anonymized_at";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "deleted_at";
@@ -0,0 +1,27 @@
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "deleted_at" timestamp;
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "anonymized_at" timestamp;
+
+-- A deleted patient keeps its row, so CPF and CNS only need to be unique
+-- among the active ones.
+ALTER TABLE IF EXISTS "patients" DROP CONSTRAINT IF EXISTS "patients_cpf_key";
+
+ALTER TABLE IF EXISTS "patients" DROP CONSTRAINT IF EXISTS "patients_cns_key";
+
+CREATE UNIQUE INDEX IF NOT EXISTS "patients_cpf_active_key" ON "patients" ("cpf")
+  WHERE "deleted_at" IS NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS "patients_cns_active_key" ON "patients" ("cns")
+  WHERE "deleted_at" IS NULL;
+
+CREATE TABLE IF NOT EXISTS "audit_events" (
+  "id" serial PRIMARY KEY,
+  "actor" varchar(150) NOT NULL,
+  "action" varchar(50) NOT NULL,
+  "entity_type" varchar(50) NOT NULL,
+  "entity_id" integer,
+  "details" text,
+  "occurred_at" timestamp NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS "audit_events_entity_idx" ON "audit_events" ("entity_type", "entity_id");
@@ -0,0 +1,3 @@
+ALTER TABLE IF EXISTS "appointments" DROP COLUMN IF EXISTS "telemedicine";
+
+DROP TABLE IF EXISTS "consents";
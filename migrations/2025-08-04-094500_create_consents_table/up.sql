@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS "consents" (
+  "id" serial PRIMARY KEY,
+  "patient_id" integer NOT NULL,
+  "purpose" varchar(30) NOT NULL CHECK ("purpose" IN ('data_processing', 'telemedicine', 'insurer_sharing', 'sms_contact')),
+  "term_version" varchar(50) NOT NULL,
+  "channel" varchar(20) NOT NULL CHECK ("channel" IN ('in_person', 'online', 'phone', 'paper')),
+  "granted_at" timestamp NOT NULL DEFAULT NOW(),
+  "granted_by" varchar(150) NOT NULL,
+  "revoked_at" timestamp,
+  "revoked_by" varchar(150)
+);
+
+ALTER TABLE IF EXISTS "consents" ADD FOREIGN KEY ("patient_id") REFERENCES "patients" ("id");
+
+-- Revoked consents are kept as history, but a patient holds at most one
+-- active consent per purpose.
+CREATE UNIQUE INDEX IF NOT EXISTS "consents_active_purpose_key" ON "consents" ("patient_id", "purpose")
+  WHERE "revoked_at" IS NULL;
+
+ALTER TABLE IF EXISTS "appointments" ADD COLUMN "telemedicine" boolean NOT NULL DEFAULT FALSE;
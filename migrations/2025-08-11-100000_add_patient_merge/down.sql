@@ -0,0 +1,3 @@
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "merged_at";
+
+ALTER TABLE IF EXISTS "patients" DROP COLUMN IF EXISTS "merged_into_id";
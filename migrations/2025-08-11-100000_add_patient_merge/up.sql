@@ -0,0 +1,5 @@
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "merged_into_id" integer;
+
+ALTER TABLE IF EXISTS "patients" ADD FOREIGN KEY ("merged_into_id") REFERENCES "patients" ("id");
+
+ALTER TABLE IF EXISTS "patients" ADD COLUMN "merged_at" timestamp;
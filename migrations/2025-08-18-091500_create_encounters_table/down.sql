@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS "encounters";
+
+DROP FUNCTION IF EXISTS reject_encounter_changes();
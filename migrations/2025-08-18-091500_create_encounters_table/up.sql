@@ -0,0 +1,49 @@
+CREATE TABLE IF NOT EXISTS "encounters" (
+  "id" serial PRIMARY KEY,
+  "appointment_id" integer NOT NULL,
+  "patient_id" integer NOT NULL,
+  "professional_id" integer NOT NULL,
+  "chief_complaint" text NOT NULL,
+  "history" text,
+  "physical_exam" text,
+  "assessment" text,
+  "plan" text,
+  "amends_id" integer,
+  "amendment_reason" text,
+  "recorded_by" varchar(150) NOT NULL,
+  "recorded_at" timestamp NOT NULL DEFAULT NOW(),
+  CHECK (("amends_id" IS NULL) = ("amendment_reason" IS NULL))
+);
+
+ALTER TABLE IF EXISTS "encounters" ADD FOREIGN KEY ("appointment_id") REFERENCES "appointments" ("id");
+
+ALTER TABLE IF EXISTS "encounters" ADD FOREIGN KEY ("patient_id") REFERENCES "patients" ("id");
+
+ALTER TABLE IF EXISTS "encounters" ADD FOREIGN KEY ("professional_id") REFERENCES "professionals" ("id");
+
+ALTER TABLE IF EXISTS "encounters" ADD FOREIGN KEY ("amends_id") REFERENCES "encounters" ("id");
+
+-- Amendments share the appointment of the note they amend, so only the
+-- original note has to be unique.
+CREATE UNIQUE INDEX IF NOT EXISTS "encounters_appointment_original_key" ON "encounters" ("appointment_id")
+  WHERE "amends_id" IS NULL;
+
+CREATE INDEX IF NOT EXISTS "encounters_patient_recorded_at_idx" ON "encounters" ("patient_id", "recorded_at");
+
+CREATE INDEX IF NOT EXISTS "encounters_amends_id_idx" ON "encounters" ("amends_id");
+
+-- The record is append-only: mistakes are fixed with amendments. Only the
+-- patient may change, when duplicate patients are merged.
+CREATE OR REPLACE FUNCTION reject_encounter_changes() RETURNS trigger AS $$
+BEGIN
+  IF TG_OP = 'DELETE' OR to_jsonb(NEW) - 'patient_id' IS DISTINCT FROM to_jsonb(OLD) - 'patient_id' THEN
+    RAISE EXCEPTION 'encounters are append-only';
+  END IF;
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER "encounters_append_only"
+  BEFORE UPDATE OR DELETE ON "encounters"
+  FOR EACH ROW EXECUTE FUNCTION reject_encounter_changes();
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS "problems";
+
+DROP TABLE IF EXISTS "allergies";
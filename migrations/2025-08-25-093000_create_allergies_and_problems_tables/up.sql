@@ -0,0 +1,29 @@
+CREATE TABLE IF NOT EXISTS "allergies" (
+  "id" serial PRIMARY KEY,
+  "patient_id" integer NOT NULL,
+  "substance" varchar(150) NOT NULL,
+  "reaction" text,
+  "severity" varchar(10) NOT NULL CHECK ("severity" IN ('mild', 'moderate', 'severe')),
+  "recorded_at" timestamp NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE IF EXISTS "allergies" ADD FOREIGN KEY ("patient_id") REFERENCES "patients" ("id");
+
+CREATE UNIQUE INDEX IF NOT EXISTS "allergies_patient_substance_key" ON "allergies" ("patient_id", lower("substance"));
+
+CREATE TABLE IF NOT EXISTS "problems" (
+  "id" serial PRIMARY KEY,
+  "patient_id" integer NOT NULL,
+  "icd10_code" varchar(10) NOT NULL,
+  "description" varchar(255) NOT NULL,
+  "onset_date" date,
+  "resolved_at" date,
+  "notes" text,
+  "recorded_at" timestamp NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE IF EXISTS "problems" ADD FOREIGN KEY ("patient_id") REFERENCES "patients" ("id");
+
+-- A resolved problem may come back, but it can only be active once.
+CREATE UNIQUE INDEX IF NOT EXISTS "problems_patient_active_code_key" ON "problems" ("patient_id", "icd10_code")
+  WHERE "resolved_at" IS NULL;
@@ -0,0 +1,33 @@
+CREATE TABLE IF NOT EXISTS "prescriptions" (
+  "id" serial PRIMARY KEY,
+  "patient_id" integer NOT NULL,
+  "professional_id" integer NOT NULL,
+  "appointment_id" integer,
+  "notes" text,
+  -- Why the prescriber went ahead despite a severe allergy to one of the drugs.
+  "allergy_override_reason" text,
+  "issued_by" varchar(150) NOT NULL,
+  "issued_at" timestamp NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE IF EXISTS "prescriptions" ADD FOREIGN KEY ("patient_id") REFERENCES "patients" ("id");
+
+ALTER TABLE IF EXISTS "prescriptions" ADD FOREIGN KEY ("professional_id") REFERENCES "professionals" ("id");
+
+ALTER TABLE IF EXISTS "prescriptions" ADD FOREIGN KEY ("appointment_id") REFERENCES "appointments" ("id");
+
+CREATE INDEX IF NOT EXISTS "prescriptions_patient_id_idx" ON "prescriptions" ("patient_id");
+
+CREATE TABLE IF NOT EXISTS "prescription_items" (
+  "id" serial PRIMARY KEY,
+  "prescription_id" integer NOT NULL,
+  "position" integer NOT NULL,
+  "drug" varchar(150) NOT NULL,
+  "dose" varchar(100) NOT NULL,
+  "route" varchar(50) NOT NULL,
+  "frequency" varchar(100) NOT NULL,
+  "duration" varchar(100),
+  UNIQUE ("prescription_id", "position")
+);
+
+ALTER TABLE IF EXISTS "prescription_items" ADD FOREIGN KEY ("prescription_id") REFERENCES "prescriptions" ("id") ON DELETE CASCADE;
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS "exam_result_files";
+
+DROP TABLE IF EXISTS "exam_results";
+
+DROP TABLE IF EXISTS "exam_orders";
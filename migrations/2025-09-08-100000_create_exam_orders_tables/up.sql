@@ -0,0 +1,51 @@
+CREATE TABLE IF NOT EXISTS "exam_orders" (
+  "id" serial PRIMARY KEY,
+  "patient_id" integer NOT NULL,
+  "professional_id" integer NOT NULL,
+  "appointment_id" integer,
+  "kind" varchar(10) NOT NULL CHECK ("kind" IN ('lab', 'imaging')),
+  "exam_name" varchar(150) NOT NULL,
+  "clinical_indication" text,
+  "status" varchar(10) NOT NULL DEFAULT 'requested'
+    CHECK ("status" IN ('requested', 'collected', 'resulted', 'reviewed')),
+  "requested_by" varchar(150) NOT NULL,
+  "requested_at" timestamp NOT NULL DEFAULT NOW(),
+  "collected_at" timestamp,
+  "resulted_at" timestamp,
+  "reviewed_at" timestamp,
+  "reviewed_by" varchar(150),
+  "result_file_name" varchar(255),
+  "result_file_content_type" varchar(100),
+  "result_file_size" integer
+);
+
+ALTER TABLE IF EXISTS "exam_orders" ADD FOREIGN KEY ("patient_id") REFERENCES "patients" ("id");
+
+ALTER TABLE IF EXISTS "exam_orders" ADD FOREIGN KEY ("professional_id") REFERENCES "professionals" ("id");
+
+ALTER TABLE IF EXISTS "exam_orders" ADD FOREIGN KEY ("appointment_id") REFERENCES "appointments" ("id");
+
+CREATE INDEX IF NOT EXISTS "exam_orders_patient_id_idx" ON "exam_orders" ("patient_id");
+
+CREATE TABLE IF NOT EXISTS "exam_results" (
+  "id" serial PRIMARY KEY,
+  "exam_order_id" integer NOT NULL,
+  "analyte" varchar(150) NOT NULL,
+  "value" varchar(100) NOT NULL,
+  "unit" varchar(30),
+  "reference_low" double precision,
+  "reference_high" double precision,
+  "reference_text" varchar(100),
+  "abnormal" boolean NOT NULL,
+  "recorded_at" timestamp NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE IF EXISTS "exam_results" ADD FOREIGN KEY ("exam_order_id") REFERENCES "exam_orders" ("id") ON DELETE CASCADE;
+
+-- Kept apart so that listing orders never loads the file contents.
+CREATE TABLE IF NOT EXISTS "exam_result_files" (
+  "exam_order_id" integer PRIMARY KEY,
+  "content" bytea NOT NULL
+);
+
+ALTER TABLE IF EXISTS "exam_result_files" ADD FOREIGN KEY ("exam_order_id") REFERENCES "exam_orders" ("id") ON DELETE CASCADE;
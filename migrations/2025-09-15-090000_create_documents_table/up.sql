@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS "documents" (
+  "id" serial PRIMARY KEY,
+  "patient_id" integer NOT NULL,
+  "category" varchar(20) NOT NULL
+    CHECK ("category" IN ('referral', 'id_document', 'exam_report', 'other')),
+  "file_name" varchar(255) NOT NULL,
+  "content_type" varchar(100) NOT NULL,
+  "size_bytes" bigint NOT NULL,
+  "sha256" char(64) NOT NULL,
+  -- Where the contents live in the file storage.
+  "storage_key" varchar(255) NOT NULL UNIQUE,
+  "description" text,
+  "uploaded_by" varchar(150) NOT NULL,
+  "uploaded_at" timestamp NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE IF EXISTS "documents" ADD FOREIGN KEY ("patient_id") REFERENCES "patients" ("id");
+
+CREATE INDEX IF NOT EXISTS "documents_patient_id_idx" ON "documents" ("patient_id");
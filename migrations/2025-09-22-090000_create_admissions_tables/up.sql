@@ -0,0 +1,67 @@
+CREATE TABLE IF NOT EXISTS "wards" (
+  "id" serial PRIMARY KEY,
+  "name" varchar(100) NOT NULL UNIQUE,
+  "description" text
+);
+
+CREATE TABLE IF NOT EXISTS "rooms" (
+  "id" serial PRIMARY KEY,
+  "ward_id" integer NOT NULL,
+  "number" varchar(20) NOT NULL,
+  UNIQUE ("ward_id", "number")
+);
+
+ALTER TABLE IF EXISTS "rooms" ADD FOREIGN KEY ("ward_id") REFERENCES "wards" ("id");
+
+CREATE TABLE IF NOT EXISTS "beds" (
+  "id" serial PRIMARY KEY,
+  "room_id" integer NOT NULL,
+  "label" varchar(20) NOT NULL,
+  "status" varchar(20) NOT NULL DEFAULT 'free'
+    CHECK ("status" IN ('free', 'occupied', 'cleaning', 'blocked')),
+  UNIQUE ("room_id", "label")
+);
+
+ALTER TABLE IF EXISTS "beds" ADD FOREIGN KEY ("room_id") REFERENCES "rooms" ("id");
+
+CREATE TABLE IF NOT EXISTS "admissions" (
+  "id" serial PRIMARY KEY,
+  "patient_id" integer NOT NULL,
+  "professional_id" integer,
+  "reason" text NOT NULL,
+  "admitted_by" varchar(150) NOT NULL,
+  "admitted_at" timestamp NOT NULL DEFAULT NOW(),
+  "discharged_at" timestamp,
+  "discharged_by" varchar(150),
+  "discharge_summary" text,
+  CHECK ("discharged_at" IS NULL OR "discharged_at" >= "admitted_at")
+);
+
+ALTER TABLE IF EXISTS "admissions" ADD FOREIGN KEY ("patient_id") REFERENCES "patients" ("id");
+
+ALTER TABLE IF EXISTS "admissions" ADD FOREIGN KEY ("professional_id") REFERENCES "professionals" ("id");
+
+-- A patient is in at most one admission at a time.
+CREATE UNIQUE INDEX IF NOT EXISTS "admissions_open_patient_key" ON "admissions" ("patient_id")
+  WHERE "discharged_at" IS NULL;
+
+CREATE TABLE IF NOT EXISTS "bed_occupancies" (
+  "id" serial PRIMARY KEY,
+  "admission_id" integer NOT NULL,
+  "bed_id" integer NOT NULL,
+  "started_by" varchar(150) NOT NULL,
+  "started_at" timestamp NOT NULL,
+  "ended_at" timestamp,
+  CHECK ("ended_at" IS NULL OR "ended_at" >= "started_at"),
+  -- No bed ever holds two patients at once, past or present.
+  CONSTRAINT "bed_occupancies_no_overlap"
+    EXCLUDE USING gist ("bed_id" WITH =, tsrange("started_at", "ended_at") WITH &&)
+);
+
+ALTER TABLE IF EXISTS "bed_occupancies" ADD FOREIGN KEY ("admission_id") REFERENCES "admissions" ("id");
+
+ALTER TABLE IF EXISTS "bed_occupancies" ADD FOREIGN KEY ("bed_id") REFERENCES "beds" ("id");
+
+-- An admission is in at most one bed at a time.
+CREATE UNIQUE INDEX IF NOT EXISTS "bed_occupancies_open_admission_key"
+  ON "bed_occupancies" ("admission_id") WHERE "ended_at" IS NULL;
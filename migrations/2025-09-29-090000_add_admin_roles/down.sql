@@ -0,0 +1 @@
+ALTER TABLE IF EXISTS "admins" DROP COLUMN IF EXISTS "role";
@@ -0,0 +1,5 @@
+ALTER TABLE IF EXISTS "admins" ADD COLUMN "role" varchar(20) NOT NULL DEFAULT 'admin'
+  CHECK ("role" IN ('admin', 'physician', 'nurse', 'receptionist', 'auditor'));
+
+-- Existing accounts keep full access; new ones must be given a role explicitly.
+ALTER TABLE IF EXISTS "admins" ALTER COLUMN "role" DROP DEFAULT;
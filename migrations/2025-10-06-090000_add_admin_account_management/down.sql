@@ -0,0 +1,5 @@
+ALTER TABLE IF EXISTS "admins" DROP COLUMN IF EXISTS "password_changed_at";
+
+ALTER TABLE IF EXISTS "admins" DROP COLUMN IF EXISTS "must_change_password";
+
+ALTER TABLE IF EXISTS "admins" DROP COLUMN IF EXISTS "active";
@@ -0,0 +1,8 @@
+ALTER TABLE IF EXISTS "admins" ADD COLUMN "active" boolean NOT NULL DEFAULT TRUE;
+
+ALTER TABLE IF EXISTS "admins" ADD COLUMN "must_change_password" boolean NOT NULL DEFAULT FALSE;
+
+ALTER TABLE IF EXISTS "admins" ADD COLUMN "password_changed_at" timestamp NOT NULL DEFAULT NOW();
+
+-- The seeded account ships with a well-known password.
+UPDATE "admins" SET "must_change_password" = TRUE WHERE "email" = 'admin@email.com';
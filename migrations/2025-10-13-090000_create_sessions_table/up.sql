@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS "sessions" (
+  "id" serial PRIMARY KEY,
+  "admin_id" integer NOT NULL,
+  -- SHA-256 of the current refresh token secret; the secret itself is
+  -- never stored.
+  "refresh_token_hash" char(64) NOT NULL UNIQUE,
+  "created_at" timestamp NOT NULL,
+  "refreshed_at" timestamp NOT NULL,
+  "expires_at" timestamp NOT NULL,
+  "revoked_at" timestamp
+);
+
+ALTER TABLE IF EXISTS "sessions" ADD FOREIGN KEY ("admin_id") REFERENCES "admins" ("id") ON DELETE CASCADE;
+
+CREATE INDEX IF NOT EXISTS "sessions_admin_id_idx" ON "sessions" ("admin_id");
@@ -0,0 +1,16 @@
+-- One row per login attempt, deleted again when it succeeds. What remains
+-- is counted over a short window to slow down and lock out guessing, per
+-- e-mail and per client address; e-mails that match no account are tracked
+-- too.
+CREATE TABLE IF NOT EXISTS "failed_logins" (
+  "id" serial PRIMARY KEY,
+  "email" varchar(150) NOT NULL,
+  "ip_address" varchar(45) NOT NULL,
+  "attempted_at" timestamp NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS "failed_logins_email_idx" ON "failed_logins" ("email", "attempted_at");
+
+CREATE INDEX IF NOT EXISTS "failed_logins_ip_address_idx" ON "failed_logins" ("ip_address", "attempted_at");
+
+CREATE INDEX IF NOT EXISTS "failed_logins_attempted_at_idx" ON "failed_logins" ("attempted_at");
@@ -0,0 +1,3 @@
+DROP VIEW IF EXISTS "patients_with_invalid_cpf";
+
+DROP FUNCTION IF EXISTS "cpf_is_valid"(varchar);
@@ -0,0 +1,44 @@
+-- CPFs stored before check-digit validation may be invalid. They are kept
+-- as they are; this function and view let them be found and fixed.
+CREATE OR REPLACE FUNCTION "cpf_is_valid"("value" varchar) RETURNS boolean AS $$
+DECLARE
+  "digits" int[];
+  "total" int;
+  "check" int;
+BEGIN
+  IF "value" IS NULL OR "value" !~ '^[0-9]{11}$' OR "value" ~ '^(.)\1{10}$' THEN
+    RETURN false;
+  END IF;
+
+  "digits" := string_to_array("value", NULL)::int[];
+
+  FOR "position" IN 10..11 LOOP
+    "total" := 0;
+    FOR "i" IN 1..("position" - 1) LOOP
+      "total" := "total" + "digits"["i"] * ("position" + 1 - "i");
+    END LOOP;
+    "check" := ("total" * 10) % 11 % 10;
+    IF "check" <> "digits"["position"] THEN
+      RETURN false;
+    END IF;
+  END LOOP;
+
+  RETURN true;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
+
+CREATE OR REPLACE VIEW "patients_with_invalid_cpf" AS
+  SELECT "id", "name", "cpf"
+  FROM "patients"
+  WHERE NOT "cpf_is_valid"("cpf");
+
+DO $$
+DECLARE
+  "invalid" int;
+BEGIN
+  SELECT count(*) INTO "invalid" FROM "patients_with_invalid_cpf";
+  IF "invalid" > 0 THEN
+    RAISE NOTICE '% patient(s) have an invalid CPF; see the patients_with_invalid_cpf view', "invalid";
+  END IF;
+END;
+$$;
@@ -0,0 +1,9 @@
+-- Folding the council back into the number keeps licenses from different
+-- councils apart under the old constraint.
+ALTER TABLE IF EXISTS "professionals" DROP CONSTRAINT IF EXISTS "professionals_council_license_number_council_state_key";
+
+UPDATE "professionals" SET "license_number" = "council" || ' ' || "license_number";
+
+ALTER TABLE IF EXISTS "professionals" DROP COLUMN IF EXISTS "council";
+
+ALTER TABLE IF EXISTS "professionals" ADD CONSTRAINT "professionals_license_number_council_state_key" UNIQUE ("license_number", "council_state");
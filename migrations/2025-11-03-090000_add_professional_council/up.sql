@@ -0,0 +1,23 @@
+-- Each council keeps its own numbering, so the license is only unique
+-- together with the council. Existing numbers carry the council as a
+-- prefix, as in "CRM 123456"; it is split out into its own column.
+DO $$
+BEGIN
+  IF EXISTS (SELECT 1 FROM "professionals" WHERE "license_number" !~ '^[A-Za-z]+[ /-]*[0-9]') THEN
+    RAISE EXCEPTION 'cannot split the council out: some license numbers do not start with it';
+  END IF;
+END;
+$$;
+
+ALTER TABLE IF EXISTS "professionals" ADD COLUMN "council" varchar(10);
+
+ALTER TABLE IF EXISTS "professionals" DROP CONSTRAINT IF EXISTS "professionals_license_number_council_state_key";
+
+UPDATE "professionals"
+SET
+  "council" = upper(substring("license_number" FROM '^[A-Za-z]+')),
+  "license_number" = regexp_replace("license_number", '^[A-Za-z]+[ /-]*', '');
+
+ALTER TABLE IF EXISTS "professionals" ALTER COLUMN "council" SET NOT NULL;
+
+ALTER TABLE IF EXISTS "professionals" ADD CONSTRAINT "professionals_council_license_number_council_state_key" UNIQUE ("council", "license_number", "council_state");
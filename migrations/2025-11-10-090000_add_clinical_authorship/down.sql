@@ -0,0 +1,3 @@
+ALTER TABLE IF EXISTS "encounters" DROP COLUMN IF EXISTS "author_id";
+
+ALTER TABLE IF EXISTS "admins" DROP COLUMN IF EXISTS "professional_id";
@@ -0,0 +1,21 @@
+-- A staff account may act as one professional, whose name and license
+-- clinical records are then signed with.
+ALTER TABLE IF EXISTS "admins" ADD COLUMN "professional_id" integer;
+
+ALTER TABLE IF EXISTS "admins" ADD FOREIGN KEY ("professional_id") REFERENCES "professionals" ("id");
+
+ALTER TABLE IF EXISTS "admins" ADD CONSTRAINT "admins_professional_id_key" UNIQUE ("professional_id");
+
+-- The clinician who wrote a note, not necessarily the one the appointment
+-- was booked with. Notes written so far can only be credited to the latter.
+ALTER TABLE IF EXISTS "encounters" ADD COLUMN "author_id" integer;
+
+ALTER TABLE IF EXISTS "encounters" DISABLE TRIGGER "encounters_append_only";
+
+UPDATE "encounters" SET "author_id" = "professional_id";
+
+ALTER TABLE IF EXISTS "encounters" ENABLE TRIGGER "encounters_append_only";
+
+ALTER TABLE IF EXISTS "encounters" ALTER COLUMN "author_id" SET NOT NULL;
+
+ALTER TABLE IF EXISTS "encounters" ADD FOREIGN KEY ("author_id") REFERENCES "professionals" ("id");
@@ -0,0 +1,3 @@
+-- SHA-256 of the refresh token secret the current one replaced. Only a
+-- token matching it proves a leak; any other mismatch is a guess.
+ALTER TABLE IF EXISTS "sessions" ADD COLUMN "previous_refresh_token_hash" char(64);
@@ -0,0 +1,9 @@
+CREATE OR REPLACE FUNCTION reject_encounter_changes() RETURNS trigger AS $$
+BEGIN
+  IF TG_OP = 'DELETE' OR to_jsonb(NEW) - 'patient_id' IS DISTINCT FROM to_jsonb(OLD) - 'patient_id' THEN
+    RAISE EXCEPTION 'encounters are append-only';
+  END IF;
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
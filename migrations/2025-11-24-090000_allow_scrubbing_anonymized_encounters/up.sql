@@ -0,0 +1,20 @@
+-- Besides the patient, the free text of an anonymized patient's notes may
+-- change, so anonymization can scrub it. Nothing may ever be deleted.
+CREATE OR REPLACE FUNCTION reject_encounter_changes() RETURNS trigger AS $$
+DECLARE
+  free_text text[] := ARRAY['chief_complaint', 'history', 'physical_exam', 'assessment', 'plan', 'amendment_reason'];
+BEGIN
+  IF TG_OP = 'DELETE' THEN
+    RAISE EXCEPTION 'encounters are append-only';
+  END IF;
+
+  IF to_jsonb(NEW) - 'patient_id' IS DISTINCT FROM to_jsonb(OLD) - 'patient_id' AND (
+    to_jsonb(NEW) - free_text IS DISTINCT FROM to_jsonb(OLD) - free_text
+    OR NOT EXISTS (SELECT 1 FROM "patients" WHERE "id" = NEW."patient_id" AND "anonymized_at" IS NOT NULL)
+  ) THEN
+    RAISE EXCEPTION 'encounters are append-only';
+  END IF;
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
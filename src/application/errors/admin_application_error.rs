@@ -1,17 +1,36 @@
 use std::fmt;
 
-use crate::domain::errors::repository_error::RepositoryError;
+use crate::domain::errors::{
+    admin_entity_error::AdminEntityError, repository_error::RepositoryError,
+};
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum AdminApplicationError {
+    Conflict(String),
+    Constraint(String),
     Unexpected(String),
     NotFound(String),
+    NotFoundById(i32),
     LoginFailed(String),
+    InvalidCredentials,
+    TooManyAttempts(i64),
+    InvalidRefreshToken,
+    IncorrectPassword,
+    SelfDeactivation,
 }
 
 impl fmt::Display for AdminApplicationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            AdminApplicationError::Conflict(msg) => {
+                write!(
+                    f,
+                    "The following conflict occurred when writing a user: {msg}"
+                )
+            }
+            AdminApplicationError::Constraint(msg) => {
+                write!(f, "{msg}")
+            }
             AdminApplicationError::Unexpected(msg) => {
                 write!(f, "An unexpected error occurred: {msg}")
             }
@@ -21,9 +40,30 @@ impl fmt::Display for AdminApplicationError {
                     "An admin with the following email was not found: {email}"
                 )
             }
+            AdminApplicationError::NotFoundById(id) => {
+                write!(f, "A user with the following ID was not found: {id}")
+            }
             AdminApplicationError::LoginFailed(msg) => {
                 write!(f, "{msg}")
             }
+            AdminApplicationError::InvalidCredentials => {
+                write!(f, "The e-mail or password is invalid")
+            }
+            AdminApplicationError::TooManyAttempts(retry_after) => {
+                write!(
+                    f,
+                    "Too many failed logins, try again in {retry_after} second(s)"
+                )
+            }
+            AdminApplicationError::InvalidRefreshToken => {
+                write!(f, "The refresh token is invalid, expired or revoked")
+            }
+            AdminApplicationError::IncorrectPassword => {
+                write!(f, "The current password is incorrect")
+            }
+            AdminApplicationError::SelfDeactivation => {
+                write!(f, "A user cannot deactivate their own account")
+            }
         }
     }
 }
@@ -34,6 +74,51 @@ impl From<RepositoryError> for AdminApplicationError {
     fn from(value: RepositoryError) -> Self {
         match value {
             RepositoryError::DatabaseError(msg) => AdminApplicationError::Unexpected(msg),
+            RepositoryError::Conflict(msg) => AdminApplicationError::Conflict(msg),
         }
     }
 }
+
+impl From<AdminEntityError> for AdminApplicationError {
+    fn from(value: AdminEntityError) -> Self {
+        AdminApplicationError::Constraint(value.to_string())
+    }
+}
+
+impl From<bcrypt::BcryptError> for AdminApplicationError {
+    fn from(value: bcrypt::BcryptError) -> Self {
+        AdminApplicationError::Unexpected(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::errors::{
+        admin_entity_error::AdminEntityError, repository_error::RepositoryError,
+    };
+
+    use super::AdminApplicationError;
+
+    #[test]
+    fn from_repository_conflict_error() {
+        let err: AdminApplicationError =
+            RepositoryError::Conflict("duplicate key".to_string()).into();
+
+        assert_eq!(
+            err,
+            AdminApplicationError::Conflict("duplicate key".to_string())
+        );
+    }
+
+    #[test]
+    fn from_admin_entity_error() {
+        let err: AdminApplicationError = AdminEntityError::PasswordReused.into();
+
+        assert_eq!(
+            err,
+            AdminApplicationError::Constraint(
+                "The new password must differ from the current one".to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt;
+
+use crate::domain::errors::{
+    admission_entity_error::AdmissionEntityError, repository_error::RepositoryError,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum AdmissionApplicationError {
+    Conflict(String),
+    Constraint(String),
+    Unexpected(String),
+    NotFound(i32),
+    PatientNotFound(String),
+    ProfessionalNotFound(i32),
+    WardNotFound(i32),
+    RoomNotFound(i32),
+    BedNotFound(i32),
+    AlreadyAdmitted(i32),
+}
+
+impl fmt::Display for AdmissionApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdmissionApplicationError::Conflict(msg) => {
+                write!(
+                    f,
+                    "The following conflict occurred when writing an admission: {msg}"
+                )
+            }
+            AdmissionApplicationError::Constraint(msg) => {
+                write!(f, "{msg}")
+            }
+            AdmissionApplicationError::Unexpected(msg) => {
+                write!(f, "An unexpected error occurred: {msg}")
+            }
+            AdmissionApplicationError::NotFound(id) => {
+                write!(f, "An admission with the following ID was not found: {id}")
+            }
+            AdmissionApplicationError::PatientNotFound(cpf) => {
+                write!(f, "A patient with the following CPF was not found: {cpf}")
+            }
+            AdmissionApplicationError::ProfessionalNotFound(id) => {
+                write!(
+                    f,
+                    "An active professional with the following ID was not found: {id}"
+                )
+            }
+            AdmissionApplicationError::WardNotFound(id) => {
+                write!(f, "A ward with the following ID was not found: {id}")
+            }
+            AdmissionApplicationError::RoomNotFound(id) => {
+                write!(f, "A room with the following ID was not found: {id}")
+            }
+            AdmissionApplicationError::BedNotFound(id) => {
+                write!(f, "A bed with the following ID was not found: {id}")
+            }
+            AdmissionApplicationError::AlreadyAdmitted(admission_id) => {
+                write!(
+                    f,
+                    "The patient is still in the admission {admission_id}, which must be discharged first"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AdmissionApplicationError {}
+
+impl From<RepositoryError> for AdmissionApplicationError {
+    fn from(value: RepositoryError) -> Self {
+        match value {
+            RepositoryError::DatabaseError(msg) => AdmissionApplicationError::Unexpected(msg),
+            RepositoryError::Conflict(msg) => AdmissionApplicationError::Conflict(msg),
+        }
+    }
+}
+
+impl From<AdmissionEntityError> for AdmissionApplicationError {
+    fn from(value: AdmissionEntityError) -> Self {
+        match value {
+            AdmissionEntityError::InvalidId(_) => {
+                AdmissionApplicationError::Unexpected(value.to_string())
+            }
+            err => AdmissionApplicationError::Constraint(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::errors::admission_application_error::AdmissionApplicationError,
+        domain::errors::admission_entity_error::AdmissionEntityError,
+    };
+
+    #[test]
+    fn from_admission_entity_error() {
+        let err: AdmissionApplicationError = AdmissionEntityError::SameBed(4).into();
+
+        assert_eq!(
+            err,
+            AdmissionApplicationError::Constraint(
+                "The patient is already in the bed 4".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn display_already_admitted() {
+        let err = AdmissionApplicationError::AlreadyAdmitted(9);
+
+        assert_eq!(
+            err.to_string(),
+            "The patient is still in the admission 9, which must be discharged first"
+        );
+    }
+}
@@ -9,7 +9,9 @@ pub enum AppointmentApplicationError {
     Constraint(String),
     Unexpected(String),
     PatientNotFound(String),
+    ProfessionalNotFound(i32),
     NotFound(String),
+    UnavailableSlot(String),
 }
 
 impl fmt::Display for AppointmentApplicationError {
@@ -24,9 +26,18 @@ impl fmt::Display for AppointmentApplicationError {
             AppointmentApplicationError::PatientNotFound(cpf) => {
                 write!(f, "A patient with the following CPF was not found: {cpf}")
             }
+            AppointmentApplicationError::ProfessionalNotFound(professional_id) => {
+                write!(
+                    f,
+                    "A professional with the following ID was not found: {professional_id}"
+                )
+            }
             AppointmentApplicationError::NotFound(msg) => {
                 write!(f, "{msg}")
             }
+            AppointmentApplicationError::UnavailableSlot(msg) => {
+                write!(f, "{msg}")
+            }
         }
     }
 }
@@ -37,15 +48,14 @@ impl From<RepositoryError> for AppointmentApplicationError {
     fn from(value: RepositoryError) -> Self {
         match value {
             RepositoryError::DatabaseError(msg) => AppointmentApplicationError::Unexpected(msg),
+            RepositoryError::Conflict(msg) => AppointmentApplicationError::Constraint(msg),
         }
     }
 }
 
 impl From<AppointmentEntityError> for AppointmentApplicationError {
     fn from(value: AppointmentEntityError) -> Self {
-        match value {
-            err => AppointmentApplicationError::Constraint(err.to_string()),
-        }
+        AppointmentApplicationError::Constraint(value.to_string())
     }
 }
 
@@ -0,0 +1,63 @@
+use std::fmt;
+
+use crate::domain::errors::{
+    encounter_entity_error::EncounterEntityError, repository_error::RepositoryError,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum EncounterApplicationError {
+    Conflict(String),
+    Constraint(String),
+    Unexpected(String),
+    NotFound(i32),
+    AppointmentNotFound(i32),
+    PatientNotFound(String),
+}
+
+impl fmt::Display for EncounterApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncounterApplicationError::Conflict(msg) => {
+                write!(
+                    f,
+                    "The following conflict occurred when writing an encounter: {msg}"
+                )
+            }
+            EncounterApplicationError::Constraint(msg) => {
+                write!(f, "{msg}")
+            }
+            EncounterApplicationError::Unexpected(msg) => {
+                write!(f, "An unexpected error occurred: {msg}")
+            }
+            EncounterApplicationError::NotFound(id) => {
+                write!(f, "An encounter with the following ID was not found: {id}")
+            }
+            EncounterApplicationError::AppointmentNotFound(id) => {
+                write!(
+                    f,
+                    "An appointment with the following ID was not found: {id}"
+                )
+            }
+            EncounterApplicationError::PatientNotFound(cpf) => {
+                write!(f, "A patient with the following CPF was not found: {cpf}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncounterApplicationError {}
+
+impl From<RepositoryError> for EncounterApplicationError {
+    fn from(value: RepositoryError) -> Self {
+        match value {
+            RepositoryError::DatabaseError(msg) => EncounterApplicationError::Unexpected(msg),
+            RepositoryError::Conflict(msg) => EncounterApplicationError::Conflict(msg),
+        }
+    }
+}
+
+impl From<EncounterEntityError> for EncounterApplicationError {
+    fn from(value: EncounterEntityError) -> Self {
+        EncounterApplicationError::Constraint(value.to_string())
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+
+use crate::domain::errors::{
+    exam_order_entity_error::ExamOrderEntityError, repository_error::RepositoryError,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum ExamOrderApplicationError {
+    Conflict(String),
+    Constraint(String),
+    Unexpected(String),
+    NotFound(i32),
+    AppointmentNotFound(i32),
+    PatientNotFound(String),
+    ResultFileNotFound(i32),
+}
+
+impl fmt::Display for ExamOrderApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExamOrderApplicationError::Conflict(msg) => {
+                write!(
+                    f,
+                    "The following conflict occurred when writing an exam order: {msg}"
+                )
+            }
+            ExamOrderApplicationError::Constraint(msg) => {
+                write!(f, "{msg}")
+            }
+            ExamOrderApplicationError::Unexpected(msg) => {
+                write!(f, "An unexpected error occurred: {msg}")
+            }
+            ExamOrderApplicationError::NotFound(id) => {
+                write!(f, "An exam order with the following ID was not found: {id}")
+            }
+            ExamOrderApplicationError::AppointmentNotFound(id) => {
+                write!(
+                    f,
+                    "An appointment with the following ID was not found: {id}"
+                )
+            }
+            ExamOrderApplicationError::PatientNotFound(cpf) => {
+                write!(f, "A patient with the following CPF was not found: {cpf}")
+            }
+            ExamOrderApplicationError::ResultFileNotFound(id) => {
+                write!(
+                    f,
+                    "The exam order with the following ID has no result file: {id}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExamOrderApplicationError {}
+
+impl From<RepositoryError> for ExamOrderApplicationError {
+    fn from(value: RepositoryError) -> Self {
+        match value {
+            RepositoryError::DatabaseError(msg) => ExamOrderApplicationError::Unexpected(msg),
+            RepositoryError::Conflict(msg) => ExamOrderApplicationError::Conflict(msg),
+        }
+    }
+}
+
+impl From<ExamOrderEntityError> for ExamOrderApplicationError {
+    fn from(value: ExamOrderEntityError) -> Self {
+        ExamOrderApplicationError::Constraint(value.to_string())
+    }
+}
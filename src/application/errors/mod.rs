@@ -1,3 +1,8 @@
 pub mod admin_application_error;
+pub mod admission_application_error;
 pub mod appointment_application_error;
+pub mod encounter_application_error;
+pub mod exam_order_application_error;
 pub mod patient_application_error;
+pub mod prescription_application_error;
+pub mod professional_application_error;
@@ -1,12 +1,22 @@
 use std::fmt;
 
-use crate::domain::errors::repository_error::RepositoryError;
+use crate::domain::errors::{
+    patient_entity_error::PatientEntityError, repository_error::RepositoryError,
+    storage_error::StorageError,
+};
 
 #[derive(Debug, PartialEq)]
 pub enum PatientApplicationError {
     Conflict(String),
     Unexpected(String),
     NotFound(String),
+    NotFoundById(i32),
+    InvalidCpf(String),
+    Constraint(String),
+    ConsentNotFound(String),
+    AllergyNotFound(i32),
+    ProblemNotFound(i32),
+    DocumentNotFound(i32),
 }
 
 impl fmt::Display for PatientApplicationError {
@@ -24,6 +34,27 @@ impl fmt::Display for PatientApplicationError {
             PatientApplicationError::NotFound(cpf) => {
                 write!(f, "A patient with the following CPF was not found: {cpf}")
             }
+            PatientApplicationError::NotFoundById(id) => {
+                write!(f, "A patient with the following ID was not found: {id}")
+            }
+            PatientApplicationError::InvalidCpf(cpf) => {
+                write!(f, "The following CPF is invalid: {cpf}")
+            }
+            PatientApplicationError::Constraint(msg) => {
+                write!(f, "{msg}")
+            }
+            PatientApplicationError::ConsentNotFound(msg) => {
+                write!(f, "{msg}")
+            }
+            PatientApplicationError::AllergyNotFound(id) => {
+                write!(f, "An allergy with the following ID was not found: {id}")
+            }
+            PatientApplicationError::ProblemNotFound(id) => {
+                write!(f, "A problem with the following ID was not found: {id}")
+            }
+            PatientApplicationError::DocumentNotFound(id) => {
+                write!(f, "A document with the following ID was not found: {id}")
+            }
         }
     }
 }
@@ -34,6 +65,27 @@ impl From<RepositoryError> for PatientApplicationError {
     fn from(value: RepositoryError) -> Self {
         match value {
             RepositoryError::DatabaseError(msg) => PatientApplicationError::Unexpected(msg),
+            RepositoryError::Conflict(msg) => PatientApplicationError::Conflict(msg),
+        }
+    }
+}
+
+/// A file that a document row points to must exist, so any storage failure
+/// is unexpected from the caller's point of view.
+impl From<StorageError> for PatientApplicationError {
+    fn from(value: StorageError) -> Self {
+        PatientApplicationError::Unexpected(value.to_string())
+    }
+}
+
+impl From<PatientEntityError> for PatientApplicationError {
+    fn from(value: PatientEntityError) -> Self {
+        match value {
+            PatientEntityError::InvalidCpf(cpf) => PatientApplicationError::InvalidCpf(cpf),
+            PatientEntityError::InvalidId(_) => {
+                PatientApplicationError::Unexpected(value.to_string())
+            }
+            err => PatientApplicationError::Constraint(err.to_string()),
         }
     }
 }
@@ -42,7 +94,10 @@ impl From<RepositoryError> for PatientApplicationError {
 mod test {
     use crate::{
         application::errors::patient_application_error::PatientApplicationError,
-        domain::errors::repository_error::RepositoryError,
+        domain::errors::{
+            patient_entity_error::PatientEntityError, repository_error::RepositoryError,
+            storage_error::StorageError,
+        },
     };
 
     #[test]
@@ -78,6 +133,19 @@ mod test {
         );
     }
 
+    #[test]
+    fn patient_application_error_from_storage_error() {
+        let storage_err = StorageError::NotFound("7/20250915-abc".to_string());
+        let err: PatientApplicationError = storage_err.into();
+
+        assert_eq!(
+            err,
+            PatientApplicationError::Unexpected(
+                "No file is stored under the following key: 7/20250915-abc".to_string()
+            )
+        );
+    }
+
     #[test]
     fn patient_application_error_not_found_display() {
         let cpf = "12345678901";
@@ -89,4 +157,31 @@ mod test {
             "A patient with the following CPF was not found: ".to_owned() + cpf
         );
     }
+
+    #[test]
+    fn patient_application_error_from_patient_entity_invalid_cpf_error() {
+        let cpf = "12345678900";
+        let entity_err = PatientEntityError::InvalidCpf(cpf.to_string());
+        let err: PatientApplicationError = entity_err.into();
+
+        assert_eq!(err, PatientApplicationError::InvalidCpf(cpf.to_string()));
+        assert_eq!(
+            err.to_string(),
+            "The following CPF is invalid: ".to_owned() + cpf
+        );
+    }
+
+    #[test]
+    fn patient_application_error_from_patient_entity_invalid_cns_error() {
+        let cns = "100000000000008";
+        let entity_err = PatientEntityError::InvalidCns(cns.to_string());
+        let err: PatientApplicationError = entity_err.into();
+
+        assert_eq!(
+            err,
+            PatientApplicationError::Constraint(
+                "An invalid CNS was given for a patient: ".to_owned() + cns
+            )
+        );
+    }
 }
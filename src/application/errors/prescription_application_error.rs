@@ -0,0 +1,82 @@
+use std::fmt;
+
+use crate::domain::errors::{
+    prescription_entity_error::PrescriptionEntityError, repository_error::RepositoryError,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum PrescriptionApplicationError {
+    Conflict(String),
+    Constraint(String),
+    Unexpected(String),
+    NotFound(i32),
+    AppointmentNotFound(i32),
+    PatientNotFound(String),
+    ProfessionalNotFound(i32),
+    /// The drugs match severe allergies and no override reason was given.
+    BlockedByAllergies(Vec<String>),
+}
+
+impl fmt::Display for PrescriptionApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrescriptionApplicationError::Conflict(msg) => {
+                write!(
+                    f,
+                    "The following conflict occurred when writing a prescription: {msg}"
+                )
+            }
+            PrescriptionApplicationError::Constraint(msg) => {
+                write!(f, "{msg}")
+            }
+            PrescriptionApplicationError::Unexpected(msg) => {
+                write!(f, "An unexpected error occurred: {msg}")
+            }
+            PrescriptionApplicationError::NotFound(id) => {
+                write!(
+                    f,
+                    "A prescription with the following ID was not found: {id}"
+                )
+            }
+            PrescriptionApplicationError::AppointmentNotFound(id) => {
+                write!(
+                    f,
+                    "An appointment with the following ID was not found: {id}"
+                )
+            }
+            PrescriptionApplicationError::PatientNotFound(cpf) => {
+                write!(f, "A patient with the following CPF was not found: {cpf}")
+            }
+            PrescriptionApplicationError::ProfessionalNotFound(id) => {
+                write!(
+                    f,
+                    "An active professional with the following ID was not found: {id}"
+                )
+            }
+            PrescriptionApplicationError::BlockedByAllergies(substances) => {
+                write!(
+                    f,
+                    "The patient has severe allergies to: {}. State an allergy override reason to prescribe anyway",
+                    substances.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PrescriptionApplicationError {}
+
+impl From<RepositoryError> for PrescriptionApplicationError {
+    fn from(value: RepositoryError) -> Self {
+        match value {
+            RepositoryError::DatabaseError(msg) => PrescriptionApplicationError::Unexpected(msg),
+            RepositoryError::Conflict(msg) => PrescriptionApplicationError::Conflict(msg),
+        }
+    }
+}
+
+impl From<PrescriptionEntityError> for PrescriptionApplicationError {
+    fn from(value: PrescriptionEntityError) -> Self {
+        PrescriptionApplicationError::Constraint(value.to_string())
+    }
+}
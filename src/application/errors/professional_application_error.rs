@@ -0,0 +1,65 @@
+use std::fmt;
+
+use crate::domain::errors::{
+    professional_entity_error::ProfessionalEntityError, repository_error::RepositoryError,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum ProfessionalApplicationError {
+    Conflict(String),
+    Constraint(String),
+    Unexpected(String),
+    NotFound(i32),
+    ScheduleNotFound(String),
+}
+
+impl fmt::Display for ProfessionalApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfessionalApplicationError::Conflict(msg) => {
+                write!(
+                    f,
+                    "The following conflict occurred when writing a professional: {msg}"
+                )
+            }
+            ProfessionalApplicationError::Constraint(msg) => {
+                write!(f, "{msg}")
+            }
+            ProfessionalApplicationError::Unexpected(msg) => {
+                write!(f, "An unexpected error occurred: {msg}")
+            }
+            ProfessionalApplicationError::NotFound(id) => {
+                write!(
+                    f,
+                    "A professional with the following ID was not found: {id}"
+                )
+            }
+            ProfessionalApplicationError::ScheduleNotFound(msg) => {
+                write!(f, "{msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProfessionalApplicationError {}
+
+impl From<RepositoryError> for ProfessionalApplicationError {
+    fn from(value: RepositoryError) -> Self {
+        match value {
+            RepositoryError::DatabaseError(msg) => ProfessionalApplicationError::Unexpected(msg),
+            RepositoryError::Conflict(msg) => ProfessionalApplicationError::Conflict(msg),
+        }
+    }
+}
+
+impl From<ProfessionalEntityError> for ProfessionalApplicationError {
+    fn from(value: ProfessionalEntityError) -> Self {
+        ProfessionalApplicationError::Constraint(value.to_string())
+    }
+}
+
+impl From<chrono::ParseError> for ProfessionalApplicationError {
+    fn from(value: chrono::ParseError) -> Self {
+        ProfessionalApplicationError::Constraint(value.to_string())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::domain::{
+    entities::professional::Professional,
+    errors::repository_error::RepositoryError,
+    repositories::{
+        admin_repository::AdminRepository, professional_repository::ProfessionalRepository,
+    },
+};
+
+/// The active professional the signed-in account `email` acts as, if any.
+/// Clinical records are credited to it, never to a professional named in
+/// the request.
+pub async fn find_clinician<A: AdminRepository, R: ProfessionalRepository>(
+    admin_repo: &A,
+    professional_repo: &R,
+    email: &str,
+) -> Result<Option<Professional>, RepositoryError> {
+    let Some(professional_id) = admin_repo
+        .find_by_email(email.to_string())
+        .await?
+        .and_then(|admin| admin.professional_id)
+    else {
+        return Ok(None);
+    };
+
+    Ok(professional_repo
+        .find_by_id(professional_id)
+        .await?
+        .filter(|professional| professional.active))
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::domain::{
+        entities::{admin::Admin, professional::Professional},
+        repositories::{
+            admin_repository::MockAdminRepository,
+            professional_repository::MockProfessionalRepository,
+        },
+        value_objects::role::Role,
+    };
+
+    use super::find_clinician;
+
+    fn make_fake_admin(professional_id: Option<i32>) -> Admin {
+        Admin {
+            professional_id,
+            ..Admin::new(
+                "Remy Hadley".to_string(),
+                "hadley@sghss.com",
+                Role::Nurse,
+                "hash".to_string(),
+            )
+            .unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn find_clinician_unlinked_account() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin(None))));
+        mock_professional_repo.expect_find_by_id().times(0);
+
+        let clinician = find_clinician(
+            &mock_admin_repo,
+            &mock_professional_repo,
+            "hadley@sghss.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(clinician, None);
+    }
+
+    #[tokio::test]
+    async fn find_clinician_inactive_professional() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin(Some(13)))));
+        mock_professional_repo
+            .expect_find_by_id()
+            .with(eq(13))
+            .times(1)
+            .return_const(Ok(Some(
+                Professional::restore(
+                    13,
+                    "Remy Hadley".to_string(),
+                    "COREN".to_string(),
+                    "654321".to_string(),
+                    "SP".to_string(),
+                    vec!["Nursing".to_string()],
+                    false,
+                )
+                .unwrap(),
+            )));
+
+        let clinician = find_clinician(
+            &mock_admin_repo,
+            &mock_professional_repo,
+            "hadley@sghss.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(clinician, None);
+    }
+}
@@ -1,39 +1,66 @@
-use jsonwebtoken::{
-    DecodingKey, EncodingKey, Header, TokenData, Validation, decode, encode,
-    errors::Result as JwtResult,
-};
+use std::sync::OnceLock;
+
+use jsonwebtoken::{TokenData, errors::Result as JwtResult};
 use serde::{Deserialize, Serialize};
 
-const SECRET_KEY: &[u8] = b"secret"; // Should be stored in environment variable in a real project
+use crate::{
+    application::security::jwt::keys::JwtConfig,
+    domain::{entities::admin::Admin, value_objects::role::Role},
+};
+
+static CONFIG: OnceLock<JwtConfig> = OnceLock::new();
 
 #[derive(Deserialize, Serialize)]
 pub struct Claims {
     pub sub: String,
+    pub role: Role,
+    /// Only the password change is allowed until it is done.
+    #[serde(default)]
+    pub must_change_password: bool,
+    /// The session the token was issued under.
+    pub sid: i32,
+    pub iss: String,
+    pub aud: String,
     pub exp: usize,
 }
 
-pub fn create_jwt(email: String) -> Option<String> {
-    let expiration = chrono::Utc::now().checked_add_signed(chrono::Duration::hours(24))?;
+/// Installs the signing keys; called once at startup, before any token is
+/// issued or checked.
+pub fn init(config: JwtConfig) {
+    let _ = CONFIG.set(config);
+}
+
+#[cfg(not(test))]
+pub fn config() -> &'static JwtConfig {
+    CONFIG
+        .get()
+        .expect("the JWT keys must be loaded at startup")
+}
+
+#[cfg(test)]
+pub fn config() -> &'static JwtConfig {
+    CONFIG.get_or_init(JwtConfig::for_tests)
+}
+
+pub fn create_jwt(admin: &Admin, session_id: i32) -> Option<String> {
+    let config = config();
+    let expiration = chrono::Utc::now()
+        .checked_add_signed(chrono::Duration::minutes(config.access_token_ttl_minutes))?;
     let expiration = expiration.timestamp();
 
     let claims = Claims {
-        sub: email,
+        sub: admin.email.clone(),
+        role: admin.role,
+        must_change_password: admin.must_change_password,
+        sid: session_id,
+        iss: config.issuer.clone(),
+        aud: config.audience.clone(),
         exp: expiration as usize,
     };
 
-    encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(SECRET_KEY),
-    )
-    .map_err(|err| err.to_string())
-    .ok()
+    config.sign(&claims)
 }
 
 pub fn validate_jwt(token: String) -> JwtResult<TokenData<Claims>> {
-    decode::<Claims>(
-        &token,
-        &DecodingKey::from_secret(SECRET_KEY),
-        &Validation::default(),
-    )
+    config().verify::<Claims>(&token)
 }
@@ -0,0 +1,136 @@
+use chrono::{Duration, NaiveDateTime};
+
+/// How far back failures are counted. It is also how long a lockout lasts:
+/// once the last failure leaves the window the count drops back to zero.
+pub const FAILURE_WINDOW_MINUTES: i64 = 15;
+
+/// How many failures are tolerated before each new attempt has to wait,
+/// with the wait doubling on every further failure, and how many lock the
+/// e-mail or address out for the rest of the window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThrottlePolicy {
+    pub free_attempts: usize,
+    pub max_failures: usize,
+    pub max_delay_seconds: i64,
+}
+
+pub const ACCOUNT_THROTTLE: ThrottlePolicy = ThrottlePolicy {
+    free_attempts: 3,
+    max_failures: 10,
+    max_delay_seconds: 60,
+};
+
+/// Looser than per account, since many staff may share one address.
+pub const ADDRESS_THROTTLE: ThrottlePolicy = ThrottlePolicy {
+    free_attempts: 10,
+    max_failures: 50,
+    max_delay_seconds: 60,
+};
+
+impl ThrottlePolicy {
+    /// Seconds to wait before the next attempt is accepted, given the
+    /// failures inside the window.
+    pub fn retry_after(&self, failures: &[NaiveDateTime], now: NaiveDateTime) -> Option<i64> {
+        let last_failure = failures.iter().max()?;
+        let count = failures.len();
+
+        let wait = if count >= self.max_failures {
+            Duration::minutes(FAILURE_WINDOW_MINUTES)
+        } else if count >= self.free_attempts {
+            let doublings = (count - self.free_attempts).min(16) as u32;
+            Duration::seconds((1_i64 << doublings).min(self.max_delay_seconds))
+        } else {
+            return None;
+        };
+
+        let remaining = (*last_failure + wait - now).num_milliseconds();
+
+        (remaining > 0).then(|| (remaining + 999) / 1000)
+    }
+
+    /// Whether one more failure on top of `failures` crosses into a lockout.
+    pub fn locks_after(&self, failures: usize) -> bool {
+        failures < self.max_failures && failures + 1 >= self.max_failures
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+
+    use super::{ACCOUNT_THROTTLE, ADDRESS_THROTTLE, FAILURE_WINDOW_MINUTES};
+
+    fn now() -> NaiveDateTime {
+        chrono::Local::now().naive_utc()
+    }
+
+    fn failures(count: usize, last: NaiveDateTime) -> Vec<NaiveDateTime> {
+        (0..count)
+            .map(|i| last - Duration::seconds(i as i64))
+            .collect()
+    }
+
+    #[test]
+    fn retry_after_within_free_attempts() {
+        let now = now();
+
+        assert_eq!(ACCOUNT_THROTTLE.retry_after(&failures(2, now), now), None);
+    }
+
+    #[test]
+    fn retry_after_doubles() {
+        let now = now();
+
+        assert_eq!(
+            ACCOUNT_THROTTLE.retry_after(&failures(3, now), now),
+            Some(1)
+        );
+        assert_eq!(
+            ACCOUNT_THROTTLE.retry_after(&failures(5, now), now),
+            Some(4)
+        );
+        assert_eq!(
+            ACCOUNT_THROTTLE.retry_after(&failures(9, now), now),
+            Some(60)
+        );
+    }
+
+    #[test]
+    fn retry_after_delay_elapsed() {
+        let now = now();
+
+        assert_eq!(
+            ACCOUNT_THROTTLE.retry_after(&failures(5, now - Duration::seconds(5)), now),
+            None
+        );
+    }
+
+    #[test]
+    fn retry_after_locked_out() {
+        let now = now();
+
+        assert_eq!(
+            ACCOUNT_THROTTLE.retry_after(&failures(10, now - Duration::minutes(5)), now),
+            Some((FAILURE_WINDOW_MINUTES - 5) * 60)
+        );
+    }
+
+    #[test]
+    fn locks_after() {
+        assert!(!ACCOUNT_THROTTLE.locks_after(8));
+        assert!(ACCOUNT_THROTTLE.locks_after(9));
+        assert!(!ACCOUNT_THROTTLE.locks_after(10));
+    }
+
+    #[test]
+    fn locks_after_once_per_burst() {
+        // Every attempt of a burst sees a different count, and only one of
+        // them crosses the threshold.
+        assert_eq!(
+            (0..60)
+                .filter(|failures| ADDRESS_THROTTLE.locks_after(*failures))
+                .collect::<Vec<_>>(),
+            vec![49]
+        );
+    }
+}
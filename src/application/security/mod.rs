@@ -1 +1,5 @@
+pub mod clinician;
 pub mod jwt;
+pub mod login_throttle;
+pub mod password;
+pub mod refresh_token;
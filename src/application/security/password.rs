@@ -0,0 +1,33 @@
+use std::sync::OnceLock;
+
+use bcrypt::{BcryptResult, hash, verify};
+
+use crate::domain::value_objects::password::Password;
+
+#[cfg(not(test))]
+const HASH_COST: u32 = bcrypt::DEFAULT_COST;
+// The minimum cost keeps the tests fast.
+#[cfg(test)]
+const HASH_COST: u32 = 4;
+
+pub fn hash_password(password: &Password) -> BcryptResult<String> {
+    hash(password.as_str(), HASH_COST)
+}
+
+/// Whether `password` matches `password_hash`. A malformed hash never
+/// matches.
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    verify(password, password_hash).unwrap_or(false)
+}
+
+/// Does the work of a real check against a throwaway hash and never matches,
+/// so an unknown e-mail takes as long to reject as a wrong password.
+pub fn verify_unknown_password(password: &str) -> bool {
+    static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+
+    let dummy_hash = DUMMY_HASH
+        .get_or_init(|| hash("no account has this password", HASH_COST).unwrap_or_default());
+    let _ = verify_password(password, dummy_hash);
+
+    false
+}
@@ -0,0 +1,102 @@
+use std::fmt;
+
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    application::security::jwt::jwt::{config, create_jwt},
+    domain::entities::admin::Admin,
+};
+
+const SECRET_BYTES: usize = 32;
+
+/// An opaque refresh token, `<session id>.<secret>`. Only the SHA-256 of the
+/// secret is stored; the secret carries enough entropy for that to be safe
+/// without a slow hash.
+#[derive(Debug, PartialEq)]
+pub struct RefreshToken {
+    pub session_id: i32,
+    pub secret: String,
+}
+
+impl RefreshToken {
+    pub fn generate_secret() -> String {
+        let mut bytes = [0u8; SECRET_BYTES];
+        rand::rng().fill_bytes(&mut bytes);
+
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let (session_id, secret) = value.split_once('.')?;
+        let session_id = session_id.parse::<i32>().ok()?;
+
+        if secret.len() != SECRET_BYTES * 2 || !secret.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        Some(Self {
+            session_id,
+            secret: secret.to_string(),
+        })
+    }
+
+    pub fn hash(&self) -> String {
+        hash_secret(&self.secret)
+    }
+}
+
+impl fmt::Display for RefreshToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.session_id, self.secret)
+    }
+}
+
+pub fn hash_secret(secret: &str) -> String {
+    format!("{:x}", Sha256::digest(secret.as_bytes()))
+}
+
+/// What a login or a refresh hands back to the client.
+#[derive(Debug, PartialEq)]
+pub struct TokenPair {
+    pub access_token: String,
+    pub refresh_token: String,
+    /// Seconds until the access token expires.
+    pub expires_in: i64,
+}
+
+impl TokenPair {
+    /// An access token for `admin` under the session, alongside the
+    /// session's refresh token.
+    pub fn issue(admin: &Admin, session_id: i32, secret: String) -> Option<Self> {
+        Some(Self {
+            access_token: create_jwt(admin, session_id)?,
+            refresh_token: RefreshToken { session_id, secret }.to_string(),
+            expires_in: config().access_token_ttl_minutes * 60,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RefreshToken;
+
+    #[test]
+    fn generate_and_parse() {
+        let secret = RefreshToken::generate_secret();
+        let token = format!("42.{secret}");
+
+        let parsed = RefreshToken::parse(&token).unwrap();
+
+        assert_eq!(parsed.session_id, 42);
+        assert_eq!(parsed.to_string(), token);
+        assert_ne!(RefreshToken::generate_secret(), secret);
+    }
+
+    #[test]
+    fn parse_malformed() {
+        for value in ["", "42", "abc.0123", "42.not-hex", "42.0123"] {
+            assert_eq!(RefreshToken::parse(value), None);
+        }
+    }
+}
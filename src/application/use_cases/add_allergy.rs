@@ -0,0 +1,125 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::allergy::Allergy,
+        repositories::{
+            allergy_repository::AllergyRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+    presentation::dtos::allergy_dto::CreateAllergyDTO,
+};
+
+pub struct AddAllergyUseCase<T: PatientRepository, A: AllergyRepository> {
+    patient_repo: T,
+    allergy_repo: A,
+}
+
+impl<T: PatientRepository, A: AllergyRepository> AddAllergyUseCase<T, A> {
+    pub fn new(patient_repo: T, allergy_repo: A) -> Self {
+        Self {
+            patient_repo,
+            allergy_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        input: CreateAllergyDTO,
+    ) -> Result<Allergy, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let allergy = Allergy::new(patient_id, input)?;
+
+        Ok(self.allergy_repo.save(&allergy).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::add_allergy::AddAllergyUseCase,
+        },
+        domain::{
+            entities::{allergy::Allergy, patient::Patient},
+            repositories::{
+                allergy_repository::MockAllergyRepository,
+                patient_repository::MockPatientRepository,
+            },
+            value_objects::{allergy_severity::AllergySeverity, cpf::Cpf, id::ID},
+        },
+        presentation::dtos::allergy_dto::CreateAllergyDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_patient_not_found() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_allergy_repo = MockAllergyRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_allergy_repo.expect_save().times(0);
+
+        let sut = AddAllergyUseCase::new(mock_patient_repo, mock_allergy_repo);
+
+        let result = sut
+            .execute(Cpf::parse("12345678909").unwrap(), make_fake_input())
+            .await;
+
+        assert!(matches!(result, Err(PatientApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_allergy_repo = MockAllergyRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(
+                Patient::restore(7, "Andrew".to_string(), Cpf::parse("12345678909").unwrap())
+                    .unwrap(),
+            )));
+
+        mock_allergy_repo
+            .expect_save()
+            .withf(|allergy: &Allergy| allergy.patient_id == 7 && allergy.substance == "Dipyrone")
+            .times(1)
+            .returning(|allergy| {
+                let mut allergy = allergy.clone();
+                allergy.id = ID::Existing(2);
+                Ok(allergy)
+            });
+
+        let sut = AddAllergyUseCase::new(mock_patient_repo, mock_allergy_repo);
+
+        let result = sut
+            .execute(Cpf::parse("12345678909").unwrap(), make_fake_input())
+            .await
+            .unwrap();
+
+        assert_eq!(result.id, ID::Existing(2));
+    }
+
+    fn make_fake_input() -> CreateAllergyDTO {
+        CreateAllergyDTO {
+            substance: "Dipyrone".to_string(),
+            reaction: Some("Anaphylaxis".to_string()),
+            severity: AllergySeverity::Severe,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{entities::ward::Bed, repositories::ward_repository::WardRepository},
+    presentation::dtos::ward_dto::AddBedDTO,
+};
+
+pub struct AddBedUseCase<W: WardRepository> {
+    ward_repo: W,
+}
+
+impl<W: WardRepository> AddBedUseCase<W> {
+    pub fn new(ward_repo: W) -> Self {
+        Self { ward_repo }
+    }
+
+    pub async fn execute(&self, input: AddBedDTO) -> Result<Bed, AdmissionApplicationError> {
+        self.ward_repo
+            .find_room_by_id(input.room_id)
+            .await?
+            .ok_or(AdmissionApplicationError::RoomNotFound(input.room_id))?;
+
+        let bed = Bed::new(input)?;
+
+        Ok(self.ward_repo.save_bed(&bed).await?)
+    }
+}
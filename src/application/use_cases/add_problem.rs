@@ -0,0 +1,44 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::problem::Problem,
+        repositories::{
+            patient_repository::PatientRepository, problem_repository::ProblemRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+    presentation::dtos::problem_dto::CreateProblemDTO,
+};
+
+pub struct AddProblemUseCase<T: PatientRepository, R: ProblemRepository> {
+    patient_repo: T,
+    problem_repo: R,
+}
+
+impl<T: PatientRepository, R: ProblemRepository> AddProblemUseCase<T, R> {
+    pub fn new(patient_repo: T, problem_repo: R) -> Self {
+        Self {
+            patient_repo,
+            problem_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        input: CreateProblemDTO,
+    ) -> Result<Problem, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let problem = Problem::new(patient_id, input)?;
+
+        Ok(self.problem_repo.save(&problem).await?)
+    }
+}
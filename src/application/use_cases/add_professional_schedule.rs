@@ -0,0 +1,175 @@
+use chrono::NaiveTime;
+
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::{
+        entities::professional_schedule::ProfessionalSchedule,
+        repositories::{
+            professional_repository::ProfessionalRepository,
+            schedule_repository::ScheduleRepository,
+        },
+    },
+    presentation::dtos::schedule_dto::CreateProfessionalScheduleDTO,
+};
+
+pub struct AddProfessionalScheduleUseCase<T: ProfessionalRepository, S: ScheduleRepository> {
+    professional_repo: T,
+    schedule_repo: S,
+}
+
+impl<T: ProfessionalRepository, S: ScheduleRepository> AddProfessionalScheduleUseCase<T, S> {
+    pub fn new(professional_repo: T, schedule_repo: S) -> Self {
+        Self {
+            professional_repo,
+            schedule_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        professional_id: i32,
+        schedule: CreateProfessionalScheduleDTO,
+    ) -> Result<ProfessionalSchedule, ProfessionalApplicationError> {
+        if self
+            .professional_repo
+            .find_by_id(professional_id)
+            .await?
+            .is_none()
+        {
+            return Err(ProfessionalApplicationError::NotFound(professional_id));
+        }
+
+        let schedule = ProfessionalSchedule::new(
+            professional_id,
+            schedule.weekday,
+            schedule.starts_at.parse::<NaiveTime>()?,
+            schedule.ends_at.parse::<NaiveTime>()?,
+        )?;
+
+        let existing_schedules = self
+            .schedule_repo
+            .find_schedules_by_professional_id(professional_id)
+            .await?;
+
+        if existing_schedules.iter().any(|existing| {
+            existing.weekday == schedule.weekday
+                && existing.starts_at < schedule.ends_at
+                && schedule.starts_at < existing.ends_at
+        }) {
+            return Err(ProfessionalApplicationError::Conflict(format!(
+                "The schedule from {} to {} overlaps another schedule on weekday {}",
+                schedule.starts_at, schedule.ends_at, schedule.weekday
+            )));
+        }
+
+        self.schedule_repo
+            .save_schedule(&schedule)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveTime;
+
+    use crate::{
+        application::{
+            errors::professional_application_error::ProfessionalApplicationError,
+            use_cases::add_professional_schedule::AddProfessionalScheduleUseCase,
+        },
+        domain::{
+            entities::{professional::Professional, professional_schedule::ProfessionalSchedule},
+            repositories::{
+                professional_repository::MockProfessionalRepository,
+                schedule_repository::MockScheduleRepository,
+            },
+        },
+        presentation::dtos::schedule_dto::CreateProfessionalScheduleDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_overlapping_schedule_error() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut mock_schedule_repo = MockScheduleRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_professional())));
+
+        mock_schedule_repo
+            .expect_find_schedules_by_professional_id()
+            .times(1)
+            .return_const(Ok(vec![
+                ProfessionalSchedule::new(
+                    42,
+                    1,
+                    "08:00".parse::<NaiveTime>().unwrap(),
+                    "12:00".parse::<NaiveTime>().unwrap(),
+                )
+                .unwrap(),
+            ]));
+
+        mock_schedule_repo.expect_save_schedule().times(0);
+
+        let sut = AddProfessionalScheduleUseCase::new(mock_professional_repo, mock_schedule_repo);
+
+        let result = sut
+            .execute(
+                42,
+                CreateProfessionalScheduleDTO {
+                    weekday: 1,
+                    starts_at: "11:00".to_string(),
+                    ends_at: "14:00".to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ProfessionalApplicationError::Conflict(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_professional_not_found() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut mock_schedule_repo = MockScheduleRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_schedule_repo.expect_save_schedule().times(0);
+
+        let sut = AddProfessionalScheduleUseCase::new(mock_professional_repo, mock_schedule_repo);
+
+        let result = sut
+            .execute(
+                42,
+                CreateProfessionalScheduleDTO {
+                    weekday: 1,
+                    starts_at: "08:00".to_string(),
+                    ends_at: "12:00".to_string(),
+                },
+            )
+            .await;
+
+        assert_eq!(result, Err(ProfessionalApplicationError::NotFound(42)));
+    }
+
+    fn make_fake_professional() -> Professional {
+        Professional::restore(
+            42,
+            "Gregory House".to_string(),
+            "CRM".to_string(),
+            "123456".to_string(),
+            "SP".to_string(),
+            vec!["Nephrology".to_string()],
+            true,
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{entities::ward::Room, repositories::ward_repository::WardRepository},
+    presentation::dtos::ward_dto::AddRoomDTO,
+};
+
+pub struct AddRoomUseCase<W: WardRepository> {
+    ward_repo: W,
+}
+
+impl<W: WardRepository> AddRoomUseCase<W> {
+    pub fn new(ward_repo: W) -> Self {
+        Self { ward_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        ward_id: i32,
+        input: AddRoomDTO,
+    ) -> Result<Room, AdmissionApplicationError> {
+        self.ward_repo
+            .find_ward_by_id(ward_id)
+            .await?
+            .ok_or(AdmissionApplicationError::WardNotFound(ward_id))?;
+
+        let room = Room::new(ward_id, input)?;
+
+        Ok(self.ward_repo.save_room(&room).await?)
+    }
+}
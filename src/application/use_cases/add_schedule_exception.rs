@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::{
+        entities::schedule_exception::ScheduleException,
+        repositories::{
+            professional_repository::ProfessionalRepository,
+            schedule_repository::ScheduleRepository,
+        },
+    },
+    presentation::dtos::schedule_dto::CreateScheduleExceptionDTO,
+};
+
+pub struct AddScheduleExceptionUseCase<T: ProfessionalRepository, S: ScheduleRepository> {
+    professional_repo: T,
+    schedule_repo: S,
+}
+
+impl<T: ProfessionalRepository, S: ScheduleRepository> AddScheduleExceptionUseCase<T, S> {
+    pub fn new(professional_repo: T, schedule_repo: S) -> Self {
+        Self {
+            professional_repo,
+            schedule_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        professional_id: i32,
+        exception: CreateScheduleExceptionDTO,
+    ) -> Result<ScheduleException, ProfessionalApplicationError> {
+        if self
+            .professional_repo
+            .find_by_id(professional_id)
+            .await?
+            .is_none()
+        {
+            return Err(ProfessionalApplicationError::NotFound(professional_id));
+        }
+
+        let exception = ScheduleException::new(
+            professional_id,
+            exception.starts_at.parse::<NaiveDateTime>()?,
+            exception.ends_at.parse::<NaiveDateTime>()?,
+            exception.reason,
+        )?;
+
+        self.schedule_repo
+            .save_exception(&exception)
+            .await
+            .map_err(|err| err.into())
+    }
+}
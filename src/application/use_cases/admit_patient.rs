@@ -0,0 +1,217 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{
+        entities::admission::{Admission, BedOccupancy},
+        repositories::{
+            admission_repository::AdmissionRepository, patient_repository::PatientRepository,
+            professional_repository::ProfessionalRepository, ward_repository::WardRepository,
+        },
+    },
+    presentation::dtos::admission_dto::AdmitPatientDTO,
+};
+
+pub struct AdmitPatientUseCase<
+    T: PatientRepository,
+    R: ProfessionalRepository,
+    W: WardRepository,
+    A: AdmissionRepository,
+> {
+    patient_repo: T,
+    professional_repo: R,
+    ward_repo: W,
+    admission_repo: A,
+}
+
+impl<T: PatientRepository, R: ProfessionalRepository, W: WardRepository, A: AdmissionRepository>
+    AdmitPatientUseCase<T, R, W, A>
+{
+    pub fn new(patient_repo: T, professional_repo: R, ward_repo: W, admission_repo: A) -> Self {
+        Self {
+            patient_repo,
+            professional_repo,
+            ward_repo,
+            admission_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: AdmitPatientDTO,
+        admitted_by: String,
+    ) -> Result<(Admission, Vec<BedOccupancy>), AdmissionApplicationError> {
+        let cpf = input.patient_cpf.clone();
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(AdmissionApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id =
+            patient_id.ok_or(AdmissionApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        if let Some(professional_id) = input.professional_id {
+            self.professional_repo
+                .find_by_id(professional_id)
+                .await?
+                .filter(|professional| professional.active)
+                .ok_or(AdmissionApplicationError::ProfessionalNotFound(
+                    professional_id,
+                ))?;
+        }
+
+        if let Some((open_admission, _)) = self
+            .admission_repo
+            .find_by_patient_id(patient_id)
+            .await?
+            .into_iter()
+            .find(|(admission, _)| !admission.is_discharged())
+        {
+            let open_admission_id: Option<i32> = open_admission.id.into();
+
+            return Err(AdmissionApplicationError::AlreadyAdmitted(
+                open_admission_id.unwrap_or_default(),
+            ));
+        }
+
+        let bed = self
+            .ward_repo
+            .find_bed_by_id(input.bed_id)
+            .await?
+            .ok_or(AdmissionApplicationError::BedNotFound(input.bed_id))?;
+
+        let (admission, occupancy) = Admission::new(patient_id, &bed, input, admitted_by)?;
+
+        Ok(self.admission_repo.admit(&admission, &occupancy).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDateTime;
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::admission_application_error::AdmissionApplicationError,
+            use_cases::admit_patient::AdmitPatientUseCase,
+        },
+        domain::{
+            entities::{admission::Admission, patient::Patient, ward::Bed},
+            repositories::{
+                admission_repository::MockAdmissionRepository,
+                patient_repository::MockPatientRepository,
+                professional_repository::MockProfessionalRepository,
+                ward_repository::MockWardRepository,
+            },
+            value_objects::{bed_status::BedStatus, cpf::Cpf, id::ID},
+        },
+        presentation::dtos::admission_dto::AdmitPatientDTO,
+    };
+
+    fn make_patient_repo() -> MockPatientRepository {
+        let mut mock_patient_repo = MockPatientRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(
+                Patient::restore(7, "Andrew".to_string(), Cpf::parse("12345678909").unwrap())
+                    .unwrap(),
+            )));
+
+        mock_patient_repo
+    }
+
+    fn make_input() -> AdmitPatientDTO {
+        AdmitPatientDTO {
+            patient_cpf: Cpf::parse("12345678909").unwrap(),
+            bed_id: 4,
+            professional_id: None,
+            reason: "Pneumonia".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_already_admitted_error() {
+        let mut mock_admission_repo = MockAdmissionRepository::new();
+        let mut mock_ward_repo = MockWardRepository::new();
+
+        mock_admission_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![(
+                Admission {
+                    id: ID::Existing(9),
+                    patient_id: 7,
+                    professional_id: None,
+                    reason: "Fracture".to_string(),
+                    admitted_by: "nurse@example.com".to_string(),
+                    admitted_at: "2025-09-20T10:00:00".parse::<NaiveDateTime>().unwrap(),
+                    discharged_at: None,
+                    discharged_by: None,
+                    discharge_summary: None,
+                },
+                vec![],
+            )]));
+
+        mock_ward_repo.expect_find_bed_by_id().times(0);
+        mock_admission_repo.expect_admit().times(0);
+
+        let sut = AdmitPatientUseCase::new(
+            make_patient_repo(),
+            MockProfessionalRepository::new(),
+            mock_ward_repo,
+            mock_admission_repo,
+        );
+
+        let result = sut
+            .execute(make_input(), "nurse@example.com".to_string())
+            .await;
+
+        assert_eq!(result, Err(AdmissionApplicationError::AlreadyAdmitted(9)));
+    }
+
+    #[tokio::test]
+    async fn execute_bed_not_free_error() {
+        let mut mock_admission_repo = MockAdmissionRepository::new();
+        let mut mock_ward_repo = MockWardRepository::new();
+
+        mock_admission_repo
+            .expect_find_by_patient_id()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        mock_ward_repo
+            .expect_find_bed_by_id()
+            .with(eq(4))
+            .times(1)
+            .return_const(Ok(Some(Bed {
+                id: ID::Existing(4),
+                room_id: 2,
+                label: "A".to_string(),
+                status: BedStatus::Blocked,
+            })));
+
+        mock_admission_repo.expect_admit().times(0);
+
+        let sut = AdmitPatientUseCase::new(
+            make_patient_repo(),
+            MockProfessionalRepository::new(),
+            mock_ward_repo,
+            mock_admission_repo,
+        );
+
+        let result = sut
+            .execute(make_input(), "nurse@example.com".to_string())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(AdmissionApplicationError::Constraint(
+                "The bed 4 is blocked and cannot take a patient".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{
+    application::{
+        errors::encounter_application_error::EncounterApplicationError,
+        security::clinician::find_clinician,
+    },
+    domain::{
+        entities::encounter::Encounter,
+        repositories::{
+            admin_repository::AdminRepository, encounter_repository::EncounterRepository,
+            professional_repository::ProfessionalRepository,
+        },
+    },
+    presentation::dtos::encounter_dto::AmendEncounterDTO,
+};
+
+/// The amendment is credited to the professional the signed-in account acts
+/// as, who may differ from the author of the amended note.
+pub struct AmendEncounterUseCase<
+    E: EncounterRepository,
+    A: AdminRepository,
+    R: ProfessionalRepository,
+> {
+    encounter_repo: E,
+    admin_repo: A,
+    professional_repo: R,
+}
+
+impl<E: EncounterRepository, A: AdminRepository, R: ProfessionalRepository>
+    AmendEncounterUseCase<E, A, R>
+{
+    pub fn new(encounter_repo: E, admin_repo: A, professional_repo: R) -> Self {
+        Self {
+            encounter_repo,
+            admin_repo,
+            professional_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        input: AmendEncounterDTO,
+        recorded_by: String,
+    ) -> Result<Encounter, EncounterApplicationError> {
+        let author = find_clinician(&self.admin_repo, &self.professional_repo, &recorded_by)
+            .await?
+            .ok_or_else(|| {
+                EncounterApplicationError::Constraint(format!(
+                    "The account {recorded_by} is not linked to an active professional"
+                ))
+            })?;
+        let author_id: Option<i32> = author.id.into();
+
+        let encounter = self
+            .encounter_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(EncounterApplicationError::NotFound(id))?;
+
+        let amendment = encounter.amend(
+            input.sections,
+            input.amendment_reason,
+            author_id.unwrap_or_default(),
+            recorded_by,
+        )?;
+
+        Ok(self.encounter_repo.save(&amendment).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDateTime;
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::encounter_application_error::EncounterApplicationError,
+            use_cases::amend_encounter::AmendEncounterUseCase,
+        },
+        domain::{
+            entities::{admin::Admin, encounter::Encounter, professional::Professional},
+            repositories::{
+                admin_repository::MockAdminRepository,
+                encounter_repository::MockEncounterRepository,
+                professional_repository::MockProfessionalRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+        presentation::dtos::encounter_dto::{AmendEncounterDTO, EncounterSectionsDTO},
+    };
+
+    #[tokio::test]
+    async fn execute_not_found() {
+        let mut mock_encounter_repo = MockEncounterRepository::new();
+
+        mock_encounter_repo
+            .expect_find_by_id()
+            .with(eq(5))
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_encounter_repo.expect_save().times(0);
+
+        let sut = AmendEncounterUseCase::new(
+            mock_encounter_repo,
+            make_fake_admin_repo(Some(13)),
+            make_fake_professional_repo(),
+        );
+
+        let result = sut
+            .execute(5, make_fake_input(), "hadley@example.com".to_string())
+            .await;
+
+        assert_eq!(result, Err(EncounterApplicationError::NotFound(5)));
+    }
+
+    #[tokio::test]
+    async fn execute_unlinked_account_error() {
+        let mut mock_encounter_repo = MockEncounterRepository::new();
+
+        mock_encounter_repo.expect_find_by_id().times(0);
+        mock_encounter_repo.expect_save().times(0);
+
+        let sut = AmendEncounterUseCase::new(
+            mock_encounter_repo,
+            make_fake_admin_repo(None),
+            MockProfessionalRepository::new(),
+        );
+
+        let result = sut
+            .execute(5, make_fake_input(), "hadley@example.com".to_string())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(EncounterApplicationError::Constraint(
+                "The account hadley@example.com is not linked to an active professional"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_encounter_repo = MockEncounterRepository::new();
+
+        mock_encounter_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_encounter())));
+
+        mock_encounter_repo
+            .expect_save()
+            .withf(|encounter: &Encounter| {
+                encounter.id == ID::New
+                    && encounter.amends_id == Some(5)
+                    && encounter.amendment_reason == Some("Typo in the complaint".to_string())
+                    && encounter.professional_id == 42
+                    && encounter.author_id == 13
+            })
+            .times(1)
+            .returning(|encounter| Ok(encounter.clone()));
+
+        let sut = AmendEncounterUseCase::new(
+            mock_encounter_repo,
+            make_fake_admin_repo(Some(13)),
+            make_fake_professional_repo(),
+        );
+
+        let result = sut
+            .execute(5, make_fake_input(), "hadley@example.com".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(result.chief_complaint, "Lower back pain");
+    }
+
+    fn make_fake_encounter() -> Encounter {
+        Encounter {
+            id: ID::Existing(5),
+            appointment_id: 1,
+            patient_id: 7,
+            professional_id: 42,
+            chief_complaint: "Lower bak pain".to_string(),
+            history: None,
+            physical_exam: None,
+            assessment: None,
+            plan: None,
+            amends_id: None,
+            amendment_reason: None,
+            recorded_by: "admin@example.com".to_string(),
+            recorded_at: "2025-06-09T08:30:00".parse::<NaiveDateTime>().unwrap(),
+            author_id: 42,
+        }
+    }
+
+    fn make_fake_admin_repo(professional_id: Option<i32>) -> MockAdminRepository {
+        let mut mock_admin_repo = MockAdminRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .with(eq("hadley@example.com".to_string()))
+            .returning(move |email| {
+                Ok(Some(Admin {
+                    professional_id,
+                    ..Admin::new(
+                        "Remy Hadley".to_string(),
+                        &email,
+                        Role::Nurse,
+                        "hash".to_string(),
+                    )
+                    .unwrap()
+                }))
+            });
+
+        mock_admin_repo
+    }
+
+    fn make_fake_professional_repo() -> MockProfessionalRepository {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .with(eq(13))
+            .return_const(Ok(Some(
+                Professional::restore(
+                    13,
+                    "Remy Hadley".to_string(),
+                    "COREN".to_string(),
+                    "654321".to_string(),
+                    "SP".to_string(),
+                    vec!["Nursing".to_string()],
+                    true,
+                )
+                .unwrap(),
+            )));
+
+        mock_professional_repo
+    }
+
+    fn make_fake_input() -> AmendEncounterDTO {
+        AmendEncounterDTO {
+            sections: EncounterSectionsDTO {
+                chief_complaint: "Lower back pain".to_string(),
+                ..Default::default()
+            },
+            amendment_reason: "Typo in the complaint".to_string(),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use log::error;
+
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::audit_event::{AuditAction, AuditEvent},
+        repositories::{
+            audit_repository::AuditRepository, file_storage::FileStorage,
+            patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct AnonymizePatientUseCase<T: PatientRepository, A: AuditRepository, S: FileStorage> {
+    patient_repo: T,
+    audit_repo: A,
+    file_storage: S,
+}
+
+impl<T: PatientRepository, A: AuditRepository, S: FileStorage> AnonymizePatientUseCase<T, A, S> {
+    pub fn new(patient_repo: T, audit_repo: A, file_storage: S) -> Self {
+        Self {
+            patient_repo,
+            audit_repo,
+            file_storage,
+        }
+    }
+
+    /// Anonymizes the patient with `cpf`, even if already soft-deleted, and
+    /// records `actor` as responsible for it. The files of their documents
+    /// are removed once the rows are gone, as when deleting a document.
+    pub async fn execute(&self, cpf: Cpf, actor: String) -> Result<(), PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf_including_deleted(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let storage_keys = self.patient_repo.anonymize(patient_id).await?;
+
+        for storage_key in storage_keys {
+            if let Err(err) = self.file_storage.delete(&storage_key).await {
+                error!("Orphaned document file of an anonymized patient: {err}");
+            }
+        }
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                actor,
+                AuditAction::PatientAnonymized,
+                "patient",
+                Some(patient_id),
+                None,
+            ))
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::anonymize_patient::AnonymizePatientUseCase,
+        },
+        domain::{
+            entities::{audit_event::AuditEvent, patient::Patient},
+            errors::{repository_error::RepositoryError, storage_error::StorageError},
+            repositories::{
+                audit_repository::MockAuditRepository, file_storage::MockFileStorage,
+                patient_repository::MockPatientRepository,
+            },
+            value_objects::cpf::Cpf,
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_patient_not_found() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+        let cpf = Cpf::parse("12345678909").unwrap();
+
+        mock_patient_repo
+            .expect_find_by_cpf_including_deleted()
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_patient_repo.expect_anonymize().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = AnonymizePatientUseCase::new(
+            mock_patient_repo,
+            mock_audit_repo,
+            MockFileStorage::new(),
+        );
+
+        let result = sut
+            .execute(cpf.clone(), "admin@sghss.com".to_string())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(PatientApplicationError::NotFound(cpf.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_patient_repository_anonymize_error() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf_including_deleted()
+            .times(1)
+            .return_const(Ok(Some(make_fake_patient())));
+
+        mock_patient_repo
+            .expect_anonymize()
+            .times(1)
+            .return_const(Err(RepositoryError::DatabaseError(
+                "Fake Error".to_string(),
+            )));
+
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = AnonymizePatientUseCase::new(
+            mock_patient_repo,
+            mock_audit_repo,
+            MockFileStorage::new(),
+        );
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                "admin@sghss.com".to_string(),
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+        let mut mock_file_storage = MockFileStorage::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf_including_deleted()
+            .times(1)
+            .return_const(Ok(Some(make_fake_patient())));
+
+        mock_patient_repo
+            .expect_anonymize()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(vec![
+                "42/20250609083000000000-86edbaa24831badf".to_string(),
+                "42/20250610090000000000-0123456789abcdef".to_string(),
+            ]));
+
+        // A file that cannot be removed does not undo the anonymization.
+        mock_file_storage
+            .expect_delete()
+            .with(eq("42/20250609083000000000-86edbaa24831badf"))
+            .times(1)
+            .return_const(Err(StorageError::Io("Fake Error".to_string())));
+        mock_file_storage
+            .expect_delete()
+            .with(eq("42/20250610090000000000-0123456789abcdef"))
+            .times(1)
+            .return_const(Ok(()));
+
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.actor == "admin@sghss.com"
+                    && event.action == "patient_anonymized"
+                    && event.entity_id == Some(42)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut =
+            AnonymizePatientUseCase::new(mock_patient_repo, mock_audit_repo, mock_file_storage);
+
+        sut.execute(Cpf::parse("12345678909")?, "admin@sghss.com".to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    fn make_fake_patient() -> Patient {
+        Patient::restore(42, "Andrew".to_string(), Cpf::parse("12345678909").unwrap()).unwrap()
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    application::errors::exam_order_application_error::ExamOrderApplicationError,
+    domain::{
+        entities::exam_order::{ExamOrder, ExamResult},
+        repositories::exam_order_repository::ExamOrderRepository,
+    },
+};
+
+pub struct AttachExamResultFileUseCase<X: ExamOrderRepository> {
+    exam_order_repo: X,
+}
+
+impl<X: ExamOrderRepository> AttachExamResultFileUseCase<X> {
+    pub fn new(exam_order_repo: X) -> Self {
+        Self { exam_order_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        file_name: String,
+        content_type: String,
+        content: Vec<u8>,
+        actor: String,
+    ) -> Result<(ExamOrder, Vec<ExamResult>), ExamOrderApplicationError> {
+        let (mut exam_order, results) = self
+            .exam_order_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(ExamOrderApplicationError::NotFound(id))?;
+
+        exam_order.attach_result_file(file_name, content_type, content.len(), &actor)?;
+
+        let exam_order = self
+            .exam_order_repo
+            .save_result_file(&exam_order, content)
+            .await?;
+
+        Ok((exam_order, results))
+    }
+}
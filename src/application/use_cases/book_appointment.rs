@@ -1,26 +1,58 @@
-use chrono::NaiveDateTime;
+use chrono::{Duration, NaiveDateTime};
 
 use crate::{
     application::errors::appointment_application_error::AppointmentApplicationError,
     domain::{
         entities::appointment::Appointment,
         repositories::{
-            appointment_repository::AppointmentRepository, patient_repository::PatientRepository,
+            appointment_repository::AppointmentRepository, consent_repository::ConsentRepository,
+            patient_repository::PatientRepository, professional_repository::ProfessionalRepository,
+            schedule_repository::ScheduleRepository,
         },
+        services::availability::{
+            DEFAULT_SLOT_DURATION_MINUTES, MAX_SLOT_DURATION_MINUTES, MIN_SLOT_DURATION_MINUTES,
+            is_available, within_clinic_hours,
+        },
+        value_objects::{consent_purpose::ConsentPurpose, id::ID, time_slot::TimeSlot},
     },
     presentation::dtos::appointment_dto::BookAppointmentDTO,
 };
 
-pub struct BookAppointmentUseCase<T: AppointmentRepository, P: PatientRepository> {
+pub struct BookAppointmentUseCase<
+    T: AppointmentRepository,
+    P: PatientRepository,
+    R: ProfessionalRepository,
+    S: ScheduleRepository,
+    C: ConsentRepository,
+> {
     appointment_repository: T,
     patient_repository: P,
+    professional_repository: R,
+    schedule_repository: S,
+    consent_repository: C,
 }
 
-impl<T: AppointmentRepository, P: PatientRepository> BookAppointmentUseCase<T, P> {
-    pub fn new(appointment_repository: T, patient_repository: P) -> Self {
+impl<
+    T: AppointmentRepository,
+    P: PatientRepository,
+    R: ProfessionalRepository,
+    S: ScheduleRepository,
+    C: ConsentRepository,
+> BookAppointmentUseCase<T, P, R, S, C>
+{
+    pub fn new(
+        appointment_repository: T,
+        patient_repository: P,
+        professional_repository: R,
+        schedule_repository: S,
+        consent_repository: C,
+    ) -> Self {
         Self {
             appointment_repository,
             patient_repository,
+            professional_repository,
+            schedule_repository,
+            consent_repository,
         }
     }
 
@@ -35,7 +67,7 @@ impl<T: AppointmentRepository, P: PatientRepository> BookAppointmentUseCase<T, P
 
         if patient.is_none() {
             return Err(AppointmentApplicationError::PatientNotFound(
-                appointment.patient_cpf,
+                appointment.patient_cpf.to_string(),
             ));
         }
 
@@ -43,33 +75,538 @@ impl<T: AppointmentRepository, P: PatientRepository> BookAppointmentUseCase<T, P
 
         if !patient.id.is_existing() {
             return Err(AppointmentApplicationError::PatientNotFound(
-                appointment.patient_cpf,
+                appointment.patient_cpf.to_string(),
             ));
         }
 
         let patient_id: Option<i32> = patient.id.into();
         let patient_id = patient_id.unwrap_or(0);
 
-        let appointment = Appointment::new(
-            patient_id,
-            appointment.appointment_at.parse::<NaiveDateTime>()?,
-            appointment.specialty,
-            appointment.notes,
-        )?;
+        if appointment.telemedicine
+            && self
+                .consent_repository
+                .find_active_by_patient_id_and_purpose(patient_id, ConsentPurpose::Telemedicine)
+                .await?
+                .is_none()
+        {
+            return Err(AppointmentApplicationError::Constraint(format!(
+                "The patient with CPF: {} has not consented to telemedicine",
+                patient.cpf
+            )));
+        }
+
+        if let Some(professional_id) = appointment.professional_id {
+            let professional = self
+                .professional_repository
+                .find_by_id(professional_id)
+                .await?
+                .ok_or(AppointmentApplicationError::ProfessionalNotFound(
+                    professional_id,
+                ))?;
+
+            if !professional.active {
+                return Err(AppointmentApplicationError::Constraint(format!(
+                    "The professional with ID: {professional_id} is not active"
+                )));
+            }
+
+            if !professional.has_specialty(&appointment.specialty) {
+                return Err(AppointmentApplicationError::Constraint(format!(
+                    "The professional with ID: {professional_id} does not attend the specialty: {}",
+                    appointment.specialty
+                )));
+            }
+        }
+
+        let appointment = Appointment {
+            telemedicine: appointment.telemedicine,
+            ..Appointment::new(
+                patient_id,
+                appointment.professional_id,
+                appointment.appointment_at.parse::<NaiveDateTime>()?,
+                appointment_duration(
+                    appointment
+                        .duration_minutes
+                        .unwrap_or(DEFAULT_SLOT_DURATION_MINUTES),
+                )?,
+                appointment.specialty,
+                appointment.notes,
+            )?
+        };
 
         if self
             .appointment_repository
-            .exists_by_patient_id_and_appointment_at(patient_id, appointment.appointment_at)
+            .exists_overlapping_by_patient_id(
+                patient_id,
+                appointment.appointment_at,
+                appointment.ends_at,
+                None,
+            )
             .await?
         {
             return Err(AppointmentApplicationError::Constraint(format!(
-                "There's already an appointment for patient with CPF: {} at: {}",
-                patient.cpf, appointment.appointment_at
+                "There's already an appointment for patient with CPF: {} between: {} and: {}",
+                patient.cpf, appointment.appointment_at, appointment.ends_at
             )));
         }
 
+        ensure_slot_is_bookable(
+            &self.appointment_repository,
+            &self.schedule_repository,
+            appointment.professional_id,
+            &appointment.time_slot(),
+            None,
+        )
+        .await?;
+
         let appointment = self.appointment_repository.save(&appointment).await?;
 
         Ok(appointment)
     }
 }
+
+/// The length of an appointment, kept to the same bounds as bookable slots.
+pub(crate) fn appointment_duration(minutes: i64) -> Result<Duration, AppointmentApplicationError> {
+    if !(MIN_SLOT_DURATION_MINUTES..=MAX_SLOT_DURATION_MINUTES).contains(&minutes) {
+        return Err(AppointmentApplicationError::Constraint(format!(
+            "The appointment duration must be between {MIN_SLOT_DURATION_MINUTES} and {MAX_SLOT_DURATION_MINUTES} minutes"
+        )));
+    }
+
+    Ok(Duration::minutes(minutes))
+}
+
+/// Fails with `UnavailableSlot` unless `slot` can be booked: with the
+/// professional when there is one, otherwise inside the clinic's hours.
+pub(crate) async fn ensure_slot_is_bookable<T: AppointmentRepository, S: ScheduleRepository>(
+    appointment_repository: &T,
+    schedule_repository: &S,
+    professional_id: Option<i32>,
+    slot: &TimeSlot,
+    excluding_id: Option<i32>,
+) -> Result<(), AppointmentApplicationError> {
+    match professional_id {
+        Some(professional_id) => {
+            ensure_professional_is_available(
+                appointment_repository,
+                schedule_repository,
+                professional_id,
+                slot,
+                excluding_id,
+            )
+            .await
+        }
+        None if within_clinic_hours(slot) => Ok(()),
+        None => Err(AppointmentApplicationError::UnavailableSlot(format!(
+            "The clinic is closed from: {} to: {}",
+            slot.starts_at, slot.ends_at
+        ))),
+    }
+}
+
+/// Fails with `UnavailableSlot` unless `slot` fits the professional's schedule
+/// and does not overlap another of their appointments. `excluding_id` leaves
+/// an appointment out of the busy slots, so it can be moved over itself.
+async fn ensure_professional_is_available<T: AppointmentRepository, S: ScheduleRepository>(
+    appointment_repository: &T,
+    schedule_repository: &S,
+    professional_id: i32,
+    slot: &TimeSlot,
+    excluding_id: Option<i32>,
+) -> Result<(), AppointmentApplicationError> {
+    let schedules = schedule_repository
+        .find_schedules_by_professional_id(professional_id)
+        .await?;
+    let exceptions = schedule_repository
+        .find_exceptions_by_professional_id_between(professional_id, slot.starts_at, slot.ends_at)
+        .await?;
+    let busy: Vec<TimeSlot> = appointment_repository
+        .find_by_professional_id_between(professional_id, slot.starts_at, slot.ends_at)
+        .await?
+        .iter()
+        .filter(|appointment| {
+            excluding_id.is_none_or(|excluding_id| appointment.id != ID::Existing(excluding_id))
+        })
+        .map(|appointment| appointment.time_slot())
+        .collect();
+
+    if !is_available(&schedules, &exceptions, &busy, slot) {
+        return Err(AppointmentApplicationError::UnavailableSlot(format!(
+            "The professional with ID: {professional_id} has no available slot from: {} to: {}",
+            slot.starts_at, slot.ends_at
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveTime;
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::appointment_application_error::AppointmentApplicationError,
+            use_cases::book_appointment::BookAppointmentUseCase,
+        },
+        domain::{
+            entities::{
+                appointment::Appointment, consent::Consent, patient::Patient,
+                professional::Professional, professional_schedule::ProfessionalSchedule,
+            },
+            repositories::{
+                appointment_repository::MockAppointmentRepository,
+                consent_repository::MockConsentRepository,
+                patient_repository::MockPatientRepository,
+                professional_repository::MockProfessionalRepository,
+                schedule_repository::MockScheduleRepository,
+            },
+            value_objects::{
+                consent_channel::ConsentChannel, consent_purpose::ConsentPurpose, cpf::Cpf, id::ID,
+            },
+        },
+        presentation::dtos::appointment_dto::BookAppointmentDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_outside_schedule_error() {
+        let (mock_patient_repo, mock_professional_repo, mock_schedule_repo) = make_fake_repos();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_exists_overlapping_by_patient_id()
+            .times(1)
+            .return_const(Ok(false));
+
+        mock_appointment_repo
+            .expect_find_by_professional_id_between()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        mock_appointment_repo.expect_save().times(0);
+
+        let sut = BookAppointmentUseCase::new(
+            mock_appointment_repo,
+            mock_patient_repo,
+            mock_professional_repo,
+            mock_schedule_repo,
+            MockConsentRepository::new(),
+        );
+
+        // 2025-06-15 is a Sunday.
+        let result = sut.execute(make_fake_input("2025-06-15T03:00:00")).await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::UnavailableSlot(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_without_professional_outside_clinic_hours_error() {
+        let (mock_patient_repo, _, mock_schedule_repo) = make_fake_repos();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_exists_overlapping_by_patient_id()
+            .times(1)
+            .return_const(Ok(false));
+
+        mock_appointment_repo.expect_save().times(0);
+
+        let sut = BookAppointmentUseCase::new(
+            mock_appointment_repo,
+            mock_patient_repo,
+            MockProfessionalRepository::new(),
+            mock_schedule_repo,
+            MockConsentRepository::new(),
+        );
+
+        let mut input = make_fake_input("2025-06-15T03:00:00");
+        input.professional_id = None;
+
+        let result = sut.execute(input).await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::UnavailableSlot(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_without_professional_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let (mock_patient_repo, _, mock_schedule_repo) = make_fake_repos();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_exists_overlapping_by_patient_id()
+            .times(1)
+            .return_const(Ok(false));
+
+        mock_appointment_repo
+            .expect_save()
+            .times(1)
+            .returning(|appointment: &Appointment| Ok(appointment.clone()));
+
+        let sut = BookAppointmentUseCase::new(
+            mock_appointment_repo,
+            mock_patient_repo,
+            MockProfessionalRepository::new(),
+            mock_schedule_repo,
+            MockConsentRepository::new(),
+        );
+
+        let mut input = make_fake_input("2025-06-14T18:30:00");
+        input.professional_id = None;
+
+        let result = sut.execute(input).await?;
+
+        assert_eq!(result.professional_id, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_duration_out_of_range_error() {
+        let (mock_patient_repo, mock_professional_repo, mock_schedule_repo) = make_fake_repos();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo.expect_save().times(0);
+
+        let sut = BookAppointmentUseCase::new(
+            mock_appointment_repo,
+            mock_patient_repo,
+            mock_professional_repo,
+            mock_schedule_repo,
+            MockConsentRepository::new(),
+        );
+
+        for duration_minutes in [0, 481, i64::MAX] {
+            let mut input = make_fake_input("2025-06-09T08:30:00");
+            input.duration_minutes = Some(duration_minutes);
+
+            let result = sut.execute(input).await;
+
+            assert!(matches!(
+                result,
+                Err(AppointmentApplicationError::Constraint(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_patient_overlap_error() {
+        let (mock_patient_repo, mock_professional_repo, mock_schedule_repo) = make_fake_repos();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_exists_overlapping_by_patient_id()
+            .times(1)
+            .return_const(Ok(true));
+
+        mock_appointment_repo.expect_save().times(0);
+
+        let sut = BookAppointmentUseCase::new(
+            mock_appointment_repo,
+            mock_patient_repo,
+            mock_professional_repo,
+            mock_schedule_repo,
+            MockConsentRepository::new(),
+        );
+
+        let result = sut.execute(make_fake_input("2025-06-09T10:05:00")).await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let (mock_patient_repo, mock_professional_repo, mock_schedule_repo) = make_fake_repos();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_exists_overlapping_by_patient_id()
+            .times(1)
+            .return_const(Ok(false));
+
+        mock_appointment_repo
+            .expect_find_by_professional_id_between()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        mock_appointment_repo
+            .expect_save()
+            .times(1)
+            .returning(|appointment: &Appointment| {
+                let mut saved = appointment.clone();
+                saved.id = ID::Existing(1);
+                Ok(saved)
+            });
+
+        let sut = BookAppointmentUseCase::new(
+            mock_appointment_repo,
+            mock_patient_repo,
+            mock_professional_repo,
+            mock_schedule_repo,
+            MockConsentRepository::new(),
+        );
+
+        // 2025-06-09 is a Monday.
+        let result = sut.execute(make_fake_input("2025-06-09T08:30:00")).await?;
+
+        assert_eq!(result.id, ID::Existing(1));
+        assert_eq!(result.professional_id, Some(42));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_telemedicine_without_consent_error() {
+        let (mock_patient_repo, mock_professional_repo, mock_schedule_repo) = make_fake_repos();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_consent_repo = MockConsentRepository::new();
+
+        mock_consent_repo
+            .expect_find_active_by_patient_id_and_purpose()
+            .with(eq(7), eq(ConsentPurpose::Telemedicine))
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_appointment_repo.expect_save().times(0);
+
+        let sut = BookAppointmentUseCase::new(
+            mock_appointment_repo,
+            mock_patient_repo,
+            mock_professional_repo,
+            mock_schedule_repo,
+            mock_consent_repo,
+        );
+
+        let mut input = make_fake_input("2025-06-09T08:30:00");
+        input.telemedicine = true;
+
+        let result = sut.execute(input).await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::Constraint(msg)) if msg.contains("telemedicine")
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_telemedicine_with_consent_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let (mock_patient_repo, mock_professional_repo, mock_schedule_repo) = make_fake_repos();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_consent_repo = MockConsentRepository::new();
+
+        mock_consent_repo
+            .expect_find_active_by_patient_id_and_purpose()
+            .times(1)
+            .return_const(Ok(Some(Consent::new(
+                7,
+                ConsentPurpose::Telemedicine,
+                "v1".to_string(),
+                ConsentChannel::Online,
+                "admin@example.com".to_string(),
+            )?)));
+
+        mock_appointment_repo
+            .expect_exists_overlapping_by_patient_id()
+            .times(1)
+            .return_const(Ok(false));
+
+        mock_appointment_repo
+            .expect_find_by_professional_id_between()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        mock_appointment_repo
+            .expect_save()
+            .times(1)
+            .returning(|appointment: &Appointment| Ok(appointment.clone()));
+
+        let sut = BookAppointmentUseCase::new(
+            mock_appointment_repo,
+            mock_patient_repo,
+            mock_professional_repo,
+            mock_schedule_repo,
+            mock_consent_repo,
+        );
+
+        let mut input = make_fake_input("2025-06-09T08:30:00");
+        input.telemedicine = true;
+
+        let result = sut.execute(input).await?;
+
+        assert!(result.telemedicine);
+
+        Ok(())
+    }
+
+    fn make_fake_repos() -> (
+        MockPatientRepository,
+        MockProfessionalRepository,
+        MockScheduleRepository,
+    ) {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut mock_schedule_repo = MockScheduleRepository::new();
+
+        mock_patient_repo.expect_find_by_cpf().return_const(Ok(Some(
+            Patient::restore(7, "Andrew".to_string(), Cpf::parse("52998224725").unwrap()).unwrap(),
+        )));
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(
+                Professional::restore(
+                    42,
+                    "Gregory House".to_string(),
+                    "CRM".to_string(),
+                    "123456".to_string(),
+                    "SP".to_string(),
+                    vec!["Nephrology".to_string()],
+                    true,
+                )
+                .unwrap(),
+            )));
+
+        mock_schedule_repo
+            .expect_find_schedules_by_professional_id()
+            .return_const(Ok(vec![
+                ProfessionalSchedule::new(
+                    42,
+                    1,
+                    "08:00".parse::<NaiveTime>().unwrap(),
+                    "12:00".parse::<NaiveTime>().unwrap(),
+                )
+                .unwrap(),
+            ]));
+
+        mock_schedule_repo
+            .expect_find_exceptions_by_professional_id_between()
+            .return_const(Ok(vec![]));
+
+        (
+            mock_patient_repo,
+            mock_professional_repo,
+            mock_schedule_repo,
+        )
+    }
+
+    fn make_fake_input(appointment_at: &str) -> BookAppointmentDTO {
+        BookAppointmentDTO {
+            patient_cpf: Cpf::parse("52998224725").unwrap(),
+            professional_id: Some(42),
+            appointment_at: appointment_at.to_string(),
+            duration_minutes: None,
+            specialty: "Nephrology".to_string(),
+            notes: None,
+            telemedicine: false,
+        }
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
             appointment_repository::AppointmentRepository, patient_repository::PatientRepository,
         },
     },
-    presentation::dtos::appointment_dto::CancelAppointmentDTO,
+    presentation::dtos::appointment_dto::{CancelAppointmentByIdDTO, CancelAppointmentDTO},
 };
 
 pub struct CancelAppointmentUseCase<T: AppointmentRepository, P: PatientRepository> {
@@ -35,7 +35,7 @@ impl<T: AppointmentRepository, P: PatientRepository> CancelAppointmentUseCase<T,
 
         if patient.is_none() {
             return Err(AppointmentApplicationError::PatientNotFound(
-                appointment.patient_cpf,
+                appointment.patient_cpf.to_string(),
             ));
         }
 
@@ -43,7 +43,7 @@ impl<T: AppointmentRepository, P: PatientRepository> CancelAppointmentUseCase<T,
 
         if !patient.id.is_existing() {
             return Err(AppointmentApplicationError::PatientNotFound(
-                appointment.patient_cpf,
+                appointment.patient_cpf.to_string(),
             ));
         }
 
@@ -64,11 +64,31 @@ impl<T: AppointmentRepository, P: PatientRepository> CancelAppointmentUseCase<T,
             )));
         }
 
-        let mut appointment = appointment.unwrap();
+        self.cancel(appointment.unwrap(), cancellation_reason).await
+    }
+
+    pub async fn execute_by_id(
+        &self,
+        id: i32,
+        input: CancelAppointmentByIdDTO,
+    ) -> Result<Appointment, AppointmentApplicationError> {
+        let appointment = self.appointment_repo.find_by_id(id).await?.ok_or(
+            AppointmentApplicationError::NotFound(format!("No appointment found with ID: {id}")),
+        )?;
+
+        self.cancel(appointment, input.cancellation_reason.unwrap_or_default())
+            .await
+    }
+
+    async fn cancel(
+        &self,
+        mut appointment: Appointment,
+        cancellation_reason: String,
+    ) -> Result<Appointment, AppointmentApplicationError> {
         if appointment.is_canceled() {
             return Ok(appointment);
         }
-        appointment.cancel(cancellation_reason);
+        appointment.cancel(cancellation_reason)?;
 
         self.appointment_repo
             .update(&appointment)
@@ -76,3 +96,103 @@ impl<T: AppointmentRepository, P: PatientRepository> CancelAppointmentUseCase<T,
             .map_err(|err| err.into())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+
+    use crate::{
+        application::{
+            errors::appointment_application_error::AppointmentApplicationError,
+            use_cases::cancel_appointment::CancelAppointmentUseCase,
+        },
+        domain::{
+            entities::appointment::Appointment,
+            repositories::{
+                appointment_repository::MockAppointmentRepository,
+                patient_repository::MockPatientRepository,
+            },
+            value_objects::{appointment_status::AppointmentStatus, id::ID},
+        },
+        presentation::dtos::appointment_dto::CancelAppointmentByIdDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_by_id_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_appointment())));
+
+        mock_appointment_repo
+            .expect_update()
+            .times(1)
+            .returning(|appointment: &Appointment| Ok(appointment.clone()));
+
+        let sut =
+            CancelAppointmentUseCase::new(mock_appointment_repo, MockPatientRepository::new());
+
+        let result = sut
+            .execute_by_id(
+                1,
+                CancelAppointmentByIdDTO {
+                    cancellation_reason: Some("Patient asked".to_string()),
+                },
+            )
+            .await?;
+
+        assert_eq!(result.status, AppointmentStatus::Canceled);
+        assert_eq!(
+            result.cancellation_reason,
+            Some("Patient asked".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_by_id_not_found_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_appointment_repo.expect_update().times(0);
+
+        let sut =
+            CancelAppointmentUseCase::new(mock_appointment_repo, MockPatientRepository::new());
+
+        let result = sut
+            .execute_by_id(
+                1,
+                CancelAppointmentByIdDTO {
+                    cancellation_reason: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::NotFound(_))
+        ));
+    }
+
+    fn make_fake_appointment() -> Appointment {
+        let mut appointment = Appointment::new(
+            7,
+            None,
+            "2025-06-23T10:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "Cardiology".to_string(),
+            None,
+        )
+        .unwrap();
+        appointment.id = ID::Existing(1);
+
+        appointment
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    application::errors::appointment_application_error::AppointmentApplicationError,
+    domain::{
+        entities::appointment::Appointment,
+        repositories::appointment_repository::AppointmentRepository,
+        value_objects::appointment_status::AppointmentStatus,
+    },
+};
+
+pub struct ChangeAppointmentStatusUseCase<T: AppointmentRepository> {
+    appointment_repo: T,
+}
+
+impl<T: AppointmentRepository> ChangeAppointmentStatusUseCase<T> {
+    pub fn new(appointment_repo: T) -> Self {
+        Self { appointment_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        status: AppointmentStatus,
+    ) -> Result<Appointment, AppointmentApplicationError> {
+        let mut appointment = self.appointment_repo.find_by_id(id).await?.ok_or(
+            AppointmentApplicationError::NotFound(format!("No appointment found with ID: {id}")),
+        )?;
+
+        appointment.transition_to(status)?;
+
+        self.appointment_repo
+            .update(&appointment)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+
+    use crate::{
+        application::{
+            errors::appointment_application_error::AppointmentApplicationError,
+            use_cases::change_appointment_status::ChangeAppointmentStatusUseCase,
+        },
+        domain::{
+            entities::appointment::Appointment,
+            repositories::appointment_repository::MockAppointmentRepository,
+            value_objects::{appointment_status::AppointmentStatus, id::ID},
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_appointment())));
+
+        mock_appointment_repo
+            .expect_update()
+            .times(1)
+            .returning(|appointment: &Appointment| Ok(appointment.clone()));
+
+        let sut = ChangeAppointmentStatusUseCase::new(mock_appointment_repo);
+
+        let result = sut.execute(1, AppointmentStatus::Confirmed).await?;
+
+        assert_eq!(result.status, AppointmentStatus::Confirmed);
+        assert!(result.confirmed_at.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_invalid_transition_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_appointment())));
+
+        mock_appointment_repo.expect_update().times(0);
+
+        let sut = ChangeAppointmentStatusUseCase::new(mock_appointment_repo);
+
+        let result = sut.execute(1, AppointmentStatus::Completed).await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_not_found_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        let sut = ChangeAppointmentStatusUseCase::new(mock_appointment_repo);
+
+        let result = sut.execute(1, AppointmentStatus::Confirmed).await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::NotFound(_))
+        ));
+    }
+
+    fn make_fake_appointment() -> Appointment {
+        let mut appointment = Appointment::new(
+            7,
+            None,
+            "2025-06-23T10:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "Cardiology".to_string(),
+            None,
+        )
+        .unwrap();
+        appointment.id = ID::Existing(1);
+
+        appointment
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{
+        entities::ward::Bed, repositories::ward_repository::WardRepository,
+        value_objects::bed_status::BedStatus,
+    },
+};
+
+pub struct ChangeBedStatusUseCase<W: WardRepository> {
+    ward_repo: W,
+}
+
+impl<W: WardRepository> ChangeBedStatusUseCase<W> {
+    pub fn new(ward_repo: W) -> Self {
+        Self { ward_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        bed_id: i32,
+        status: BedStatus,
+    ) -> Result<Bed, AdmissionApplicationError> {
+        let mut bed = self
+            .ward_repo
+            .find_bed_by_id(bed_id)
+            .await?
+            .ok_or(AdmissionApplicationError::BedNotFound(bed_id))?;
+
+        let from = bed.status;
+        bed.change_status(status)?;
+
+        self.ward_repo.update_bed_status(&bed, from).await?.ok_or(
+            AdmissionApplicationError::Conflict(format!("the bed {bed_id} is no longer {from}")),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::{always, eq};
+
+    use crate::{
+        application::{
+            errors::admission_application_error::AdmissionApplicationError,
+            use_cases::change_bed_status::ChangeBedStatusUseCase,
+        },
+        domain::{
+            entities::ward::Bed,
+            repositories::ward_repository::MockWardRepository,
+            value_objects::{bed_status::BedStatus, id::ID},
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_concurrent_change_conflict() {
+        let mut mock_ward_repo = MockWardRepository::new();
+
+        mock_ward_repo
+            .expect_find_bed_by_id()
+            .with(eq(4))
+            .times(1)
+            .return_const(Ok(Some(Bed {
+                id: ID::Existing(4),
+                room_id: 2,
+                label: "A".to_string(),
+                status: BedStatus::Cleaning,
+            })));
+
+        mock_ward_repo
+            .expect_update_bed_status()
+            .with(always(), eq(BedStatus::Cleaning))
+            .times(1)
+            .return_const(Ok(None));
+
+        let sut = ChangeBedStatusUseCase::new(mock_ward_repo);
+
+        let result = sut.execute(4, BedStatus::Free).await;
+
+        assert_eq!(
+            result,
+            Err(AdmissionApplicationError::Conflict(
+                "the bed 4 is no longer cleaning".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    application::errors::exam_order_application_error::ExamOrderApplicationError,
+    domain::{
+        entities::exam_order::{ExamOrder, ExamResult},
+        repositories::exam_order_repository::ExamOrderRepository,
+        value_objects::exam_order_status::ExamOrderStatus,
+    },
+};
+
+pub struct ChangeExamOrderStatusUseCase<X: ExamOrderRepository> {
+    exam_order_repo: X,
+}
+
+impl<X: ExamOrderRepository> ChangeExamOrderStatusUseCase<X> {
+    pub fn new(exam_order_repo: X) -> Self {
+        Self { exam_order_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        status: ExamOrderStatus,
+        actor: String,
+    ) -> Result<(ExamOrder, Vec<ExamResult>), ExamOrderApplicationError> {
+        let (mut exam_order, results) = self
+            .exam_order_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(ExamOrderApplicationError::NotFound(id))?;
+
+        exam_order.transition_to(status, &actor)?;
+
+        let exam_order = self.exam_order_repo.update(&exam_order).await?;
+
+        Ok((exam_order, results))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::exam_order_application_error::ExamOrderApplicationError,
+            use_cases::change_exam_order_status::ChangeExamOrderStatusUseCase,
+        },
+        domain::{
+            entities::exam_order::ExamOrder,
+            repositories::exam_order_repository::MockExamOrderRepository,
+            value_objects::{
+                cpf::Cpf, exam_kind::ExamKind, exam_order_status::ExamOrderStatus, id::ID,
+            },
+        },
+        presentation::dtos::exam_order_dto::OrderExamDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_review_before_results_error() {
+        let mut mock_exam_order_repo = MockExamOrderRepository::new();
+
+        let mut exam_order = make_fake_exam_order();
+        exam_order.status = ExamOrderStatus::Collected;
+
+        mock_exam_order_repo
+            .expect_find_by_id()
+            .with(eq(3))
+            .times(1)
+            .return_const(Ok(Some((exam_order, vec![]))));
+
+        mock_exam_order_repo.expect_update().times(0);
+
+        let sut = ChangeExamOrderStatusUseCase::new(mock_exam_order_repo);
+
+        let result = sut
+            .execute(
+                3,
+                ExamOrderStatus::Reviewed,
+                "doctor@example.com".to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ExamOrderApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_review_ok() {
+        let mut mock_exam_order_repo = MockExamOrderRepository::new();
+
+        let mut exam_order = make_fake_exam_order();
+        exam_order.status = ExamOrderStatus::Resulted;
+
+        mock_exam_order_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some((exam_order, vec![]))));
+
+        mock_exam_order_repo
+            .expect_update()
+            .withf(|exam_order: &ExamOrder| {
+                exam_order.status == ExamOrderStatus::Reviewed
+                    && exam_order.reviewed_by.as_deref() == Some("doctor@example.com")
+                    && exam_order.reviewed_at.is_some()
+            })
+            .times(1)
+            .returning(|exam_order| Ok(exam_order.clone()));
+
+        let sut = ChangeExamOrderStatusUseCase::new(mock_exam_order_repo);
+
+        let (result, _) = sut
+            .execute(
+                3,
+                ExamOrderStatus::Reviewed,
+                "doctor@example.com".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.status, ExamOrderStatus::Reviewed);
+    }
+
+    fn make_fake_exam_order() -> ExamOrder {
+        let mut exam_order = ExamOrder::new(
+            7,
+            42,
+            None,
+            OrderExamDTO {
+                patient_cpf: Cpf::parse("12345678909").unwrap(),
+                appointment_id: None,
+                kind: ExamKind::Imaging,
+                exam_name: "Chest X-ray".to_string(),
+                clinical_indication: None,
+            },
+            "admin@example.com".to_string(),
+        )
+        .unwrap();
+        exam_order.id = ID::Existing(3);
+
+        exam_order
+    }
+}
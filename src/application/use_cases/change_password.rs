@@ -0,0 +1,250 @@
+use crate::{
+    application::{
+        errors::admin_application_error::AdminApplicationError,
+        security::{
+            password::{hash_password, verify_password},
+            refresh_token::{RefreshToken, TokenPair, hash_secret},
+        },
+    },
+    domain::{
+        entities::{
+            audit_event::{AuditAction, AuditEvent},
+            session::Session,
+        },
+        errors::admin_entity_error::AdminEntityError,
+        repositories::{
+            admin_repository::AdminRepository, audit_repository::AuditRepository,
+            session_repository::SessionRepository,
+        },
+        value_objects::{id::ID, password::Password},
+    },
+    presentation::dtos::admin_dto::ChangePasswordDTO,
+};
+
+pub struct ChangePasswordUseCase<T: AdminRepository, S: SessionRepository, A: AuditRepository> {
+    admin_repo: T,
+    session_repo: S,
+    audit_repo: A,
+}
+
+impl<T: AdminRepository, S: SessionRepository, A: AuditRepository> ChangePasswordUseCase<T, S, A> {
+    pub fn new(admin_repo: T, session_repo: S, audit_repo: A) -> Self {
+        Self {
+            admin_repo,
+            session_repo,
+            audit_repo,
+        }
+    }
+
+    /// Changes the password of the account with `email`, which must prove it
+    /// knows the current one. Every session of the account is revoked and
+    /// the caller gets a new one, whose tokens no longer demand a password
+    /// change.
+    pub async fn execute(
+        &self,
+        email: String,
+        input: ChangePasswordDTO,
+    ) -> Result<TokenPair, AdminApplicationError> {
+        let mut admin = self
+            .admin_repo
+            .find_by_email(email.clone())
+            .await?
+            .filter(|admin| admin.active)
+            .ok_or(AdminApplicationError::NotFound(email.clone()))?;
+
+        if !verify_password(&input.current_password, &admin.password_hash) {
+            return Err(AdminApplicationError::IncorrectPassword);
+        }
+
+        let new_password = Password::parse(&input.new_password)?;
+        if verify_password(new_password.as_str(), &admin.password_hash) {
+            return Err(AdminEntityError::PasswordReused.into());
+        }
+
+        admin.change_password(hash_password(&new_password)?);
+
+        let admin = self.admin_repo.update(&admin).await?;
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                email,
+                AuditAction::PasswordChanged,
+                "user",
+                admin.id.clone().into(),
+                None,
+            ))
+            .await?;
+
+        let ID::Existing(admin_id) = admin.id else {
+            return Err(AdminApplicationError::NotFound(admin.email));
+        };
+
+        self.session_repo.revoke_all_by_admin_id(admin_id).await?;
+
+        let secret = RefreshToken::generate_secret();
+        let session_id = self
+            .session_repo
+            .save(&Session::start(admin_id, hash_secret(&secret)))
+            .await?;
+
+        TokenPair::issue(&admin, session_id, secret).ok_or(AdminApplicationError::Unexpected(
+            format!("Could not generate JWT token for admin {}", admin.email),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            security::jwt::jwt::validate_jwt, use_cases::change_password::ChangePasswordUseCase,
+        },
+        domain::{
+            entities::{admin::Admin, audit_event::AuditEvent, session::Session},
+            repositories::{
+                admin_repository::MockAdminRepository, audit_repository::MockAuditRepository,
+                session_repository::MockSessionRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+        presentation::dtos::admin_dto::ChangePasswordDTO,
+    };
+
+    const CURRENT_PASSWORD: &str = "Temporary-Pass-1";
+
+    fn make_fake_admin() -> Admin {
+        Admin {
+            id: ID::Existing(4),
+            ..Admin::new(
+                "Allison Cameron".to_string(),
+                "cameron@sghss.com",
+                Role::Physician,
+                bcrypt::hash(CURRENT_PASSWORD, 4).unwrap(),
+            )
+            .unwrap()
+        }
+    }
+
+    fn make_input(current_password: &str, new_password: &str) -> ChangePasswordDTO {
+        ChangePasswordDTO {
+            current_password: current_password.to_string(),
+            new_password: new_password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_incorrect_password() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin())));
+        mock_admin_repo.expect_update().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = ChangePasswordUseCase::new(
+            mock_admin_repo,
+            MockSessionRepository::new(),
+            mock_audit_repo,
+        );
+
+        let result = sut
+            .execute(
+                "cameron@sghss.com".to_string(),
+                make_input("Wrong-Password-1", "Immunology-Now-2"),
+            )
+            .await;
+
+        assert_eq!(result, Err(AdminApplicationError::IncorrectPassword));
+    }
+
+    #[tokio::test]
+    async fn execute_password_reused() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin())));
+        mock_admin_repo.expect_update().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = ChangePasswordUseCase::new(
+            mock_admin_repo,
+            MockSessionRepository::new(),
+            mock_audit_repo,
+        );
+
+        let result = sut
+            .execute(
+                "cameron@sghss.com".to_string(),
+                make_input(CURRENT_PASSWORD, CURRENT_PASSWORD),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(AdminApplicationError::Constraint(
+                "The new password must differ from the current one".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin())));
+        mock_admin_repo
+            .expect_update()
+            .withf(|admin: &Admin| {
+                !admin.must_change_password
+                    && bcrypt::verify("Immunology-Now-2", &admin.password_hash).unwrap()
+            })
+            .times(1)
+            .returning(|admin| Ok(admin.clone()));
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.action == "password_changed"
+                    && event.actor == "cameron@sghss.com"
+                    && event.entity_id == Some(4)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        mock_session_repo
+            .expect_revoke_all_by_admin_id()
+            .withf(|admin_id| *admin_id == 4)
+            .times(1)
+            .return_const(Ok(1));
+        mock_session_repo
+            .expect_save()
+            .withf(|session: &Session| session.admin_id == 4)
+            .times(1)
+            .return_const(Ok(21));
+
+        let sut = ChangePasswordUseCase::new(mock_admin_repo, mock_session_repo, mock_audit_repo);
+
+        let tokens = sut
+            .execute(
+                "cameron@sghss.com".to_string(),
+                make_input(CURRENT_PASSWORD, "Immunology-Now-2"),
+            )
+            .await
+            .unwrap();
+
+        let claims = validate_jwt(tokens.access_token).unwrap().claims;
+        assert!(!claims.must_change_password);
+        assert_eq!(claims.sid, 21);
+    }
+}
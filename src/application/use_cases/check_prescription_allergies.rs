@@ -0,0 +1,53 @@
+use crate::{
+    application::errors::prescription_application_error::PrescriptionApplicationError,
+    domain::{
+        entities::prescription::PrescriptionItem,
+        repositories::{
+            allergy_repository::AllergyRepository, patient_repository::PatientRepository,
+        },
+        services::allergy_check::{AllergyWarning, allergy_warnings},
+        value_objects::cpf::Cpf,
+    },
+    presentation::dtos::prescription_dto::PrescriptionItemDTO,
+};
+
+pub struct CheckPrescriptionAllergiesUseCase<T: PatientRepository, A: AllergyRepository> {
+    patient_repo: T,
+    allergy_repo: A,
+}
+
+impl<T: PatientRepository, A: AllergyRepository> CheckPrescriptionAllergiesUseCase<T, A> {
+    pub fn new(patient_repo: T, allergy_repo: A) -> Self {
+        Self {
+            patient_repo,
+            allergy_repo,
+        }
+    }
+
+    /// Validates the items against the patient's allergies without issuing
+    /// anything, so the prescriber can review the warnings first.
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        items: Vec<PrescriptionItemDTO>,
+    ) -> Result<Vec<AllergyWarning>, PrescriptionApplicationError> {
+        let patient = self.patient_repo.find_by_cpf(cpf.clone()).await?.ok_or(
+            PrescriptionApplicationError::PatientNotFound(cpf.to_string()),
+        )?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PrescriptionApplicationError::PatientNotFound(
+            cpf.to_string(),
+        ))?;
+
+        let items = items
+            .into_iter()
+            .zip(1..)
+            .map(|(item, position)| PrescriptionItem::new(position, item))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let allergies = self.allergy_repo.find_by_patient_id(patient_id).await?;
+
+        Ok(allergy_warnings(&items, &allergies))
+    }
+}
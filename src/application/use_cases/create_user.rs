@@ -0,0 +1,201 @@
+use crate::{
+    application::{
+        errors::admin_application_error::AdminApplicationError, security::password::hash_password,
+    },
+    domain::{
+        entities::{
+            admin::Admin,
+            audit_event::{AuditAction, AuditEvent},
+        },
+        repositories::{admin_repository::AdminRepository, audit_repository::AuditRepository},
+        value_objects::{id::ID, password::Password},
+    },
+    presentation::dtos::admin_dto::CreateUserDTO,
+};
+
+pub struct CreateUserUseCase<T: AdminRepository, A: AuditRepository> {
+    admin_repo: T,
+    audit_repo: A,
+}
+
+impl<T: AdminRepository, A: AuditRepository> CreateUserUseCase<T, A> {
+    pub fn new(admin_repo: T, audit_repo: A) -> Self {
+        Self {
+            admin_repo,
+            audit_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: CreateUserDTO,
+        actor: String,
+    ) -> Result<Admin, AdminApplicationError> {
+        let password = Password::parse(&input.password)?;
+        let mut admin = Admin::new(
+            input.name,
+            &input.email,
+            input.role,
+            hash_password(&password)?,
+        )?;
+
+        if self
+            .admin_repo
+            .find_by_email(admin.email.clone())
+            .await?
+            .is_some()
+        {
+            return Err(AdminApplicationError::Conflict(format!(
+                "the e-mail {} is already in use",
+                admin.email
+            )));
+        }
+
+        let admin_id = self.admin_repo.save(&admin).await?;
+        admin.id = ID::Existing(admin_id);
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                actor,
+                AuditAction::UserCreated,
+                "user",
+                Some(admin_id),
+                Some(format!("Created with the role {}", admin.role)),
+            ))
+            .await?;
+
+        Ok(admin)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            use_cases::create_user::CreateUserUseCase,
+        },
+        domain::{
+            entities::{admin::Admin, audit_event::AuditEvent},
+            repositories::{
+                admin_repository::MockAdminRepository, audit_repository::MockAuditRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+        presentation::dtos::admin_dto::CreateUserDTO,
+    };
+
+    fn make_input(password: &str) -> CreateUserDTO {
+        CreateUserDTO {
+            name: "James Wilson".to_string(),
+            email: "Wilson@Example.com".to_string(),
+            role: Role::Physician,
+            password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_weak_password() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo.expect_save().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = CreateUserUseCase::new(mock_admin_repo, mock_audit_repo);
+
+        let result = sut
+            .execute(make_input("password"), "admin@sghss.com".to_string())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(AdminApplicationError::Constraint(
+                "The password is too weak: it must have at least 12 characters".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_email_in_use() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .withf(|email| email == "wilson@example.com")
+            .times(1)
+            .returning(|email| {
+                Ok(Some(
+                    Admin::new(
+                        "James Wilson".to_string(),
+                        &email,
+                        Role::Physician,
+                        "hash".to_string(),
+                    )
+                    .unwrap(),
+                ))
+            });
+        mock_admin_repo.expect_save().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = CreateUserUseCase::new(mock_admin_repo, mock_audit_repo);
+
+        let result = sut
+            .execute(
+                make_input("Oncology-Rocks-1"),
+                "admin@sghss.com".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(AdminApplicationError::Conflict(
+                "the e-mail wilson@example.com is already in use".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(None));
+        mock_admin_repo
+            .expect_save()
+            .withf(|admin: &Admin| {
+                admin.email == "wilson@example.com"
+                    && admin.must_change_password
+                    && bcrypt::verify("Oncology-Rocks-1", &admin.password_hash).unwrap()
+            })
+            .times(1)
+            .return_const(Ok(5));
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.action == "user_created"
+                    && event.actor == "admin@sghss.com"
+                    && event.entity_id == Some(5)
+                    && event.details.as_deref() == Some("Created with the role physician")
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = CreateUserUseCase::new(mock_admin_repo, mock_audit_repo);
+
+        let admin = sut
+            .execute(
+                make_input("Oncology-Rocks-1"),
+                "admin@sghss.com".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(admin.id, ID::Existing(5));
+        assert_eq!(admin.role, Role::Physician);
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{entities::ward::Ward, repositories::ward_repository::WardRepository},
+    presentation::dtos::ward_dto::CreateWardDTO,
+};
+
+pub struct CreateWardUseCase<W: WardRepository> {
+    ward_repo: W,
+}
+
+impl<W: WardRepository> CreateWardUseCase<W> {
+    pub fn new(ward_repo: W) -> Self {
+        Self { ward_repo }
+    }
+
+    pub async fn execute(&self, input: CreateWardDTO) -> Result<Ward, AdmissionApplicationError> {
+        let ward = Ward::new(input)?;
+
+        Ok(self.ward_repo.save_ward(&ward).await?)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::repositories::professional_repository::ProfessionalRepository,
+};
+
+/// Professionals are referenced by past appointments, so they are never
+/// removed; deleting one only marks it as inactive.
+pub struct DeactivateProfessionalByIdUseCase<T: ProfessionalRepository> {
+    professional_repo: T,
+}
+
+impl<T: ProfessionalRepository> DeactivateProfessionalByIdUseCase<T> {
+    pub fn new(professional_repo: T) -> Self {
+        Self { professional_repo }
+    }
+
+    pub async fn execute(&self, id: i32) -> Result<(), ProfessionalApplicationError> {
+        let professional = self.professional_repo.find_by_id(id).await?;
+
+        if professional.is_none() {
+            return Err(ProfessionalApplicationError::NotFound(id));
+        }
+
+        let mut professional = professional.unwrap();
+        if !professional.active {
+            return Ok(());
+        }
+        professional.deactivate();
+
+        self.professional_repo.update(&professional).await?;
+
+        Ok(())
+    }
+}
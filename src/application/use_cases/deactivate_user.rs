@@ -0,0 +1,149 @@
+use crate::{
+    application::errors::admin_application_error::AdminApplicationError,
+    domain::{
+        entities::{
+            admin::Admin,
+            audit_event::{AuditAction, AuditEvent},
+        },
+        repositories::{
+            admin_repository::AdminRepository, audit_repository::AuditRepository,
+            session_repository::SessionRepository,
+        },
+    },
+};
+
+/// Accounts are referenced by the records they signed, so they are never
+/// removed; a deactivated account can no longer log in.
+pub struct DeactivateUserUseCase<T: AdminRepository, S: SessionRepository, A: AuditRepository> {
+    admin_repo: T,
+    session_repo: S,
+    audit_repo: A,
+}
+
+impl<T: AdminRepository, S: SessionRepository, A: AuditRepository> DeactivateUserUseCase<T, S, A> {
+    pub fn new(admin_repo: T, session_repo: S, audit_repo: A) -> Self {
+        Self {
+            admin_repo,
+            session_repo,
+            audit_repo,
+        }
+    }
+
+    pub async fn execute(&self, id: i32, actor: String) -> Result<Admin, AdminApplicationError> {
+        let mut admin = self
+            .admin_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(AdminApplicationError::NotFoundById(id))?;
+
+        if admin.email == actor {
+            return Err(AdminApplicationError::SelfDeactivation);
+        }
+
+        if !admin.active {
+            return Ok(admin);
+        }
+        admin.deactivate();
+
+        let admin = self.admin_repo.update(&admin).await?;
+        self.session_repo.revoke_all_by_admin_id(id).await?;
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                actor,
+                AuditAction::UserDeactivated,
+                "user",
+                Some(id),
+                None,
+            ))
+            .await?;
+
+        Ok(admin)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            use_cases::deactivate_user::DeactivateUserUseCase,
+        },
+        domain::{
+            entities::{admin::Admin, audit_event::AuditEvent},
+            repositories::{
+                admin_repository::MockAdminRepository, audit_repository::MockAuditRepository,
+                session_repository::MockSessionRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+    };
+
+    fn make_fake_admin(email: &str) -> Admin {
+        Admin {
+            id: ID::Existing(4),
+            ..Admin::new(
+                "Lisa Cuddy".to_string(),
+                email,
+                Role::Admin,
+                "hash".to_string(),
+            )
+            .unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_self_deactivation() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin("cuddy@sghss.com"))));
+        mock_admin_repo.expect_update().times(0);
+        mock_session_repo.expect_revoke_all_by_admin_id().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = DeactivateUserUseCase::new(mock_admin_repo, mock_session_repo, mock_audit_repo);
+
+        let result = sut.execute(4, "cuddy@sghss.com".to_string()).await;
+
+        assert_eq!(result, Err(AdminApplicationError::SelfDeactivation));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin("cuddy@sghss.com"))));
+        mock_admin_repo
+            .expect_update()
+            .withf(|admin: &Admin| !admin.active)
+            .times(1)
+            .returning(|admin| Ok(admin.clone()));
+        mock_session_repo
+            .expect_revoke_all_by_admin_id()
+            .times(1)
+            .return_const(Ok(1));
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.action == "user_deactivated" && event.entity_id == Some(4)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = DeactivateUserUseCase::new(mock_admin_repo, mock_session_repo, mock_audit_repo);
+
+        let admin = sut.execute(4, "admin@sghss.com".to_string()).await.unwrap();
+
+        assert!(!admin.active);
+    }
+}
@@ -0,0 +1,108 @@
+use log::error;
+
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        repositories::{
+            document_repository::DocumentRepository, file_storage::FileStorage,
+            patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct DeleteDocumentUseCase<T: PatientRepository, D: DocumentRepository, S: FileStorage> {
+    patient_repo: T,
+    document_repo: D,
+    file_storage: S,
+}
+
+impl<T: PatientRepository, D: DocumentRepository, S: FileStorage> DeleteDocumentUseCase<T, D, S> {
+    pub fn new(patient_repo: T, document_repo: D, file_storage: S) -> Self {
+        Self {
+            patient_repo,
+            document_repo,
+            file_storage,
+        }
+    }
+
+    /// The row goes first. A file left behind by a failed removal is only
+    /// wasted space, while a row without its file would break downloads.
+    pub async fn execute(&self, cpf: Cpf, document_id: i32) -> Result<(), PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let document = self
+            .document_repo
+            .find_by_id(patient_id, document_id)
+            .await?
+            .ok_or(PatientApplicationError::DocumentNotFound(document_id))?;
+
+        if !self.document_repo.delete(patient_id, document_id).await? {
+            return Err(PatientApplicationError::DocumentNotFound(document_id));
+        }
+
+        if let Err(err) = self.file_storage.delete(&document.storage_key).await {
+            error!("Orphaned document file: {err}");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::delete_document::DeleteDocumentUseCase,
+        },
+        domain::{
+            entities::patient::Patient,
+            repositories::{
+                document_repository::MockDocumentRepository, file_storage::MockFileStorage,
+                patient_repository::MockPatientRepository,
+            },
+            value_objects::cpf::Cpf,
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_document_not_found() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_document_repo = MockDocumentRepository::new();
+        let mut mock_file_storage = MockFileStorage::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(
+                Patient::restore(7, "Andrew".to_string(), Cpf::parse("12345678909").unwrap())
+                    .unwrap(),
+            )));
+
+        mock_document_repo
+            .expect_find_by_id()
+            .with(eq(7), eq(4))
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_document_repo.expect_delete().times(0);
+        mock_file_storage.expect_delete().times(0);
+
+        let sut =
+            DeleteDocumentUseCase::new(mock_patient_repo, mock_document_repo, mock_file_storage);
+
+        let result = sut.execute(Cpf::parse("12345678909").unwrap(), 4).await;
+
+        assert_eq!(result, Err(PatientApplicationError::DocumentNotFound(4)));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     application::errors::patient_application_error::PatientApplicationError,
-    domain::repositories::patient_repository::PatientRepository,
+    domain::{repositories::patient_repository::PatientRepository, value_objects::cpf::Cpf},
 };
 
 pub struct DeletePatientByCpfUseCase<T: PatientRepository> {
@@ -12,7 +12,7 @@ impl<T: PatientRepository> DeletePatientByCpfUseCase<T> {
         Self { patient_repo }
     }
 
-    pub async fn execute(&self, cpf: String) -> Result<(), PatientApplicationError> {
+    pub async fn execute(&self, cpf: Cpf) -> Result<(), PatientApplicationError> {
         self.patient_repo
             .delete_by_cpf(cpf)
             .await
@@ -28,7 +28,7 @@ mod test {
         application::use_cases::delete_patient_by_cpf::DeletePatientByCpfUseCase,
         domain::{
             errors::repository_error::RepositoryError,
-            repositories::patient_repository::MockPatientRepository,
+            repositories::patient_repository::MockPatientRepository, value_objects::cpf::Cpf,
         },
     };
 
@@ -45,7 +45,7 @@ mod test {
 
         let sut = DeletePatientByCpfUseCase::new(mock_patient_repo);
 
-        let result = sut.execute("12345678901".to_string()).await;
+        let result = sut.execute(Cpf::parse("12345678909").unwrap()).await;
 
         assert!(result.is_err());
     }
@@ -53,19 +53,17 @@ mod test {
     #[tokio::test]
     async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
         let mut mock_patient_repo = MockPatientRepository::new();
-        let cpf = "12345678901";
+        let cpf = Cpf::parse("12345678909")?;
 
         mock_patient_repo
             .expect_delete_by_cpf()
             .times(1)
-            .with(eq(cpf.to_string()))
+            .with(eq(cpf.clone()))
             .return_const(Ok(()));
 
         let sut = DeletePatientByCpfUseCase::new(mock_patient_repo);
 
-        let result = sut.execute(cpf.to_string()).await?;
-
-        assert_eq!(result, ());
+        sut.execute(cpf).await?;
 
         Ok(())
     }
@@ -0,0 +1,35 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{
+        entities::admission::{Admission, BedOccupancy},
+        repositories::admission_repository::AdmissionRepository,
+    },
+    presentation::dtos::admission_dto::DischargePatientDTO,
+};
+
+pub struct DischargePatientUseCase<A: AdmissionRepository> {
+    admission_repo: A,
+}
+
+impl<A: AdmissionRepository> DischargePatientUseCase<A> {
+    pub fn new(admission_repo: A) -> Self {
+        Self { admission_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        admission_id: i32,
+        input: DischargePatientDTO,
+        actor: String,
+    ) -> Result<(Admission, Vec<BedOccupancy>), AdmissionApplicationError> {
+        let (mut admission, _) = self
+            .admission_repo
+            .find_by_id(admission_id)
+            .await?
+            .ok_or(AdmissionApplicationError::NotFound(admission_id))?;
+
+        admission.discharge(input.summary, actor)?;
+
+        Ok(self.admission_repo.discharge(&admission).await?)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::document::Document,
+        repositories::{
+            document_repository::DocumentRepository,
+            file_storage::{FileStorage, FileStream},
+            patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct DownloadDocumentUseCase<T: PatientRepository, D: DocumentRepository, S: FileStorage> {
+    patient_repo: T,
+    document_repo: D,
+    file_storage: S,
+}
+
+impl<T: PatientRepository, D: DocumentRepository, S: FileStorage> DownloadDocumentUseCase<T, D, S> {
+    pub fn new(patient_repo: T, document_repo: D, file_storage: S) -> Self {
+        Self {
+            patient_repo,
+            document_repo,
+            file_storage,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        document_id: i32,
+    ) -> Result<(Document, FileStream), PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let document = self
+            .document_repo
+            .find_by_id(patient_id, document_id)
+            .await?
+            .ok_or(PatientApplicationError::DocumentNotFound(document_id))?;
+
+        let content = self.file_storage.get(&document.storage_key).await?;
+
+        Ok((document, content))
+    }
+}
@@ -0,0 +1,393 @@
+use chrono::NaiveDateTime;
+
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::{
+            admission::{Admission, BedOccupancy},
+            allergy::Allergy,
+            appointment::Appointment,
+            consent::Consent,
+            document::Document,
+            encounter::Encounter,
+            exam_order::{ExamOrder, ExamResult},
+            patient::Patient,
+            prescription::{Prescription, PrescriptionItem},
+            problem::Problem,
+            professional::Professional,
+        },
+        repositories::{
+            admission_repository::AdmissionRepository, allergy_repository::AllergyRepository,
+            appointment_repository::AppointmentRepository, consent_repository::ConsentRepository,
+            document_repository::DocumentRepository, encounter_repository::EncounterRepository,
+            exam_order_repository::ExamOrderRepository, patient_repository::PatientRepository,
+            prescription_repository::PrescriptionRepository, problem_repository::ProblemRepository,
+            professional_repository::ProfessionalRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+/// Everything held about a single patient, as handed out on an LGPD access
+/// request.
+pub struct PatientDataExport {
+    pub exported_at: NaiveDateTime,
+    pub patient: Patient,
+    pub appointments: Vec<Appointment>,
+    pub professionals: Vec<Professional>,
+    pub consents: Vec<Consent>,
+    pub encounters: Vec<Encounter>,
+    pub allergies: Vec<Allergy>,
+    pub problems: Vec<Problem>,
+    pub prescriptions: Vec<(Prescription, Vec<PrescriptionItem>)>,
+    /// Result files are left out, only their names are listed.
+    pub exam_orders: Vec<(ExamOrder, Vec<ExamResult>)>,
+    /// Attached documents are listed by name and checksum, without contents.
+    pub documents: Vec<Document>,
+    pub admissions: Vec<(Admission, Vec<BedOccupancy>)>,
+}
+
+pub struct ExportPatientDataUseCase<
+    T: PatientRepository,
+    P: AppointmentRepository,
+    R: ProfessionalRepository,
+    C: ConsentRepository,
+    E: EncounterRepository,
+    A: AllergyRepository,
+    B: ProblemRepository,
+    S: PrescriptionRepository,
+    X: ExamOrderRepository,
+    D: DocumentRepository,
+    M: AdmissionRepository,
+> {
+    patient_repo: T,
+    appointment_repo: P,
+    professional_repo: R,
+    consent_repo: C,
+    encounter_repo: E,
+    allergy_repo: A,
+    problem_repo: B,
+    prescription_repo: S,
+    exam_order_repo: X,
+    document_repo: D,
+    admission_repo: M,
+}
+
+impl<
+    T: PatientRepository,
+    P: AppointmentRepository,
+    R: ProfessionalRepository,
+    C: ConsentRepository,
+    E: EncounterRepository,
+    A: AllergyRepository,
+    B: ProblemRepository,
+    S: PrescriptionRepository,
+    X: ExamOrderRepository,
+    D: DocumentRepository,
+    M: AdmissionRepository,
+> ExportPatientDataUseCase<T, P, R, C, E, A, B, S, X, D, M>
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        patient_repo: T,
+        appointment_repo: P,
+        professional_repo: R,
+        consent_repo: C,
+        encounter_repo: E,
+        allergy_repo: A,
+        problem_repo: B,
+        prescription_repo: S,
+        exam_order_repo: X,
+        document_repo: D,
+        admission_repo: M,
+    ) -> Self {
+        Self {
+            patient_repo,
+            appointment_repo,
+            professional_repo,
+            consent_repo,
+            encounter_repo,
+            allergy_repo,
+            problem_repo,
+            prescription_repo,
+            exam_order_repo,
+            document_repo,
+            admission_repo,
+        }
+    }
+
+    pub async fn execute(&self, cpf: Cpf) -> Result<PatientDataExport, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.clone().into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let appointments = self.appointment_repo.find_by_patient_id(patient_id).await?;
+        let prescriptions = self
+            .prescription_repo
+            .find_by_patient_id(patient_id)
+            .await?;
+        let exam_orders = self.exam_order_repo.find_by_patient_id(patient_id).await?;
+        let admissions = self.admission_repo.find_by_patient_id(patient_id).await?;
+
+        let mut professional_ids: Vec<i32> = appointments
+            .iter()
+            .filter_map(|appointment| appointment.professional_id)
+            .chain(
+                prescriptions
+                    .iter()
+                    .map(|(prescription, _)| prescription.professional_id),
+            )
+            .chain(
+                exam_orders
+                    .iter()
+                    .map(|(exam_order, _)| exam_order.professional_id),
+            )
+            .chain(
+                admissions
+                    .iter()
+                    .filter_map(|(admission, _)| admission.professional_id),
+            )
+            .collect();
+        professional_ids.sort_unstable();
+        professional_ids.dedup();
+
+        let mut professionals = Vec::with_capacity(professional_ids.len());
+        for professional_id in professional_ids {
+            if let Some(professional) = self.professional_repo.find_by_id(professional_id).await? {
+                professionals.push(professional);
+            }
+        }
+
+        let consents = self.consent_repo.find_by_patient_id(patient_id).await?;
+        let encounters = self.encounter_repo.find_by_patient_id(patient_id).await?;
+        let allergies = self.allergy_repo.find_by_patient_id(patient_id).await?;
+        let problems = self.problem_repo.find_by_patient_id(patient_id).await?;
+        let documents = self.document_repo.find_by_patient_id(patient_id).await?;
+
+        Ok(PatientDataExport {
+            exported_at: chrono::Local::now().naive_utc(),
+            patient,
+            appointments,
+            professionals,
+            consents,
+            encounters,
+            allergies,
+            problems,
+            prescriptions,
+            exam_orders,
+            documents,
+            admissions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::export_patient_data::ExportPatientDataUseCase,
+        },
+        domain::{
+            entities::{appointment::Appointment, patient::Patient, professional::Professional},
+            repositories::{
+                admission_repository::MockAdmissionRepository,
+                allergy_repository::MockAllergyRepository,
+                appointment_repository::MockAppointmentRepository,
+                consent_repository::MockConsentRepository,
+                document_repository::MockDocumentRepository,
+                encounter_repository::MockEncounterRepository,
+                exam_order_repository::MockExamOrderRepository,
+                patient_repository::MockPatientRepository,
+                prescription_repository::MockPrescriptionRepository,
+                problem_repository::MockProblemRepository,
+                professional_repository::MockProfessionalRepository,
+            },
+            value_objects::cpf::Cpf,
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_patient_not_found() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let cpf = Cpf::parse("12345678909").unwrap();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_appointment_repo.expect_find_by_patient_id().times(0);
+
+        let sut = ExportPatientDataUseCase::new(
+            mock_patient_repo,
+            mock_appointment_repo,
+            MockProfessionalRepository::new(),
+            MockConsentRepository::new(),
+            MockEncounterRepository::new(),
+            MockAllergyRepository::new(),
+            MockProblemRepository::new(),
+            MockPrescriptionRepository::new(),
+            MockExamOrderRepository::new(),
+            MockDocumentRepository::new(),
+            MockAdmissionRepository::new(),
+        );
+
+        let result = sut.execute(cpf.clone()).await;
+
+        assert!(matches!(
+            result,
+            Err(PatientApplicationError::NotFound(not_found_cpf)) if not_found_cpf == cpf.to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(Patient::restore(
+                7,
+                "Andrew".to_string(),
+                Cpf::parse("12345678909")?,
+            )?)));
+
+        let mut canceled = make_fake_appointment("2025-06-09T08:00:00");
+        canceled.cancel("Patient asked".to_string())?;
+
+        mock_appointment_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![
+                canceled,
+                make_fake_appointment("2025-06-16T08:00:00"),
+            ]));
+
+        // Both appointments are with the same professional.
+        mock_professional_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(Professional::restore(
+                42,
+                "Gregory House".to_string(),
+                "CRM".to_string(),
+                "123456".to_string(),
+                "SP".to_string(),
+                vec!["Nephrology".to_string()],
+                true,
+            )?)));
+
+        let mut mock_consent_repo = MockConsentRepository::new();
+
+        mock_consent_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut mock_encounter_repo = MockEncounterRepository::new();
+
+        mock_encounter_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut mock_allergy_repo = MockAllergyRepository::new();
+
+        mock_allergy_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut mock_problem_repo = MockProblemRepository::new();
+
+        mock_problem_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut mock_prescription_repo = MockPrescriptionRepository::new();
+
+        mock_prescription_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut mock_exam_order_repo = MockExamOrderRepository::new();
+
+        mock_exam_order_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut mock_document_repo = MockDocumentRepository::new();
+
+        mock_document_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut mock_admission_repo = MockAdmissionRepository::new();
+
+        mock_admission_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let sut = ExportPatientDataUseCase::new(
+            mock_patient_repo,
+            mock_appointment_repo,
+            mock_professional_repo,
+            mock_consent_repo,
+            mock_encounter_repo,
+            mock_allergy_repo,
+            mock_problem_repo,
+            mock_prescription_repo,
+            mock_exam_order_repo,
+            mock_document_repo,
+            mock_admission_repo,
+        );
+
+        let result = sut.execute(Cpf::parse("12345678909")?).await?;
+
+        assert_eq!(result.appointments.len(), 2);
+        assert!(result.appointments[0].is_canceled());
+        assert_eq!(result.professionals.len(), 1);
+
+        Ok(())
+    }
+
+    fn make_fake_appointment(appointment_at: &str) -> Appointment {
+        Appointment::new(
+            7,
+            Some(42),
+            appointment_at.parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "Nephrology".to_string(),
+            None,
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{
+        entities::admission::{Admission, BedOccupancy},
+        repositories::admission_repository::AdmissionRepository,
+    },
+};
+
+pub struct FindAdmissionByIdUseCase<A: AdmissionRepository> {
+    admission_repo: A,
+}
+
+impl<A: AdmissionRepository> FindAdmissionByIdUseCase<A> {
+    pub fn new(admission_repo: A) -> Self {
+        Self { admission_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        admission_id: i32,
+    ) -> Result<(Admission, Vec<BedOccupancy>), AdmissionApplicationError> {
+        self.admission_repo
+            .find_by_id(admission_id)
+            .await?
+            .ok_or(AdmissionApplicationError::NotFound(admission_id))
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    application::errors::appointment_application_error::AppointmentApplicationError,
+    domain::{
+        entities::appointment::Appointment,
+        repositories::appointment_repository::AppointmentRepository,
+    },
+};
+
+pub struct FindAppointmentByIdUseCase<T: AppointmentRepository> {
+    appointment_repo: T,
+}
+
+impl<T: AppointmentRepository> FindAppointmentByIdUseCase<T> {
+    pub fn new(appointment_repo: T) -> Self {
+        Self { appointment_repo }
+    }
+
+    pub async fn execute(&self, id: i32) -> Result<Appointment, AppointmentApplicationError> {
+        self.appointment_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(AppointmentApplicationError::NotFound(format!(
+                "No appointment found with ID: {id}"
+            )))
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::NaiveDateTime;
+
+use crate::{
+    application::errors::appointment_application_error::AppointmentApplicationError,
+    domain::{
+        entities::appointment::Appointment,
+        repositories::{
+            appointment_repository::AppointmentRepository, patient_repository::PatientRepository,
+        },
+    },
+    presentation::dtos::appointment_dto::AppointmentLookupQueryDTO,
+};
+
+pub struct FindAppointmentByPatientCpfAndTimeUseCase<T: AppointmentRepository, P: PatientRepository>
+{
+    appointment_repo: T,
+    patient_repo: P,
+}
+
+impl<T: AppointmentRepository, P: PatientRepository>
+    FindAppointmentByPatientCpfAndTimeUseCase<T, P>
+{
+    pub fn new(appointment_repo: T, patient_repo: P) -> Self {
+        Self {
+            appointment_repo,
+            patient_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: AppointmentLookupQueryDTO,
+    ) -> Result<Appointment, AppointmentApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(query.patient_cpf.clone())
+            .await?
+            .ok_or(AppointmentApplicationError::PatientNotFound(
+                query.patient_cpf.to_string(),
+            ))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(AppointmentApplicationError::PatientNotFound(
+            query.patient_cpf.to_string(),
+        ))?;
+        let appointment_at = query.appointment_at.parse::<NaiveDateTime>()?;
+
+        self.appointment_repo
+            .find_by_patient_id_and_appointment_at(patient_id, appointment_at)
+            .await?
+            .ok_or(AppointmentApplicationError::NotFound(format!(
+                "No appointment found for patient with CPF: {} at: {}",
+                patient.cpf, appointment_at
+            )))
+    }
+}
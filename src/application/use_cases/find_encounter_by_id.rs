@@ -0,0 +1,32 @@
+use crate::{
+    application::errors::encounter_application_error::EncounterApplicationError,
+    domain::{
+        entities::encounter::Encounter, repositories::encounter_repository::EncounterRepository,
+    },
+};
+
+pub struct FindEncounterByIdUseCase<E: EncounterRepository> {
+    encounter_repo: E,
+}
+
+impl<E: EncounterRepository> FindEncounterByIdUseCase<E> {
+    pub fn new(encounter_repo: E) -> Self {
+        Self { encounter_repo }
+    }
+
+    /// The encounter along with its amendments, oldest first.
+    pub async fn execute(
+        &self,
+        id: i32,
+    ) -> Result<(Encounter, Vec<Encounter>), EncounterApplicationError> {
+        let encounter = self
+            .encounter_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(EncounterApplicationError::NotFound(id))?;
+
+        let amendments = self.encounter_repo.find_amendments(id).await?;
+
+        Ok((encounter, amendments))
+    }
+}
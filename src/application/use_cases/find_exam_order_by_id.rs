@@ -0,0 +1,27 @@
+use crate::{
+    application::errors::exam_order_application_error::ExamOrderApplicationError,
+    domain::{
+        entities::exam_order::{ExamOrder, ExamResult},
+        repositories::exam_order_repository::ExamOrderRepository,
+    },
+};
+
+pub struct FindExamOrderByIdUseCase<X: ExamOrderRepository> {
+    exam_order_repo: X,
+}
+
+impl<X: ExamOrderRepository> FindExamOrderByIdUseCase<X> {
+    pub fn new(exam_order_repo: X) -> Self {
+        Self { exam_order_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+    ) -> Result<(ExamOrder, Vec<ExamResult>), ExamOrderApplicationError> {
+        self.exam_order_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(ExamOrderApplicationError::NotFound(id))
+    }
+}
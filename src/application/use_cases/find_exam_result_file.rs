@@ -0,0 +1,37 @@
+use crate::{
+    application::errors::exam_order_application_error::ExamOrderApplicationError,
+    domain::{
+        entities::exam_order::ExamOrder, repositories::exam_order_repository::ExamOrderRepository,
+    },
+};
+
+pub struct FindExamResultFileUseCase<X: ExamOrderRepository> {
+    exam_order_repo: X,
+}
+
+impl<X: ExamOrderRepository> FindExamResultFileUseCase<X> {
+    pub fn new(exam_order_repo: X) -> Self {
+        Self { exam_order_repo }
+    }
+
+    /// The order, which holds the file name and content type, along with the
+    /// file contents.
+    pub async fn execute(
+        &self,
+        id: i32,
+    ) -> Result<(ExamOrder, Vec<u8>), ExamOrderApplicationError> {
+        let (exam_order, _) = self
+            .exam_order_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(ExamOrderApplicationError::NotFound(id))?;
+
+        let content = self
+            .exam_order_repo
+            .find_result_file(id)
+            .await?
+            .ok_or(ExamOrderApplicationError::ResultFileNotFound(id))?;
+
+        Ok((exam_order, content))
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
     application::errors::patient_application_error::PatientApplicationError,
-    domain::{entities::patient::Patient, repositories::patient_repository::PatientRepository},
+    domain::{
+        entities::patient::Patient, repositories::patient_repository::PatientRepository,
+        value_objects::cpf::Cpf,
+    },
 };
 
 pub struct FindPatientByCpfUseCase<T: PatientRepository> {
@@ -12,7 +15,7 @@ impl<T: PatientRepository> FindPatientByCpfUseCase<T> {
         Self { patient_repo }
     }
 
-    pub async fn execute(&self, cpf: String) -> Result<Option<Patient>, PatientApplicationError> {
+    pub async fn execute(&self, cpf: Cpf) -> Result<Option<Patient>, PatientApplicationError> {
         self.patient_repo
             .find_by_cpf(cpf)
             .await
@@ -28,7 +31,7 @@ mod test {
         application::use_cases::find_patient_by_cpf::FindPatientByCpfUseCase,
         domain::{
             entities::patient::Patient, errors::repository_error::RepositoryError,
-            repositories::patient_repository::MockPatientRepository,
+            repositories::patient_repository::MockPatientRepository, value_objects::cpf::Cpf,
         },
     };
 
@@ -45,7 +48,7 @@ mod test {
 
         let sut = FindPatientByCpfUseCase::new(mock_patient_repo);
 
-        let result = sut.execute("00011122233".to_string()).await;
+        let result = sut.execute(Cpf::parse("00011122285").unwrap()).await;
 
         assert!(result.is_err());
     }
@@ -54,7 +57,7 @@ mod test {
     async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
         let mut mock_patient_repo = MockPatientRepository::new();
 
-        let fake_patient = Patient::restore(42, "Andrew".to_string(), "00011122233".to_string())?;
+        let fake_patient = Patient::restore(42, "Andrew".to_string(), Cpf::parse("00011122285")?)?;
 
         mock_patient_repo
             .expect_find_by_cpf()
@@ -0,0 +1,79 @@
+use crate::{
+    application::errors::prescription_application_error::PrescriptionApplicationError,
+    domain::{
+        entities::{
+            patient::Patient,
+            prescription::{Prescription, PrescriptionItem},
+            professional::Professional,
+        },
+        repositories::{
+            patient_repository::PatientRepository, prescription_repository::PrescriptionRepository,
+            professional_repository::ProfessionalRepository,
+        },
+    },
+};
+
+/// Everything needed to print a prescription.
+pub struct PrescriptionDocument {
+    pub prescription: Prescription,
+    pub items: Vec<PrescriptionItem>,
+    pub patient: Patient,
+    pub professional: Professional,
+}
+
+pub struct FindPrescriptionByIdUseCase<
+    S: PrescriptionRepository,
+    T: PatientRepository,
+    R: ProfessionalRepository,
+> {
+    prescription_repo: S,
+    patient_repo: T,
+    professional_repo: R,
+}
+
+impl<S: PrescriptionRepository, T: PatientRepository, R: ProfessionalRepository>
+    FindPrescriptionByIdUseCase<S, T, R>
+{
+    pub fn new(prescription_repo: S, patient_repo: T, professional_repo: R) -> Self {
+        Self {
+            prescription_repo,
+            patient_repo,
+            professional_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+    ) -> Result<PrescriptionDocument, PrescriptionApplicationError> {
+        let (prescription, items) = self
+            .prescription_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(PrescriptionApplicationError::NotFound(id))?;
+
+        // Deleted patients are not shown, so neither are their prescriptions.
+        let patient = self
+            .patient_repo
+            .find_by_id(prescription.patient_id)
+            .await?
+            .ok_or(PrescriptionApplicationError::NotFound(id))?;
+
+        // The prescriber may have been deactivated since, which does not
+        // invalidate what they signed.
+        let professional = self
+            .professional_repo
+            .find_by_id(prescription.professional_id)
+            .await?
+            .ok_or(PrescriptionApplicationError::ProfessionalNotFound(
+                prescription.professional_id,
+            ))?;
+
+        Ok(PrescriptionDocument {
+            prescription,
+            items,
+            patient,
+            professional,
+        })
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::{
+        entities::professional::Professional,
+        repositories::professional_repository::ProfessionalRepository,
+    },
+};
+
+pub struct FindProfessionalByIdUseCase<T: ProfessionalRepository> {
+    professional_repo: T,
+}
+
+impl<T: ProfessionalRepository> FindProfessionalByIdUseCase<T> {
+    pub fn new(professional_repo: T) -> Self {
+        Self { professional_repo }
+    }
+
+    pub async fn execute(&self, id: i32) -> Result<Professional, ProfessionalApplicationError> {
+        self.professional_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(ProfessionalApplicationError::NotFound(id))
+    }
+}
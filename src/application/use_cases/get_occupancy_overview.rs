@@ -0,0 +1,54 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{
+        repositories::{
+            admission_repository::AdmissionRepository, patient_repository::PatientRepository,
+            ward_repository::WardRepository,
+        },
+        services::bed_occupancy::{WardOccupancy, occupancy_overview},
+    },
+};
+
+pub struct GetOccupancyOverviewUseCase<
+    W: WardRepository,
+    A: AdmissionRepository,
+    T: PatientRepository,
+> {
+    ward_repo: W,
+    admission_repo: A,
+    patient_repo: T,
+}
+
+impl<W: WardRepository, A: AdmissionRepository, T: PatientRepository>
+    GetOccupancyOverviewUseCase<W, A, T>
+{
+    pub fn new(ward_repo: W, admission_repo: A, patient_repo: T) -> Self {
+        Self {
+            ward_repo,
+            admission_repo,
+            patient_repo,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<WardOccupancy>, AdmissionApplicationError> {
+        let wards = self.ward_repo.find_wards().await?;
+        let rooms = self.ward_repo.find_rooms().await?;
+        let beds = self.ward_repo.find_beds().await?;
+        let open_admissions = self.admission_repo.find_open().await?;
+
+        let mut patients = Vec::with_capacity(open_admissions.len());
+        for (admission, _) in &open_admissions {
+            if let Some(patient) = self.patient_repo.find_by_id(admission.patient_id).await? {
+                patients.push(patient);
+            }
+        }
+
+        Ok(occupancy_overview(
+            wards,
+            &rooms,
+            beds,
+            &open_admissions,
+            &patients,
+        ))
+    }
+}
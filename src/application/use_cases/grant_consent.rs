@@ -0,0 +1,176 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::consent::Consent,
+        repositories::{
+            consent_repository::ConsentRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+    presentation::dtos::consent_dto::GrantConsentDTO,
+};
+
+pub struct GrantConsentUseCase<T: PatientRepository, C: ConsentRepository> {
+    patient_repo: T,
+    consent_repo: C,
+}
+
+impl<T: PatientRepository, C: ConsentRepository> GrantConsentUseCase<T, C> {
+    pub fn new(patient_repo: T, consent_repo: C) -> Self {
+        Self {
+            patient_repo,
+            consent_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        input: GrantConsentDTO,
+        granted_by: String,
+    ) -> Result<Consent, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let consent = Consent::new(
+            patient_id,
+            input.purpose,
+            input.term_version,
+            input.channel,
+            granted_by,
+        )?;
+
+        Ok(self.consent_repo.save(&consent).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::grant_consent::GrantConsentUseCase,
+        },
+        domain::{
+            entities::patient::Patient,
+            repositories::{
+                consent_repository::MockConsentRepository,
+                patient_repository::MockPatientRepository,
+            },
+            value_objects::{
+                consent_channel::ConsentChannel, consent_purpose::ConsentPurpose, cpf::Cpf, id::ID,
+            },
+        },
+        presentation::dtos::consent_dto::GrantConsentDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_patient_not_found() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_consent_repo = MockConsentRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_consent_repo.expect_save().times(0);
+
+        let sut = GrantConsentUseCase::new(mock_patient_repo, mock_consent_repo);
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                make_fake_input("v1"),
+                "admin@example.com".to_string(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(PatientApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_invalid_term_version_error() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_consent_repo = MockConsentRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(make_fake_patient())));
+
+        mock_consent_repo.expect_save().times(0);
+
+        let sut = GrantConsentUseCase::new(mock_patient_repo, mock_consent_repo);
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                make_fake_input(""),
+                "admin@example.com".to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PatientApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_consent_repo = MockConsentRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(make_fake_patient())));
+
+        mock_consent_repo
+            .expect_save()
+            .withf(|consent| {
+                consent.patient_id == 7
+                    && consent.purpose == ConsentPurpose::Telemedicine
+                    && consent.granted_by == "admin@example.com"
+            })
+            .times(1)
+            .returning(|consent| {
+                let mut consent = consent.clone();
+                consent.id = ID::Existing(1);
+                Ok(consent)
+            });
+
+        let sut = GrantConsentUseCase::new(mock_patient_repo, mock_consent_repo);
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                make_fake_input("v1"),
+                "admin@example.com".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.id, ID::Existing(1));
+        assert!(result.is_active());
+    }
+
+    fn make_fake_patient() -> Patient {
+        Patient::restore(7, "Andrew".to_string(), Cpf::parse("12345678909").unwrap()).unwrap()
+    }
+
+    fn make_fake_input(term_version: &str) -> GrantConsentDTO {
+        GrantConsentDTO {
+            purpose: ConsentPurpose::Telemedicine,
+            term_version: term_version.to_string(),
+            channel: ConsentChannel::Online,
+        }
+    }
+}
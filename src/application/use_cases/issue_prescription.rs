@@ -0,0 +1,431 @@
+use crate::{
+    application::{
+        errors::prescription_application_error::PrescriptionApplicationError,
+        security::clinician::find_clinician,
+    },
+    domain::{
+        entities::prescription::{Prescription, PrescriptionItem},
+        repositories::{
+            admin_repository::AdminRepository, allergy_repository::AllergyRepository,
+            appointment_repository::AppointmentRepository, patient_repository::PatientRepository,
+            prescription_repository::PrescriptionRepository,
+            professional_repository::ProfessionalRepository,
+        },
+        services::allergy_check::{AllergyWarning, allergy_warnings},
+        value_objects::cpf::Cpf,
+    },
+    presentation::dtos::prescription_dto::IssuePrescriptionDTO,
+};
+
+/// A saved prescription along with the allergies its drugs matched.
+pub struct IssuedPrescription {
+    pub prescription: Prescription,
+    pub items: Vec<PrescriptionItem>,
+    pub warnings: Vec<AllergyWarning>,
+}
+
+/// The prescription is signed by the professional the signed-in account is
+/// linked to.
+pub struct IssuePrescriptionUseCase<
+    T: PatientRepository,
+    R: ProfessionalRepository,
+    P: AppointmentRepository,
+    A: AllergyRepository,
+    S: PrescriptionRepository,
+    U: AdminRepository,
+> {
+    patient_repo: T,
+    professional_repo: R,
+    appointment_repo: P,
+    allergy_repo: A,
+    prescription_repo: S,
+    admin_repo: U,
+}
+
+impl<
+    T: PatientRepository,
+    R: ProfessionalRepository,
+    P: AppointmentRepository,
+    A: AllergyRepository,
+    S: PrescriptionRepository,
+    U: AdminRepository,
+> IssuePrescriptionUseCase<T, R, P, A, S, U>
+{
+    pub fn new(
+        patient_repo: T,
+        professional_repo: R,
+        appointment_repo: P,
+        allergy_repo: A,
+        prescription_repo: S,
+        admin_repo: U,
+    ) -> Self {
+        Self {
+            patient_repo,
+            professional_repo,
+            appointment_repo,
+            allergy_repo,
+            prescription_repo,
+            admin_repo,
+        }
+    }
+
+    /// Issues the prescription unless one of its drugs matches a severe
+    /// allergy and no override reason was given. Milder matches are only
+    /// reported back as warnings.
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        input: IssuePrescriptionDTO,
+        issued_by: String,
+    ) -> Result<IssuedPrescription, PrescriptionApplicationError> {
+        let patient = self.patient_repo.find_by_cpf(cpf.clone()).await?.ok_or(
+            PrescriptionApplicationError::PatientNotFound(cpf.to_string()),
+        )?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PrescriptionApplicationError::PatientNotFound(
+            cpf.to_string(),
+        ))?;
+
+        let prescriber = find_clinician(&self.admin_repo, &self.professional_repo, &issued_by)
+            .await?
+            .ok_or_else(|| {
+                PrescriptionApplicationError::Constraint(format!(
+                    "The account {issued_by} is not linked to an active professional"
+                ))
+            })?;
+        let professional_id: Option<i32> = prescriber.id.into();
+
+        let appointment = match input.appointment_id {
+            Some(appointment_id) => Some(
+                self.appointment_repo
+                    .find_by_id(appointment_id)
+                    .await?
+                    .ok_or(PrescriptionApplicationError::AppointmentNotFound(
+                        appointment_id,
+                    ))?,
+            ),
+            None => None,
+        };
+
+        let (prescription, items) = Prescription::new(
+            patient_id,
+            professional_id.unwrap_or_default(),
+            appointment.as_ref(),
+            input,
+            issued_by,
+        )?;
+
+        let allergies = self.allergy_repo.find_by_patient_id(patient_id).await?;
+        let warnings = allergy_warnings(&items, &allergies);
+
+        if prescription.allergy_override_reason.is_none() {
+            let mut blocking_substances: Vec<String> = warnings
+                .iter()
+                .filter(|warning| warning.is_blocking())
+                .map(|warning| warning.substance.clone())
+                .collect();
+            blocking_substances.sort_unstable();
+            blocking_substances.dedup();
+
+            if !blocking_substances.is_empty() {
+                return Err(PrescriptionApplicationError::BlockedByAllergies(
+                    blocking_substances,
+                ));
+            }
+        }
+
+        let (prescription, items) = self.prescription_repo.save(&prescription, &items).await?;
+
+        Ok(IssuedPrescription {
+            prescription,
+            items,
+            warnings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::prescription_application_error::PrescriptionApplicationError,
+            use_cases::issue_prescription::IssuePrescriptionUseCase,
+        },
+        domain::{
+            entities::{
+                admin::Admin,
+                allergy::Allergy,
+                patient::Patient,
+                prescription::{Prescription, PrescriptionItem},
+                professional::Professional,
+            },
+            repositories::{
+                admin_repository::MockAdminRepository, allergy_repository::MockAllergyRepository,
+                appointment_repository::MockAppointmentRepository,
+                patient_repository::MockPatientRepository,
+                prescription_repository::MockPrescriptionRepository,
+                professional_repository::MockProfessionalRepository,
+            },
+            value_objects::{allergy_severity::AllergySeverity, cpf::Cpf, id::ID, role::Role},
+        },
+        presentation::dtos::{
+            allergy_dto::CreateAllergyDTO,
+            prescription_dto::{IssuePrescriptionDTO, PrescriptionItemDTO},
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_inactive_professional_error() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut mock_prescription_repo = MockPrescriptionRepository::new();
+
+        let mut professional = make_fake_professional();
+        professional.active = false;
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(professional)));
+
+        mock_prescription_repo.expect_save().times(0);
+
+        let sut = IssuePrescriptionUseCase::new(
+            make_fake_patient_repo(),
+            mock_professional_repo,
+            MockAppointmentRepository::new(),
+            MockAllergyRepository::new(),
+            mock_prescription_repo,
+            make_fake_admin_repo(Some(42)),
+        );
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                make_fake_input(None),
+                "house@example.com".to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PrescriptionApplicationError::Constraint(msg)) if msg == "The account house@example.com is not linked to an active professional"
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_unlinked_account_error() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut mock_prescription_repo = MockPrescriptionRepository::new();
+
+        mock_professional_repo.expect_find_by_id().times(0);
+        mock_prescription_repo.expect_save().times(0);
+
+        let sut = IssuePrescriptionUseCase::new(
+            make_fake_patient_repo(),
+            mock_professional_repo,
+            MockAppointmentRepository::new(),
+            MockAllergyRepository::new(),
+            mock_prescription_repo,
+            make_fake_admin_repo(None),
+        );
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                make_fake_input(None),
+                "house@example.com".to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PrescriptionApplicationError::Constraint(msg)) if msg == "The account house@example.com is not linked to an active professional"
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_blocked_by_severe_allergy() {
+        let mut mock_allergy_repo = MockAllergyRepository::new();
+        let mut mock_prescription_repo = MockPrescriptionRepository::new();
+
+        mock_allergy_repo
+            .expect_find_by_patient_id()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(vec![make_fake_allergy(
+                "Amoxicillin",
+                AllergySeverity::Severe,
+            )]));
+
+        mock_prescription_repo.expect_save().times(0);
+
+        let sut = IssuePrescriptionUseCase::new(
+            make_fake_patient_repo(),
+            make_fake_professional_repo(),
+            MockAppointmentRepository::new(),
+            mock_allergy_repo,
+            mock_prescription_repo,
+            make_fake_admin_repo(Some(42)),
+        );
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                make_fake_input(None),
+                "house@example.com".to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PrescriptionApplicationError::BlockedByAllergies(substances)) if substances == vec!["Amoxicillin".to_string()]
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_severe_allergy_overridden() {
+        let mut mock_allergy_repo = MockAllergyRepository::new();
+        let mut mock_prescription_repo = MockPrescriptionRepository::new();
+
+        mock_allergy_repo
+            .expect_find_by_patient_id()
+            .times(1)
+            .return_const(Ok(vec![make_fake_allergy(
+                "Amoxicillin",
+                AllergySeverity::Severe,
+            )]));
+
+        mock_prescription_repo
+            .expect_save()
+            .withf(|prescription: &Prescription, items: &[PrescriptionItem]| {
+                prescription.allergy_override_reason.as_deref() == Some("Desensitized")
+                    && prescription.issued_by == "house@example.com"
+                    && prescription.professional_id == 42
+                    && items.len() == 1
+            })
+            .times(1)
+            .returning(|prescription, items| {
+                let mut prescription = prescription.clone();
+                prescription.id = ID::Existing(9);
+                Ok((prescription, items.to_vec()))
+            });
+
+        let sut = IssuePrescriptionUseCase::new(
+            make_fake_patient_repo(),
+            make_fake_professional_repo(),
+            MockAppointmentRepository::new(),
+            mock_allergy_repo,
+            mock_prescription_repo,
+            make_fake_admin_repo(Some(42)),
+        );
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                make_fake_input(Some("Desensitized".to_string())),
+                "house@example.com".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.prescription.id, ID::Existing(9));
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].is_blocking());
+    }
+
+    fn make_fake_patient_repo() -> MockPatientRepository {
+        let mut mock_patient_repo = MockPatientRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .returning(|cpf| {
+                Ok(Some(
+                    Patient::restore(7, "Andrew".to_string(), cpf).unwrap(),
+                ))
+            });
+
+        mock_patient_repo
+    }
+
+    fn make_fake_admin_repo(professional_id: Option<i32>) -> MockAdminRepository {
+        let mut mock_admin_repo = MockAdminRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .with(eq("house@example.com".to_string()))
+            .times(1)
+            .returning(move |email| {
+                Ok(Some(Admin {
+                    professional_id,
+                    ..Admin::new(
+                        "Gregory House".to_string(),
+                        &email,
+                        Role::Physician,
+                        "hash".to_string(),
+                    )
+                    .unwrap()
+                }))
+            });
+
+        mock_admin_repo
+    }
+
+    fn make_fake_professional_repo() -> MockProfessionalRepository {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_professional())));
+
+        mock_professional_repo
+    }
+
+    fn make_fake_professional() -> Professional {
+        Professional::restore(
+            42,
+            "Gregory House".to_string(),
+            "CRM".to_string(),
+            "123456".to_string(),
+            "SP".to_string(),
+            vec!["Nephrology".to_string()],
+            true,
+        )
+        .unwrap()
+    }
+
+    fn make_fake_allergy(substance: &str, severity: AllergySeverity) -> Allergy {
+        let mut allergy = Allergy::new(
+            7,
+            CreateAllergyDTO {
+                substance: substance.to_string(),
+                reaction: None,
+                severity,
+            },
+        )
+        .unwrap();
+        allergy.id = ID::Existing(3);
+
+        allergy
+    }
+
+    fn make_fake_input(allergy_override_reason: Option<String>) -> IssuePrescriptionDTO {
+        IssuePrescriptionDTO {
+            appointment_id: None,
+            items: vec![PrescriptionItemDTO {
+                drug: "Amoxicillin".to_string(),
+                dose: "500 mg".to_string(),
+                route: "oral".to_string(),
+                frequency: "every 8 hours".to_string(),
+                duration: Some("7 days".to_string()),
+            }],
+            notes: None,
+            allergy_override_reason,
+        }
+    }
+}
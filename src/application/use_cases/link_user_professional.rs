@@ -0,0 +1,216 @@
+use crate::{
+    application::errors::admin_application_error::AdminApplicationError,
+    domain::{
+        entities::{
+            admin::Admin,
+            audit_event::{AuditAction, AuditEvent},
+        },
+        repositories::{
+            admin_repository::AdminRepository, audit_repository::AuditRepository,
+            professional_repository::ProfessionalRepository,
+        },
+    },
+    presentation::dtos::admin_dto::LinkProfessionalDTO,
+};
+
+/// Clinical records are credited to the professional an account is linked
+/// to, so only an active professional can be linked, and to one account.
+pub struct LinkUserProfessionalUseCase<
+    T: AdminRepository,
+    R: ProfessionalRepository,
+    A: AuditRepository,
+> {
+    admin_repo: T,
+    professional_repo: R,
+    audit_repo: A,
+}
+
+impl<T: AdminRepository, R: ProfessionalRepository, A: AuditRepository>
+    LinkUserProfessionalUseCase<T, R, A>
+{
+    pub fn new(admin_repo: T, professional_repo: R, audit_repo: A) -> Self {
+        Self {
+            admin_repo,
+            professional_repo,
+            audit_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        input: LinkProfessionalDTO,
+        actor: String,
+    ) -> Result<Admin, AdminApplicationError> {
+        let mut admin = self
+            .admin_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(AdminApplicationError::NotFoundById(id))?;
+
+        if let Some(professional_id) = input.professional_id {
+            let professional = self.professional_repo.find_by_id(professional_id).await?;
+
+            if !professional.is_some_and(|professional| professional.active) {
+                return Err(AdminApplicationError::Constraint(format!(
+                    "No active professional with the ID {professional_id}"
+                )));
+            }
+        }
+
+        if admin.professional_id == input.professional_id {
+            return Ok(admin);
+        }
+        admin.professional_id = input.professional_id;
+
+        let admin = self.admin_repo.update(&admin).await?;
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                actor,
+                AuditAction::UserProfessionalLinked,
+                "user",
+                Some(id),
+                input.professional_id.map(|id| format!("professional {id}")),
+            ))
+            .await?;
+
+        Ok(admin)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            use_cases::link_user_professional::LinkUserProfessionalUseCase,
+        },
+        domain::{
+            entities::{admin::Admin, audit_event::AuditEvent, professional::Professional},
+            repositories::{
+                admin_repository::MockAdminRepository, audit_repository::MockAuditRepository,
+                professional_repository::MockProfessionalRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+        presentation::dtos::admin_dto::LinkProfessionalDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_inactive_professional_error() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin())));
+        mock_professional_repo
+            .expect_find_by_id()
+            .with(eq(13))
+            .times(1)
+            .return_const(Ok(Some(make_fake_professional(false))));
+        mock_admin_repo.expect_update().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = LinkUserProfessionalUseCase::new(
+            mock_admin_repo,
+            mock_professional_repo,
+            mock_audit_repo,
+        );
+
+        let result = sut
+            .execute(
+                9,
+                LinkProfessionalDTO {
+                    professional_id: Some(13),
+                },
+                "admin@sghss.com".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(AdminApplicationError::Constraint(
+                "No active professional with the ID 13".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin())));
+        mock_professional_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_professional(true))));
+        mock_admin_repo
+            .expect_update()
+            .withf(|admin: &Admin| admin.professional_id == Some(13))
+            .times(1)
+            .returning(|admin| Ok(admin.clone()));
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.action == "user_professional_linked" && event.entity_id == Some(9)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = LinkUserProfessionalUseCase::new(
+            mock_admin_repo,
+            mock_professional_repo,
+            mock_audit_repo,
+        );
+
+        let result = sut
+            .execute(
+                9,
+                LinkProfessionalDTO {
+                    professional_id: Some(13),
+                },
+                "admin@sghss.com".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.professional_id, Some(13));
+    }
+
+    fn make_fake_admin() -> Admin {
+        Admin {
+            id: ID::Existing(9),
+            ..Admin::new(
+                "Remy Hadley".to_string(),
+                "hadley@sghss.com",
+                Role::Nurse,
+                "hash".to_string(),
+            )
+            .unwrap()
+        }
+    }
+
+    fn make_fake_professional(active: bool) -> Professional {
+        Professional::restore(
+            13,
+            "Remy Hadley".to_string(),
+            "COREN".to_string(),
+            "654321".to_string(),
+            "SP".to_string(),
+            vec!["Nursing".to_string()],
+            active,
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{
+        entities::admission::{Admission, BedOccupancy},
+        repositories::{
+            admission_repository::AdmissionRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct ListAdmissionsByPatientCpfUseCase<T: PatientRepository, A: AdmissionRepository> {
+    patient_repo: T,
+    admission_repo: A,
+}
+
+impl<T: PatientRepository, A: AdmissionRepository> ListAdmissionsByPatientCpfUseCase<T, A> {
+    pub fn new(patient_repo: T, admission_repo: A) -> Self {
+        Self {
+            patient_repo,
+            admission_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+    ) -> Result<Vec<(Admission, Vec<BedOccupancy>)>, AdmissionApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(AdmissionApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id =
+            patient_id.ok_or(AdmissionApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        Ok(self.admission_repo.find_by_patient_id(patient_id).await?)
+    }
+}
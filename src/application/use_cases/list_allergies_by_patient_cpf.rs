@@ -0,0 +1,37 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::allergy::Allergy,
+        repositories::{
+            allergy_repository::AllergyRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct ListAllergiesByPatientCpfUseCase<T: PatientRepository, A: AllergyRepository> {
+    patient_repo: T,
+    allergy_repo: A,
+}
+
+impl<T: PatientRepository, A: AllergyRepository> ListAllergiesByPatientCpfUseCase<T, A> {
+    pub fn new(patient_repo: T, allergy_repo: A) -> Self {
+        Self {
+            patient_repo,
+            allergy_repo,
+        }
+    }
+
+    pub async fn execute(&self, cpf: Cpf) -> Result<Vec<Allergy>, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        Ok(self.allergy_repo.find_by_patient_id(patient_id).await?)
+    }
+}
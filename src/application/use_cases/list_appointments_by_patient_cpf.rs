@@ -5,6 +5,7 @@ use crate::{
         repositories::{
             appointment_repository::AppointmentRepository, patient_repository::PatientRepository,
         },
+        value_objects::cpf::Cpf,
     },
 };
 
@@ -21,20 +22,21 @@ impl<T: PatientRepository, P: AppointmentRepository> ListAppointmentsByPatientCp
         }
     }
 
-    pub async fn execute(
-        &self,
-        cpf: String,
-    ) -> Result<Vec<Appointment>, AppointmentApplicationError> {
+    pub async fn execute(&self, cpf: Cpf) -> Result<Vec<Appointment>, AppointmentApplicationError> {
         let patient = self.patient_repo.find_by_cpf(cpf.clone()).await?;
 
         if patient.is_none() {
-            return Err(AppointmentApplicationError::PatientNotFound(cpf));
+            return Err(AppointmentApplicationError::PatientNotFound(
+                cpf.to_string(),
+            ));
         }
 
         let patient = patient.unwrap();
 
         if !patient.id.is_existing() {
-            return Err(AppointmentApplicationError::PatientNotFound(cpf));
+            return Err(AppointmentApplicationError::PatientNotFound(
+                cpf.to_string(),
+            ));
         }
 
         let patient_id: Option<i32> = patient.id.into();
@@ -0,0 +1,221 @@
+use chrono::{Duration, NaiveDate, NaiveTime};
+
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::{
+        repositories::{
+            appointment_repository::AppointmentRepository,
+            professional_repository::ProfessionalRepository,
+            schedule_repository::ScheduleRepository,
+        },
+        services::availability::{
+            DEFAULT_SLOT_DURATION_MINUTES, MAX_SLOT_DURATION_MINUTES, MIN_SLOT_DURATION_MINUTES,
+            available_slots,
+        },
+        value_objects::time_slot::TimeSlot,
+    },
+    presentation::dtos::schedule_dto::AvailableSlotsQueryDTO,
+};
+
+const MAX_RANGE_DAYS: i64 = 31;
+
+pub struct ListAvailableSlotsUseCase<
+    T: ProfessionalRepository,
+    S: ScheduleRepository,
+    A: AppointmentRepository,
+> {
+    professional_repo: T,
+    schedule_repo: S,
+    appointment_repo: A,
+}
+
+impl<T: ProfessionalRepository, S: ScheduleRepository, A: AppointmentRepository>
+    ListAvailableSlotsUseCase<T, S, A>
+{
+    pub fn new(professional_repo: T, schedule_repo: S, appointment_repo: A) -> Self {
+        Self {
+            professional_repo,
+            schedule_repo,
+            appointment_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        professional_id: i32,
+        query: AvailableSlotsQueryDTO,
+    ) -> Result<Vec<TimeSlot>, ProfessionalApplicationError> {
+        let from = query.from.parse::<NaiveDate>()?;
+        let to = query.to.parse::<NaiveDate>()?;
+        let duration = query.duration.unwrap_or(DEFAULT_SLOT_DURATION_MINUTES);
+
+        if from > to || (to - from).num_days() >= MAX_RANGE_DAYS {
+            return Err(ProfessionalApplicationError::Constraint(format!(
+                "The date range must go forward and span at most {MAX_RANGE_DAYS} days"
+            )));
+        }
+
+        if !(MIN_SLOT_DURATION_MINUTES..=MAX_SLOT_DURATION_MINUTES).contains(&duration) {
+            return Err(ProfessionalApplicationError::Constraint(format!(
+                "The slot duration must be between {MIN_SLOT_DURATION_MINUTES} and {MAX_SLOT_DURATION_MINUTES} minutes"
+            )));
+        }
+
+        let professional = self
+            .professional_repo
+            .find_by_id(professional_id)
+            .await?
+            .ok_or(ProfessionalApplicationError::NotFound(professional_id))?;
+
+        if !professional.active {
+            return Ok(Vec::new());
+        }
+
+        let range_start = from.and_time(NaiveTime::MIN);
+        let range_end = to.succ_opt().unwrap_or(to).and_time(NaiveTime::MIN);
+
+        let schedules = self
+            .schedule_repo
+            .find_schedules_by_professional_id(professional_id)
+            .await?;
+        let exceptions = self
+            .schedule_repo
+            .find_exceptions_by_professional_id_between(professional_id, range_start, range_end)
+            .await?;
+        let busy: Vec<TimeSlot> = self
+            .appointment_repo
+            .find_by_professional_id_between(professional_id, range_start, range_end)
+            .await?
+            .iter()
+            .map(|appointment| appointment.time_slot())
+            .collect();
+
+        Ok(available_slots(
+            &schedules,
+            &exceptions,
+            &busy,
+            from,
+            to,
+            Duration::minutes(duration),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime, NaiveTime};
+
+    use crate::{
+        application::{
+            errors::professional_application_error::ProfessionalApplicationError,
+            use_cases::list_available_slots::ListAvailableSlotsUseCase,
+        },
+        domain::{
+            entities::{
+                appointment::Appointment, professional::Professional,
+                professional_schedule::ProfessionalSchedule,
+            },
+            repositories::{
+                appointment_repository::MockAppointmentRepository,
+                professional_repository::MockProfessionalRepository,
+                schedule_repository::MockScheduleRepository,
+            },
+        },
+        presentation::dtos::schedule_dto::AvailableSlotsQueryDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_invalid_range_error() {
+        let mock_professional_repo = MockProfessionalRepository::new();
+        let mock_schedule_repo = MockScheduleRepository::new();
+        let mock_appointment_repo = MockAppointmentRepository::new();
+
+        let sut = ListAvailableSlotsUseCase::new(
+            mock_professional_repo,
+            mock_schedule_repo,
+            mock_appointment_repo,
+        );
+
+        let result = sut
+            .execute(42, make_fake_query("2025-06-15", "2025-06-09"))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ProfessionalApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut mock_schedule_repo = MockScheduleRepository::new();
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(Professional::restore(
+                42,
+                "Gregory House".to_string(),
+                "CRM".to_string(),
+                "123456".to_string(),
+                "SP".to_string(),
+                vec!["Nephrology".to_string()],
+                true,
+            )?)));
+
+        mock_schedule_repo
+            .expect_find_schedules_by_professional_id()
+            .times(1)
+            .return_const(Ok(vec![ProfessionalSchedule::new(
+                42,
+                1,
+                "08:00".parse::<NaiveTime>()?,
+                "09:00".parse::<NaiveTime>()?,
+            )?]));
+
+        mock_schedule_repo
+            .expect_find_exceptions_by_professional_id_between()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        mock_appointment_repo
+            .expect_find_by_professional_id_between()
+            .times(1)
+            .return_const(Ok(vec![Appointment::new(
+                7,
+                Some(42),
+                "2025-06-09T08:00:00".parse::<NaiveDateTime>()?,
+                Duration::minutes(30),
+                "Nephrology".to_string(),
+                None,
+            )?]));
+
+        let sut = ListAvailableSlotsUseCase::new(
+            mock_professional_repo,
+            mock_schedule_repo,
+            mock_appointment_repo,
+        );
+
+        let result = sut
+            .execute(42, make_fake_query("2025-06-09", "2025-06-10"))
+            .await?;
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].starts_at,
+            "2025-06-09T08:30:00".parse::<NaiveDateTime>()?
+        );
+
+        Ok(())
+    }
+
+    fn make_fake_query(from: &str, to: &str) -> AvailableSlotsQueryDTO {
+        AvailableSlotsQueryDTO {
+            from: from.to_string(),
+            to: to.to_string(),
+            duration: None,
+        }
+    }
+}
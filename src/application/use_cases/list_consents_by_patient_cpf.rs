@@ -0,0 +1,37 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::consent::Consent,
+        repositories::{
+            consent_repository::ConsentRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct ListConsentsByPatientCpfUseCase<T: PatientRepository, C: ConsentRepository> {
+    patient_repo: T,
+    consent_repo: C,
+}
+
+impl<T: PatientRepository, C: ConsentRepository> ListConsentsByPatientCpfUseCase<T, C> {
+    pub fn new(patient_repo: T, consent_repo: C) -> Self {
+        Self {
+            patient_repo,
+            consent_repo,
+        }
+    }
+
+    pub async fn execute(&self, cpf: Cpf) -> Result<Vec<Consent>, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        Ok(self.consent_repo.find_by_patient_id(patient_id).await?)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::document::Document,
+        repositories::{
+            document_repository::DocumentRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct ListDocumentsByPatientCpfUseCase<T: PatientRepository, D: DocumentRepository> {
+    patient_repo: T,
+    document_repo: D,
+}
+
+impl<T: PatientRepository, D: DocumentRepository> ListDocumentsByPatientCpfUseCase<T, D> {
+    pub fn new(patient_repo: T, document_repo: D) -> Self {
+        Self {
+            patient_repo,
+            document_repo,
+        }
+    }
+
+    pub async fn execute(&self, cpf: Cpf) -> Result<Vec<Document>, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        Ok(self.document_repo.find_by_patient_id(patient_id).await?)
+    }
+}
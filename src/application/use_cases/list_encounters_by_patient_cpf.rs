@@ -0,0 +1,40 @@
+use crate::{
+    application::errors::encounter_application_error::EncounterApplicationError,
+    domain::{
+        entities::encounter::Encounter,
+        repositories::{
+            encounter_repository::EncounterRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct ListEncountersByPatientCpfUseCase<T: PatientRepository, E: EncounterRepository> {
+    patient_repo: T,
+    encounter_repo: E,
+}
+
+impl<T: PatientRepository, E: EncounterRepository> ListEncountersByPatientCpfUseCase<T, E> {
+    pub fn new(patient_repo: T, encounter_repo: E) -> Self {
+        Self {
+            patient_repo,
+            encounter_repo,
+        }
+    }
+
+    /// The patient's full clinical record, amendments included, in the order
+    /// it was written.
+    pub async fn execute(&self, cpf: Cpf) -> Result<Vec<Encounter>, EncounterApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(EncounterApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id =
+            patient_id.ok_or(EncounterApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        Ok(self.encounter_repo.find_by_patient_id(patient_id).await?)
+    }
+}
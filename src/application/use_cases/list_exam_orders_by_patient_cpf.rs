@@ -0,0 +1,41 @@
+use crate::{
+    application::errors::exam_order_application_error::ExamOrderApplicationError,
+    domain::{
+        entities::exam_order::{ExamOrder, ExamResult},
+        repositories::{
+            exam_order_repository::ExamOrderRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct ListExamOrdersByPatientCpfUseCase<T: PatientRepository, X: ExamOrderRepository> {
+    patient_repo: T,
+    exam_order_repo: X,
+}
+
+impl<T: PatientRepository, X: ExamOrderRepository> ListExamOrdersByPatientCpfUseCase<T, X> {
+    pub fn new(patient_repo: T, exam_order_repo: X) -> Self {
+        Self {
+            patient_repo,
+            exam_order_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+    ) -> Result<Vec<(ExamOrder, Vec<ExamResult>)>, ExamOrderApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(ExamOrderApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id =
+            patient_id.ok_or(ExamOrderApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        Ok(self.exam_order_repo.find_by_patient_id(patient_id).await?)
+    }
+}
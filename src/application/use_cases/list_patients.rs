@@ -0,0 +1,136 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::patient::Patient,
+        repositories::patient_repository::PatientRepository,
+        value_objects::pagination::{Page, Pagination},
+    },
+    presentation::dtos::patient_dto::ListPatientsQueryDTO,
+};
+
+pub struct ListPatientsUseCase<T: PatientRepository> {
+    patient_repo: T,
+}
+
+impl<T: PatientRepository> ListPatientsUseCase<T> {
+    pub fn new(patient_repo: T) -> Self {
+        Self { patient_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ListPatientsQueryDTO,
+    ) -> Result<Page<Patient>, PatientApplicationError> {
+        let name = query
+            .name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+
+        self.patient_repo
+            .search(name, Pagination::new(query.page, query.limit))
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::use_cases::list_patients::ListPatientsUseCase,
+        domain::{
+            entities::patient::Patient,
+            errors::repository_error::RepositoryError,
+            repositories::patient_repository::MockPatientRepository,
+            value_objects::{
+                cpf::Cpf,
+                pagination::{Page, Pagination},
+            },
+        },
+        presentation::dtos::patient_dto::ListPatientsQueryDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_patient_repository_error() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+
+        mock_patient_repo.expect_search().times(1).return_const(Err(
+            RepositoryError::DatabaseError("Fake Error".to_string()),
+        ));
+
+        let sut = ListPatientsUseCase::new(mock_patient_repo);
+
+        let result = sut
+            .execute(ListPatientsQueryDTO {
+                name: None,
+                page: None,
+                limit: None,
+            })
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_blank_name_lists_all() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_patient_repo = MockPatientRepository::new();
+
+        mock_patient_repo
+            .expect_search()
+            .with(eq(None), eq(Pagination::default()))
+            .times(1)
+            .return_const(Ok(Page {
+                items: vec![],
+                total: 0,
+                pagination: Pagination::default(),
+            }));
+
+        let sut = ListPatientsUseCase::new(mock_patient_repo);
+
+        let result = sut
+            .execute(ListPatientsQueryDTO {
+                name: Some("   ".to_string()),
+                page: None,
+                limit: None,
+            })
+            .await?;
+
+        assert_eq!(result.total, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_patient_repo = MockPatientRepository::new();
+
+        let fake_patient = Patient::restore(42, "José".to_string(), Cpf::parse("00011122285")?)?;
+        let pagination = Pagination::new(Some(1), Some(10));
+
+        mock_patient_repo
+            .expect_search()
+            .with(eq(Some("jose".to_string())), eq(pagination))
+            .times(1)
+            .return_const(Ok(Page {
+                items: vec![fake_patient.clone()],
+                total: 1,
+                pagination,
+            }));
+
+        let sut = ListPatientsUseCase::new(mock_patient_repo);
+
+        let result = sut
+            .execute(ListPatientsQueryDTO {
+                name: Some(" jose ".to_string()),
+                page: Some(1),
+                limit: Some(10),
+            })
+            .await?;
+
+        assert_eq!(result.items, vec![fake_patient]);
+        assert_eq!(result.total_pages(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    application::errors::prescription_application_error::PrescriptionApplicationError,
+    domain::{
+        entities::prescription::{Prescription, PrescriptionItem},
+        repositories::{
+            patient_repository::PatientRepository, prescription_repository::PrescriptionRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct ListPrescriptionsByPatientCpfUseCase<T: PatientRepository, S: PrescriptionRepository> {
+    patient_repo: T,
+    prescription_repo: S,
+}
+
+impl<T: PatientRepository, S: PrescriptionRepository> ListPrescriptionsByPatientCpfUseCase<T, S> {
+    pub fn new(patient_repo: T, prescription_repo: S) -> Self {
+        Self {
+            patient_repo,
+            prescription_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+    ) -> Result<Vec<(Prescription, Vec<PrescriptionItem>)>, PrescriptionApplicationError> {
+        let patient = self.patient_repo.find_by_cpf(cpf.clone()).await?.ok_or(
+            PrescriptionApplicationError::PatientNotFound(cpf.to_string()),
+        )?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PrescriptionApplicationError::PatientNotFound(
+            cpf.to_string(),
+        ))?;
+
+        Ok(self
+            .prescription_repo
+            .find_by_patient_id(patient_id)
+            .await?)
+    }
+}
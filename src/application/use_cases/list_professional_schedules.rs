@@ -0,0 +1,27 @@
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::{
+        entities::professional_schedule::ProfessionalSchedule,
+        repositories::schedule_repository::ScheduleRepository,
+    },
+};
+
+pub struct ListProfessionalSchedulesUseCase<S: ScheduleRepository> {
+    schedule_repo: S,
+}
+
+impl<S: ScheduleRepository> ListProfessionalSchedulesUseCase<S> {
+    pub fn new(schedule_repo: S) -> Self {
+        Self { schedule_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        professional_id: i32,
+    ) -> Result<Vec<ProfessionalSchedule>, ProfessionalApplicationError> {
+        self.schedule_repo
+            .find_schedules_by_professional_id(professional_id)
+            .await
+            .map_err(|err| err.into())
+    }
+}
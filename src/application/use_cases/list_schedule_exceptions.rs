@@ -0,0 +1,44 @@
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::{
+        entities::schedule_exception::ScheduleException,
+        repositories::schedule_repository::ScheduleRepository,
+    },
+    presentation::dtos::schedule_dto::DateRangeQueryDTO,
+};
+
+pub struct ListScheduleExceptionsUseCase<S: ScheduleRepository> {
+    schedule_repo: S,
+}
+
+impl<S: ScheduleRepository> ListScheduleExceptionsUseCase<S> {
+    pub fn new(schedule_repo: S) -> Self {
+        Self { schedule_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        professional_id: i32,
+        range: DateRangeQueryDTO,
+    ) -> Result<Vec<ScheduleException>, ProfessionalApplicationError> {
+        let from = range.from.parse::<NaiveDate>()?;
+        let to = range.to.parse::<NaiveDate>()?;
+
+        if from > to {
+            return Err(ProfessionalApplicationError::Constraint(format!(
+                "The start date {from} is after the end date {to}"
+            )));
+        }
+
+        self.schedule_repo
+            .find_exceptions_by_professional_id_between(
+                professional_id,
+                from.and_time(NaiveTime::MIN),
+                to.succ_opt().unwrap_or(to).and_time(NaiveTime::MIN),
+            )
+            .await
+            .map_err(|err| err.into())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{
+    application::errors::admin_application_error::AdminApplicationError,
+    domain::{entities::admin::Admin, repositories::admin_repository::AdminRepository},
+};
+
+pub struct ListUsersUseCase<T: AdminRepository> {
+    admin_repo: T,
+}
+
+impl<T: AdminRepository> ListUsersUseCase<T> {
+    pub fn new(admin_repo: T) -> Self {
+        Self { admin_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<Admin>, AdminApplicationError> {
+        Ok(self.admin_repo.find_all().await?)
+    }
+}
@@ -1,46 +1,468 @@
-use bcrypt::verify;
+use chrono::Duration;
 
 use crate::{
     application::{
-        errors::admin_application_error::AdminApplicationError, security::jwt::jwt::create_jwt,
+        errors::admin_application_error::AdminApplicationError,
+        security::{
+            login_throttle::{ACCOUNT_THROTTLE, ADDRESS_THROTTLE, FAILURE_WINDOW_MINUTES},
+            password::{verify_password, verify_unknown_password},
+            refresh_token::{RefreshToken, TokenPair, hash_secret},
+        },
+    },
+    domain::{
+        entities::{
+            audit_event::{AuditAction, AuditEvent},
+            failed_login::{FailedLogin, RecordedAttempt},
+            session::Session,
+        },
+        repositories::{
+            admin_repository::AdminRepository, audit_repository::AuditRepository,
+            failed_login_repository::FailedLoginRepository, session_repository::SessionRepository,
+        },
+        value_objects::{email::EMAIL_MAX_LENGTH, id::ID},
     },
-    domain::repositories::admin_repository::AdminRepository,
     presentation::dtos::admin_dto::LoginDTO,
 };
 
-pub struct LoginUseCase<T: AdminRepository> {
+pub struct LoginUseCase<
+    T: AdminRepository,
+    S: SessionRepository,
+    F: FailedLoginRepository,
+    A: AuditRepository,
+> {
     admin_repo: T,
+    session_repo: S,
+    failed_login_repo: F,
+    audit_repo: A,
 }
 
-impl<T: AdminRepository> LoginUseCase<T> {
-    pub fn new(admin_repo: T) -> Self {
-        Self { admin_repo }
+impl<T: AdminRepository, S: SessionRepository, F: FailedLoginRepository, A: AuditRepository>
+    LoginUseCase<T, S, F, A>
+{
+    pub fn new(admin_repo: T, session_repo: S, failed_login_repo: F, audit_repo: A) -> Self {
+        Self {
+            admin_repo,
+            session_repo,
+            failed_login_repo,
+            audit_repo,
+        }
     }
 
-    pub async fn execute(&self, input: LoginDTO) -> Result<String, AdminApplicationError> {
-        let admin = self.admin_repo.find_by_email(input.email.clone()).await?;
+    /// Checks the credentials and opens a new session. Repeated failures for
+    /// the e-mail or from `ip_address` make further attempts wait, and
+    /// eventually lock them out. Unknown e-mails, wrong passwords and
+    /// deactivated accounts are rejected alike, in about the same time.
+    pub async fn execute(
+        &self,
+        input: LoginDTO,
+        ip_address: String,
+    ) -> Result<TokenPair, AdminApplicationError> {
+        let email = input.email.trim().to_lowercase();
+
+        // No account can have it, and it would not fit in the attempt log.
+        if email.chars().count() > EMAIL_MAX_LENGTH {
+            return Err(AdminApplicationError::InvalidCredentials);
+        }
+
+        let now = chrono::Local::now().naive_utc();
+        let since = now - Duration::minutes(FAILURE_WINDOW_MINUTES);
 
-        if admin.is_none() {
-            return Err(AdminApplicationError::NotFound(input.email));
+        self.failed_login_repo.delete_older_than(since).await?;
+
+        // The attempt counts as a failure until proven otherwise, so
+        // concurrent guesses cannot all slip in under the same count.
+        let attempt = self
+            .failed_login_repo
+            .record_attempt(
+                &FailedLogin::new(email.clone(), ip_address.clone(), now),
+                since,
+            )
+            .await?;
+
+        let retry_after = ACCOUNT_THROTTLE
+            .retry_after(&attempt.account_failures, now)
+            .max(ADDRESS_THROTTLE.retry_after(&attempt.address_failures, now));
+
+        if let Some(retry_after) = retry_after {
+            // Hammering a locked e-mail must not keep it locked forever.
+            self.failed_login_repo.delete(attempt.id).await?;
+            return Err(AdminApplicationError::TooManyAttempts(retry_after));
         }
 
-        let admin = admin.unwrap();
-        let admin_email = admin.email.clone();
-        let password_hash = admin.password_hash.clone();
-
-        if verify(&input.password, &password_hash).unwrap_or(false) {
-            let token = create_jwt(admin_email.clone());
-            if token.is_none() {
-                return Err(AdminApplicationError::LoginFailed(format!(
-                    "Could not generate JWT token for admin {}",
-                    admin_email
-                )));
+        let admin = self.admin_repo.find_by_email(email.clone()).await?;
+        let password_matches = match &admin {
+            Some(admin) => verify_password(&input.password, &admin.password_hash),
+            None => verify_unknown_password(&input.password),
+        };
+
+        let admin = match admin {
+            Some(admin) if password_matches && admin.active => admin,
+            admin => {
+                let admin_id = admin.and_then(|admin| admin.id.into());
+                self.audit_lockouts(&email, &ip_address, admin_id, &attempt)
+                    .await?;
+
+                return Err(AdminApplicationError::InvalidCredentials);
             }
-            return Ok(token.unwrap());
         };
 
-        Err(AdminApplicationError::LoginFailed(format!(
-            "The provided credentials are invalid: {admin_email}"
-        )))
+        self.failed_login_repo.delete_by_email(email).await?;
+
+        let ID::Existing(admin_id) = admin.id else {
+            return Err(AdminApplicationError::InvalidCredentials);
+        };
+
+        let secret = RefreshToken::generate_secret();
+        let session_id = self
+            .session_repo
+            .save(&Session::start(admin_id, hash_secret(&secret)))
+            .await?;
+
+        TokenPair::issue(&admin, session_id, secret).ok_or(AdminApplicationError::LoginFailed(
+            format!("Could not generate JWT token for admin {}", admin.email),
+        ))
+    }
+
+    /// Audits the lockouts the failed `attempt` triggers.
+    async fn audit_lockouts(
+        &self,
+        email: &str,
+        ip_address: &str,
+        admin_id: Option<i32>,
+        attempt: &RecordedAttempt,
+    ) -> Result<(), AdminApplicationError> {
+        if ACCOUNT_THROTTLE.locks_after(attempt.account_failures.len()) {
+            self.audit_repo
+                .save(&AuditEvent::new(
+                    "system".to_string(),
+                    AuditAction::AccountLocked,
+                    "user",
+                    admin_id,
+                    Some(format!(
+                        "{} failed logins for {email}, the last from {ip_address}",
+                        ACCOUNT_THROTTLE.max_failures
+                    )),
+                ))
+                .await?;
+        }
+
+        if ADDRESS_THROTTLE.locks_after(attempt.address_failures.len()) {
+            self.audit_repo
+                .save(&AuditEvent::new(
+                    "system".to_string(),
+                    AuditAction::AddressLocked,
+                    "ip_address",
+                    None,
+                    Some(format!(
+                        "{} failed logins from {ip_address}",
+                        ADDRESS_THROTTLE.max_failures
+                    )),
+                ))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            security::{
+                jwt::jwt::validate_jwt,
+                refresh_token::{RefreshToken, TokenPair},
+            },
+            use_cases::login::LoginUseCase,
+        },
+        domain::{
+            entities::{
+                admin::Admin,
+                audit_event::AuditEvent,
+                failed_login::{FailedLogin, RecordedAttempt},
+                session::Session,
+            },
+            repositories::{
+                admin_repository::MockAdminRepository, audit_repository::MockAuditRepository,
+                failed_login_repository::MockFailedLoginRepository,
+                session_repository::MockSessionRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+        presentation::dtos::admin_dto::LoginDTO,
+    };
+
+    fn make_fake_admin(active: bool) -> Admin {
+        Admin {
+            id: ID::Existing(3),
+            active,
+            ..Admin::new(
+                "Eric Foreman".to_string(),
+                "foreman@sghss.com",
+                Role::Physician,
+                bcrypt::hash("Neurology-Rules-1", 4).unwrap(),
+            )
+            .unwrap()
+        }
+    }
+
+    fn make_input(password: &str) -> LoginDTO {
+        LoginDTO {
+            email: "Foreman@SGHSS.com".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    fn make_failures(count: usize, minutes_ago: i64) -> Vec<NaiveDateTime> {
+        let attempted_at = chrono::Local::now().naive_utc() - Duration::minutes(minutes_ago);
+
+        vec![attempted_at; count]
+    }
+
+    fn mock_failed_login_repo(
+        account_failures: Vec<NaiveDateTime>,
+        address_failures: Vec<NaiveDateTime>,
+    ) -> MockFailedLoginRepository {
+        let mut mock_failed_login_repo = MockFailedLoginRepository::new();
+
+        mock_failed_login_repo
+            .expect_delete_older_than()
+            .return_const(Ok(0));
+        mock_failed_login_repo
+            .expect_record_attempt()
+            .withf(|attempt: &FailedLogin, _| {
+                attempt.email == "foreman@sghss.com" && attempt.ip_address == "10.0.0.7"
+            })
+            .times(1)
+            .return_const(Ok(RecordedAttempt {
+                id: 7,
+                account_failures,
+                address_failures,
+            }));
+
+        mock_failed_login_repo
+    }
+
+    async fn login(
+        mock_admin_repo: MockAdminRepository,
+        mock_session_repo: MockSessionRepository,
+        mock_failed_login_repo: MockFailedLoginRepository,
+        mock_audit_repo: MockAuditRepository,
+        password: &str,
+    ) -> Result<TokenPair, AdminApplicationError> {
+        LoginUseCase::new(
+            mock_admin_repo,
+            mock_session_repo,
+            mock_failed_login_repo,
+            mock_audit_repo,
+        )
+        .execute(make_input(password), "10.0.0.7".to_string())
+        .await
+    }
+
+    #[tokio::test]
+    async fn execute_unknown_email() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_failed_login_repo = mock_failed_login_repo(vec![], vec![]);
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(None));
+        mock_failed_login_repo.expect_delete_by_email().times(0);
+
+        let result = login(
+            mock_admin_repo,
+            MockSessionRepository::new(),
+            mock_failed_login_repo,
+            MockAuditRepository::new(),
+            "Neurology-Rules-1",
+        )
+        .await;
+
+        assert_eq!(result, Err(AdminApplicationError::InvalidCredentials));
+    }
+
+    #[tokio::test]
+    async fn execute_wrong_password() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_failed_login_repo = mock_failed_login_repo(vec![], vec![]);
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin(true))));
+        mock_failed_login_repo.expect_delete_by_email().times(0);
+        mock_session_repo.expect_save().times(0);
+
+        let result = login(
+            mock_admin_repo,
+            mock_session_repo,
+            mock_failed_login_repo,
+            MockAuditRepository::new(),
+            "Oncology-Rules-1",
+        )
+        .await;
+
+        assert_eq!(result, Err(AdminApplicationError::InvalidCredentials));
+    }
+
+    #[tokio::test]
+    async fn execute_deactivated_account() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_failed_login_repo = mock_failed_login_repo(vec![], vec![]);
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin(false))));
+        mock_failed_login_repo.expect_delete_by_email().times(0);
+        mock_session_repo.expect_save().times(0);
+
+        let result = login(
+            mock_admin_repo,
+            mock_session_repo,
+            mock_failed_login_repo,
+            MockAuditRepository::new(),
+            "Neurology-Rules-1",
+        )
+        .await;
+
+        assert_eq!(result, Err(AdminApplicationError::InvalidCredentials));
+    }
+
+    #[tokio::test]
+    async fn execute_email_too_long() {
+        let mut mock_failed_login_repo = MockFailedLoginRepository::new();
+
+        mock_failed_login_repo.expect_record_attempt().times(0);
+
+        let sut = LoginUseCase::new(
+            MockAdminRepository::new(),
+            MockSessionRepository::new(),
+            mock_failed_login_repo,
+            MockAuditRepository::new(),
+        );
+
+        let result = sut
+            .execute(
+                LoginDTO {
+                    email: format!("{}@sghss.com", "a".repeat(150)),
+                    password: "Neurology-Rules-1".to_string(),
+                },
+                "10.0.0.7".to_string(),
+            )
+            .await;
+
+        assert_eq!(result, Err(AdminApplicationError::InvalidCredentials));
+    }
+
+    #[tokio::test]
+    async fn execute_throttled() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_failed_login_repo =
+            mock_failed_login_repo(make_failures(10, 5), make_failures(10, 5));
+
+        mock_failed_login_repo
+            .expect_delete()
+            .with(eq(7))
+            .times(1)
+            .return_const(Ok(()));
+        mock_admin_repo.expect_find_by_email().times(0);
+
+        let result = login(
+            mock_admin_repo,
+            MockSessionRepository::new(),
+            mock_failed_login_repo,
+            MockAuditRepository::new(),
+            "Neurology-Rules-1",
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(AdminApplicationError::TooManyAttempts(retry_after)) if retry_after > 590 && retry_after <= 600
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_locks_account() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin(true))));
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.action == "account_locked"
+                    && event.entity_id == Some(3)
+                    && event.details.as_deref()
+                        == Some("10 failed logins for foreman@sghss.com, the last from 10.0.0.7")
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = login(
+            mock_admin_repo,
+            MockSessionRepository::new(),
+            mock_failed_login_repo(make_failures(9, 2), vec![]),
+            mock_audit_repo,
+            "Oncology-Rules-1",
+        )
+        .await;
+
+        assert_eq!(result, Err(AdminApplicationError::InvalidCredentials));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_failed_login_repo = mock_failed_login_repo(make_failures(2, 2), vec![]);
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .withf(|email| email == "foreman@sghss.com")
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin(true))));
+        mock_failed_login_repo
+            .expect_delete_by_email()
+            .withf(|email| email == "foreman@sghss.com")
+            .times(1)
+            .return_const(Ok(3));
+        mock_session_repo
+            .expect_save()
+            .withf(|session: &Session| session.admin_id == 3 && session.revoked_at.is_none())
+            .times(1)
+            .return_const(Ok(12));
+
+        let tokens = login(
+            mock_admin_repo,
+            mock_session_repo,
+            mock_failed_login_repo,
+            MockAuditRepository::new(),
+            "Neurology-Rules-1",
+        )
+        .await
+        .unwrap();
+
+        let claims = validate_jwt(tokens.access_token).unwrap().claims;
+        assert_eq!(claims.sub, "foreman@sghss.com");
+        assert_eq!(claims.sid, 12);
+        assert_eq!(
+            RefreshToken::parse(&tokens.refresh_token)
+                .unwrap()
+                .session_id,
+            12
+        );
     }
 }
@@ -0,0 +1,19 @@
+use crate::{
+    application::errors::admin_application_error::AdminApplicationError,
+    domain::repositories::session_repository::SessionRepository,
+};
+
+/// Ends the session the caller's access token was issued under.
+pub struct LogoutUseCase<S: SessionRepository> {
+    session_repo: S,
+}
+
+impl<S: SessionRepository> LogoutUseCase<S> {
+    pub fn new(session_repo: S) -> Self {
+        Self { session_repo }
+    }
+
+    pub async fn execute(&self, session_id: i32) -> Result<(), AdminApplicationError> {
+        Ok(self.session_repo.revoke(session_id).await?)
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::audit_event::{AuditAction, AuditEvent},
+        repositories::{
+            audit_repository::AuditRepository,
+            patient_repository::{PatientMergeReport, PatientRepository},
+        },
+    },
+    presentation::dtos::patient_dto::MergePatientsDTO,
+};
+
+pub struct MergePatientsUseCase<T: PatientRepository, A: AuditRepository> {
+    patient_repo: T,
+    audit_repo: A,
+}
+
+impl<T: PatientRepository, A: AuditRepository> MergePatientsUseCase<T, A> {
+    pub fn new(patient_repo: T, audit_repo: A) -> Self {
+        Self {
+            patient_repo,
+            audit_repo,
+        }
+    }
+
+    /// Merges the duplicate patient into the surviving one and records
+    /// `actor` as responsible for it.
+    pub async fn execute(
+        &self,
+        input: MergePatientsDTO,
+        actor: String,
+    ) -> Result<PatientMergeReport, PatientApplicationError> {
+        let surviving_id = input.surviving_patient_id;
+        let duplicate_id = input.duplicate_patient_id;
+
+        if surviving_id == duplicate_id {
+            return Err(PatientApplicationError::Constraint(format!(
+                "The patient with ID: {surviving_id} cannot be merged into itself"
+            )));
+        }
+
+        for patient_id in [surviving_id, duplicate_id] {
+            if self.patient_repo.find_by_id(patient_id).await?.is_none() {
+                return Err(PatientApplicationError::NotFoundById(patient_id));
+            }
+        }
+
+        let report = self
+            .patient_repo
+            .merge(surviving_id, duplicate_id, actor.clone())
+            .await?;
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                actor,
+                AuditAction::PatientMerged,
+                "patient",
+                Some(duplicate_id),
+                Some(format!(
+                    "Merged into patient {surviving_id}: {} appointment(s), {} consent(s), {} encounter(s), {} allergy(ies), {} problem(s), {} prescription(s), {} exam order(s), {} document(s) and {} admission(s) moved, {} consent(s) revoked",
+                    report.moved_appointment_ids.len(),
+                    report.moved_consent_ids.len(),
+                    report.moved_encounter_ids.len(),
+                    report.moved_allergy_ids.len(),
+                    report.moved_problem_ids.len(),
+                    report.moved_prescription_ids.len(),
+                    report.moved_exam_order_ids.len(),
+                    report.moved_document_ids.len(),
+                    report.moved_admission_ids.len(),
+                    report.revoked_consent_ids.len()
+                )),
+            ))
+            .await?;
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::merge_patients::MergePatientsUseCase,
+        },
+        domain::{
+            entities::{audit_event::AuditEvent, patient::Patient},
+            repositories::{
+                audit_repository::MockAuditRepository,
+                patient_repository::{MockPatientRepository, PatientMergeReport},
+            },
+            value_objects::cpf::Cpf,
+        },
+        presentation::dtos::patient_dto::MergePatientsDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_same_patient_error() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_patient_repo.expect_merge().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = MergePatientsUseCase::new(mock_patient_repo, mock_audit_repo);
+
+        let result = sut
+            .execute(make_fake_input(3, 3), "admin@example.com".to_string())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PatientApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_duplicate_not_found() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_id()
+            .with(eq(3))
+            .times(1)
+            .return_const(Ok(Some(make_fake_patient(3))));
+
+        mock_patient_repo
+            .expect_find_by_id()
+            .with(eq(8))
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_patient_repo.expect_merge().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = MergePatientsUseCase::new(mock_patient_repo, mock_audit_repo);
+
+        let result = sut
+            .execute(make_fake_input(3, 8), "admin@example.com".to_string())
+            .await;
+
+        assert_eq!(result, Err(PatientApplicationError::NotFoundById(8)));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_id()
+            .times(2)
+            .returning(|patient_id| Ok(Some(make_fake_patient(patient_id))));
+
+        let report = PatientMergeReport {
+            surviving_patient_id: 3,
+            duplicate_patient_id: 8,
+            moved_appointment_ids: vec![10, 11],
+            moved_consent_ids: vec![4],
+            moved_encounter_ids: vec![],
+            moved_allergy_ids: vec![],
+            moved_problem_ids: vec![],
+            moved_prescription_ids: vec![12],
+            moved_exam_order_ids: vec![],
+            moved_document_ids: vec![2],
+            moved_admission_ids: vec![5],
+            revoked_consent_ids: vec![],
+        };
+
+        mock_patient_repo
+            .expect_merge()
+            .with(eq(3), eq(8), eq("admin@example.com".to_string()))
+            .times(1)
+            .return_const(Ok(report.clone()));
+
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.action == "patient_merged"
+                    && event.entity_id == Some(8)
+                    && event.details.as_deref()
+                        == Some(
+                            "Merged into patient 3: 2 appointment(s), 1 consent(s), 0 encounter(s), 0 allergy(ies), 0 problem(s), 1 prescription(s), 0 exam order(s), 1 document(s) and 1 admission(s) moved, 0 consent(s) revoked",
+                        )
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = MergePatientsUseCase::new(mock_patient_repo, mock_audit_repo);
+
+        let result = sut
+            .execute(make_fake_input(3, 8), "admin@example.com".to_string())
+            .await;
+
+        assert_eq!(result, Ok(report));
+    }
+
+    fn make_fake_patient(patient_id: i32) -> Patient {
+        Patient::restore(
+            patient_id,
+            "Andrew".to_string(),
+            Cpf::parse("12345678909").unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn make_fake_input(surviving_patient_id: i32, duplicate_patient_id: i32) -> MergePatientsDTO {
+        MergePatientsDTO {
+            surviving_patient_id,
+            duplicate_patient_id,
+        }
+    }
+}
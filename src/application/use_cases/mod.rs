@@ -1,8 +1,79 @@
+pub mod add_allergy;
+pub mod add_bed;
+pub mod add_problem;
+pub mod add_professional_schedule;
+pub mod add_room;
+pub mod add_schedule_exception;
+pub mod admit_patient;
+pub mod amend_encounter;
+pub mod anonymize_patient;
+pub mod attach_exam_result_file;
 pub mod book_appointment;
 pub mod cancel_appointment;
+pub mod change_appointment_status;
+pub mod change_bed_status;
+pub mod change_exam_order_status;
+pub mod change_password;
+pub mod check_prescription_allergies;
+pub mod create_user;
+pub mod create_ward;
+pub mod deactivate_professional_by_id;
+pub mod deactivate_user;
+pub mod delete_document;
 pub mod delete_patient_by_cpf;
+pub mod discharge_patient;
+pub mod download_document;
+pub mod export_patient_data;
+pub mod find_admission_by_id;
+pub mod find_appointment_by_id;
+pub mod find_appointment_by_patient_cpf_and_time;
+pub mod find_encounter_by_id;
+pub mod find_exam_order_by_id;
+pub mod find_exam_result_file;
 pub mod find_patient_by_cpf;
+pub mod find_prescription_by_id;
+pub mod find_professional_by_id;
+pub mod get_occupancy_overview;
+pub mod grant_consent;
+pub mod issue_prescription;
+pub mod link_user_professional;
+pub mod list_admissions_by_patient_cpf;
+pub mod list_allergies_by_patient_cpf;
 pub mod list_appointments_by_patient_cpf;
+pub mod list_available_slots;
+pub mod list_consents_by_patient_cpf;
+pub mod list_documents_by_patient_cpf;
+pub mod list_encounters_by_patient_cpf;
+pub mod list_exam_orders_by_patient_cpf;
+pub mod list_patients;
+pub mod list_prescriptions_by_patient_cpf;
+pub mod list_problems_by_patient_cpf;
+pub mod list_professional_schedules;
+pub mod list_schedule_exceptions;
+pub mod list_users;
 pub mod login;
+pub mod logout;
+pub mod merge_patients;
+pub mod order_exam;
+pub mod reactivate_user;
+pub mod record_encounter;
+pub mod record_exam_results;
+pub mod refresh_session;
 pub mod register_patient;
+pub mod register_professional;
+pub mod remove_allergy;
+pub mod remove_default_admin;
+pub mod remove_problem;
+pub mod remove_professional_schedule;
+pub mod remove_schedule_exception;
+pub mod reschedule_appointment;
+pub mod reset_password;
+pub mod revoke_consent;
+pub mod revoke_sessions;
+pub mod search_appointments;
+pub mod transfer_patient;
+pub mod update_allergy;
 pub mod update_patient_by_cpf;
+pub mod update_problem;
+pub mod update_professional_by_id;
+pub mod upload_document;
@@ -0,0 +1,324 @@
+use crate::{
+    application::{
+        errors::exam_order_application_error::ExamOrderApplicationError,
+        security::clinician::find_clinician,
+    },
+    domain::{
+        entities::exam_order::ExamOrder,
+        repositories::{
+            admin_repository::AdminRepository, appointment_repository::AppointmentRepository,
+            exam_order_repository::ExamOrderRepository, patient_repository::PatientRepository,
+            professional_repository::ProfessionalRepository,
+        },
+    },
+    presentation::dtos::exam_order_dto::OrderExamDTO,
+};
+
+/// The exam is ordered by the professional the signed-in account is linked
+/// to.
+pub struct OrderExamUseCase<
+    T: PatientRepository,
+    R: ProfessionalRepository,
+    P: AppointmentRepository,
+    X: ExamOrderRepository,
+    U: AdminRepository,
+> {
+    patient_repo: T,
+    professional_repo: R,
+    appointment_repo: P,
+    exam_order_repo: X,
+    admin_repo: U,
+}
+
+impl<
+    T: PatientRepository,
+    R: ProfessionalRepository,
+    P: AppointmentRepository,
+    X: ExamOrderRepository,
+    U: AdminRepository,
+> OrderExamUseCase<T, R, P, X, U>
+{
+    pub fn new(
+        patient_repo: T,
+        professional_repo: R,
+        appointment_repo: P,
+        exam_order_repo: X,
+        admin_repo: U,
+    ) -> Self {
+        Self {
+            patient_repo,
+            professional_repo,
+            appointment_repo,
+            exam_order_repo,
+            admin_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: OrderExamDTO,
+        requested_by: String,
+    ) -> Result<ExamOrder, ExamOrderApplicationError> {
+        let cpf = input.patient_cpf.clone();
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(ExamOrderApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id =
+            patient_id.ok_or(ExamOrderApplicationError::PatientNotFound(cpf.to_string()))?;
+
+        let orderer = find_clinician(&self.admin_repo, &self.professional_repo, &requested_by)
+            .await?
+            .ok_or_else(|| {
+                ExamOrderApplicationError::Constraint(format!(
+                    "The account {requested_by} is not linked to an active professional"
+                ))
+            })?;
+        let professional_id: Option<i32> = orderer.id.into();
+
+        let appointment = match input.appointment_id {
+            Some(appointment_id) => Some(
+                self.appointment_repo
+                    .find_by_id(appointment_id)
+                    .await?
+                    .ok_or(ExamOrderApplicationError::AppointmentNotFound(
+                        appointment_id,
+                    ))?,
+            ),
+            None => None,
+        };
+
+        let exam_order = ExamOrder::new(
+            patient_id,
+            professional_id.unwrap_or_default(),
+            appointment.as_ref(),
+            input,
+            requested_by,
+        )?;
+
+        Ok(self.exam_order_repo.save(&exam_order).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::exam_order_application_error::ExamOrderApplicationError,
+            use_cases::order_exam::OrderExamUseCase,
+        },
+        domain::{
+            entities::{
+                admin::Admin, appointment::Appointment, exam_order::ExamOrder, patient::Patient,
+                professional::Professional,
+            },
+            repositories::{
+                admin_repository::MockAdminRepository,
+                appointment_repository::MockAppointmentRepository,
+                exam_order_repository::MockExamOrderRepository,
+                patient_repository::MockPatientRepository,
+                professional_repository::MockProfessionalRepository,
+            },
+            value_objects::{cpf::Cpf, exam_kind::ExamKind, id::ID, role::Role},
+        },
+        presentation::dtos::exam_order_dto::OrderExamDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_appointment_of_another_patient_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_exam_order_repo = MockExamOrderRepository::new();
+
+        let mut appointment = make_fake_appointment();
+        appointment.patient_id = 8;
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .with(eq(1))
+            .times(1)
+            .return_const(Ok(Some(appointment)));
+
+        mock_exam_order_repo.expect_save().times(0);
+
+        let sut = OrderExamUseCase::new(
+            make_fake_patient_repo(),
+            make_fake_professional_repo(),
+            mock_appointment_repo,
+            mock_exam_order_repo,
+            make_fake_admin_repo(Some(42)),
+        );
+
+        let result = sut
+            .execute(make_fake_input(), "house@example.com".to_string())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(ExamOrderApplicationError::Constraint(
+                "The exam order cannot be tied to the appointment: 1 belongs to another patient"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_unlinked_account_error() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut mock_exam_order_repo = MockExamOrderRepository::new();
+
+        mock_professional_repo.expect_find_by_id().times(0);
+        mock_exam_order_repo.expect_save().times(0);
+
+        let sut = OrderExamUseCase::new(
+            make_fake_patient_repo(),
+            mock_professional_repo,
+            MockAppointmentRepository::new(),
+            mock_exam_order_repo,
+            make_fake_admin_repo(None),
+        );
+
+        let result = sut
+            .execute(make_fake_input(), "house@example.com".to_string())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(ExamOrderApplicationError::Constraint(
+                "The account house@example.com is not linked to an active professional".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_exam_order_repo = MockExamOrderRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_appointment())));
+
+        mock_exam_order_repo
+            .expect_save()
+            .withf(|exam_order: &ExamOrder| {
+                exam_order.patient_id == 7
+                    && exam_order.appointment_id == Some(1)
+                    && exam_order.exam_name == "Complete blood count"
+                    && exam_order.professional_id == 42
+            })
+            .times(1)
+            .returning(|exam_order| {
+                let mut exam_order = exam_order.clone();
+                exam_order.id = ID::Existing(3);
+                Ok(exam_order)
+            });
+
+        let sut = OrderExamUseCase::new(
+            make_fake_patient_repo(),
+            make_fake_professional_repo(),
+            mock_appointment_repo,
+            mock_exam_order_repo,
+            make_fake_admin_repo(Some(42)),
+        );
+
+        let result = sut
+            .execute(make_fake_input(), "house@example.com".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(result.id, ID::Existing(3));
+    }
+
+    fn make_fake_patient_repo() -> MockPatientRepository {
+        let mut mock_patient_repo = MockPatientRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .returning(|cpf| {
+                Ok(Some(
+                    Patient::restore(7, "Andrew".to_string(), cpf).unwrap(),
+                ))
+            });
+
+        mock_patient_repo
+    }
+
+    fn make_fake_admin_repo(professional_id: Option<i32>) -> MockAdminRepository {
+        let mut mock_admin_repo = MockAdminRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .with(eq("house@example.com".to_string()))
+            .times(1)
+            .returning(move |email| {
+                Ok(Some(Admin {
+                    professional_id,
+                    ..Admin::new(
+                        "Gregory House".to_string(),
+                        &email,
+                        Role::Physician,
+                        "hash".to_string(),
+                    )
+                    .unwrap()
+                }))
+            });
+
+        mock_admin_repo
+    }
+
+    fn make_fake_professional_repo() -> MockProfessionalRepository {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(
+                Professional::restore(
+                    42,
+                    "Gregory House".to_string(),
+                    "CRM".to_string(),
+                    "123456".to_string(),
+                    "SP".to_string(),
+                    vec!["Nephrology".to_string()],
+                    true,
+                )
+                .unwrap(),
+            )));
+
+        mock_professional_repo
+    }
+
+    fn make_fake_appointment() -> Appointment {
+        let mut appointment = Appointment::new(
+            7,
+            Some(42),
+            "2025-06-09T08:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "Nephrology".to_string(),
+            None,
+        )
+        .unwrap();
+        appointment.id = ID::Existing(1);
+
+        appointment
+    }
+
+    fn make_fake_input() -> OrderExamDTO {
+        OrderExamDTO {
+            patient_cpf: Cpf::parse("12345678909").unwrap(),
+            appointment_id: Some(1),
+            kind: ExamKind::Lab,
+            exam_name: " Complete blood count ".to_string(),
+            clinical_indication: Some("Fatigue".to_string()),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    application::errors::admin_application_error::AdminApplicationError,
+    domain::{
+        entities::{
+            admin::Admin,
+            audit_event::{AuditAction, AuditEvent},
+        },
+        repositories::{admin_repository::AdminRepository, audit_repository::AuditRepository},
+    },
+};
+
+pub struct ReactivateUserUseCase<T: AdminRepository, A: AuditRepository> {
+    admin_repo: T,
+    audit_repo: A,
+}
+
+impl<T: AdminRepository, A: AuditRepository> ReactivateUserUseCase<T, A> {
+    pub fn new(admin_repo: T, audit_repo: A) -> Self {
+        Self {
+            admin_repo,
+            audit_repo,
+        }
+    }
+
+    pub async fn execute(&self, id: i32, actor: String) -> Result<Admin, AdminApplicationError> {
+        let mut admin = self
+            .admin_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(AdminApplicationError::NotFoundById(id))?;
+
+        if admin.active {
+            return Ok(admin);
+        }
+        admin.reactivate();
+
+        let admin = self.admin_repo.update(&admin).await?;
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                actor,
+                AuditAction::UserReactivated,
+                "user",
+                Some(id),
+                None,
+            ))
+            .await?;
+
+        Ok(admin)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            use_cases::reactivate_user::ReactivateUserUseCase,
+        },
+        domain::repositories::{
+            admin_repository::MockAdminRepository, audit_repository::MockAuditRepository,
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_user_not_found() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+        mock_admin_repo.expect_update().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = ReactivateUserUseCase::new(mock_admin_repo, mock_audit_repo);
+
+        let result = sut.execute(9, "admin@sghss.com".to_string()).await;
+
+        assert_eq!(result, Err(AdminApplicationError::NotFoundById(9)));
+    }
+}
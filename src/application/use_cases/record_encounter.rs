@@ -0,0 +1,326 @@
+use crate::{
+    application::{
+        errors::encounter_application_error::EncounterApplicationError,
+        security::clinician::find_clinician,
+    },
+    domain::{
+        entities::encounter::Encounter,
+        repositories::{
+            admin_repository::AdminRepository, appointment_repository::AppointmentRepository,
+            encounter_repository::EncounterRepository,
+            professional_repository::ProfessionalRepository,
+        },
+    },
+    presentation::dtos::encounter_dto::EncounterSectionsDTO,
+};
+
+/// The note is credited to the professional the signed-in account acts as.
+pub struct RecordEncounterUseCase<
+    T: AppointmentRepository,
+    E: EncounterRepository,
+    A: AdminRepository,
+    R: ProfessionalRepository,
+> {
+    appointment_repo: T,
+    encounter_repo: E,
+    admin_repo: A,
+    professional_repo: R,
+}
+
+impl<
+    T: AppointmentRepository,
+    E: EncounterRepository,
+    A: AdminRepository,
+    R: ProfessionalRepository,
+> RecordEncounterUseCase<T, E, A, R>
+{
+    pub fn new(
+        appointment_repo: T,
+        encounter_repo: E,
+        admin_repo: A,
+        professional_repo: R,
+    ) -> Self {
+        Self {
+            appointment_repo,
+            encounter_repo,
+            admin_repo,
+            professional_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        appointment_id: i32,
+        sections: EncounterSectionsDTO,
+        recorded_by: String,
+    ) -> Result<Encounter, EncounterApplicationError> {
+        let author = find_clinician(&self.admin_repo, &self.professional_repo, &recorded_by)
+            .await?
+            .ok_or_else(|| {
+                EncounterApplicationError::Constraint(format!(
+                    "The account {recorded_by} is not linked to an active professional"
+                ))
+            })?;
+        let author_id: Option<i32> = author.id.into();
+
+        let appointment = self
+            .appointment_repo
+            .find_by_id(appointment_id)
+            .await?
+            .ok_or(EncounterApplicationError::AppointmentNotFound(
+                appointment_id,
+            ))?;
+
+        if self
+            .encounter_repo
+            .exists_by_appointment_id(appointment_id)
+            .await?
+        {
+            return Err(EncounterApplicationError::Constraint(format!(
+                "An encounter was already recorded for the appointment with ID: {appointment_id}, amend it instead"
+            )));
+        }
+
+        let encounter = Encounter::new(
+            &appointment,
+            sections,
+            author_id.unwrap_or_default(),
+            recorded_by,
+        )?;
+
+        Ok(self.encounter_repo.save(&encounter).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::encounter_application_error::EncounterApplicationError,
+            use_cases::record_encounter::RecordEncounterUseCase,
+        },
+        domain::{
+            entities::{
+                admin::Admin, appointment::Appointment, encounter::Encounter,
+                professional::Professional,
+            },
+            repositories::{
+                admin_repository::MockAdminRepository,
+                appointment_repository::MockAppointmentRepository,
+                encounter_repository::MockEncounterRepository,
+                professional_repository::MockProfessionalRepository,
+            },
+            value_objects::{appointment_status::AppointmentStatus, id::ID, role::Role},
+        },
+        presentation::dtos::encounter_dto::EncounterSectionsDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_appointment_not_found() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_encounter_repo = MockEncounterRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .with(eq(1))
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_encounter_repo.expect_save().times(0);
+
+        let sut = RecordEncounterUseCase::new(
+            mock_appointment_repo,
+            mock_encounter_repo,
+            make_fake_admin_repo(Some(13)),
+            make_fake_professional_repo(),
+        );
+
+        let result = sut
+            .execute(1, make_fake_sections(), "hadley@example.com".to_string())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(EncounterApplicationError::AppointmentNotFound(1))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_unlinked_account_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_encounter_repo = MockEncounterRepository::new();
+
+        mock_appointment_repo.expect_find_by_id().times(0);
+        mock_encounter_repo.expect_save().times(0);
+
+        let sut = RecordEncounterUseCase::new(
+            mock_appointment_repo,
+            mock_encounter_repo,
+            make_fake_admin_repo(None),
+            MockProfessionalRepository::new(),
+        );
+
+        let result = sut
+            .execute(1, make_fake_sections(), "hadley@example.com".to_string())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(EncounterApplicationError::Constraint(
+                "The account hadley@example.com is not linked to an active professional"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_already_recorded_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_encounter_repo = MockEncounterRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_appointment())));
+
+        mock_encounter_repo
+            .expect_exists_by_appointment_id()
+            .with(eq(1))
+            .times(1)
+            .return_const(Ok(true));
+
+        mock_encounter_repo.expect_save().times(0);
+
+        let sut = RecordEncounterUseCase::new(
+            mock_appointment_repo,
+            mock_encounter_repo,
+            make_fake_admin_repo(Some(13)),
+            make_fake_professional_repo(),
+        );
+
+        let result = sut
+            .execute(1, make_fake_sections(), "hadley@example.com".to_string())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(EncounterApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_encounter_repo = MockEncounterRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_appointment())));
+
+        mock_encounter_repo
+            .expect_exists_by_appointment_id()
+            .times(1)
+            .return_const(Ok(false));
+
+        mock_encounter_repo
+            .expect_save()
+            .withf(|encounter: &Encounter| {
+                encounter.appointment_id == 1
+                    && encounter.professional_id == 42
+                    && encounter.author_id == 13
+                    && encounter.recorded_by == "hadley@example.com"
+            })
+            .times(1)
+            .returning(|encounter| {
+                let mut encounter = encounter.clone();
+                encounter.id = ID::Existing(5);
+                Ok(encounter)
+            });
+
+        let sut = RecordEncounterUseCase::new(
+            mock_appointment_repo,
+            mock_encounter_repo,
+            make_fake_admin_repo(Some(13)),
+            make_fake_professional_repo(),
+        );
+
+        let result = sut
+            .execute(1, make_fake_sections(), "hadley@example.com".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(result.id, ID::Existing(5));
+    }
+
+    fn make_fake_admin_repo(professional_id: Option<i32>) -> MockAdminRepository {
+        let mut mock_admin_repo = MockAdminRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .with(eq("hadley@example.com".to_string()))
+            .returning(move |email| {
+                Ok(Some(Admin {
+                    professional_id,
+                    ..Admin::new(
+                        "Remy Hadley".to_string(),
+                        &email,
+                        Role::Nurse,
+                        "hash".to_string(),
+                    )
+                    .unwrap()
+                }))
+            });
+
+        mock_admin_repo
+    }
+
+    fn make_fake_professional_repo() -> MockProfessionalRepository {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .with(eq(13))
+            .return_const(Ok(Some(
+                Professional::restore(
+                    13,
+                    "Remy Hadley".to_string(),
+                    "COREN".to_string(),
+                    "654321".to_string(),
+                    "SP".to_string(),
+                    vec!["Nursing".to_string()],
+                    true,
+                )
+                .unwrap(),
+            )));
+
+        mock_professional_repo
+    }
+
+    fn make_fake_appointment() -> Appointment {
+        let mut appointment = Appointment::new(
+            7,
+            Some(42),
+            "2025-06-09T08:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "Nephrology".to_string(),
+            None,
+        )
+        .unwrap();
+        appointment.id = ID::Existing(1);
+        appointment.status = AppointmentStatus::Completed;
+
+        appointment
+    }
+
+    fn make_fake_sections() -> EncounterSectionsDTO {
+        EncounterSectionsDTO {
+            chief_complaint: "Lower back pain".to_string(),
+            plan: Some("Ultrasound of the kidneys".to_string()),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    application::errors::exam_order_application_error::ExamOrderApplicationError,
+    domain::{
+        entities::exam_order::{ExamOrder, ExamResult},
+        repositories::exam_order_repository::ExamOrderRepository,
+    },
+    presentation::dtos::exam_order_dto::ExamResultDTO,
+};
+
+pub struct RecordExamResultsUseCase<X: ExamOrderRepository> {
+    exam_order_repo: X,
+}
+
+impl<X: ExamOrderRepository> RecordExamResultsUseCase<X> {
+    pub fn new(exam_order_repo: X) -> Self {
+        Self { exam_order_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        inputs: Vec<ExamResultDTO>,
+        actor: String,
+    ) -> Result<(ExamOrder, Vec<ExamResult>), ExamOrderApplicationError> {
+        let (mut exam_order, _) = self
+            .exam_order_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(ExamOrderApplicationError::NotFound(id))?;
+
+        let results = exam_order.record_results(inputs, &actor)?;
+
+        Ok(self
+            .exam_order_repo
+            .save_results(&exam_order, &results)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::exam_order_application_error::ExamOrderApplicationError,
+            use_cases::record_exam_results::RecordExamResultsUseCase,
+        },
+        domain::{
+            entities::exam_order::{ExamOrder, ExamResult},
+            repositories::exam_order_repository::MockExamOrderRepository,
+            value_objects::{
+                cpf::Cpf, exam_kind::ExamKind, exam_order_status::ExamOrderStatus, id::ID,
+            },
+        },
+        presentation::dtos::exam_order_dto::{ExamResultDTO, OrderExamDTO},
+    };
+
+    #[tokio::test]
+    async fn execute_not_found() {
+        let mut mock_exam_order_repo = MockExamOrderRepository::new();
+
+        mock_exam_order_repo
+            .expect_find_by_id()
+            .with(eq(3))
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_exam_order_repo.expect_save_results().times(0);
+
+        let sut = RecordExamResultsUseCase::new(mock_exam_order_repo);
+
+        let result = sut
+            .execute(3, vec![make_fake_result()], "lab@example.com".to_string())
+            .await;
+
+        assert_eq!(result, Err(ExamOrderApplicationError::NotFound(3)));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_exam_order_repo = MockExamOrderRepository::new();
+
+        mock_exam_order_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some((make_fake_exam_order(), vec![]))));
+
+        mock_exam_order_repo
+            .expect_save_results()
+            .withf(|exam_order: &ExamOrder, results: &[ExamResult]| {
+                exam_order.status == ExamOrderStatus::Resulted
+                    && results.len() == 1
+                    && results[0].exam_order_id == 3
+                    && results[0].abnormal
+            })
+            .times(1)
+            .returning(|exam_order, results| Ok((exam_order.clone(), results.to_vec())));
+
+        let sut = RecordExamResultsUseCase::new(mock_exam_order_repo);
+
+        let (exam_order, results) = sut
+            .execute(3, vec![make_fake_result()], "lab@example.com".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(exam_order.status, ExamOrderStatus::Resulted);
+        assert_eq!(results.len(), 1);
+    }
+
+    fn make_fake_exam_order() -> ExamOrder {
+        let mut exam_order = ExamOrder::new(
+            7,
+            42,
+            None,
+            OrderExamDTO {
+                patient_cpf: Cpf::parse("12345678909").unwrap(),
+                appointment_id: None,
+                kind: ExamKind::Lab,
+                exam_name: "Fasting glucose".to_string(),
+                clinical_indication: None,
+            },
+            "admin@example.com".to_string(),
+        )
+        .unwrap();
+        exam_order.id = ID::Existing(3);
+        exam_order.status = ExamOrderStatus::Collected;
+
+        exam_order
+    }
+
+    fn make_fake_result() -> ExamResultDTO {
+        ExamResultDTO {
+            analyte: "Glucose".to_string(),
+            value: "126".to_string(),
+            unit: Some("mg/dL".to_string()),
+            reference_low: Some(70.0),
+            reference_high: Some(99.0),
+            ..Default::default()
+        }
+    }
+}
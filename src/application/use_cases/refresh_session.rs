@@ -0,0 +1,248 @@
+use log::warn;
+
+use crate::{
+    application::{
+        errors::admin_application_error::AdminApplicationError,
+        security::refresh_token::{RefreshToken, TokenPair, hash_secret},
+    },
+    domain::repositories::{
+        admin_repository::AdminRepository, session_repository::SessionRepository,
+    },
+};
+
+/// Trades a refresh token for a new access token and a new refresh token.
+/// Each refresh token works once: presenting the one that was just rotated
+/// means it leaked, and the whole session is revoked. Any other secret is
+/// merely rejected, so guessing one cannot log the user out.
+pub struct RefreshSessionUseCase<T: AdminRepository, S: SessionRepository> {
+    admin_repo: T,
+    session_repo: S,
+}
+
+impl<T: AdminRepository, S: SessionRepository> RefreshSessionUseCase<T, S> {
+    pub fn new(admin_repo: T, session_repo: S) -> Self {
+        Self {
+            admin_repo,
+            session_repo,
+        }
+    }
+
+    pub async fn execute(&self, refresh_token: &str) -> Result<TokenPair, AdminApplicationError> {
+        let token =
+            RefreshToken::parse(refresh_token).ok_or(AdminApplicationError::InvalidRefreshToken)?;
+
+        let mut session = self
+            .session_repo
+            .find_by_id(token.session_id)
+            .await?
+            .filter(|session| session.is_active(chrono::Local::now().naive_utc()))
+            .ok_or(AdminApplicationError::InvalidRefreshToken)?;
+
+        let hash = token.hash();
+        if hash != session.refresh_token_hash {
+            if session.previous_refresh_token_hash.as_ref() == Some(&hash) {
+                warn!(
+                    "A rotated refresh token was reused; revoking the session {}",
+                    token.session_id
+                );
+                self.session_repo.revoke(token.session_id).await?;
+            }
+            return Err(AdminApplicationError::InvalidRefreshToken);
+        }
+
+        let admin = self
+            .admin_repo
+            .find_by_id(session.admin_id)
+            .await?
+            .filter(|admin| admin.active)
+            .ok_or(AdminApplicationError::InvalidRefreshToken)?;
+
+        let secret = RefreshToken::generate_secret();
+        let previous_hash = session.refresh_token_hash.clone();
+        session.rotate(hash_secret(&secret));
+
+        // Losing the race against a concurrent refresh with the same token.
+        if self
+            .session_repo
+            .rotate(&session, previous_hash)
+            .await?
+            .is_none()
+        {
+            return Err(AdminApplicationError::InvalidRefreshToken);
+        }
+
+        TokenPair::issue(&admin, token.session_id, secret).ok_or(AdminApplicationError::Unexpected(
+            format!("Could not generate JWT token for admin {}", admin.email),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            security::refresh_token::{RefreshToken, hash_secret},
+            use_cases::refresh_session::RefreshSessionUseCase,
+        },
+        domain::{
+            entities::{admin::Admin, session::Session},
+            repositories::{
+                admin_repository::MockAdminRepository, session_repository::MockSessionRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+    };
+
+    fn make_fake_session(secret: &str) -> Session {
+        Session {
+            id: ID::Existing(12),
+            ..Session::start(3, hash_secret(secret))
+        }
+    }
+
+    fn make_fake_admin() -> Admin {
+        Admin {
+            id: ID::Existing(3),
+            must_change_password: false,
+            ..Admin::new(
+                "Eric Foreman".to_string(),
+                "foreman@sghss.com",
+                Role::Physician,
+                "hash".to_string(),
+            )
+            .unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_malformed_token() {
+        let mut mock_session_repo = MockSessionRepository::new();
+
+        mock_session_repo.expect_find_by_id().times(0);
+
+        let sut = RefreshSessionUseCase::new(MockAdminRepository::new(), mock_session_repo);
+
+        let result = sut.execute("12.not-a-secret").await;
+
+        assert!(matches!(
+            result,
+            Err(AdminApplicationError::InvalidRefreshToken)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_reused_token_revokes_session() {
+        let mut mock_session_repo = MockSessionRepository::new();
+        let rotated_secret = RefreshToken::generate_secret();
+        let mut session = make_fake_session(&rotated_secret);
+        session.rotate(hash_secret(&RefreshToken::generate_secret()));
+
+        mock_session_repo
+            .expect_find_by_id()
+            .with(eq(12))
+            .times(1)
+            .return_const(Ok(Some(session)));
+        mock_session_repo
+            .expect_revoke()
+            .with(eq(12))
+            .times(1)
+            .return_const(Ok(()));
+        mock_session_repo.expect_rotate().times(0);
+
+        let sut = RefreshSessionUseCase::new(MockAdminRepository::new(), mock_session_repo);
+
+        let result = sut.execute(&format!("12.{rotated_secret}")).await;
+
+        assert!(matches!(
+            result,
+            Err(AdminApplicationError::InvalidRefreshToken)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_forged_token_keeps_session() {
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut session = make_fake_session(&RefreshToken::generate_secret());
+        session.rotate(hash_secret(&RefreshToken::generate_secret()));
+
+        mock_session_repo
+            .expect_find_by_id()
+            .with(eq(12))
+            .times(1)
+            .return_const(Ok(Some(session)));
+        mock_session_repo.expect_revoke().times(0);
+        mock_session_repo.expect_rotate().times(0);
+
+        let sut = RefreshSessionUseCase::new(MockAdminRepository::new(), mock_session_repo);
+
+        let result = sut
+            .execute(&format!("12.{}", RefreshToken::generate_secret()))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AdminApplicationError::InvalidRefreshToken)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_revoked_session() {
+        let mut mock_session_repo = MockSessionRepository::new();
+        let secret = RefreshToken::generate_secret();
+        let mut session = make_fake_session(&secret);
+        session.revoke();
+
+        mock_session_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(session)));
+        mock_session_repo.expect_rotate().times(0);
+
+        let sut = RefreshSessionUseCase::new(MockAdminRepository::new(), mock_session_repo);
+
+        let result = sut.execute(&format!("12.{secret}")).await;
+
+        assert!(matches!(
+            result,
+            Err(AdminApplicationError::InvalidRefreshToken)
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let secret = RefreshToken::generate_secret();
+        let previous_hash = hash_secret(&secret);
+
+        mock_session_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_session(&secret))));
+        mock_admin_repo
+            .expect_find_by_id()
+            .with(eq(3))
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin())));
+        mock_session_repo
+            .expect_rotate()
+            .withf(move |session, previous| {
+                *previous == previous_hash
+                    && session.refresh_token_hash != previous_hash
+                    && session.previous_refresh_token_hash.as_ref() == Some(&previous_hash)
+            })
+            .times(1)
+            .returning(|session, _| Ok(Some(session.clone())));
+
+        let sut = RefreshSessionUseCase::new(mock_admin_repo, mock_session_repo);
+
+        let tokens = sut.execute(&format!("12.{secret}")).await.unwrap();
+        let refresh_token = RefreshToken::parse(&tokens.refresh_token).unwrap();
+
+        assert_eq!(refresh_token.session_id, 12);
+        assert_ne!(refresh_token.secret, secret);
+    }
+}
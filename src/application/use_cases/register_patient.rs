@@ -21,7 +21,7 @@ impl<T: PatientRepository> RegisterPatientUseCase<T> {
             )));
         }
 
-        let patient: Patient = patient.into();
+        let patient = Patient::try_from(patient)?;
 
         self.patient_repo
             .save(&patient)
@@ -39,9 +39,9 @@ mod test {
         },
         domain::{
             entities::patient::Patient, errors::repository_error::RepositoryError,
-            repositories::patient_repository::MockPatientRepository,
+            repositories::patient_repository::MockPatientRepository, value_objects::cpf::Cpf,
         },
-        presentation::dtos::patient_dto::CreatePatientDTO,
+        presentation::dtos::patient_dto::{CreatePatientDTO, PatientProfileDTO},
     };
 
     #[tokio::test]
@@ -121,14 +121,14 @@ mod test {
     async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
         let mut mock_patient_repo = MockPatientRepository::new();
 
-        let cpf = "11122233344";
-        let fake_patient = make_fake_create_patient_dto(Some(cpf.to_string()));
+        let cpf = Cpf::parse("11122233396")?;
+        let fake_patient = make_fake_create_patient_dto(Some(cpf.clone()));
 
-        let fake_patient_entity: Patient = fake_patient.clone().into();
+        let fake_patient_entity = Patient::try_from(fake_patient.clone())?;
 
         mock_patient_repo
             .expect_exists_by_cpf()
-            .withf(|expected_email: &str| *expected_email == *cpf)
+            .withf(move |expected_cpf: &Cpf| *expected_cpf == cpf)
             .times(1)
             .return_const(Ok(false));
 
@@ -149,13 +149,14 @@ mod test {
         Ok(())
     }
 
-    fn make_fake_create_patient_dto(cpf: Option<String>) -> CreatePatientDTO {
+    fn make_fake_create_patient_dto(cpf: Option<Cpf>) -> CreatePatientDTO {
         CreatePatientDTO {
             name: "Andrew".to_string(),
             cpf: match cpf {
-                None => "00011122233".to_string(),
+                None => Cpf::parse("00011122285").unwrap(),
                 Some(value) => value,
             },
+            profile: PatientProfileDTO::default(),
         }
     }
 }
@@ -0,0 +1,178 @@
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::{
+        entities::professional::Professional,
+        repositories::professional_repository::ProfessionalRepository,
+    },
+    presentation::dtos::professional_dto::CreateProfessionalDTO,
+};
+
+pub struct RegisterProfessionalUseCase<T: ProfessionalRepository> {
+    professional_repo: T,
+}
+
+impl<T: ProfessionalRepository> RegisterProfessionalUseCase<T> {
+    pub fn new(professional_repo: T) -> Self {
+        Self { professional_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        professional: CreateProfessionalDTO,
+    ) -> Result<i32, ProfessionalApplicationError> {
+        let professional = Professional::new(
+            professional.name,
+            professional.council,
+            professional.license_number,
+            professional.council_state,
+            professional.specialties,
+        )?;
+
+        if self
+            .professional_repo
+            .exists_by_license(
+                &professional.council,
+                &professional.license_number,
+                &professional.council_state,
+            )
+            .await?
+        {
+            return Err(ProfessionalApplicationError::Conflict(format!(
+                "The license {} {}/{} is already taken",
+                professional.council, professional.license_number, professional.council_state
+            )));
+        }
+
+        self.professional_repo
+            .save(&professional)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::{
+            errors::professional_application_error::ProfessionalApplicationError,
+            use_cases::register_professional::RegisterProfessionalUseCase,
+        },
+        domain::{
+            entities::professional::Professional, errors::repository_error::RepositoryError,
+            repositories::professional_repository::MockProfessionalRepository,
+        },
+        presentation::dtos::professional_dto::CreateProfessionalDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_invalid_council_state_error() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let mut fake_professional = make_fake_create_professional_dto();
+        fake_professional.council_state = "XX".to_string();
+
+        mock_professional_repo.expect_exists_by_license().times(0);
+        mock_professional_repo.expect_save().times(0);
+
+        let sut = RegisterProfessionalUseCase::new(mock_professional_repo);
+
+        let result = sut.execute(fake_professional).await;
+
+        assert_eq!(
+            result,
+            Err(ProfessionalApplicationError::Constraint(
+                "An invalid council state was given: XX".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_license_taken_error() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_professional_repo
+            .expect_exists_by_license()
+            .times(1)
+            .return_const(Ok(true));
+
+        mock_professional_repo.expect_save().times(0);
+
+        let sut = RegisterProfessionalUseCase::new(mock_professional_repo);
+
+        let result = sut.execute(make_fake_create_professional_dto()).await;
+
+        assert_eq!(
+            result,
+            Err(ProfessionalApplicationError::Conflict(
+                "The license CRM 123456/SP is already taken".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_professional_repository_save_error() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_professional_repo
+            .expect_exists_by_license()
+            .times(1)
+            .return_const(Ok(false));
+
+        mock_professional_repo
+            .expect_save()
+            .times(1)
+            .return_const(Err(RepositoryError::DatabaseError(
+                "Fake Error".to_string(),
+            )));
+
+        let sut = RegisterProfessionalUseCase::new(mock_professional_repo);
+
+        let result = sut.execute(make_fake_create_professional_dto()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let fake_professional = make_fake_create_professional_dto();
+        let fake_professional_entity = Professional::new(
+            fake_professional.name.clone(),
+            fake_professional.council.clone(),
+            fake_professional.license_number.clone(),
+            fake_professional.council_state.clone(),
+            fake_professional.specialties.clone(),
+        )?;
+
+        mock_professional_repo
+            .expect_exists_by_license()
+            .withf(|council: &str, license_number: &str, council_state: &str| {
+                council == "CRM" && license_number == "123456" && council_state == "SP"
+            })
+            .times(1)
+            .return_const(Ok(false));
+
+        mock_professional_repo
+            .expect_save()
+            .withf(move |expected: &Professional| *expected == fake_professional_entity)
+            .times(1)
+            .return_const(Ok(42));
+
+        let sut = RegisterProfessionalUseCase::new(mock_professional_repo);
+
+        let result = sut.execute(fake_professional).await?;
+
+        assert_eq!(result, 42);
+
+        Ok(())
+    }
+
+    fn make_fake_create_professional_dto() -> CreateProfessionalDTO {
+        CreateProfessionalDTO {
+            name: "Gregory House".to_string(),
+            council: "crm".to_string(),
+            license_number: "crm 123456".to_string(),
+            council_state: "sp".to_string(),
+            specialties: vec!["Nephrology".to_string()],
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        repositories::{
+            allergy_repository::AllergyRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct RemoveAllergyUseCase<T: PatientRepository, A: AllergyRepository> {
+    patient_repo: T,
+    allergy_repo: A,
+}
+
+impl<T: PatientRepository, A: AllergyRepository> RemoveAllergyUseCase<T, A> {
+    pub fn new(patient_repo: T, allergy_repo: A) -> Self {
+        Self {
+            patient_repo,
+            allergy_repo,
+        }
+    }
+
+    pub async fn execute(&self, cpf: Cpf, allergy_id: i32) -> Result<(), PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        if !self.allergy_repo.delete(patient_id, allergy_id).await? {
+            return Err(PatientApplicationError::AllergyNotFound(allergy_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::remove_allergy::RemoveAllergyUseCase,
+        },
+        domain::{
+            entities::patient::Patient,
+            repositories::{
+                allergy_repository::MockAllergyRepository,
+                patient_repository::MockPatientRepository,
+            },
+            value_objects::cpf::Cpf,
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_allergy_not_found() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_allergy_repo = MockAllergyRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(
+                Patient::restore(7, "Andrew".to_string(), Cpf::parse("12345678909").unwrap())
+                    .unwrap(),
+            )));
+
+        mock_allergy_repo
+            .expect_delete()
+            .with(eq(7), eq(4))
+            .times(1)
+            .return_const(Ok(false));
+
+        let sut = RemoveAllergyUseCase::new(mock_patient_repo, mock_allergy_repo);
+
+        let result = sut.execute(Cpf::parse("12345678909").unwrap(), 4).await;
+
+        assert_eq!(result, Err(PatientApplicationError::AllergyNotFound(4)));
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    application::{
+        errors::admin_application_error::AdminApplicationError, security::password::verify_password,
+    },
+    domain::{
+        entities::audit_event::{AuditAction, AuditEvent},
+        repositories::{admin_repository::AdminRepository, audit_repository::AuditRepository},
+        value_objects::role::Role,
+    },
+};
+
+/// The account seeded by the `create_dumb_admin` migration.
+pub const DEFAULT_ADMIN_EMAIL: &str = "admin@email.com";
+const DEFAULT_ADMIN_PASSWORD: &str = "123";
+
+/// Run on production startup: deactivates the seeded account while it still
+/// accepts its well-known password. Refuses to when it is the only active
+/// administrator, since nobody could manage accounts afterwards.
+pub struct RemoveDefaultAdminUseCase<T: AdminRepository, A: AuditRepository> {
+    admin_repo: T,
+    audit_repo: A,
+}
+
+impl<T: AdminRepository, A: AuditRepository> RemoveDefaultAdminUseCase<T, A> {
+    pub fn new(admin_repo: T, audit_repo: A) -> Self {
+        Self {
+            admin_repo,
+            audit_repo,
+        }
+    }
+
+    /// Whether the seeded account was deactivated.
+    pub async fn execute(&self) -> Result<bool, AdminApplicationError> {
+        let Some(mut admin) = self
+            .admin_repo
+            .find_by_email(DEFAULT_ADMIN_EMAIL.to_string())
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        if !admin.active || !verify_password(DEFAULT_ADMIN_PASSWORD, &admin.password_hash) {
+            return Ok(false);
+        }
+
+        let has_other_admin = self.admin_repo.find_all().await?.iter().any(|other| {
+            other.active && other.role == Role::Admin && other.email != DEFAULT_ADMIN_EMAIL
+        });
+
+        if !has_other_admin {
+            return Err(AdminApplicationError::Constraint(format!(
+                "The account {DEFAULT_ADMIN_EMAIL} still has its default password and is the only administrator; create another administrator before running in production"
+            )));
+        }
+
+        admin.deactivate();
+        let admin = self.admin_repo.update(&admin).await?;
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                "system".to_string(),
+                AuditAction::UserDeactivated,
+                "user",
+                admin.id.into(),
+                Some("Seeded account still using its default password".to_string()),
+            ))
+            .await?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            use_cases::remove_default_admin::{DEFAULT_ADMIN_EMAIL, RemoveDefaultAdminUseCase},
+        },
+        domain::{
+            entities::{admin::Admin, audit_event::AuditEvent},
+            repositories::{
+                admin_repository::MockAdminRepository, audit_repository::MockAuditRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+    };
+
+    fn make_fake_admin(id: i32, email: &str, password: &str) -> Admin {
+        Admin {
+            id: ID::Existing(id),
+            ..Admin::new(
+                "Admin".to_string(),
+                email,
+                Role::Admin,
+                bcrypt::hash(password, 4).unwrap(),
+            )
+            .unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_password_already_changed() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin(
+                1,
+                DEFAULT_ADMIN_EMAIL,
+                "Changed-Pass-99",
+            ))));
+        mock_admin_repo.expect_update().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = RemoveDefaultAdminUseCase::new(mock_admin_repo, mock_audit_repo);
+
+        assert_eq!(sut.execute().await, Ok(false));
+    }
+
+    #[tokio::test]
+    async fn execute_only_admin() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+        let default_admin = make_fake_admin(1, DEFAULT_ADMIN_EMAIL, "123");
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(default_admin.clone())));
+        mock_admin_repo
+            .expect_find_all()
+            .times(1)
+            .return_const(Ok(vec![default_admin]));
+        mock_admin_repo.expect_update().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = RemoveDefaultAdminUseCase::new(mock_admin_repo, mock_audit_repo);
+
+        assert!(matches!(
+            sut.execute().await,
+            Err(AdminApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+        let default_admin = make_fake_admin(1, DEFAULT_ADMIN_EMAIL, "123");
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(default_admin.clone())));
+        mock_admin_repo
+            .expect_find_all()
+            .times(1)
+            .return_const(Ok(vec![
+                default_admin,
+                make_fake_admin(2, "cuddy@sghss.com", "Dean-Of-Med-1"),
+            ]));
+        mock_admin_repo
+            .expect_update()
+            .withf(|admin: &Admin| admin.id == ID::Existing(1) && !admin.active)
+            .times(1)
+            .returning(|admin| Ok(admin.clone()));
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.action == "user_deactivated"
+                    && event.actor == "system"
+                    && event.entity_id == Some(1)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = RemoveDefaultAdminUseCase::new(mock_admin_repo, mock_audit_repo);
+
+        assert_eq!(sut.execute().await, Ok(true));
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        repositories::{
+            patient_repository::PatientRepository, problem_repository::ProblemRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+};
+
+pub struct RemoveProblemUseCase<T: PatientRepository, R: ProblemRepository> {
+    patient_repo: T,
+    problem_repo: R,
+}
+
+impl<T: PatientRepository, R: ProblemRepository> RemoveProblemUseCase<T, R> {
+    pub fn new(patient_repo: T, problem_repo: R) -> Self {
+        Self {
+            patient_repo,
+            problem_repo,
+        }
+    }
+
+    pub async fn execute(&self, cpf: Cpf, problem_id: i32) -> Result<(), PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        if !self.problem_repo.delete(patient_id, problem_id).await? {
+            return Err(PatientApplicationError::ProblemNotFound(problem_id));
+        }
+
+        Ok(())
+    }
+}
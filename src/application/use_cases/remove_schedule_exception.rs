@@ -0,0 +1,32 @@
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::repositories::schedule_repository::ScheduleRepository,
+};
+
+pub struct RemoveScheduleExceptionUseCase<S: ScheduleRepository> {
+    schedule_repo: S,
+}
+
+impl<S: ScheduleRepository> RemoveScheduleExceptionUseCase<S> {
+    pub fn new(schedule_repo: S) -> Self {
+        Self { schedule_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        professional_id: i32,
+        exception_id: i32,
+    ) -> Result<(), ProfessionalApplicationError> {
+        if !self
+            .schedule_repo
+            .delete_exception(professional_id, exception_id)
+            .await?
+        {
+            return Err(ProfessionalApplicationError::ScheduleNotFound(format!(
+                "No schedule exception {exception_id} found for professional with ID: {professional_id}"
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+use chrono::NaiveDateTime;
+
+use crate::{
+    application::{
+        errors::appointment_application_error::AppointmentApplicationError,
+        use_cases::book_appointment::{appointment_duration, ensure_slot_is_bookable},
+    },
+    domain::{
+        entities::appointment::Appointment,
+        repositories::{
+            appointment_repository::AppointmentRepository, schedule_repository::ScheduleRepository,
+        },
+    },
+    presentation::dtos::appointment_dto::RescheduleAppointmentDTO,
+};
+
+pub struct RescheduleAppointmentUseCase<T: AppointmentRepository, S: ScheduleRepository> {
+    appointment_repository: T,
+    schedule_repository: S,
+}
+
+impl<T: AppointmentRepository, S: ScheduleRepository> RescheduleAppointmentUseCase<T, S> {
+    pub fn new(appointment_repository: T, schedule_repository: S) -> Self {
+        Self {
+            appointment_repository,
+            schedule_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        input: RescheduleAppointmentDTO,
+        rescheduled_by: String,
+    ) -> Result<Appointment, AppointmentApplicationError> {
+        let mut appointment = self.appointment_repository.find_by_id(id).await?.ok_or(
+            AppointmentApplicationError::NotFound(format!("No appointment found with ID: {id}")),
+        )?;
+
+        let duration = match input.duration_minutes {
+            Some(duration_minutes) => appointment_duration(duration_minutes)?,
+            None => appointment.duration(),
+        };
+
+        appointment.reschedule(
+            input.appointment_at.parse::<NaiveDateTime>()?,
+            duration,
+            rescheduled_by,
+            input.reason,
+        )?;
+
+        if self
+            .appointment_repository
+            .exists_overlapping_by_patient_id(
+                appointment.patient_id,
+                appointment.appointment_at,
+                appointment.ends_at,
+                Some(id),
+            )
+            .await?
+        {
+            return Err(AppointmentApplicationError::Constraint(format!(
+                "There's already an appointment for the patient between: {} and: {}",
+                appointment.appointment_at, appointment.ends_at
+            )));
+        }
+
+        ensure_slot_is_bookable(
+            &self.appointment_repository,
+            &self.schedule_repository,
+            appointment.professional_id,
+            &appointment.time_slot(),
+            Some(id),
+        )
+        .await?;
+
+        self.appointment_repository
+            .update(&appointment)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime, NaiveTime};
+
+    use crate::{
+        application::{
+            errors::appointment_application_error::AppointmentApplicationError,
+            use_cases::reschedule_appointment::RescheduleAppointmentUseCase,
+        },
+        domain::{
+            entities::{appointment::Appointment, professional_schedule::ProfessionalSchedule},
+            repositories::{
+                appointment_repository::MockAppointmentRepository,
+                schedule_repository::MockScheduleRepository,
+            },
+            value_objects::id::ID,
+        },
+        presentation::dtos::appointment_dto::RescheduleAppointmentDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_over_itself_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_appointment())));
+
+        mock_appointment_repo
+            .expect_exists_overlapping_by_patient_id()
+            .withf(|_, _, _, excluding_id| *excluding_id == Some(1))
+            .times(1)
+            .return_const(Ok(false));
+
+        // The appointment being moved still occupies its old slot.
+        mock_appointment_repo
+            .expect_find_by_professional_id_between()
+            .times(1)
+            .return_const(Ok(vec![make_fake_appointment()]));
+
+        mock_appointment_repo
+            .expect_update()
+            .times(1)
+            .returning(|appointment: &Appointment| Ok(appointment.clone()));
+
+        let sut =
+            RescheduleAppointmentUseCase::new(mock_appointment_repo, make_fake_schedule_repo());
+
+        let result = sut
+            .execute(
+                1,
+                make_fake_input("2025-06-09T08:15:00"),
+                "admin@sghss.com".to_string(),
+            )
+            .await?;
+
+        assert_eq!(
+            result.previous_appointment_at,
+            Some("2025-06-09T08:00:00".parse::<NaiveDateTime>()?)
+        );
+        assert_eq!(
+            result.ends_at,
+            "2025-06-09T08:45:00".parse::<NaiveDateTime>()?
+        );
+        assert_eq!(result.rescheduled_by, Some("admin@sghss.com".to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_unavailable_slot_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_appointment())));
+
+        mock_appointment_repo
+            .expect_exists_overlapping_by_patient_id()
+            .times(1)
+            .return_const(Ok(false));
+
+        mock_appointment_repo
+            .expect_find_by_professional_id_between()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        mock_appointment_repo.expect_update().times(0);
+
+        let sut =
+            RescheduleAppointmentUseCase::new(mock_appointment_repo, make_fake_schedule_repo());
+
+        let result = sut
+            .execute(
+                1,
+                make_fake_input("2025-06-09T13:00:00"),
+                "admin@sghss.com".to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::UnavailableSlot(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_duration_out_of_range_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_appointment())));
+
+        mock_appointment_repo.expect_update().times(0);
+
+        let sut =
+            RescheduleAppointmentUseCase::new(mock_appointment_repo, make_fake_schedule_repo());
+
+        let mut input = make_fake_input("2025-06-09T08:15:00");
+        input.duration_minutes = Some(i64::MAX);
+
+        let result = sut.execute(1, input, "admin@sghss.com".to_string()).await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_not_found_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        let sut =
+            RescheduleAppointmentUseCase::new(mock_appointment_repo, make_fake_schedule_repo());
+
+        let result = sut
+            .execute(
+                1,
+                make_fake_input("2025-06-09T08:15:00"),
+                "admin@sghss.com".to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::NotFound(_))
+        ));
+    }
+
+    fn make_fake_schedule_repo() -> MockScheduleRepository {
+        let mut mock_schedule_repo = MockScheduleRepository::new();
+
+        mock_schedule_repo
+            .expect_find_schedules_by_professional_id()
+            .return_const(Ok(vec![
+                ProfessionalSchedule::new(
+                    42,
+                    1,
+                    "08:00".parse::<NaiveTime>().unwrap(),
+                    "12:00".parse::<NaiveTime>().unwrap(),
+                )
+                .unwrap(),
+            ]));
+
+        mock_schedule_repo
+            .expect_find_exceptions_by_professional_id_between()
+            .return_const(Ok(vec![]));
+
+        mock_schedule_repo
+    }
+
+    fn make_fake_appointment() -> Appointment {
+        // 2025-06-09 is a Monday.
+        let mut appointment = Appointment::new(
+            7,
+            Some(42),
+            "2025-06-09T08:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "Nephrology".to_string(),
+            Some("Bring previous exams".to_string()),
+        )
+        .unwrap();
+        appointment.id = ID::Existing(1);
+
+        appointment
+    }
+
+    fn make_fake_input(appointment_at: &str) -> RescheduleAppointmentDTO {
+        RescheduleAppointmentDTO {
+            appointment_at: appointment_at.to_string(),
+            duration_minutes: None,
+            reason: Some("Patient is traveling".to_string()),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    application::{
+        errors::admin_application_error::AdminApplicationError, security::password::hash_password,
+    },
+    domain::{
+        entities::{
+            admin::Admin,
+            audit_event::{AuditAction, AuditEvent},
+        },
+        repositories::{
+            admin_repository::AdminRepository, audit_repository::AuditRepository,
+            session_repository::SessionRepository,
+        },
+        value_objects::password::Password,
+    },
+    presentation::dtos::admin_dto::ResetPasswordDTO,
+};
+
+/// Gives an account a temporary password, which its owner must change before
+/// doing anything else.
+pub struct ResetPasswordUseCase<T: AdminRepository, S: SessionRepository, A: AuditRepository> {
+    admin_repo: T,
+    session_repo: S,
+    audit_repo: A,
+}
+
+impl<T: AdminRepository, S: SessionRepository, A: AuditRepository> ResetPasswordUseCase<T, S, A> {
+    pub fn new(admin_repo: T, session_repo: S, audit_repo: A) -> Self {
+        Self {
+            admin_repo,
+            session_repo,
+            audit_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        input: ResetPasswordDTO,
+        actor: String,
+    ) -> Result<Admin, AdminApplicationError> {
+        let mut admin = self
+            .admin_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(AdminApplicationError::NotFoundById(id))?;
+
+        let temporary_password = Password::parse(&input.temporary_password)?;
+        admin.reset_password(hash_password(&temporary_password)?);
+
+        let admin = self.admin_repo.update(&admin).await?;
+        self.session_repo.revoke_all_by_admin_id(id).await?;
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                actor,
+                AuditAction::PasswordReset,
+                "user",
+                Some(id),
+                None,
+            ))
+            .await?;
+
+        Ok(admin)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            use_cases::reset_password::ResetPasswordUseCase,
+        },
+        domain::{
+            entities::{admin::Admin, audit_event::AuditEvent},
+            repositories::{
+                admin_repository::MockAdminRepository, audit_repository::MockAuditRepository,
+                session_repository::MockSessionRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+        presentation::dtos::admin_dto::ResetPasswordDTO,
+    };
+
+    fn make_fake_admin() -> Admin {
+        let mut admin = Admin {
+            id: ID::Existing(6),
+            ..Admin::new(
+                "Robert Chase".to_string(),
+                "chase@sghss.com",
+                Role::Nurse,
+                "hash".to_string(),
+            )
+            .unwrap()
+        };
+        admin.change_password("chosen".to_string());
+        admin
+    }
+
+    #[tokio::test]
+    async fn execute_weak_password() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin())));
+        mock_admin_repo.expect_update().times(0);
+        mock_session_repo.expect_revoke_all_by_admin_id().times(0);
+        mock_audit_repo.expect_save().times(0);
+
+        let sut = ResetPasswordUseCase::new(mock_admin_repo, mock_session_repo, mock_audit_repo);
+
+        let result = sut
+            .execute(
+                6,
+                ResetPasswordDTO {
+                    temporary_password: "NoSymbols12345".to_string(),
+                },
+                "admin@sghss.com".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(AdminApplicationError::Constraint(
+                "The password is too weak: it must have a symbol".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_admin())));
+        mock_admin_repo
+            .expect_update()
+            .withf(|admin: &Admin| admin.must_change_password)
+            .times(1)
+            .returning(|admin| Ok(admin.clone()));
+        mock_session_repo
+            .expect_revoke_all_by_admin_id()
+            .times(1)
+            .return_const(Ok(1));
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.action == "password_reset" && event.entity_id == Some(6)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = ResetPasswordUseCase::new(mock_admin_repo, mock_session_repo, mock_audit_repo);
+
+        let result = sut
+            .execute(
+                6,
+                ResetPasswordDTO {
+                    temporary_password: "Temporary-Pass-1".to_string(),
+                },
+                "admin@sghss.com".to_string(),
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::consent::Consent,
+        repositories::{
+            consent_repository::ConsentRepository, patient_repository::PatientRepository,
+        },
+        value_objects::{consent_purpose::ConsentPurpose, cpf::Cpf},
+    },
+};
+
+pub struct RevokeConsentUseCase<T: PatientRepository, C: ConsentRepository> {
+    patient_repo: T,
+    consent_repo: C,
+}
+
+impl<T: PatientRepository, C: ConsentRepository> RevokeConsentUseCase<T, C> {
+    pub fn new(patient_repo: T, consent_repo: C) -> Self {
+        Self {
+            patient_repo,
+            consent_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        purpose: ConsentPurpose,
+        revoked_by: String,
+    ) -> Result<Consent, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let mut consent = self
+            .consent_repo
+            .find_active_by_patient_id_and_purpose(patient_id, purpose)
+            .await?
+            .ok_or(PatientApplicationError::ConsentNotFound(format!(
+                "No active consent for {purpose} found for patient with CPF: {cpf}"
+            )))?;
+
+        consent.revoke(revoked_by)?;
+
+        Ok(self.consent_repo.update(&consent).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::revoke_consent::RevokeConsentUseCase,
+        },
+        domain::{
+            entities::{consent::Consent, patient::Patient},
+            repositories::{
+                consent_repository::MockConsentRepository,
+                patient_repository::MockPatientRepository,
+            },
+            value_objects::{
+                consent_channel::ConsentChannel, consent_purpose::ConsentPurpose, cpf::Cpf, id::ID,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_consent_not_found() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_consent_repo = MockConsentRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(make_fake_patient())));
+
+        mock_consent_repo
+            .expect_find_active_by_patient_id_and_purpose()
+            .with(eq(7), eq(ConsentPurpose::SmsContact))
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_consent_repo.expect_update().times(0);
+
+        let sut = RevokeConsentUseCase::new(mock_patient_repo, mock_consent_repo);
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                ConsentPurpose::SmsContact,
+                "admin@example.com".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(PatientApplicationError::ConsentNotFound(
+                "No active consent for sms_contact found for patient with CPF: 12345678909"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_consent_repo = MockConsentRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(make_fake_patient())));
+
+        let mut consent = Consent::new(
+            7,
+            ConsentPurpose::SmsContact,
+            "v1".to_string(),
+            ConsentChannel::Phone,
+            "admin@example.com".to_string(),
+        )
+        .unwrap();
+        consent.id = ID::Existing(3);
+
+        mock_consent_repo
+            .expect_find_active_by_patient_id_and_purpose()
+            .times(1)
+            .return_const(Ok(Some(consent)));
+
+        mock_consent_repo
+            .expect_update()
+            .withf(|consent| {
+                !consent.is_active() && consent.revoked_by == Some("dpo@example.com".to_string())
+            })
+            .times(1)
+            .returning(|consent| Ok(consent.clone()));
+
+        let sut = RevokeConsentUseCase::new(mock_patient_repo, mock_consent_repo);
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                ConsentPurpose::SmsContact,
+                "dpo@example.com".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert!(result.revoked_at.is_some());
+    }
+
+    fn make_fake_patient() -> Patient {
+        Patient::restore(7, "Andrew".to_string(), Cpf::parse("12345678909").unwrap()).unwrap()
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    application::errors::admin_application_error::AdminApplicationError,
+    domain::{
+        entities::audit_event::{AuditAction, AuditEvent},
+        repositories::{
+            admin_repository::AdminRepository, audit_repository::AuditRepository,
+            session_repository::SessionRepository,
+        },
+    },
+};
+
+/// Logs an account out everywhere: every access and refresh token it holds
+/// stops working.
+pub struct RevokeSessionsUseCase<T: AdminRepository, S: SessionRepository, A: AuditRepository> {
+    admin_repo: T,
+    session_repo: S,
+    audit_repo: A,
+}
+
+impl<T: AdminRepository, S: SessionRepository, A: AuditRepository> RevokeSessionsUseCase<T, S, A> {
+    pub fn new(admin_repo: T, session_repo: S, audit_repo: A) -> Self {
+        Self {
+            admin_repo,
+            session_repo,
+            audit_repo,
+        }
+    }
+
+    /// Returns how many sessions were revoked.
+    pub async fn execute(&self, id: i32, actor: String) -> Result<usize, AdminApplicationError> {
+        self.admin_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(AdminApplicationError::NotFoundById(id))?;
+
+        let revoked = self.session_repo.revoke_all_by_admin_id(id).await?;
+
+        self.audit_repo
+            .save(&AuditEvent::new(
+                actor,
+                AuditAction::SessionsRevoked,
+                "user",
+                Some(id),
+                Some(format!("{revoked} session(s) revoked")),
+            ))
+            .await?;
+
+        Ok(revoked)
+    }
+
+    /// Revokes the caller's own sessions, the current one included.
+    pub async fn execute_own(&self, email: String) -> Result<usize, AdminApplicationError> {
+        let admin = self
+            .admin_repo
+            .find_by_email(email.clone())
+            .await?
+            .ok_or(AdminApplicationError::NotFound(email.clone()))?;
+        let id: Option<i32> = admin.id.into();
+
+        self.execute(id.unwrap_or_default(), email).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::admin_application_error::AdminApplicationError,
+            use_cases::revoke_sessions::RevokeSessionsUseCase,
+        },
+        domain::{
+            entities::{admin::Admin, audit_event::AuditEvent},
+            repositories::{
+                admin_repository::MockAdminRepository, audit_repository::MockAuditRepository,
+                session_repository::MockSessionRepository,
+            },
+            value_objects::{id::ID, role::Role},
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_user_not_found() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+        mock_session_repo.expect_revoke_all_by_admin_id().times(0);
+
+        let sut = RevokeSessionsUseCase::new(
+            mock_admin_repo,
+            mock_session_repo,
+            MockAuditRepository::new(),
+        );
+
+        let result = sut.execute(8, "admin@sghss.com".to_string()).await;
+
+        assert_eq!(result, Err(AdminApplicationError::NotFoundById(8)));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+
+        mock_admin_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(Admin {
+                id: ID::Existing(8),
+                ..Admin::new(
+                    "Chris Taub".to_string(),
+                    "taub@sghss.com",
+                    Role::Physician,
+                    "hash".to_string(),
+                )
+                .unwrap()
+            })));
+        mock_session_repo
+            .expect_revoke_all_by_admin_id()
+            .with(eq(8))
+            .times(1)
+            .return_const(Ok(2));
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| {
+                event.action == "sessions_revoked"
+                    && event.entity_id == Some(8)
+                    && event.details.as_deref() == Some("2 session(s) revoked")
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = RevokeSessionsUseCase::new(mock_admin_repo, mock_session_repo, mock_audit_repo);
+
+        let result = sut.execute(8, "admin@sghss.com".to_string()).await;
+
+        assert_eq!(result, Ok(2));
+    }
+
+    #[tokio::test]
+    async fn execute_own_looks_up_caller() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_session_repo = MockSessionRepository::new();
+        let mut mock_audit_repo = MockAuditRepository::new();
+        let admin = Admin {
+            id: ID::Existing(3),
+            ..Admin::new(
+                "Lisa Cuddy".to_string(),
+                "cuddy@sghss.com",
+                Role::Admin,
+                "hash".to_string(),
+            )
+            .unwrap()
+        };
+
+        mock_admin_repo
+            .expect_find_by_email()
+            .with(eq("cuddy@sghss.com".to_string()))
+            .times(1)
+            .return_const(Ok(Some(admin.clone())));
+        mock_admin_repo
+            .expect_find_by_id()
+            .with(eq(3))
+            .times(1)
+            .return_const(Ok(Some(admin)));
+        mock_session_repo
+            .expect_revoke_all_by_admin_id()
+            .with(eq(3))
+            .times(1)
+            .return_const(Ok(1));
+        mock_audit_repo
+            .expect_save()
+            .withf(|event: &AuditEvent| event.actor == "cuddy@sghss.com")
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = RevokeSessionsUseCase::new(mock_admin_repo, mock_session_repo, mock_audit_repo);
+
+        let result = sut.execute_own("cuddy@sghss.com".to_string()).await;
+
+        assert_eq!(result, Ok(1));
+    }
+}
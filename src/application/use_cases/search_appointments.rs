@@ -0,0 +1,219 @@
+use chrono::NaiveDateTime;
+
+use crate::{
+    application::errors::appointment_application_error::AppointmentApplicationError,
+    domain::{
+        entities::appointment::Appointment,
+        repositories::{
+            appointment_repository::{AppointmentFilter, AppointmentRepository},
+            patient_repository::PatientRepository,
+        },
+        value_objects::pagination::{Page, Pagination},
+    },
+    presentation::dtos::appointment_dto::SearchAppointmentsQueryDTO,
+};
+
+pub struct SearchAppointmentsUseCase<T: AppointmentRepository, P: PatientRepository> {
+    appointment_repo: T,
+    patient_repo: P,
+}
+
+impl<T: AppointmentRepository, P: PatientRepository> SearchAppointmentsUseCase<T, P> {
+    pub fn new(appointment_repo: T, patient_repo: P) -> Self {
+        Self {
+            appointment_repo,
+            patient_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: SearchAppointmentsQueryDTO,
+    ) -> Result<Page<Appointment>, AppointmentApplicationError> {
+        let patient_id = match query.patient_cpf {
+            Some(cpf) => {
+                let patient = self.patient_repo.find_by_cpf(cpf.clone()).await?.ok_or(
+                    AppointmentApplicationError::PatientNotFound(cpf.to_string()),
+                )?;
+
+                patient.id.into()
+            }
+            None => None,
+        };
+
+        let filter = AppointmentFilter {
+            from: query
+                .from
+                .map(|from| from.parse::<NaiveDateTime>())
+                .transpose()?,
+            to: query.to.map(|to| to.parse::<NaiveDateTime>()).transpose()?,
+            specialty: query
+                .specialty
+                .map(|specialty| specialty.trim().to_string()),
+            professional_id: query.professional_id,
+            status: query.status,
+            patient_id,
+            sort: query.sort.unwrap_or_default(),
+        };
+
+        if let (Some(from), Some(to)) = (filter.from, filter.to)
+            && from >= to
+        {
+            return Err(AppointmentApplicationError::Constraint(format!(
+                "The search range start: {from} must be before its end: {to}"
+            )));
+        }
+
+        self.appointment_repo
+            .search(filter, Pagination::new(query.page, query.limit))
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        application::{
+            errors::appointment_application_error::AppointmentApplicationError,
+            use_cases::search_appointments::SearchAppointmentsUseCase,
+        },
+        domain::{
+            entities::patient::Patient,
+            repositories::{
+                appointment_repository::{AppointmentFilter, MockAppointmentRepository},
+                patient_repository::MockPatientRepository,
+            },
+            value_objects::{
+                appointment_status::AppointmentStatus,
+                cpf::Cpf,
+                pagination::{Page, Pagination, SortOrder},
+            },
+        },
+        presentation::dtos::appointment_dto::SearchAppointmentsQueryDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_builds_filter() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_patient_repo = MockPatientRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(
+                Patient::restore(7, "Andrew".to_string(), Cpf::parse("52998224725").unwrap())
+                    .unwrap(),
+            )));
+
+        mock_appointment_repo
+            .expect_search()
+            .withf(|filter: &AppointmentFilter, pagination: &Pagination| {
+                *filter
+                    == AppointmentFilter {
+                        from: Some("2025-07-01T00:00:00".parse::<NaiveDateTime>().unwrap()),
+                        to: None,
+                        specialty: Some("Cardiology".to_string()),
+                        professional_id: None,
+                        status: Some(AppointmentStatus::Confirmed),
+                        patient_id: Some(7),
+                        sort: SortOrder::Desc,
+                    }
+                    && *pagination == Pagination::new(Some(2), Some(10))
+            })
+            .times(1)
+            .returning(|_, pagination| {
+                Ok(Page {
+                    items: vec![],
+                    total: 11,
+                    pagination,
+                })
+            });
+
+        let sut = SearchAppointmentsUseCase::new(mock_appointment_repo, mock_patient_repo);
+
+        let result = sut
+            .execute(SearchAppointmentsQueryDTO {
+                from: Some("2025-07-01T00:00:00".to_string()),
+                specialty: Some(" Cardiology ".to_string()),
+                status: Some(AppointmentStatus::Confirmed),
+                patient_cpf: Some(Cpf::parse("52998224725").unwrap()),
+                sort: Some(SortOrder::Desc),
+                page: Some(2),
+                limit: Some(10),
+                ..make_empty_query()
+            })
+            .await?;
+
+        assert_eq!(result.total, 11);
+        assert_eq!(result.total_pages(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_patient_not_found_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+        let mut mock_patient_repo = MockPatientRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_appointment_repo.expect_search().times(0);
+
+        let sut = SearchAppointmentsUseCase::new(mock_appointment_repo, mock_patient_repo);
+
+        let result = sut
+            .execute(SearchAppointmentsQueryDTO {
+                patient_cpf: Some(Cpf::parse("52998224725").unwrap()),
+                ..make_empty_query()
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::PatientNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_inverted_range_error() {
+        let mut mock_appointment_repo = MockAppointmentRepository::new();
+
+        mock_appointment_repo.expect_search().times(0);
+
+        let sut =
+            SearchAppointmentsUseCase::new(mock_appointment_repo, MockPatientRepository::new());
+
+        let result = sut
+            .execute(SearchAppointmentsQueryDTO {
+                from: Some("2025-07-02T00:00:00".to_string()),
+                to: Some("2025-07-01T00:00:00".to_string()),
+                ..make_empty_query()
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppointmentApplicationError::Constraint(_))
+        ));
+    }
+
+    fn make_empty_query() -> SearchAppointmentsQueryDTO {
+        SearchAppointmentsQueryDTO {
+            from: None,
+            to: None,
+            specialty: None,
+            professional_id: None,
+            status: None,
+            patient_cpf: None,
+            sort: None,
+            page: None,
+            limit: None,
+        }
+    }
+}
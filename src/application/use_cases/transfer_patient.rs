@@ -0,0 +1,123 @@
+use crate::{
+    application::errors::admission_application_error::AdmissionApplicationError,
+    domain::{
+        entities::admission::{Admission, BedOccupancy},
+        repositories::{
+            admission_repository::AdmissionRepository, ward_repository::WardRepository,
+        },
+    },
+    presentation::dtos::admission_dto::TransferPatientDTO,
+};
+
+pub struct TransferPatientUseCase<W: WardRepository, A: AdmissionRepository> {
+    ward_repo: W,
+    admission_repo: A,
+}
+
+impl<W: WardRepository, A: AdmissionRepository> TransferPatientUseCase<W, A> {
+    pub fn new(ward_repo: W, admission_repo: A) -> Self {
+        Self {
+            ward_repo,
+            admission_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        admission_id: i32,
+        input: TransferPatientDTO,
+        actor: String,
+    ) -> Result<(Admission, Vec<BedOccupancy>), AdmissionApplicationError> {
+        let (admission, occupancies) = self
+            .admission_repo
+            .find_by_id(admission_id)
+            .await?
+            .ok_or(AdmissionApplicationError::NotFound(admission_id))?;
+
+        let bed = self
+            .ward_repo
+            .find_bed_by_id(input.bed_id)
+            .await?
+            .ok_or(AdmissionApplicationError::BedNotFound(input.bed_id))?;
+
+        let occupancy = admission.transfer_to(&occupancies, &bed, actor)?;
+
+        Ok(self.admission_repo.transfer(&occupancy).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDateTime;
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::admission_application_error::AdmissionApplicationError,
+            use_cases::transfer_patient::TransferPatientUseCase,
+        },
+        domain::{
+            entities::{admission::Admission, ward::Bed},
+            repositories::{
+                admission_repository::MockAdmissionRepository, ward_repository::MockWardRepository,
+            },
+            value_objects::{bed_status::BedStatus, id::ID},
+        },
+        presentation::dtos::admission_dto::TransferPatientDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_discharged_admission_error() {
+        let mut mock_admission_repo = MockAdmissionRepository::new();
+        let mut mock_ward_repo = MockWardRepository::new();
+        let admitted_at = "2025-09-20T10:00:00".parse::<NaiveDateTime>().unwrap();
+
+        mock_admission_repo
+            .expect_find_by_id()
+            .with(eq(9))
+            .times(1)
+            .return_const(Ok(Some((
+                Admission {
+                    id: ID::Existing(9),
+                    patient_id: 7,
+                    professional_id: None,
+                    reason: "Fracture".to_string(),
+                    admitted_by: "nurse@example.com".to_string(),
+                    admitted_at,
+                    discharged_at: Some(admitted_at),
+                    discharged_by: Some("physician@example.com".to_string()),
+                    discharge_summary: None,
+                },
+                vec![],
+            ))));
+
+        mock_ward_repo
+            .expect_find_bed_by_id()
+            .times(1)
+            .return_const(Ok(Some(Bed {
+                id: ID::Existing(5),
+                room_id: 2,
+                label: "B".to_string(),
+                status: BedStatus::Free,
+            })));
+
+        mock_admission_repo.expect_transfer().times(0);
+
+        let sut = TransferPatientUseCase::new(mock_ward_repo, mock_admission_repo);
+
+        let result = sut
+            .execute(
+                9,
+                TransferPatientDTO { bed_id: 5 },
+                "nurse@example.com".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(AdmissionApplicationError::Constraint(
+                "The admission 9 was already discharged".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::allergy::Allergy,
+        repositories::{
+            allergy_repository::AllergyRepository, patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+    presentation::dtos::allergy_dto::UpdateAllergyDTO,
+};
+
+pub struct UpdateAllergyUseCase<T: PatientRepository, A: AllergyRepository> {
+    patient_repo: T,
+    allergy_repo: A,
+}
+
+impl<T: PatientRepository, A: AllergyRepository> UpdateAllergyUseCase<T, A> {
+    pub fn new(patient_repo: T, allergy_repo: A) -> Self {
+        Self {
+            patient_repo,
+            allergy_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        allergy_id: i32,
+        input: UpdateAllergyDTO,
+    ) -> Result<Allergy, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let mut allergy = self
+            .allergy_repo
+            .find_by_id(patient_id, allergy_id)
+            .await?
+            .ok_or(PatientApplicationError::AllergyNotFound(allergy_id))?;
+
+        allergy.apply_update(input)?;
+
+        Ok(self.allergy_repo.update(&allergy).await?)
+    }
+}
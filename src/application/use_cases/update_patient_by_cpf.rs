@@ -1,6 +1,9 @@
 use crate::{
     application::errors::patient_application_error::PatientApplicationError,
-    domain::{entities::patient::Patient, repositories::patient_repository::PatientRepository},
+    domain::{
+        entities::patient::Patient, repositories::patient_repository::PatientRepository,
+        value_objects::cpf::Cpf,
+    },
     presentation::dtos::patient_dto::UpdatePatientDTO,
 };
 
@@ -15,17 +18,18 @@ impl<T: PatientRepository> UpdatePatientByCpfUseCase<T> {
 
     pub async fn execute(
         &self,
-        cpf: String,
+        cpf: Cpf,
         updated_patient: UpdatePatientDTO,
     ) -> Result<Patient, PatientApplicationError> {
         let patient = self.patient_repo.find_by_cpf(cpf.clone()).await?;
 
         if patient.is_none() {
-            return Err(PatientApplicationError::NotFound(cpf));
+            return Err(PatientApplicationError::NotFound(cpf.to_string()));
         }
 
         let mut patient = patient.unwrap();
         patient.name = updated_patient.name.unwrap_or(patient.name);
+        patient.apply_profile(updated_patient.profile)?;
 
         self.patient_repo
             .update(&patient)
@@ -45,9 +49,9 @@ mod test {
         },
         domain::{
             entities::patient::Patient, errors::repository_error::RepositoryError,
-            repositories::patient_repository::MockPatientRepository,
+            repositories::patient_repository::MockPatientRepository, value_objects::cpf::Cpf,
         },
-        presentation::dtos::patient_dto::UpdatePatientDTO,
+        presentation::dtos::patient_dto::{PatientProfileDTO, UpdatePatientDTO},
     };
 
     #[tokio::test]
@@ -83,7 +87,10 @@ mod test {
 
         let result = sut.execute(cpf.clone(), updated_patient).await;
 
-        assert_eq!(result, Err(PatientApplicationError::NotFound(cpf)));
+        assert_eq!(
+            result,
+            Err(PatientApplicationError::NotFound(cpf.to_string()))
+        );
     }
 
     #[tokio::test]
@@ -138,18 +145,25 @@ mod test {
     }
 
     fn make_fake_patient() -> Patient {
-        Patient::restore(42, "Andrew".to_string(), "12345678901".to_string()).unwrap()
+        Patient::restore(42, "Andrew".to_string(), Cpf::parse("12345678909").unwrap()).unwrap()
     }
 
-    fn make_fake_input(name: Option<String>) -> (String, UpdatePatientDTO) {
-        let cpf = "12345678901".to_string();
+    fn make_fake_input(name: Option<String>) -> (Cpf, UpdatePatientDTO) {
+        let cpf = Cpf::parse("12345678909").unwrap();
 
         match name {
-            Some(name) => (cpf.clone(), UpdatePatientDTO { name: Some(name) }),
+            Some(name) => (
+                cpf.clone(),
+                UpdatePatientDTO {
+                    name: Some(name),
+                    profile: PatientProfileDTO::default(),
+                },
+            ),
             None => (
                 cpf.clone(),
                 UpdatePatientDTO {
                     name: Some("Andrew Updated".to_string()),
+                    profile: PatientProfileDTO::default(),
                 },
             ),
         }
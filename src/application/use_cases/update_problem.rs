@@ -0,0 +1,157 @@
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::problem::Problem,
+        repositories::{
+            patient_repository::PatientRepository, problem_repository::ProblemRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+    presentation::dtos::problem_dto::UpdateProblemDTO,
+};
+
+pub struct UpdateProblemUseCase<T: PatientRepository, R: ProblemRepository> {
+    patient_repo: T,
+    problem_repo: R,
+}
+
+impl<T: PatientRepository, R: ProblemRepository> UpdateProblemUseCase<T, R> {
+    pub fn new(patient_repo: T, problem_repo: R) -> Self {
+        Self {
+            patient_repo,
+            problem_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        problem_id: i32,
+        input: UpdateProblemDTO,
+    ) -> Result<Problem, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let mut problem = self
+            .problem_repo
+            .find_by_id(patient_id, problem_id)
+            .await?
+            .ok_or(PatientApplicationError::ProblemNotFound(problem_id))?;
+
+        problem.apply_update(input)?;
+
+        Ok(self.problem_repo.update(&problem).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::update_problem::UpdateProblemUseCase,
+        },
+        domain::{
+            entities::{patient::Patient, problem::Problem},
+            repositories::{
+                patient_repository::MockPatientRepository,
+                problem_repository::MockProblemRepository,
+            },
+            value_objects::{cpf::Cpf, icd10::Icd10, id::ID},
+        },
+        presentation::dtos::problem_dto::{CreateProblemDTO, UpdateProblemDTO},
+    };
+
+    #[tokio::test]
+    async fn execute_problem_not_found() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_problem_repo = MockProblemRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(make_fake_patient())));
+
+        mock_problem_repo
+            .expect_find_by_id()
+            .with(eq(7), eq(3))
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_problem_repo.expect_update().times(0);
+
+        let sut = UpdateProblemUseCase::new(mock_patient_repo, mock_problem_repo);
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                3,
+                UpdateProblemDTO::default(),
+            )
+            .await;
+
+        assert_eq!(result, Err(PatientApplicationError::ProblemNotFound(3)));
+    }
+
+    #[tokio::test]
+    async fn execute_resolves() {
+        let mut mock_patient_repo = MockPatientRepository::new();
+        let mut mock_problem_repo = MockProblemRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(make_fake_patient())));
+
+        let mut problem = Problem::new(
+            7,
+            CreateProblemDTO {
+                icd10_code: Icd10::parse("J45.0").unwrap(),
+                description: "Asthma".to_string(),
+                onset_date: None,
+                notes: None,
+            },
+        )
+        .unwrap();
+        problem.id = ID::Existing(3);
+
+        mock_problem_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(problem)));
+
+        mock_problem_repo
+            .expect_update()
+            .withf(|problem: &Problem| !problem.is_active())
+            .times(1)
+            .returning(|problem| Ok(problem.clone()));
+
+        let sut = UpdateProblemUseCase::new(mock_patient_repo, mock_problem_repo);
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                3,
+                UpdateProblemDTO {
+                    resolved_at: Some("2025-01-15".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.resolved_at.unwrap().to_string(), "2025-01-15");
+    }
+
+    fn make_fake_patient() -> Patient {
+        Patient::restore(7, "Andrew".to_string(), Cpf::parse("12345678909").unwrap()).unwrap()
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{
+    application::errors::professional_application_error::ProfessionalApplicationError,
+    domain::{
+        entities::professional::Professional,
+        repositories::professional_repository::ProfessionalRepository,
+    },
+    presentation::dtos::professional_dto::UpdateProfessionalDTO,
+};
+
+pub struct UpdateProfessionalByIdUseCase<T: ProfessionalRepository> {
+    professional_repo: T,
+}
+
+impl<T: ProfessionalRepository> UpdateProfessionalByIdUseCase<T> {
+    pub fn new(professional_repo: T) -> Self {
+        Self { professional_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        id: i32,
+        updated_professional: UpdateProfessionalDTO,
+    ) -> Result<Professional, ProfessionalApplicationError> {
+        let professional = self.professional_repo.find_by_id(id).await?;
+
+        if professional.is_none() {
+            return Err(ProfessionalApplicationError::NotFound(id));
+        }
+
+        let mut professional = professional.unwrap();
+
+        if let Some(name) = updated_professional.name {
+            professional.rename(name)?;
+        }
+
+        if let Some(specialties) = updated_professional.specialties {
+            professional.set_specialties(specialties)?;
+        }
+
+        professional.active = updated_professional.active.unwrap_or(professional.active);
+
+        self.professional_repo
+            .update(&professional)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            errors::professional_application_error::ProfessionalApplicationError,
+            use_cases::update_professional_by_id::UpdateProfessionalByIdUseCase,
+        },
+        domain::{
+            entities::professional::Professional,
+            repositories::professional_repository::MockProfessionalRepository,
+        },
+        presentation::dtos::professional_dto::UpdateProfessionalDTO,
+    };
+
+    #[tokio::test]
+    async fn execute_professional_not_found() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        mock_professional_repo.expect_update().times(0);
+
+        let sut = UpdateProfessionalByIdUseCase::new(mock_professional_repo);
+
+        let result = sut.execute(42, make_fake_input()).await;
+
+        assert_eq!(result, Err(ProfessionalApplicationError::NotFound(42)));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+        let fake_professional = make_fake_professional();
+        let mut updated_professional = fake_professional.clone();
+        updated_professional.specialties = vec!["Infectious Disease".to_string()];
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(fake_professional)));
+
+        mock_professional_repo
+            .expect_update()
+            .with(eq(updated_professional.clone()))
+            .times(1)
+            .return_const(Ok(updated_professional.clone()));
+
+        let sut = UpdateProfessionalByIdUseCase::new(mock_professional_repo);
+
+        let result = sut.execute(42, make_fake_input()).await?;
+
+        assert_eq!(result, updated_professional);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_blank_name_error() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_professional())));
+        mock_professional_repo.expect_update().times(0);
+
+        let sut = UpdateProfessionalByIdUseCase::new(mock_professional_repo);
+
+        let result = sut
+            .execute(
+                42,
+                UpdateProfessionalDTO {
+                    name: Some(" ".to_string()),
+                    specialties: None,
+                    active: None,
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(ProfessionalApplicationError::Constraint(
+                "An invalid name was given for a professional: ' '".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_duplicate_specialties_error() {
+        let mut mock_professional_repo = MockProfessionalRepository::new();
+
+        mock_professional_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(make_fake_professional())));
+        mock_professional_repo.expect_update().times(0);
+
+        let sut = UpdateProfessionalByIdUseCase::new(mock_professional_repo);
+
+        let result = sut
+            .execute(
+                42,
+                UpdateProfessionalDTO {
+                    name: None,
+                    specialties: Some(vec!["Nephrology".to_string(), "NEPHROLOGY".to_string()]),
+                    active: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ProfessionalApplicationError::Constraint(_))
+        ));
+    }
+
+    fn make_fake_professional() -> Professional {
+        Professional::restore(
+            42,
+            "Gregory House".to_string(),
+            "CRM".to_string(),
+            "123456".to_string(),
+            "SP".to_string(),
+            vec!["Nephrology".to_string()],
+            true,
+        )
+        .unwrap()
+    }
+
+    fn make_fake_input() -> UpdateProfessionalDTO {
+        UpdateProfessionalDTO {
+            name: None,
+            specialties: Some(vec!["Infectious Disease".to_string()]),
+            active: None,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use log::error;
+
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::document::Document,
+        repositories::{
+            document_repository::DocumentRepository, file_storage::FileStorage,
+            patient_repository::PatientRepository,
+        },
+        value_objects::cpf::Cpf,
+    },
+    presentation::dtos::document_dto::UploadDocumentDTO,
+};
+
+pub struct UploadDocumentUseCase<T: PatientRepository, D: DocumentRepository, S: FileStorage> {
+    patient_repo: T,
+    document_repo: D,
+    file_storage: S,
+}
+
+impl<T: PatientRepository, D: DocumentRepository, S: FileStorage> UploadDocumentUseCase<T, D, S> {
+    pub fn new(patient_repo: T, document_repo: D, file_storage: S) -> Self {
+        Self {
+            patient_repo,
+            document_repo,
+            file_storage,
+        }
+    }
+
+    /// The file is stored before its row is saved, and removed again if the
+    /// save fails, so a document row never points to a missing file.
+    pub async fn execute(
+        &self,
+        cpf: Cpf,
+        input: UploadDocumentDTO,
+        uploaded_by: String,
+    ) -> Result<Document, PatientApplicationError> {
+        let patient = self
+            .patient_repo
+            .find_by_cpf(cpf.clone())
+            .await?
+            .ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let patient_id: Option<i32> = patient.id.into();
+        let patient_id = patient_id.ok_or(PatientApplicationError::NotFound(cpf.to_string()))?;
+
+        let document = Document::new(patient_id, &input, uploaded_by)?;
+
+        self.file_storage
+            .put(&document.storage_key, input.content)
+            .await?;
+
+        match self.document_repo.save(&document).await {
+            Ok(document) => Ok(document),
+            Err(err) => {
+                if let Err(storage_err) = self.file_storage.delete(&document.storage_key).await {
+                    error!("Orphaned document file: {storage_err}");
+                }
+
+                Err(err.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::always;
+
+    use crate::{
+        application::{
+            errors::patient_application_error::PatientApplicationError,
+            use_cases::upload_document::UploadDocumentUseCase,
+        },
+        domain::{
+            entities::patient::Patient,
+            errors::repository_error::RepositoryError,
+            repositories::{
+                document_repository::MockDocumentRepository, file_storage::MockFileStorage,
+                patient_repository::MockPatientRepository,
+            },
+            value_objects::{cpf::Cpf, document_category::DocumentCategory},
+        },
+        presentation::dtos::document_dto::UploadDocumentDTO,
+    };
+
+    fn make_patient_repo() -> MockPatientRepository {
+        let mut mock_patient_repo = MockPatientRepository::new();
+
+        mock_patient_repo
+            .expect_find_by_cpf()
+            .times(1)
+            .return_const(Ok(Some(
+                Patient::restore(7, "Andrew".to_string(), Cpf::parse("12345678909").unwrap())
+                    .unwrap(),
+            )));
+
+        mock_patient_repo
+    }
+
+    fn make_upload(content_type: &str) -> UploadDocumentDTO {
+        UploadDocumentDTO {
+            category: DocumentCategory::ExamReport,
+            file_name: "report.pdf".to_string(),
+            content_type: content_type.to_string(),
+            description: None,
+            content: b"%PDF-1.7".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_invalid_document_stores_nothing() {
+        let mut mock_file_storage = MockFileStorage::new();
+        mock_file_storage.expect_put().times(0);
+
+        let sut = UploadDocumentUseCase::new(
+            make_patient_repo(),
+            MockDocumentRepository::new(),
+            mock_file_storage,
+        );
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                make_upload("text/html"),
+                "admin@example.com".to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PatientApplicationError::Constraint(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_failed_save_removes_stored_file() {
+        let mut mock_document_repo = MockDocumentRepository::new();
+        let mut mock_file_storage = MockFileStorage::new();
+
+        mock_file_storage
+            .expect_put()
+            .withf(|key, content| key.starts_with("7/") && content == b"%PDF-1.7")
+            .times(1)
+            .return_const(Ok(()));
+
+        mock_document_repo.expect_save().times(1).return_const(Err(
+            RepositoryError::DatabaseError("connection lost".to_string()),
+        ));
+
+        mock_file_storage
+            .expect_delete()
+            .with(always())
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut =
+            UploadDocumentUseCase::new(make_patient_repo(), mock_document_repo, mock_file_storage);
+
+        let result = sut
+            .execute(
+                Cpf::parse("12345678909").unwrap(),
+                make_upload("application/pdf"),
+                "admin@example.com".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(PatientApplicationError::Unexpected(
+                "connection lost".to_string()
+            ))
+        );
+    }
+}
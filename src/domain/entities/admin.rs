@@ -1,11 +1,144 @@
-use crate::domain::value_objects::id::ID;
-use diesel::prelude::Queryable;
+use chrono::NaiveDateTime;
+use diesel::{
+    ExpressionMethods,
+    prelude::{AsChangeset, Insertable, Queryable},
+};
 
-#[derive(Queryable)]
+use crate::{
+    domain::{
+        errors::admin_entity_error::AdminEntityError,
+        value_objects::{email::Email, id::ID, role::Role},
+    },
+    schema::admins,
+};
+
+/// A staff account. Whatever its role, it is stored in the `admins` table.
+#[derive(AsChangeset, Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = admins, treat_none_as_null = true)]
 pub struct Admin {
     #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
     pub id: ID,
     pub name: String,
     pub email: String,
     pub password_hash: String,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub role: Role,
+    pub active: bool,
+    /// Set while the account holds a password chosen by someone else; until
+    /// it is changed, the account may do nothing but change it.
+    pub must_change_password: bool,
+    pub password_changed_at: NaiveDateTime,
+    /// The professional this account acts as. Clinical records written from
+    /// it are credited to that professional.
+    pub professional_id: Option<i32>,
+}
+
+impl Admin {
+    /// A new account is given a temporary password, which its owner must
+    /// change on first login.
+    pub fn new(
+        name: String,
+        email: &str,
+        role: Role,
+        password_hash: String,
+    ) -> Result<Self, AdminEntityError> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(AdminEntityError::InvalidName);
+        }
+
+        let email =
+            Email::parse(email).map_err(|_| AdminEntityError::InvalidEmail(email.to_string()))?;
+
+        Ok(Self {
+            id: ID::New,
+            name,
+            email: email.into(),
+            password_hash,
+            role,
+            active: true,
+            must_change_password: true,
+            password_changed_at: chrono::Local::now().naive_utc(),
+            professional_id: None,
+        })
+    }
+
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    pub fn reactivate(&mut self) {
+        self.active = true;
+    }
+
+    /// The owner chose a new password.
+    pub fn change_password(&mut self, password_hash: String) {
+        self.password_hash = password_hash;
+        self.must_change_password = false;
+        self.password_changed_at = chrono::Local::now().naive_utc();
+    }
+
+    /// Someone else set a temporary password, which the owner must change.
+    pub fn reset_password(&mut self, password_hash: String) {
+        self.password_hash = password_hash;
+        self.must_change_password = true;
+        self.password_changed_at = chrono::Local::now().naive_utc();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{errors::admin_entity_error::AdminEntityError, value_objects::role::Role};
+
+    use super::Admin;
+
+    #[test]
+    fn new_admin() {
+        let admin = Admin::new(
+            " Lisa Cuddy ".to_string(),
+            "Cuddy@Example.com",
+            Role::Admin,
+            "hash".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(admin.name, "Lisa Cuddy");
+        assert_eq!(admin.email, "cuddy@example.com");
+        assert!(admin.active);
+        assert!(admin.must_change_password);
+    }
+
+    #[test]
+    fn new_admin_invalid_email() {
+        let result = Admin::new(
+            "Lisa Cuddy".to_string(),
+            "cuddy",
+            Role::Admin,
+            "hash".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            Err(AdminEntityError::InvalidEmail("cuddy".to_string()))
+        );
+    }
+
+    #[test]
+    fn change_and_reset_password() {
+        let mut admin = Admin::new(
+            "Lisa Cuddy".to_string(),
+            "cuddy@example.com",
+            Role::Admin,
+            "temporary".to_string(),
+        )
+        .unwrap();
+
+        admin.change_password("chosen".to_string());
+        assert_eq!(admin.password_hash, "chosen");
+        assert!(!admin.must_change_password);
+
+        admin.reset_password("temporary".to_string());
+        assert_eq!(admin.password_hash, "temporary");
+        assert!(admin.must_change_password);
+    }
 }
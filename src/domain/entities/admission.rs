@@ -0,0 +1,266 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::{AsChangeset, Insertable, Queryable};
+
+use crate::{
+    domain::{
+        entities::ward::Bed, errors::admission_entity_error::AdmissionEntityError,
+        value_objects::id::ID,
+    },
+    presentation::dtos::admission_dto::AdmitPatientDTO,
+    schema::{admissions, bed_occupancies},
+};
+
+#[derive(AsChangeset, Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = admissions)]
+pub struct Admission {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub patient_id: i32,
+    pub professional_id: Option<i32>,
+    pub reason: String,
+    pub admitted_by: String,
+    pub admitted_at: NaiveDateTime,
+    pub discharged_at: Option<NaiveDateTime>,
+    pub discharged_by: Option<String>,
+    pub discharge_summary: Option<String>,
+}
+
+/// A stretch of time an admitted patient spent in a bed. Transfers close the
+/// current one and open another, so the list of an admission is its full
+/// bed history.
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = bed_occupancies)]
+pub struct BedOccupancy {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub admission_id: i32,
+    pub bed_id: i32,
+    pub started_by: String,
+    pub started_at: NaiveDateTime,
+    pub ended_at: Option<NaiveDateTime>,
+}
+
+impl Admission {
+    /// Returns the admission along with its first occupancy, whose
+    /// `admission_id` is filled in once the admission is saved.
+    pub fn new(
+        patient_id: i32,
+        bed: &Bed,
+        input: AdmitPatientDTO,
+        admitted_by: String,
+    ) -> Result<(Self, BedOccupancy), AdmissionEntityError> {
+        if patient_id <= 0 {
+            return Err(AdmissionEntityError::InvalidId(patient_id));
+        }
+
+        let reason = input.reason.trim().to_string();
+
+        if reason.is_empty() {
+            return Err(AdmissionEntityError::InvalidReason(input.reason));
+        }
+
+        let admitted_at = chrono::Local::now().naive_utc();
+        let occupancy = BedOccupancy::start(0, bed, admitted_by.clone(), admitted_at)?;
+
+        Ok((
+            Self {
+                id: ID::New,
+                patient_id,
+                professional_id: input.professional_id,
+                reason,
+                admitted_by,
+                admitted_at,
+                discharged_at: None,
+                discharged_by: None,
+                discharge_summary: None,
+            },
+            occupancy,
+        ))
+    }
+
+    pub fn is_discharged(&self) -> bool {
+        self.discharged_at.is_some()
+    }
+
+    /// Opens the occupancy of `bed`. The current one is closed when the
+    /// transfer is saved.
+    pub fn transfer_to(
+        &self,
+        occupancies: &[BedOccupancy],
+        bed: &Bed,
+        actor: String,
+    ) -> Result<BedOccupancy, AdmissionEntityError> {
+        let admission_id = self.ensure_open()?;
+
+        if let Some(current_bed_id) = current_bed_id(occupancies)
+            && bed.id == ID::Existing(current_bed_id)
+        {
+            return Err(AdmissionEntityError::SameBed(current_bed_id));
+        }
+
+        BedOccupancy::start(admission_id, bed, actor, chrono::Local::now().naive_utc())
+    }
+
+    pub fn discharge(
+        &mut self,
+        summary: Option<String>,
+        actor: String,
+    ) -> Result<(), AdmissionEntityError> {
+        self.ensure_open()?;
+
+        self.discharged_at = Some(chrono::Local::now().naive_utc());
+        self.discharged_by = Some(actor);
+        self.discharge_summary = summary;
+
+        Ok(())
+    }
+
+    fn ensure_open(&self) -> Result<i32, AdmissionEntityError> {
+        let admission_id: Option<i32> = self.id.clone().into();
+        let admission_id = admission_id.ok_or(AdmissionEntityError::InvalidId(0))?;
+
+        if self.is_discharged() {
+            return Err(AdmissionEntityError::AlreadyDischarged(admission_id));
+        }
+
+        Ok(admission_id)
+    }
+}
+
+impl BedOccupancy {
+    fn start(
+        admission_id: i32,
+        bed: &Bed,
+        started_by: String,
+        started_at: NaiveDateTime,
+    ) -> Result<Self, AdmissionEntityError> {
+        Ok(Self {
+            id: ID::New,
+            admission_id,
+            bed_id: bed.ensure_free()?,
+            started_by,
+            started_at,
+            ended_at: None,
+        })
+    }
+}
+
+/// The bed the patient is in, if the admission is still open.
+pub fn current_bed_id(occupancies: &[BedOccupancy]) -> Option<i32> {
+    occupancies
+        .iter()
+        .find(|occupancy| occupancy.ended_at.is_none())
+        .map(|occupancy| occupancy.bed_id)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        domain::{
+            entities::{admission::Admission, ward::Bed},
+            errors::admission_entity_error::AdmissionEntityError,
+            value_objects::{bed_status::BedStatus, cpf::Cpf, id::ID},
+        },
+        presentation::dtos::admission_dto::AdmitPatientDTO,
+    };
+
+    fn make_bed(bed_id: i32, status: BedStatus) -> Bed {
+        Bed {
+            id: ID::Existing(bed_id),
+            room_id: 2,
+            label: "A".to_string(),
+            status,
+        }
+    }
+
+    fn make_admission(bed_id: i32) -> (Admission, Vec<super::BedOccupancy>) {
+        let (mut admission, mut occupancy) = Admission::new(
+            7,
+            &make_bed(bed_id, BedStatus::Free),
+            AdmitPatientDTO {
+                patient_cpf: Cpf::parse("12345678909").unwrap(),
+                bed_id,
+                professional_id: None,
+                reason: " Pneumonia ".to_string(),
+            },
+            "nurse@example.com".to_string(),
+        )
+        .unwrap();
+
+        admission.id = ID::Existing(9);
+        occupancy.id = ID::Existing(1);
+        occupancy.admission_id = 9;
+
+        (admission, vec![occupancy])
+    }
+
+    #[test]
+    fn new_occupied_bed_error() {
+        let result = Admission::new(
+            7,
+            &make_bed(4, BedStatus::Occupied),
+            AdmitPatientDTO {
+                patient_cpf: Cpf::parse("12345678909").unwrap(),
+                bed_id: 4,
+                professional_id: None,
+                reason: "Pneumonia".to_string(),
+            },
+            "nurse@example.com".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            Err(AdmissionEntityError::BedNotAvailable(
+                4,
+                "occupied".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn transfer_to_same_bed_error() {
+        let (admission, occupancies) = make_admission(4);
+
+        assert_eq!(
+            admission.transfer_to(
+                &occupancies,
+                &make_bed(4, BedStatus::Free),
+                "nurse@example.com".to_string()
+            ),
+            Err(AdmissionEntityError::SameBed(4))
+        );
+    }
+
+    #[test]
+    fn transfer_to_free_bed() {
+        let (admission, occupancies) = make_admission(4);
+
+        let occupancy = admission
+            .transfer_to(
+                &occupancies,
+                &make_bed(5, BedStatus::Free),
+                "nurse@example.com".to_string(),
+            )
+            .unwrap();
+
+        assert_eq!(occupancy.admission_id, 9);
+        assert_eq!(occupancy.bed_id, 5);
+        assert_eq!(occupancy.ended_at, None);
+    }
+
+    #[test]
+    fn discharge_twice_error() {
+        let (mut admission, _) = make_admission(4);
+
+        admission
+            .discharge(None, "physician@example.com".to_string())
+            .unwrap();
+
+        assert_eq!(admission.reason, "Pneumonia");
+        assert!(admission.is_discharged());
+        assert_eq!(
+            admission.discharge(None, "physician@example.com".to_string()),
+            Err(AdmissionEntityError::AlreadyDischarged(9))
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    ExpressionMethods,
+    prelude::{AsChangeset, Insertable, Queryable},
+};
+
+use crate::{
+    domain::{
+        errors::patient_entity_error::PatientEntityError,
+        value_objects::{allergy_severity::AllergySeverity, id::ID},
+    },
+    presentation::dtos::allergy_dto::{CreateAllergyDTO, UpdateAllergyDTO},
+    schema::allergies,
+};
+
+const MAX_SUBSTANCE_LENGTH: usize = 150;
+
+#[derive(AsChangeset, Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = allergies)]
+pub struct Allergy {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub patient_id: i32,
+    pub substance: String,
+    pub reaction: Option<String>,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub severity: AllergySeverity,
+    pub recorded_at: NaiveDateTime,
+}
+
+impl Allergy {
+    pub fn new(patient_id: i32, input: CreateAllergyDTO) -> Result<Self, PatientEntityError> {
+        if patient_id <= 0 {
+            return Err(PatientEntityError::InvalidId(patient_id));
+        }
+
+        Ok(Self {
+            id: ID::New,
+            patient_id,
+            substance: normalize_substance(input.substance)?,
+            reaction: input.reaction,
+            severity: input.severity,
+            recorded_at: chrono::Local::now().naive_utc(),
+        })
+    }
+
+    /// Overwrites the fields present in `input`, leaving the absent ones
+    /// untouched.
+    pub fn apply_update(&mut self, input: UpdateAllergyDTO) -> Result<(), PatientEntityError> {
+        if let Some(substance) = input.substance {
+            self.substance = normalize_substance(substance)?;
+        }
+
+        self.reaction = input.reaction.or(self.reaction.take());
+        self.severity = input.severity.unwrap_or(self.severity);
+
+        Ok(())
+    }
+}
+
+fn normalize_substance(substance: String) -> Result<String, PatientEntityError> {
+    let normalized = substance.trim().to_string();
+
+    if normalized.is_empty() || normalized.len() > MAX_SUBSTANCE_LENGTH {
+        return Err(PatientEntityError::InvalidSubstance(substance));
+    }
+
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        domain::{
+            entities::allergy::Allergy, errors::patient_entity_error::PatientEntityError,
+            value_objects::allergy_severity::AllergySeverity,
+        },
+        presentation::dtos::allergy_dto::{CreateAllergyDTO, UpdateAllergyDTO},
+    };
+
+    #[test]
+    fn new_empty_substance_error() {
+        assert_eq!(
+            Allergy::new(
+                7,
+                CreateAllergyDTO {
+                    substance: " ".to_string(),
+                    reaction: None,
+                    severity: AllergySeverity::Mild,
+                }
+            ),
+            Err(PatientEntityError::InvalidSubstance(" ".to_string()))
+        );
+    }
+
+    #[test]
+    fn apply_update_keeps_absent_fields() {
+        let mut allergy = Allergy::new(
+            7,
+            CreateAllergyDTO {
+                substance: " Penicillin ".to_string(),
+                reaction: Some("Hives".to_string()),
+                severity: AllergySeverity::Moderate,
+            },
+        )
+        .unwrap();
+
+        allergy
+            .apply_update(UpdateAllergyDTO {
+                severity: Some(AllergySeverity::Severe),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(allergy.substance, "Penicillin");
+        assert_eq!(allergy.reaction, Some("Hives".to_string()));
+        assert_eq!(allergy.severity, AllergySeverity::Severe);
+    }
+}
@@ -1,9 +1,15 @@
 use crate::{
-    domain::{errors::appointment_entity_error::AppointmentEntityError, value_objects::id::ID},
+    domain::{
+        errors::appointment_entity_error::AppointmentEntityError,
+        value_objects::{appointment_status::AppointmentStatus, id::ID, time_slot::TimeSlot},
+    },
     schema::appointments,
 };
-use chrono::NaiveDateTime;
-use diesel::prelude::{AsChangeset, Insertable, Queryable};
+use chrono::{Duration, NaiveDateTime};
+use diesel::{
+    ExpressionMethods,
+    prelude::{AsChangeset, Insertable, Queryable},
+};
 
 #[derive(AsChangeset, Clone, Insertable, Queryable)]
 #[diesel(table_name = appointments)]
@@ -14,15 +20,30 @@ pub struct Appointment {
     pub appointment_at: NaiveDateTime,
     pub specialty: String,
     pub notes: Option<String>,
-    pub canceled: bool,
     pub canceled_at: Option<NaiveDateTime>,
     pub cancellation_reason: Option<String>,
+    pub professional_id: Option<i32>,
+    pub ends_at: NaiveDateTime,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub status: AppointmentStatus,
+    pub confirmed_at: Option<NaiveDateTime>,
+    pub checked_in_at: Option<NaiveDateTime>,
+    pub started_at: Option<NaiveDateTime>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub no_show_at: Option<NaiveDateTime>,
+    pub previous_appointment_at: Option<NaiveDateTime>,
+    pub rescheduled_at: Option<NaiveDateTime>,
+    pub rescheduled_by: Option<String>,
+    pub reschedule_reason: Option<String>,
+    pub telemedicine: bool,
 }
 
 impl Appointment {
     pub fn new(
         patient_id: i32,
+        professional_id: Option<i32>,
         appointment_at: NaiveDateTime,
+        duration: Duration,
         specialty: String,
         notes: Option<String>,
     ) -> Result<Self, AppointmentEntityError> {
@@ -30,25 +51,318 @@ impl Appointment {
             return Err(AppointmentEntityError::InvalidPatientId(patient_id));
         }
 
+        if let Some(professional_id) = professional_id
+            && professional_id <= 0
+        {
+            return Err(AppointmentEntityError::InvalidProfessionalId(
+                professional_id,
+            ));
+        }
+
+        let ends_at = ends_at(appointment_at, duration)?;
+
         Ok(Self {
             id: ID::New,
             patient_id,
             appointment_at,
             specialty,
             notes,
-            canceled: false,
             canceled_at: None,
             cancellation_reason: None,
+            professional_id,
+            ends_at,
+            status: AppointmentStatus::Scheduled,
+            confirmed_at: None,
+            checked_in_at: None,
+            started_at: None,
+            completed_at: None,
+            no_show_at: None,
+            previous_appointment_at: None,
+            rescheduled_at: None,
+            rescheduled_by: None,
+            reschedule_reason: None,
+            telemedicine: false,
         })
     }
 
-    pub fn cancel(&mut self, cancellation_reason: String) {
-        self.canceled = true;
-        self.canceled_at = Some(chrono::Local::now().naive_utc());
+    /// Moves the appointment to `status`, stamping the matching transition
+    /// time, or fails if the current status does not allow it.
+    pub fn transition_to(
+        &mut self,
+        status: AppointmentStatus,
+    ) -> Result<(), AppointmentEntityError> {
+        if !self.status.can_transition_to(status) {
+            return Err(AppointmentEntityError::InvalidStatusTransition(
+                self.status.to_string(),
+                status.to_string(),
+            ));
+        }
+
+        let now = Some(chrono::Local::now().naive_utc());
+
+        match status {
+            AppointmentStatus::Scheduled => {}
+            AppointmentStatus::Confirmed => self.confirmed_at = now,
+            AppointmentStatus::CheckedIn => self.checked_in_at = now,
+            AppointmentStatus::InProgress => self.started_at = now,
+            AppointmentStatus::Completed => self.completed_at = now,
+            AppointmentStatus::NoShow => self.no_show_at = now,
+            AppointmentStatus::Canceled => self.canceled_at = now,
+        }
+
+        self.status = status;
+
+        Ok(())
+    }
+
+    pub fn cancel(&mut self, cancellation_reason: String) -> Result<(), AppointmentEntityError> {
+        self.transition_to(AppointmentStatus::Canceled)?;
         self.cancellation_reason = Some(cancellation_reason);
+
+        Ok(())
+    }
+
+    /// Moves the appointment to a new time, keeping the previous one and who
+    /// asked for the change. A confirmed appointment goes back to scheduled,
+    /// since the new time has not been confirmed yet.
+    pub fn reschedule(
+        &mut self,
+        appointment_at: NaiveDateTime,
+        duration: Duration,
+        rescheduled_by: String,
+        reschedule_reason: Option<String>,
+    ) -> Result<(), AppointmentEntityError> {
+        if !matches!(
+            self.status,
+            AppointmentStatus::Scheduled | AppointmentStatus::Confirmed
+        ) {
+            return Err(AppointmentEntityError::NotReschedulable(
+                self.status.to_string(),
+            ));
+        }
+
+        let ends_at = ends_at(appointment_at, duration)?;
+
+        self.previous_appointment_at = Some(self.appointment_at);
+        self.appointment_at = appointment_at;
+        self.ends_at = ends_at;
+        self.status = AppointmentStatus::Scheduled;
+        self.confirmed_at = None;
+        self.rescheduled_at = Some(chrono::Local::now().naive_utc());
+        self.rescheduled_by = Some(rescheduled_by);
+        self.reschedule_reason = reschedule_reason;
+
+        Ok(())
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.ends_at - self.appointment_at
     }
 
     pub fn is_canceled(&self) -> bool {
-        self.canceled
+        self.status == AppointmentStatus::Canceled
+    }
+
+    pub fn time_slot(&self) -> TimeSlot {
+        TimeSlot::new(self.appointment_at, self.ends_at)
+    }
+}
+
+fn ends_at(
+    appointment_at: NaiveDateTime,
+    duration: Duration,
+) -> Result<NaiveDateTime, AppointmentEntityError> {
+    if duration <= Duration::zero() {
+        return Err(AppointmentEntityError::InvalidDuration(
+            duration.num_minutes(),
+        ));
+    }
+
+    appointment_at
+        .checked_add_signed(duration)
+        .ok_or(AppointmentEntityError::InvalidDuration(
+            duration.num_minutes(),
+        ))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+
+    use crate::domain::{
+        entities::appointment::Appointment,
+        errors::appointment_entity_error::AppointmentEntityError,
+        value_objects::appointment_status::AppointmentStatus,
+    };
+
+    #[test]
+    fn new_computes_ends_at() {
+        let appointment_at = "2025-06-16T10:00:00".parse::<NaiveDateTime>().unwrap();
+
+        let appointment = Appointment::new(
+            1,
+            None,
+            appointment_at,
+            Duration::minutes(45),
+            "Cardiology".to_string(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            appointment.ends_at,
+            "2025-06-16T10:45:00".parse::<NaiveDateTime>().unwrap()
+        );
+        assert_eq!(appointment.time_slot().starts_at, appointment_at);
+    }
+
+    #[test]
+    fn new_ends_after_last_representable_time_error() {
+        let appointment = Appointment::new(
+            1,
+            None,
+            NaiveDateTime::MAX,
+            Duration::minutes(30),
+            "Nephrology".to_string(),
+            None,
+        );
+
+        assert!(matches!(
+            appointment,
+            Err(AppointmentEntityError::InvalidDuration(30))
+        ));
+    }
+
+    #[test]
+    fn new_invalid_duration_error() {
+        let appointment = Appointment::new(
+            1,
+            None,
+            "2025-06-16T10:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::zero(),
+            "Cardiology".to_string(),
+            None,
+        );
+
+        assert!(matches!(
+            appointment,
+            Err(AppointmentEntityError::InvalidDuration(0))
+        ));
+    }
+
+    #[test]
+    fn transition_to_stamps_timestamps() {
+        let mut appointment = make_fake_appointment();
+
+        appointment
+            .transition_to(AppointmentStatus::Confirmed)
+            .unwrap();
+        appointment
+            .transition_to(AppointmentStatus::CheckedIn)
+            .unwrap();
+        appointment
+            .transition_to(AppointmentStatus::InProgress)
+            .unwrap();
+        appointment
+            .transition_to(AppointmentStatus::Completed)
+            .unwrap();
+
+        assert_eq!(appointment.status, AppointmentStatus::Completed);
+        assert!(appointment.confirmed_at.is_some());
+        assert!(appointment.checked_in_at.is_some());
+        assert!(appointment.started_at.is_some());
+        assert!(appointment.completed_at.is_some());
+        assert!(appointment.no_show_at.is_none());
+    }
+
+    #[test]
+    fn transition_to_invalid_transition_error() {
+        let mut appointment = make_fake_appointment();
+
+        let result = appointment.transition_to(AppointmentStatus::Completed);
+
+        assert!(matches!(
+            result,
+            Err(AppointmentEntityError::InvalidStatusTransition(_, _))
+        ));
+        assert_eq!(appointment.status, AppointmentStatus::Scheduled);
+        assert!(appointment.completed_at.is_none());
+    }
+
+    #[test]
+    fn cancel() {
+        let mut appointment = make_fake_appointment();
+
+        appointment.cancel("Patient asked".to_string()).unwrap();
+
+        assert!(appointment.is_canceled());
+        assert!(appointment.canceled_at.is_some());
+        assert_eq!(
+            appointment.cancellation_reason,
+            Some("Patient asked".to_string())
+        );
+        assert!(appointment.cancel("Again".to_string()).is_err());
+    }
+
+    #[test]
+    fn reschedule_keeps_previous_time() {
+        let mut appointment = make_fake_appointment();
+        appointment
+            .transition_to(AppointmentStatus::Confirmed)
+            .unwrap();
+
+        appointment
+            .reschedule(
+                "2025-06-17T14:00:00".parse::<NaiveDateTime>().unwrap(),
+                appointment.duration(),
+                "admin@sghss.com".to_string(),
+                Some("Patient is traveling".to_string()),
+            )
+            .unwrap();
+
+        assert_eq!(
+            appointment.previous_appointment_at,
+            Some("2025-06-16T10:00:00".parse::<NaiveDateTime>().unwrap())
+        );
+        assert_eq!(
+            appointment.ends_at,
+            "2025-06-17T14:30:00".parse::<NaiveDateTime>().unwrap()
+        );
+        assert_eq!(appointment.status, AppointmentStatus::Scheduled);
+        assert!(appointment.confirmed_at.is_none());
+        assert_eq!(
+            appointment.rescheduled_by,
+            Some("admin@sghss.com".to_string())
+        );
+    }
+
+    #[test]
+    fn reschedule_not_reschedulable_error() {
+        let mut appointment = make_fake_appointment();
+        appointment.cancel("Patient asked".to_string()).unwrap();
+
+        let result = appointment.reschedule(
+            "2025-06-17T14:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "admin@sghss.com".to_string(),
+            None,
+        );
+
+        assert!(matches!(
+            result,
+            Err(AppointmentEntityError::NotReschedulable(_))
+        ));
+    }
+
+    fn make_fake_appointment() -> Appointment {
+        Appointment::new(
+            1,
+            None,
+            "2025-06-16T10:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "Cardiology".to_string(),
+            None,
+        )
+        .unwrap()
     }
 }
@@ -0,0 +1,71 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::{Insertable, Queryable};
+
+use crate::{domain::value_objects::id::ID, schema::audit_events};
+
+/// Sensitive operations that must leave a trace of who performed them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuditAction {
+    PatientAnonymized,
+    PatientMerged,
+    UserCreated,
+    UserDeactivated,
+    UserReactivated,
+    UserProfessionalLinked,
+    PasswordChanged,
+    PasswordReset,
+    SessionsRevoked,
+    AccountLocked,
+    AddressLocked,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::PatientAnonymized => "patient_anonymized",
+            AuditAction::PatientMerged => "patient_merged",
+            AuditAction::UserCreated => "user_created",
+            AuditAction::UserDeactivated => "user_deactivated",
+            AuditAction::UserReactivated => "user_reactivated",
+            AuditAction::UserProfessionalLinked => "user_professional_linked",
+            AuditAction::PasswordChanged => "password_changed",
+            AuditAction::PasswordReset => "password_reset",
+            AuditAction::SessionsRevoked => "sessions_revoked",
+            AuditAction::AccountLocked => "account_locked",
+            AuditAction::AddressLocked => "address_locked",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = audit_events)]
+pub struct AuditEvent {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub actor: String,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: Option<i32>,
+    pub details: Option<String>,
+    pub occurred_at: NaiveDateTime,
+}
+
+impl AuditEvent {
+    pub fn new(
+        actor: String,
+        action: AuditAction,
+        entity_type: &str,
+        entity_id: Option<i32>,
+        details: Option<String>,
+    ) -> Self {
+        Self {
+            id: ID::New,
+            actor,
+            action: action.as_str().to_string(),
+            entity_type: entity_type.to_string(),
+            entity_id,
+            details,
+            occurred_at: chrono::Local::now().naive_utc(),
+        }
+    }
+}
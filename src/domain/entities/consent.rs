@@ -0,0 +1,142 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    ExpressionMethods,
+    prelude::{AsChangeset, Insertable, Queryable},
+};
+
+use crate::{
+    domain::{
+        errors::patient_entity_error::PatientEntityError,
+        value_objects::{consent_channel::ConsentChannel, consent_purpose::ConsentPurpose, id::ID},
+    },
+    schema::consents,
+};
+
+const MAX_TERM_VERSION_LENGTH: usize = 50;
+
+/// A patient's agreement to one purpose, under a given version of the term
+/// text. Revoking keeps the record, so the history of what was agreed to is
+/// never lost.
+#[derive(AsChangeset, Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = consents)]
+pub struct Consent {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub patient_id: i32,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub purpose: ConsentPurpose,
+    pub term_version: String,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub channel: ConsentChannel,
+    pub granted_at: NaiveDateTime,
+    pub granted_by: String,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub revoked_by: Option<String>,
+}
+
+impl Consent {
+    pub fn new(
+        patient_id: i32,
+        purpose: ConsentPurpose,
+        term_version: String,
+        channel: ConsentChannel,
+        granted_by: String,
+    ) -> Result<Self, PatientEntityError> {
+        if patient_id <= 0 {
+            return Err(PatientEntityError::InvalidId(patient_id));
+        }
+
+        let term_version = term_version.trim().to_string();
+
+        if term_version.is_empty() || term_version.len() > MAX_TERM_VERSION_LENGTH {
+            return Err(PatientEntityError::InvalidTermVersion(term_version));
+        }
+
+        Ok(Self {
+            id: ID::New,
+            patient_id,
+            purpose,
+            term_version,
+            channel,
+            granted_at: chrono::Local::now().naive_utc(),
+            granted_by,
+            revoked_at: None,
+            revoked_by: None,
+        })
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+
+    pub fn revoke(&mut self, revoked_by: String) -> Result<(), PatientEntityError> {
+        if !self.is_active() {
+            return Err(PatientEntityError::ConsentAlreadyRevoked(
+                self.purpose.to_string(),
+            ));
+        }
+
+        self.revoked_at = Some(chrono::Local::now().naive_utc());
+        self.revoked_by = Some(revoked_by);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{
+        entities::consent::Consent,
+        errors::patient_entity_error::PatientEntityError,
+        value_objects::{consent_channel::ConsentChannel, consent_purpose::ConsentPurpose},
+    };
+
+    #[test]
+    fn new_trims_term_version() {
+        let consent = make_fake_consent("  v2.1 ").unwrap();
+
+        assert_eq!(consent.term_version, "v2.1");
+        assert!(consent.is_active());
+    }
+
+    #[test]
+    fn new_invalid_term_version_error() {
+        assert_eq!(
+            make_fake_consent("   "),
+            Err(PatientEntityError::InvalidTermVersion(String::new()))
+        );
+    }
+
+    #[test]
+    fn revoke() {
+        let mut consent = make_fake_consent("v1").unwrap();
+
+        consent.revoke("admin@example.com".to_string()).unwrap();
+
+        assert!(!consent.is_active());
+        assert_eq!(consent.revoked_by, Some("admin@example.com".to_string()));
+    }
+
+    #[test]
+    fn revoke_already_revoked_error() {
+        let mut consent = make_fake_consent("v1").unwrap();
+        consent.revoke("admin@example.com".to_string()).unwrap();
+
+        assert_eq!(
+            consent.revoke("admin@example.com".to_string()),
+            Err(PatientEntityError::ConsentAlreadyRevoked(
+                "telemedicine".to_string()
+            ))
+        );
+    }
+
+    fn make_fake_consent(term_version: &str) -> Result<Consent, PatientEntityError> {
+        Consent::new(
+            7,
+            ConsentPurpose::Telemedicine,
+            term_version.to_string(),
+            ConsentChannel::Online,
+            "admin@example.com".to_string(),
+        )
+    }
+}
@@ -0,0 +1,194 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::{Insertable, Queryable};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    domain::{
+        errors::patient_entity_error::PatientEntityError,
+        value_objects::{document_category::DocumentCategory, id::ID},
+    },
+    presentation::dtos::document_dto::UploadDocumentDTO,
+    schema::documents,
+};
+
+pub const MAX_DOCUMENT_SIZE: usize = 10 * 1024 * 1024;
+const MAX_FILE_NAME_LENGTH: usize = 255;
+/// Accepted content types and the leading bytes every file of that type
+/// starts with.
+const DOCUMENT_SIGNATURES: [(&str, &[u8]); 3] = [
+    ("application/pdf", b"%PDF-"),
+    ("image/jpeg", &[0xFF, 0xD8, 0xFF]),
+    (
+        "image/png",
+        &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A],
+    ),
+];
+
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = documents)]
+pub struct Document {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub patient_id: i32,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub category: DocumentCategory,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub storage_key: String,
+    pub description: Option<String>,
+    pub uploaded_by: String,
+    pub uploaded_at: NaiveDateTime,
+}
+
+impl Document {
+    /// Validates the upload and derives its checksum and storage key. The
+    /// declared content type must match the file's leading bytes.
+    pub fn new(
+        patient_id: i32,
+        input: &UploadDocumentDTO,
+        uploaded_by: String,
+    ) -> Result<Self, PatientEntityError> {
+        if patient_id <= 0 {
+            return Err(PatientEntityError::InvalidId(patient_id));
+        }
+
+        let file_name = input.file_name.trim().to_string();
+
+        if file_name.is_empty()
+            || file_name.len() > MAX_FILE_NAME_LENGTH
+            || file_name.contains(['/', '\\'])
+        {
+            return Err(PatientEntityError::InvalidDocument(format!(
+                "{file_name:?} is not a valid file name"
+            )));
+        }
+
+        let Some((_, signature)) = DOCUMENT_SIGNATURES
+            .iter()
+            .find(|(content_type, _)| *content_type == input.content_type)
+        else {
+            return Err(PatientEntityError::InvalidDocument(format!(
+                "{} files are not accepted, only {}",
+                input.content_type,
+                DOCUMENT_SIGNATURES
+                    .map(|(content_type, _)| content_type)
+                    .join(", ")
+            )));
+        };
+
+        if input.content.is_empty() || input.content.len() > MAX_DOCUMENT_SIZE {
+            return Err(PatientEntityError::InvalidDocument(format!(
+                "the file must have between 1 and {MAX_DOCUMENT_SIZE} bytes"
+            )));
+        }
+
+        if !input.content.starts_with(signature) {
+            return Err(PatientEntityError::InvalidDocument(format!(
+                "the file contents are not {}",
+                input.content_type
+            )));
+        }
+
+        let sha256 = format!("{:x}", Sha256::digest(&input.content));
+        let uploaded_at = chrono::Local::now().naive_utc();
+
+        Ok(Self {
+            id: ID::New,
+            patient_id,
+            category: input.category,
+            file_name,
+            content_type: input.content_type.clone(),
+            size_bytes: input.content.len() as i64,
+            storage_key: format!(
+                "{patient_id}/{}-{}",
+                uploaded_at.format("%Y%m%d%H%M%S%6f"),
+                &sha256[..16]
+            ),
+            sha256,
+            description: input
+                .description
+                .as_deref()
+                .map(str::trim)
+                .filter(|description| !description.is_empty())
+                .map(str::to_string),
+            uploaded_by,
+            uploaded_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        domain::{
+            entities::document::Document, errors::patient_entity_error::PatientEntityError,
+            value_objects::document_category::DocumentCategory,
+        },
+        presentation::dtos::document_dto::UploadDocumentDTO,
+    };
+
+    fn make_upload(content_type: &str, content: &[u8]) -> UploadDocumentDTO {
+        UploadDocumentDTO {
+            category: DocumentCategory::Referral,
+            file_name: " referral.pdf ".to_string(),
+            content_type: content_type.to_string(),
+            description: Some(" ".to_string()),
+            content: content.to_vec(),
+        }
+    }
+
+    #[test]
+    fn new_computes_checksum_and_storage_key() {
+        let document = Document::new(
+            7,
+            &make_upload("application/pdf", b"%PDF-1.7"),
+            "admin@example.com".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(document.file_name, "referral.pdf");
+        assert_eq!(document.size_bytes, 8);
+        assert_eq!(
+            document.sha256,
+            "86edbaa24831badfa0a8b04bb410141e2ee4182b6d0014493fe262a7a331c20b"
+        );
+        assert!(document.storage_key.starts_with("7/"));
+        assert!(document.storage_key.ends_with("-86edbaa24831badf"));
+        assert_eq!(document.description, None);
+    }
+
+    #[test]
+    fn new_unsupported_content_type_error() {
+        let result = Document::new(
+            7,
+            &make_upload("application/zip", b"PK\x03\x04"),
+            "admin@example.com".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            Err(PatientEntityError::InvalidDocument(
+                "application/zip files are not accepted, only application/pdf, image/jpeg, image/png"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new_mismatched_contents_error() {
+        let result = Document::new(
+            7,
+            &make_upload("image/png", b"%PDF-1.7"),
+            "admin@example.com".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            Err(PatientEntityError::InvalidDocument(
+                "the file contents are not image/png".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::{Insertable, Queryable};
+
+use crate::{
+    domain::{
+        entities::appointment::Appointment,
+        errors::encounter_entity_error::EncounterEntityError,
+        value_objects::{appointment_status::AppointmentStatus, id::ID},
+    },
+    presentation::dtos::encounter_dto::EncounterSectionsDTO,
+    schema::encounters,
+};
+
+/// The clinical note of an appointment. Notes are never edited: an amendment
+/// is a new note, pointing to the original one through `amends_id`, that
+/// supersedes it.
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = encounters)]
+pub struct Encounter {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub appointment_id: i32,
+    pub patient_id: i32,
+    pub professional_id: i32,
+    pub chief_complaint: String,
+    pub history: Option<String>,
+    pub physical_exam: Option<String>,
+    pub assessment: Option<String>,
+    pub plan: Option<String>,
+    pub amends_id: Option<i32>,
+    pub amendment_reason: Option<String>,
+    pub recorded_by: String,
+    pub recorded_at: NaiveDateTime,
+    /// The professional who wrote the note, who need not be the one the
+    /// appointment was booked with.
+    pub author_id: i32,
+}
+
+impl Encounter {
+    /// Records the note of `appointment`, which must have a professional and
+    /// have been attended, written by the professional `author_id`.
+    pub fn new(
+        appointment: &Appointment,
+        sections: EncounterSectionsDTO,
+        author_id: i32,
+        recorded_by: String,
+    ) -> Result<Self, EncounterEntityError> {
+        let appointment_id: Option<i32> = appointment.id.clone().into();
+        let appointment_id = appointment_id.ok_or(
+            EncounterEntityError::UnrecordableAppointment("it was not saved yet".to_string()),
+        )?;
+
+        let professional_id =
+            appointment
+                .professional_id
+                .ok_or(EncounterEntityError::UnrecordableAppointment(format!(
+                    "{appointment_id} has no professional"
+                )))?;
+
+        if !matches!(
+            appointment.status,
+            AppointmentStatus::CheckedIn
+                | AppointmentStatus::InProgress
+                | AppointmentStatus::Completed
+        ) {
+            return Err(EncounterEntityError::UnrecordableAppointment(format!(
+                "{appointment_id} is {}",
+                appointment.status
+            )));
+        }
+
+        Self::with_sections(
+            appointment_id,
+            appointment.patient_id,
+            professional_id,
+            sections,
+            author_id,
+            recorded_by,
+        )
+    }
+
+    /// A new note superseding this one. Amending an amendment amends the
+    /// original note, so every amendment points straight to it.
+    pub fn amend(
+        &self,
+        sections: EncounterSectionsDTO,
+        amendment_reason: String,
+        author_id: i32,
+        recorded_by: String,
+    ) -> Result<Self, EncounterEntityError> {
+        let amendment_reason = amendment_reason.trim().to_string();
+
+        if amendment_reason.is_empty() {
+            return Err(EncounterEntityError::MissingAmendmentReason);
+        }
+
+        let amends_id = match (self.amends_id, &self.id) {
+            (Some(amends_id), _) => amends_id,
+            (None, ID::Existing(id)) => *id,
+            (None, ID::New) => return Err(EncounterEntityError::InvalidId(0)),
+        };
+
+        Ok(Self {
+            amends_id: Some(amends_id),
+            amendment_reason: Some(amendment_reason),
+            ..Self::with_sections(
+                self.appointment_id,
+                self.patient_id,
+                self.professional_id,
+                sections,
+                author_id,
+                recorded_by,
+            )?
+        })
+    }
+
+    fn with_sections(
+        appointment_id: i32,
+        patient_id: i32,
+        professional_id: i32,
+        sections: EncounterSectionsDTO,
+        author_id: i32,
+        recorded_by: String,
+    ) -> Result<Self, EncounterEntityError> {
+        let chief_complaint = sections.chief_complaint.trim().to_string();
+
+        if chief_complaint.is_empty() {
+            return Err(EncounterEntityError::MissingChiefComplaint);
+        }
+
+        Ok(Self {
+            id: ID::New,
+            appointment_id,
+            patient_id,
+            professional_id,
+            chief_complaint,
+            history: sections.history,
+            physical_exam: sections.physical_exam,
+            assessment: sections.assessment,
+            plan: sections.plan,
+            amends_id: None,
+            amendment_reason: None,
+            recorded_by,
+            recorded_at: chrono::Local::now().naive_utc(),
+            author_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+
+    use crate::{
+        domain::{
+            entities::{appointment::Appointment, encounter::Encounter},
+            errors::encounter_entity_error::EncounterEntityError,
+            value_objects::{appointment_status::AppointmentStatus, id::ID},
+        },
+        presentation::dtos::encounter_dto::EncounterSectionsDTO,
+    };
+
+    #[test]
+    fn new_scheduled_appointment_error() {
+        let mut appointment = make_fake_appointment();
+        appointment.status = AppointmentStatus::Scheduled;
+
+        assert_eq!(
+            Encounter::new(
+                &appointment,
+                make_fake_sections("Headache"),
+                13,
+                "admin".to_string()
+            ),
+            Err(EncounterEntityError::UnrecordableAppointment(
+                "1 is scheduled".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new_missing_chief_complaint_error() {
+        assert_eq!(
+            Encounter::new(
+                &make_fake_appointment(),
+                make_fake_sections("  "),
+                13,
+                "admin".to_string()
+            ),
+            Err(EncounterEntityError::MissingChiefComplaint)
+        );
+    }
+
+    #[test]
+    fn new_ok() {
+        let encounter = Encounter::new(
+            &make_fake_appointment(),
+            make_fake_sections("Headache"),
+            13,
+            "admin".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(encounter.appointment_id, 1);
+        assert_eq!(encounter.patient_id, 7);
+        assert_eq!(encounter.professional_id, 42);
+        assert_eq!(encounter.author_id, 13);
+        assert_eq!(encounter.amends_id, None);
+    }
+
+    #[test]
+    fn amend_points_to_the_original() {
+        let mut original = Encounter::new(
+            &make_fake_appointment(),
+            make_fake_sections("Headache"),
+            13,
+            "admin".to_string(),
+        )
+        .unwrap();
+        original.id = ID::Existing(5);
+
+        let mut amendment = original
+            .amend(
+                make_fake_sections("Migraine"),
+                "Wrong complaint".to_string(),
+                13,
+                "admin".to_string(),
+            )
+            .unwrap();
+        amendment.id = ID::Existing(6);
+
+        let second_amendment = amendment
+            .amend(
+                make_fake_sections("Migraine with aura"),
+                "More detail".to_string(),
+                13,
+                "admin".to_string(),
+            )
+            .unwrap();
+
+        assert_eq!(amendment.amends_id, Some(5));
+        assert_eq!(second_amendment.amends_id, Some(5));
+        assert_eq!(second_amendment.chief_complaint, "Migraine with aura");
+        assert_eq!(second_amendment.professional_id, 42);
+    }
+
+    #[test]
+    fn amend_missing_reason_error() {
+        let mut original = Encounter::new(
+            &make_fake_appointment(),
+            make_fake_sections("Headache"),
+            13,
+            "admin".to_string(),
+        )
+        .unwrap();
+        original.id = ID::Existing(5);
+
+        assert_eq!(
+            original.amend(
+                make_fake_sections("Migraine"),
+                " ".to_string(),
+                13,
+                "admin".to_string()
+            ),
+            Err(EncounterEntityError::MissingAmendmentReason)
+        );
+    }
+
+    fn make_fake_appointment() -> Appointment {
+        let mut appointment = Appointment::new(
+            7,
+            Some(42),
+            "2025-06-09T08:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "Nephrology".to_string(),
+            None,
+        )
+        .unwrap();
+        appointment.id = ID::Existing(1);
+        appointment.status = AppointmentStatus::InProgress;
+
+        appointment
+    }
+
+    fn make_fake_sections(chief_complaint: &str) -> EncounterSectionsDTO {
+        EncounterSectionsDTO {
+            chief_complaint: chief_complaint.to_string(),
+            ..Default::default()
+        }
+    }
+}
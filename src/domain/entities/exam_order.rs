@@ -0,0 +1,465 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    ExpressionMethods,
+    prelude::{AsChangeset, Insertable, Queryable},
+};
+
+use crate::{
+    domain::{
+        entities::appointment::Appointment,
+        errors::exam_order_entity_error::ExamOrderEntityError,
+        value_objects::{exam_kind::ExamKind, exam_order_status::ExamOrderStatus, id::ID},
+    },
+    presentation::dtos::exam_order_dto::{ExamResultDTO, OrderExamDTO},
+    schema::{exam_orders, exam_results},
+};
+
+pub const MAX_RESULT_FILE_SIZE: usize = 20 * 1024 * 1024;
+const RESULT_FILE_CONTENT_TYPES: [&str; 4] = [
+    "application/pdf",
+    "image/jpeg",
+    "image/png",
+    "application/dicom",
+];
+const MAX_EXAM_NAME_LENGTH: usize = 150;
+const MAX_FILE_NAME_LENGTH: usize = 255;
+
+#[derive(AsChangeset, Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = exam_orders)]
+pub struct ExamOrder {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub patient_id: i32,
+    pub professional_id: i32,
+    pub appointment_id: Option<i32>,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub kind: ExamKind,
+    pub exam_name: String,
+    pub clinical_indication: Option<String>,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub status: ExamOrderStatus,
+    pub requested_by: String,
+    pub requested_at: NaiveDateTime,
+    pub collected_at: Option<NaiveDateTime>,
+    pub resulted_at: Option<NaiveDateTime>,
+    pub reviewed_at: Option<NaiveDateTime>,
+    pub reviewed_by: Option<String>,
+    pub result_file_name: Option<String>,
+    pub result_file_content_type: Option<String>,
+    pub result_file_size: Option<i32>,
+}
+
+/// A structured value of an exam, flagged as abnormal when it falls outside
+/// its reference.
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = exam_results)]
+pub struct ExamResult {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub exam_order_id: i32,
+    pub analyte: String,
+    pub value: String,
+    pub unit: Option<String>,
+    pub reference_low: Option<f64>,
+    pub reference_high: Option<f64>,
+    pub reference_text: Option<String>,
+    pub abnormal: bool,
+    pub recorded_at: NaiveDateTime,
+}
+
+impl ExamOrder {
+    /// Requests an exam for the patient on behalf of `professional_id`,
+    /// optionally tied to one of their appointments.
+    pub fn new(
+        patient_id: i32,
+        professional_id: i32,
+        appointment: Option<&Appointment>,
+        input: OrderExamDTO,
+        requested_by: String,
+    ) -> Result<Self, ExamOrderEntityError> {
+        if patient_id <= 0 {
+            return Err(ExamOrderEntityError::InvalidId(patient_id));
+        }
+
+        let appointment_id = match appointment {
+            Some(appointment) => {
+                let appointment_id: Option<i32> = appointment.id.clone().into();
+                let appointment_id = appointment_id.ok_or(
+                    ExamOrderEntityError::UnrelatedAppointment("it was not saved yet".to_string()),
+                )?;
+
+                if appointment.patient_id != patient_id {
+                    return Err(ExamOrderEntityError::UnrelatedAppointment(format!(
+                        "{appointment_id} belongs to another patient"
+                    )));
+                }
+
+                if appointment.is_canceled() {
+                    return Err(ExamOrderEntityError::UnrelatedAppointment(format!(
+                        "{appointment_id} is canceled"
+                    )));
+                }
+
+                Some(appointment_id)
+            }
+            None => None,
+        };
+
+        let exam_name = input.exam_name.trim().to_string();
+
+        if exam_name.is_empty() || exam_name.len() > MAX_EXAM_NAME_LENGTH {
+            return Err(ExamOrderEntityError::InvalidExamName(input.exam_name));
+        }
+
+        Ok(Self {
+            id: ID::New,
+            patient_id,
+            professional_id,
+            appointment_id,
+            kind: input.kind,
+            exam_name,
+            clinical_indication: input.clinical_indication,
+            status: ExamOrderStatus::Requested,
+            requested_by,
+            requested_at: chrono::Local::now().naive_utc(),
+            collected_at: None,
+            resulted_at: None,
+            reviewed_at: None,
+            reviewed_by: None,
+            result_file_name: None,
+            result_file_content_type: None,
+            result_file_size: None,
+        })
+    }
+
+    /// Moves the order to `status`, stamping the matching transition time,
+    /// or fails if the current status does not allow it.
+    pub fn transition_to(
+        &mut self,
+        status: ExamOrderStatus,
+        actor: &str,
+    ) -> Result<(), ExamOrderEntityError> {
+        if !self.status.can_transition_to(status) {
+            return Err(ExamOrderEntityError::InvalidStatusTransition(
+                self.status.to_string(),
+                status.to_string(),
+            ));
+        }
+
+        let now = Some(chrono::Local::now().naive_utc());
+
+        match status {
+            ExamOrderStatus::Requested => {}
+            ExamOrderStatus::Collected => self.collected_at = now,
+            ExamOrderStatus::Resulted => self.resulted_at = now,
+            ExamOrderStatus::Reviewed => {
+                self.reviewed_at = now;
+                self.reviewed_by = Some(actor.to_string());
+            }
+        }
+
+        self.status = status;
+
+        Ok(())
+    }
+
+    /// Builds the results to be added to the order. Results may keep coming
+    /// in until the order is reviewed; the first ones mark it as resulted.
+    pub fn record_results(
+        &mut self,
+        inputs: Vec<ExamResultDTO>,
+        actor: &str,
+    ) -> Result<Vec<ExamResult>, ExamOrderEntityError> {
+        let exam_order_id: Option<i32> = self.id.clone().into();
+        let exam_order_id = exam_order_id.ok_or(ExamOrderEntityError::InvalidId(0))?;
+
+        if inputs.is_empty() {
+            return Err(ExamOrderEntityError::InvalidResult(
+                "at least one result must be given".to_string(),
+            ));
+        }
+
+        let results = inputs
+            .into_iter()
+            .map(|input| ExamResult::new(exam_order_id, input))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.mark_resulted(actor)?;
+
+        Ok(results)
+    }
+
+    /// Records the metadata of the result file, replacing any previous one.
+    pub fn attach_result_file(
+        &mut self,
+        file_name: String,
+        content_type: String,
+        size: usize,
+        actor: &str,
+    ) -> Result<(), ExamOrderEntityError> {
+        let file_name = file_name.trim().to_string();
+
+        if file_name.is_empty()
+            || file_name.len() > MAX_FILE_NAME_LENGTH
+            || file_name.contains(['/', '\\'])
+        {
+            return Err(ExamOrderEntityError::InvalidResultFile(format!(
+                "{file_name:?} is not a valid file name"
+            )));
+        }
+
+        if !RESULT_FILE_CONTENT_TYPES.contains(&content_type.as_str()) {
+            return Err(ExamOrderEntityError::InvalidResultFile(format!(
+                "{content_type} files are not accepted, only {}",
+                RESULT_FILE_CONTENT_TYPES.join(", ")
+            )));
+        }
+
+        if size == 0 || size > MAX_RESULT_FILE_SIZE {
+            return Err(ExamOrderEntityError::InvalidResultFile(format!(
+                "the file must have between 1 and {MAX_RESULT_FILE_SIZE} bytes"
+            )));
+        }
+
+        self.mark_resulted(actor)?;
+
+        self.result_file_name = Some(file_name);
+        self.result_file_content_type = Some(content_type);
+        self.result_file_size = Some(size as i32);
+
+        Ok(())
+    }
+
+    fn mark_resulted(&mut self, actor: &str) -> Result<(), ExamOrderEntityError> {
+        match self.status {
+            ExamOrderStatus::Collected => self.transition_to(ExamOrderStatus::Resulted, actor),
+            ExamOrderStatus::Resulted => Ok(()),
+            status => Err(ExamOrderEntityError::InvalidStatusTransition(
+                status.to_string(),
+                ExamOrderStatus::Resulted.to_string(),
+            )),
+        }
+    }
+}
+
+impl ExamResult {
+    pub fn new(exam_order_id: i32, input: ExamResultDTO) -> Result<Self, ExamOrderEntityError> {
+        let analyte = bounded_text("analyte", input.analyte, 150)?;
+        let value = bounded_text("value", input.value, 100)?;
+        let unit = input
+            .unit
+            .map(|unit| bounded_text("unit", unit, 30))
+            .transpose()?;
+        let reference_text = input
+            .reference_text
+            .map(|reference_text| bounded_text("reference text", reference_text, 100))
+            .transpose()?;
+
+        if let (Some(low), Some(high)) = (input.reference_low, input.reference_high)
+            && low > high
+        {
+            return Err(ExamOrderEntityError::InvalidResult(format!(
+                "the reference range of {analyte} starts after it ends"
+            )));
+        }
+
+        let abnormal = input.abnormal.unwrap_or_else(|| {
+            is_out_of_reference(
+                &value,
+                input.reference_low,
+                input.reference_high,
+                reference_text.as_deref(),
+            )
+        });
+
+        Ok(Self {
+            id: ID::New,
+            exam_order_id,
+            analyte,
+            value,
+            unit,
+            reference_low: input.reference_low,
+            reference_high: input.reference_high,
+            reference_text,
+            abnormal,
+            recorded_at: chrono::Local::now().naive_utc(),
+        })
+    }
+}
+
+/// Numeric values are checked against the range, accepting a decimal comma;
+/// anything else against the expected text, ignoring case. Values that
+/// cannot be judged are not flagged.
+fn is_out_of_reference(
+    value: &str,
+    low: Option<f64>,
+    high: Option<f64>,
+    reference_text: Option<&str>,
+) -> bool {
+    if low.is_some() || high.is_some() {
+        return match value.replace(',', ".").parse::<f64>() {
+            Ok(number) => {
+                low.is_some_and(|low| number < low) || high.is_some_and(|high| number > high)
+            }
+            Err(_) => false,
+        };
+    }
+
+    reference_text.is_some_and(|reference_text| !value.eq_ignore_ascii_case(reference_text))
+}
+
+fn bounded_text(
+    field: &str,
+    value: String,
+    max_length: usize,
+) -> Result<String, ExamOrderEntityError> {
+    let trimmed = value.trim().to_string();
+
+    if trimmed.is_empty() || trimmed.len() > max_length {
+        return Err(ExamOrderEntityError::InvalidResult(format!(
+            "the {field} must have between 1 and {max_length} characters"
+        )));
+    }
+
+    Ok(trimmed)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        domain::{
+            entities::exam_order::{ExamOrder, ExamResult},
+            errors::exam_order_entity_error::ExamOrderEntityError,
+            value_objects::{
+                cpf::Cpf, exam_kind::ExamKind, exam_order_status::ExamOrderStatus, id::ID,
+            },
+        },
+        presentation::dtos::exam_order_dto::{ExamResultDTO, OrderExamDTO},
+    };
+
+    #[test]
+    fn transition_to_skipping_collection_error() {
+        let mut exam_order = make_fake_exam_order();
+
+        assert_eq!(
+            exam_order.transition_to(ExamOrderStatus::Resulted, "lab@example.com"),
+            Err(ExamOrderEntityError::InvalidStatusTransition(
+                "requested".to_string(),
+                "resulted".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn record_results_marks_resulted() {
+        let mut exam_order = make_fake_exam_order();
+        exam_order
+            .transition_to(ExamOrderStatus::Collected, "lab@example.com")
+            .unwrap();
+
+        let results = exam_order
+            .record_results(
+                vec![make_fake_result("5.9"), make_fake_result("4,2")],
+                "lab@example.com",
+            )
+            .unwrap();
+
+        assert_eq!(exam_order.status, ExamOrderStatus::Resulted);
+        assert!(exam_order.resulted_at.is_some());
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| result.abnormal)
+                .collect::<Vec<_>>(),
+            vec![true, false]
+        );
+    }
+
+    #[test]
+    fn record_results_after_review_error() {
+        let mut exam_order = make_fake_exam_order();
+        exam_order.status = ExamOrderStatus::Reviewed;
+
+        assert_eq!(
+            exam_order.record_results(vec![make_fake_result("5.0")], "lab@example.com"),
+            Err(ExamOrderEntityError::InvalidStatusTransition(
+                "reviewed".to_string(),
+                "resulted".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn attach_result_file_unsupported_content_type_error() {
+        let mut exam_order = make_fake_exam_order();
+        exam_order.status = ExamOrderStatus::Collected;
+
+        let result = exam_order.attach_result_file(
+            "report.exe".to_string(),
+            "application/octet-stream".to_string(),
+            10,
+            "lab@example.com",
+        );
+
+        assert!(matches!(
+            result,
+            Err(ExamOrderEntityError::InvalidResultFile(_))
+        ));
+        assert_eq!(exam_order.status, ExamOrderStatus::Collected);
+    }
+
+    #[test]
+    fn new_result_text_reference() {
+        let result = ExamResult::new(
+            1,
+            ExamResultDTO {
+                analyte: "HIV 1/2 antibodies".to_string(),
+                value: "Reactive".to_string(),
+                reference_text: Some("Non-reactive".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(result.abnormal);
+    }
+
+    #[test]
+    fn new_result_manual_flag_wins() {
+        let mut input = make_fake_result("5.9");
+        input.abnormal = Some(false);
+
+        assert!(!ExamResult::new(1, input).unwrap().abnormal);
+    }
+
+    fn make_fake_exam_order() -> ExamOrder {
+        let mut exam_order = ExamOrder::new(
+            7,
+            42,
+            None,
+            OrderExamDTO {
+                patient_cpf: Cpf::parse("12345678909").unwrap(),
+                appointment_id: None,
+                kind: ExamKind::Lab,
+                exam_name: "Potassium".to_string(),
+                clinical_indication: None,
+            },
+            "admin@example.com".to_string(),
+        )
+        .unwrap();
+        exam_order.id = ID::Existing(3);
+
+        exam_order
+    }
+
+    fn make_fake_result(value: &str) -> ExamResultDTO {
+        ExamResultDTO {
+            analyte: "Potassium".to_string(),
+            value: value.to_string(),
+            unit: Some("mmol/L".to_string()),
+            reference_low: Some(3.5),
+            reference_high: Some(5.1),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::{Insertable, Queryable};
+
+use crate::{domain::value_objects::id::ID, schema::failed_logins};
+
+/// A login attempt, recorded before the password is checked and deleted
+/// again when it succeeds, so whatever is left are the failures used to
+/// throttle whoever keeps guessing.
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = failed_logins)]
+pub struct FailedLogin {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub email: String,
+    pub ip_address: String,
+    pub attempted_at: NaiveDateTime,
+}
+
+impl FailedLogin {
+    pub fn new(email: String, ip_address: String, attempted_at: NaiveDateTime) -> Self {
+        Self {
+            id: ID::New,
+            email,
+            ip_address,
+            attempted_at,
+        }
+    }
+}
+
+/// The row stored for an attempt, and the failures recorded before it for
+/// the same e-mail and the same address.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedAttempt {
+    pub id: i32,
+    pub account_failures: Vec<NaiveDateTime>,
+    pub address_failures: Vec<NaiveDateTime>,
+}
@@ -1,3 +1,18 @@
 pub mod admin;
+pub mod admission;
+pub mod allergy;
 pub mod appointment;
+pub mod audit_event;
+pub mod consent;
+pub mod document;
+pub mod encounter;
+pub mod exam_order;
+pub mod failed_login;
 pub mod patient;
+pub mod prescription;
+pub mod problem;
+pub mod professional;
+pub mod professional_schedule;
+pub mod schedule_exception;
+pub mod session;
+pub mod ward;
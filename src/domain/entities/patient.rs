@@ -1,45 +1,144 @@
-use diesel::prelude::{Insertable, Queryable};
+use chrono::{NaiveDate, NaiveDateTime};
+use diesel::prelude::{AsChangeset, Insertable, Queryable};
 
 use crate::{
-    domain::{errors::patient_entity_error::PatientEntityError, value_objects::id::ID},
-    presentation::dtos::patient_dto::CreatePatientDTO,
+    domain::{
+        errors::patient_entity_error::PatientEntityError,
+        value_objects::{
+            cep::Cep, cns::Cns, cpf::Cpf, email::Email, id::ID, phone::normalize_phone, sex::Sex,
+            uf::is_valid_uf,
+        },
+    },
+    presentation::dtos::patient_dto::{CreatePatientDTO, PatientProfileDTO},
     schema::patients,
 };
 
-#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[derive(AsChangeset, Clone, Debug, Insertable, PartialEq, Queryable)]
 #[diesel(table_name = patients)]
 pub struct Patient {
     #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
     pub id: ID,
     pub name: String,
-    pub cpf: String,
+    pub cpf: Cpf,
+    pub social_name: Option<String>,
+    pub birth_date: Option<NaiveDate>,
+    pub sex: Option<Sex>,
+    pub gender_identity: Option<String>,
+    pub mother_name: Option<String>,
+    pub cns: Option<Cns>,
+    pub email: Option<Email>,
+    pub phones: Vec<String>,
+    pub cep: Option<Cep>,
+    pub street: Option<String>,
+    pub address_number: Option<String>,
+    pub address_complement: Option<String>,
+    pub neighborhood: Option<String>,
+    pub city: Option<String>,
+    pub uf: Option<String>,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub anonymized_at: Option<NaiveDateTime>,
+    pub merged_into_id: Option<i32>,
+    pub merged_at: Option<NaiveDateTime>,
 }
 
 impl Patient {
-    pub fn new(name: String, cpf: String) -> Self {
+    pub fn new(name: String, cpf: Cpf) -> Self {
         Self {
             id: ID::New,
             name,
             cpf,
+            social_name: None,
+            birth_date: None,
+            sex: None,
+            gender_identity: None,
+            mother_name: None,
+            cns: None,
+            email: None,
+            phones: Vec::new(),
+            cep: None,
+            street: None,
+            address_number: None,
+            address_complement: None,
+            neighborhood: None,
+            city: None,
+            uf: None,
+            deleted_at: None,
+            anonymized_at: None,
+            merged_into_id: None,
+            merged_at: None,
         }
     }
 
-    pub fn restore(id: i32, name: String, cpf: String) -> Result<Self, PatientEntityError> {
+    pub fn restore(id: i32, name: String, cpf: Cpf) -> Result<Self, PatientEntityError> {
         if id <= 0 {
             return Err(PatientEntityError::InvalidId(id));
         }
 
         Ok(Self {
             id: ID::Existing(id),
-            name,
-            cpf,
+            ..Self::new(name, cpf)
         })
     }
+
+    /// Overwrites the demographic fields present in `profile`, leaving the
+    /// absent ones untouched.
+    pub fn apply_profile(&mut self, profile: PatientProfileDTO) -> Result<(), PatientEntityError> {
+        if let Some(birth_date) = profile.birth_date {
+            let parsed = birth_date
+                .parse::<NaiveDate>()
+                .map_err(|_| PatientEntityError::InvalidBirthDate(birth_date.clone()))?;
+
+            if parsed > chrono::Local::now().date_naive() {
+                return Err(PatientEntityError::InvalidBirthDate(birth_date));
+            }
+
+            self.birth_date = Some(parsed);
+        }
+
+        if let Some(uf) = profile.uf {
+            let normalized = uf.trim().to_uppercase();
+
+            if !is_valid_uf(&normalized) {
+                return Err(PatientEntityError::InvalidUf(uf));
+            }
+
+            self.uf = Some(normalized);
+        }
+
+        if let Some(phones) = profile.phones {
+            self.phones = phones
+                .iter()
+                .map(|phone| normalize_phone(phone))
+                .collect::<Result<Vec<String>, PatientEntityError>>()?;
+        }
+
+        self.social_name = profile.social_name.or(self.social_name.take());
+        self.sex = profile.sex.or(self.sex);
+        self.gender_identity = profile.gender_identity.or(self.gender_identity.take());
+        self.mother_name = profile.mother_name.or(self.mother_name.take());
+        self.cns = profile.cns.or(self.cns.take());
+        self.email = profile.email.or(self.email.take());
+        self.cep = profile.cep.or(self.cep.take());
+        self.street = profile.street.or(self.street.take());
+        self.address_number = profile.address_number.or(self.address_number.take());
+        self.address_complement = profile
+            .address_complement
+            .or(self.address_complement.take());
+        self.neighborhood = profile.neighborhood.or(self.neighborhood.take());
+        self.city = profile.city.or(self.city.take());
+
+        Ok(())
+    }
 }
 
-impl From<CreatePatientDTO> for Patient {
-    fn from(value: CreatePatientDTO) -> Self {
-        Self::new(value.name, value.cpf)
+impl TryFrom<CreatePatientDTO> for Patient {
+    type Error = PatientEntityError;
+
+    fn try_from(value: CreatePatientDTO) -> Result<Self, Self::Error> {
+        let mut patient = Self::new(value.name, value.cpf);
+        patient.apply_profile(value.profile)?;
+
+        Ok(patient)
     }
 }
 
@@ -47,18 +146,19 @@ impl From<CreatePatientDTO> for Patient {
 mod test {
     use crate::{
         domain::{
-            entities::patient::Patient, errors::patient_entity_error::PatientEntityError,
-            value_objects::id::ID,
+            entities::patient::Patient,
+            errors::patient_entity_error::PatientEntityError,
+            value_objects::{cpf::Cpf, id::ID},
         },
-        presentation::dtos::patient_dto::CreatePatientDTO,
+        presentation::dtos::patient_dto::{CreatePatientDTO, PatientProfileDTO},
     };
 
     #[test]
     fn new() {
         let name = "Andrew";
-        let cpf = "00011122233";
+        let cpf = Cpf::parse("00011122285").unwrap();
 
-        let patient = Patient::new(name.to_string(), cpf.to_string());
+        let patient = Patient::new(name.to_string(), cpf.clone());
 
         assert_eq!(patient.id, ID::New);
         assert_eq!(patient.name, name);
@@ -69,7 +169,8 @@ mod test {
     fn restore_non_positive_id_error() {
         let id = 0;
 
-        let patient = Patient::restore(id, "Andrew".to_string(), "00011122233".to_string());
+        let patient =
+            Patient::restore(id, "Andrew".to_string(), Cpf::parse("00011122285").unwrap());
 
         assert_eq!(patient, Err(PatientEntityError::InvalidId(0)))
     }
@@ -78,9 +179,9 @@ mod test {
     fn restore_ok() {
         let id = 42;
         let name = "Andrew";
-        let cpf = "00011122233";
+        let cpf = Cpf::parse("00011122285").unwrap();
 
-        let patient = Patient::restore(id, name.to_string(), cpf.to_string()).unwrap();
+        let patient = Patient::restore(id, name.to_string(), cpf.clone()).unwrap();
 
         assert_eq!(patient.id, ID::Existing(42));
         assert_eq!(patient.name, name);
@@ -91,13 +192,81 @@ mod test {
     fn from_create_patient_dto() {
         let dto = CreatePatientDTO {
             name: String::from("Andrew"),
-            cpf: String::from("00011122233"),
+            cpf: Cpf::parse("00011122285").unwrap(),
+            profile: PatientProfileDTO::default(),
         };
 
-        let patient: Patient = dto.clone().into();
+        let patient = Patient::try_from(dto.clone()).unwrap();
 
         assert_eq!(patient.id, ID::New);
         assert_eq!(patient.name, dto.name);
         assert_eq!(patient.cpf, dto.cpf);
     }
+
+    #[test]
+    fn apply_profile_normalizes_fields() {
+        let patient = Patient::try_from(CreatePatientDTO {
+            name: String::from("Andrew"),
+            cpf: Cpf::parse("00011122285").unwrap(),
+            profile: PatientProfileDTO {
+                birth_date: Some("1990-05-17".to_string()),
+                uf: Some("sp".to_string()),
+                phones: Some(vec!["(11) 98765-4321".to_string()]),
+                ..Default::default()
+            },
+        })
+        .unwrap();
+
+        assert_eq!(patient.uf, Some("SP".to_string()));
+        assert_eq!(patient.phones, vec!["11987654321".to_string()]);
+        assert_eq!(
+            patient.birth_date,
+            Some("1990-05-17".parse::<chrono::NaiveDate>().unwrap())
+        );
+    }
+
+    #[test]
+    fn apply_profile_keeps_absent_fields() {
+        let mut patient = Patient::new("Andrew".to_string(), Cpf::parse("00011122285").unwrap());
+        patient.city = Some("Campinas".to_string());
+
+        patient
+            .apply_profile(PatientProfileDTO {
+                social_name: Some("Andy".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(patient.city, Some("Campinas".to_string()));
+        assert_eq!(patient.social_name, Some("Andy".to_string()));
+    }
+
+    #[test]
+    fn apply_profile_future_birth_date_error() {
+        let mut patient = Patient::new("Andrew".to_string(), Cpf::parse("00011122285").unwrap());
+
+        let result = patient.apply_profile(PatientProfileDTO {
+            birth_date: Some("2999-01-01".to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            result,
+            Err(PatientEntityError::InvalidBirthDate(
+                "2999-01-01".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn apply_profile_invalid_uf_error() {
+        let mut patient = Patient::new("Andrew".to_string(), Cpf::parse("00011122285").unwrap());
+
+        let result = patient.apply_profile(PatientProfileDTO {
+            uf: Some("XX".to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(result, Err(PatientEntityError::InvalidUf("XX".to_string())));
+    }
 }
@@ -0,0 +1,281 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::{Insertable, Queryable};
+
+use crate::{
+    domain::{
+        entities::appointment::Appointment,
+        errors::prescription_entity_error::PrescriptionEntityError, value_objects::id::ID,
+    },
+    presentation::dtos::prescription_dto::{IssuePrescriptionDTO, PrescriptionItemDTO},
+    schema::{prescription_items, prescriptions},
+};
+
+const MAX_DRUG_LENGTH: usize = 150;
+const MAX_DOSE_LENGTH: usize = 100;
+const MAX_ROUTE_LENGTH: usize = 50;
+const MAX_FREQUENCY_LENGTH: usize = 100;
+const MAX_DURATION_LENGTH: usize = 100;
+
+/// The header of a prescription. Its items are stored apart, in the order
+/// given by their `position`.
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = prescriptions)]
+pub struct Prescription {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub patient_id: i32,
+    pub professional_id: i32,
+    pub appointment_id: Option<i32>,
+    pub notes: Option<String>,
+    pub allergy_override_reason: Option<String>,
+    pub issued_by: String,
+    pub issued_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = prescription_items)]
+pub struct PrescriptionItem {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    /// Set by the repository when the prescription is saved.
+    pub prescription_id: i32,
+    pub position: i32,
+    pub drug: String,
+    pub dose: String,
+    pub route: String,
+    pub frequency: String,
+    pub duration: Option<String>,
+}
+
+impl Prescription {
+    /// Issues a prescription for the patient signed by `professional_id`,
+    /// optionally tied to one of their appointments with that professional.
+    pub fn new(
+        patient_id: i32,
+        professional_id: i32,
+        appointment: Option<&Appointment>,
+        input: IssuePrescriptionDTO,
+        issued_by: String,
+    ) -> Result<(Self, Vec<PrescriptionItem>), PrescriptionEntityError> {
+        if patient_id <= 0 {
+            return Err(PrescriptionEntityError::InvalidId(patient_id));
+        }
+
+        let appointment_id = match appointment {
+            Some(appointment) => {
+                let appointment_id: Option<i32> = appointment.id.clone().into();
+                let appointment_id =
+                    appointment_id.ok_or(PrescriptionEntityError::UnrelatedAppointment(
+                        "it was not saved yet".to_string(),
+                    ))?;
+
+                if appointment.patient_id != patient_id {
+                    return Err(PrescriptionEntityError::UnrelatedAppointment(format!(
+                        "{appointment_id} belongs to another patient"
+                    )));
+                }
+
+                if appointment.is_canceled() {
+                    return Err(PrescriptionEntityError::UnrelatedAppointment(format!(
+                        "{appointment_id} is canceled"
+                    )));
+                }
+
+                if appointment
+                    .professional_id
+                    .is_some_and(|appointment_professional_id| {
+                        appointment_professional_id != professional_id
+                    })
+                {
+                    return Err(PrescriptionEntityError::UnrelatedAppointment(format!(
+                        "{appointment_id} is with another professional"
+                    )));
+                }
+
+                Some(appointment_id)
+            }
+            None => None,
+        };
+
+        if input.items.is_empty() {
+            return Err(PrescriptionEntityError::MissingItems);
+        }
+
+        let items = input
+            .items
+            .into_iter()
+            .zip(1..)
+            .map(|(item, position)| PrescriptionItem::new(position, item))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((
+            Self {
+                id: ID::New,
+                patient_id,
+                professional_id,
+                appointment_id,
+                notes: input.notes,
+                allergy_override_reason: input
+                    .allergy_override_reason
+                    .map(|reason| reason.trim().to_string())
+                    .filter(|reason| !reason.is_empty()),
+                issued_by,
+                issued_at: chrono::Local::now().naive_utc(),
+            },
+            items,
+        ))
+    }
+}
+
+impl PrescriptionItem {
+    pub fn new(position: i32, input: PrescriptionItemDTO) -> Result<Self, PrescriptionEntityError> {
+        Ok(Self {
+            id: ID::New,
+            prescription_id: 0,
+            position,
+            drug: required_field("drug", input.drug, MAX_DRUG_LENGTH)?,
+            dose: required_field("dose", input.dose, MAX_DOSE_LENGTH)?,
+            route: required_field("route", input.route, MAX_ROUTE_LENGTH)?,
+            frequency: required_field("frequency", input.frequency, MAX_FREQUENCY_LENGTH)?,
+            duration: input
+                .duration
+                .map(|duration| required_field("duration", duration, MAX_DURATION_LENGTH))
+                .transpose()?,
+        })
+    }
+}
+
+fn required_field(
+    field: &str,
+    value: String,
+    max_length: usize,
+) -> Result<String, PrescriptionEntityError> {
+    let trimmed = value.trim().to_string();
+
+    if trimmed.is_empty() || trimmed.len() > max_length {
+        return Err(PrescriptionEntityError::InvalidItem(format!(
+            "the {field} must have between 1 and {max_length} characters"
+        )));
+    }
+
+    Ok(trimmed)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDateTime};
+
+    use crate::{
+        domain::{
+            entities::{appointment::Appointment, prescription::Prescription},
+            errors::prescription_entity_error::PrescriptionEntityError,
+            value_objects::id::ID,
+        },
+        presentation::dtos::prescription_dto::{IssuePrescriptionDTO, PrescriptionItemDTO},
+    };
+
+    #[test]
+    fn new_missing_items_error() {
+        let mut input = make_fake_input();
+        input.items.clear();
+
+        assert_eq!(
+            Prescription::new(7, 42, None, input, "admin".to_string()),
+            Err(PrescriptionEntityError::MissingItems)
+        );
+    }
+
+    #[test]
+    fn new_blank_dose_error() {
+        let mut input = make_fake_input();
+        input.items[0].dose = " ".to_string();
+
+        assert_eq!(
+            Prescription::new(7, 42, None, input, "admin".to_string()),
+            Err(PrescriptionEntityError::InvalidItem(
+                "the dose must have between 1 and 100 characters".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new_appointment_with_another_professional_error() {
+        assert_eq!(
+            Prescription::new(
+                7,
+                43,
+                Some(&make_fake_appointment()),
+                make_fake_input(),
+                "admin".to_string()
+            ),
+            Err(PrescriptionEntityError::UnrelatedAppointment(
+                "1 is with another professional".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new_ok() {
+        let mut input = make_fake_input();
+        input.allergy_override_reason = Some("  ".to_string());
+
+        let (prescription, items) = Prescription::new(
+            7,
+            42,
+            Some(&make_fake_appointment()),
+            input,
+            "admin".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(prescription.professional_id, 42);
+        assert_eq!(prescription.appointment_id, Some(1));
+        assert_eq!(prescription.allergy_override_reason, None);
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| (item.position, item.drug.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "Amoxicillin"), (2, "Dipyrone")]
+        );
+    }
+
+    fn make_fake_input() -> IssuePrescriptionDTO {
+        IssuePrescriptionDTO {
+            appointment_id: Some(1),
+            items: vec![
+                PrescriptionItemDTO {
+                    drug: " Amoxicillin ".to_string(),
+                    dose: "500 mg".to_string(),
+                    route: "oral".to_string(),
+                    frequency: "every 8 hours".to_string(),
+                    duration: Some("7 days".to_string()),
+                },
+                PrescriptionItemDTO {
+                    drug: "Dipyrone".to_string(),
+                    dose: "1 g".to_string(),
+                    route: "oral".to_string(),
+                    frequency: "every 6 hours if in pain".to_string(),
+                    duration: None,
+                },
+            ],
+            notes: None,
+            allergy_override_reason: None,
+        }
+    }
+
+    fn make_fake_appointment() -> Appointment {
+        let mut appointment = Appointment::new(
+            7,
+            Some(42),
+            "2025-06-09T08:00:00".parse::<NaiveDateTime>().unwrap(),
+            Duration::minutes(30),
+            "Nephrology".to_string(),
+            None,
+        )
+        .unwrap();
+        appointment.id = ID::Existing(1);
+
+        appointment
+    }
+}
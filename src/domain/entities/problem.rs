@@ -0,0 +1,171 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use diesel::{
+    ExpressionMethods,
+    prelude::{AsChangeset, Insertable, Queryable},
+};
+
+use crate::{
+    domain::{
+        errors::patient_entity_error::PatientEntityError,
+        value_objects::{icd10::Icd10, id::ID},
+    },
+    presentation::dtos::problem_dto::{CreateProblemDTO, UpdateProblemDTO},
+    schema::problems,
+};
+
+/// A diagnosis on the patient's problem list. It stays active until it gets
+/// a `resolved_at` date.
+#[derive(AsChangeset, Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = problems)]
+pub struct Problem {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub patient_id: i32,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub icd10_code: Icd10,
+    pub description: String,
+    pub onset_date: Option<NaiveDate>,
+    pub resolved_at: Option<NaiveDate>,
+    pub notes: Option<String>,
+    pub recorded_at: NaiveDateTime,
+}
+
+impl Problem {
+    pub fn new(patient_id: i32, input: CreateProblemDTO) -> Result<Self, PatientEntityError> {
+        if patient_id <= 0 {
+            return Err(PatientEntityError::InvalidId(patient_id));
+        }
+
+        let mut problem = Self {
+            id: ID::New,
+            patient_id,
+            icd10_code: input.icd10_code,
+            description: String::new(),
+            onset_date: None,
+            resolved_at: None,
+            notes: input.notes,
+            recorded_at: chrono::Local::now().naive_utc(),
+        };
+
+        problem.apply_update(UpdateProblemDTO {
+            description: Some(input.description),
+            onset_date: input.onset_date,
+            ..Default::default()
+        })?;
+
+        Ok(problem)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.resolved_at.is_none()
+    }
+
+    /// Overwrites the fields present in `input`, leaving the absent ones
+    /// untouched. Setting `resolved_at` resolves the problem.
+    pub fn apply_update(&mut self, input: UpdateProblemDTO) -> Result<(), PatientEntityError> {
+        if let Some(description) = input.description {
+            let description = description.trim().to_string();
+
+            if description.is_empty() {
+                return Err(PatientEntityError::InvalidProblem(
+                    "the description cannot be empty".to_string(),
+                ));
+            }
+
+            self.description = description;
+        }
+
+        if let Some(onset_date) = input.onset_date {
+            self.onset_date = Some(parse_past_date(&onset_date)?);
+        }
+
+        if let Some(resolved_at) = input.resolved_at {
+            self.resolved_at = Some(parse_past_date(&resolved_at)?);
+        }
+
+        if let (Some(onset_date), Some(resolved_at)) = (self.onset_date, self.resolved_at)
+            && resolved_at < onset_date
+        {
+            return Err(PatientEntityError::InvalidProblem(format!(
+                "resolved at {resolved_at}, before its onset on {onset_date}"
+            )));
+        }
+
+        self.notes = input.notes.or(self.notes.take());
+
+        Ok(())
+    }
+}
+
+fn parse_past_date(value: &str) -> Result<NaiveDate, PatientEntityError> {
+    let date = value
+        .parse::<NaiveDate>()
+        .map_err(|_| PatientEntityError::InvalidProblem(format!("invalid date: {value}")))?;
+
+    if date > chrono::Local::now().date_naive() {
+        return Err(PatientEntityError::InvalidProblem(format!(
+            "the date is in the future: {value}"
+        )));
+    }
+
+    Ok(date)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        domain::{
+            entities::problem::Problem, errors::patient_entity_error::PatientEntityError,
+            value_objects::icd10::Icd10,
+        },
+        presentation::dtos::problem_dto::{CreateProblemDTO, UpdateProblemDTO},
+    };
+
+    #[test]
+    fn new_ok() {
+        let problem = make_fake_problem();
+
+        assert_eq!(problem.description, "Type 2 diabetes");
+        assert_eq!(problem.onset_date.unwrap().to_string(), "2020-03-01");
+        assert!(problem.is_active());
+    }
+
+    #[test]
+    fn apply_update_resolves() {
+        let mut problem = make_fake_problem();
+
+        problem
+            .apply_update(UpdateProblemDTO {
+                resolved_at: Some("2024-01-10".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert!(!problem.is_active());
+    }
+
+    #[test]
+    fn apply_update_resolved_before_onset_error() {
+        let mut problem = make_fake_problem();
+
+        let result = problem.apply_update(UpdateProblemDTO {
+            resolved_at: Some("2019-01-10".to_string()),
+            ..Default::default()
+        });
+
+        assert!(matches!(result, Err(PatientEntityError::InvalidProblem(_))));
+    }
+
+    fn make_fake_problem() -> Problem {
+        Problem::new(
+            7,
+            CreateProblemDTO {
+                icd10_code: Icd10::parse("E11.9").unwrap(),
+                description: "Type 2 diabetes ".to_string(),
+                onset_date: Some("2020-03-01".to_string()),
+                notes: None,
+            },
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,326 @@
+use diesel::prelude::{AsChangeset, Insertable, Queryable};
+
+use crate::{
+    domain::{
+        errors::professional_entity_error::ProfessionalEntityError,
+        value_objects::{council::is_valid_council, id::ID, uf::is_valid_uf},
+    },
+    schema::professionals,
+};
+
+const LICENSE_NUMBER_MAX_LENGTH: usize = 20;
+
+#[derive(AsChangeset, Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = professionals)]
+pub struct Professional {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub name: String,
+    pub license_number: String,
+    pub council_state: String,
+    pub specialties: Vec<String>,
+    pub active: bool,
+    pub council: String,
+}
+
+impl Professional {
+    /// A license number written with its council prefix, as in "CRM 123456",
+    /// is stored without it.
+    pub fn new(
+        name: String,
+        council: String,
+        license_number: String,
+        council_state: String,
+        specialties: Vec<String>,
+    ) -> Result<Self, ProfessionalEntityError> {
+        let council = council.trim().to_uppercase();
+        let license_number = license_number.trim().to_uppercase();
+        let license_number = license_number
+            .strip_prefix(&council)
+            .map_or(license_number.as_str(), |number| {
+                number.trim_start_matches([' ', '-', '/'])
+            })
+            .to_string();
+        let council_state = council_state.trim().to_uppercase();
+
+        if !is_valid_council(&council) {
+            return Err(ProfessionalEntityError::InvalidCouncil(council));
+        }
+
+        if license_number.is_empty()
+            || license_number.len() > LICENSE_NUMBER_MAX_LENGTH
+            || !license_number
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '/'))
+        {
+            return Err(ProfessionalEntityError::InvalidLicenseNumber(
+                license_number,
+            ));
+        }
+
+        if !is_valid_uf(&council_state) {
+            return Err(ProfessionalEntityError::InvalidCouncilState(council_state));
+        }
+
+        let name = parse_name(name)?;
+        let specialties = parse_specialties(specialties)?;
+
+        Ok(Self {
+            id: ID::New,
+            name,
+            license_number,
+            council_state,
+            specialties,
+            active: true,
+            council,
+        })
+    }
+
+    pub fn restore(
+        id: i32,
+        name: String,
+        council: String,
+        license_number: String,
+        council_state: String,
+        specialties: Vec<String>,
+        active: bool,
+    ) -> Result<Self, ProfessionalEntityError> {
+        if id <= 0 {
+            return Err(ProfessionalEntityError::InvalidId(id));
+        }
+
+        Ok(Self {
+            id: ID::Existing(id),
+            name,
+            license_number,
+            council_state,
+            specialties,
+            active,
+            council,
+        })
+    }
+
+    pub fn has_specialty(&self, specialty: &str) -> bool {
+        self.specialties
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(specialty.trim()))
+    }
+
+    pub fn rename(&mut self, name: String) -> Result<(), ProfessionalEntityError> {
+        self.name = parse_name(name)?;
+
+        Ok(())
+    }
+
+    pub fn set_specialties(
+        &mut self,
+        specialties: Vec<String>,
+    ) -> Result<(), ProfessionalEntityError> {
+        self.specialties = parse_specialties(specialties)?;
+
+        Ok(())
+    }
+
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+}
+
+fn parse_name(name: String) -> Result<String, ProfessionalEntityError> {
+    let trimmed = name.trim();
+
+    if trimmed.is_empty() {
+        return Err(ProfessionalEntityError::InvalidName(name));
+    }
+
+    Ok(trimmed.to_string())
+}
+
+/// At least one specialty, none blank and none repeated, ignoring case.
+fn parse_specialties(specialties: Vec<String>) -> Result<Vec<String>, ProfessionalEntityError> {
+    if specialties.is_empty() {
+        return Err(ProfessionalEntityError::InvalidSpecialties(
+            "at least one is required".to_string(),
+        ));
+    }
+
+    let mut parsed: Vec<String> = Vec::with_capacity(specialties.len());
+
+    for specialty in specialties {
+        let specialty = specialty.trim();
+
+        if specialty.is_empty() {
+            return Err(ProfessionalEntityError::InvalidSpecialties(
+                "a blank specialty was given".to_string(),
+            ));
+        }
+
+        if parsed.iter().any(|s| s.eq_ignore_ascii_case(specialty)) {
+            return Err(ProfessionalEntityError::InvalidSpecialties(format!(
+                "{specialty} is listed more than once"
+            )));
+        }
+
+        parsed.push(specialty.to_string());
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{
+        entities::professional::Professional,
+        errors::professional_entity_error::ProfessionalEntityError, value_objects::id::ID,
+    };
+
+    #[test]
+    fn new_normalizes_license() {
+        let professional = Professional::new(
+            "Gregory House".to_string(),
+            "CRM".to_string(),
+            " crm 123456 ".to_string(),
+            "sp".to_string(),
+            vec!["Cardiology".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(professional.id, ID::New);
+        assert_eq!(professional.council, "CRM");
+        assert_eq!(professional.license_number, "123456");
+        assert_eq!(professional.council_state, "SP");
+        assert!(professional.active);
+    }
+
+    #[test]
+    fn new_invalid_license_number_error() {
+        let professional = Professional::new(
+            "Gregory House".to_string(),
+            "CRM".to_string(),
+            "   ".to_string(),
+            "SP".to_string(),
+            vec![],
+        );
+
+        assert_eq!(
+            professional,
+            Err(ProfessionalEntityError::InvalidLicenseNumber(String::new()))
+        );
+    }
+
+    #[test]
+    fn new_keeps_numbers_without_prefix() {
+        let professional = Professional::new(
+            "Florence Nightingale".to_string(),
+            " coren ".to_string(),
+            "123456-ENF".to_string(),
+            "SP".to_string(),
+            vec!["Nursing".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(professional.council, "COREN");
+        assert_eq!(professional.license_number, "123456-ENF");
+    }
+
+    #[test]
+    fn new_invalid_council_error() {
+        let professional = Professional::new(
+            "Gregory House".to_string(),
+            "XYZ".to_string(),
+            "123456".to_string(),
+            "SP".to_string(),
+            vec!["Cardiology".to_string()],
+        );
+
+        assert_eq!(
+            professional,
+            Err(ProfessionalEntityError::InvalidCouncil("XYZ".to_string()))
+        );
+    }
+
+    #[test]
+    fn new_invalid_council_state_error() {
+        let professional = Professional::new(
+            "Gregory House".to_string(),
+            "CRM".to_string(),
+            "CRM 123456".to_string(),
+            "XX".to_string(),
+            vec![],
+        );
+
+        assert_eq!(
+            professional,
+            Err(ProfessionalEntityError::InvalidCouncilState(
+                "XX".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new_blank_name_error() {
+        let professional = Professional::new(
+            "  ".to_string(),
+            "CRM".to_string(),
+            "CRM 123456".to_string(),
+            "SP".to_string(),
+            vec!["Cardiology".to_string()],
+        );
+
+        assert_eq!(
+            professional,
+            Err(ProfessionalEntityError::InvalidName("  ".to_string()))
+        );
+    }
+
+    #[test]
+    fn set_specialties_rejects_empty_and_duplicates() {
+        let mut professional = Professional::new(
+            "Gregory House".to_string(),
+            "CRM".to_string(),
+            "CRM 123456".to_string(),
+            "SP".to_string(),
+            vec!["Cardiology".to_string()],
+        )
+        .unwrap();
+
+        assert!(professional.set_specialties(vec![]).is_err());
+        assert_eq!(
+            professional.set_specialties(vec!["Nephrology".to_string(), "nephrology ".to_string()]),
+            Err(ProfessionalEntityError::InvalidSpecialties(
+                "nephrology is listed more than once".to_string()
+            ))
+        );
+        assert_eq!(professional.specialties, vec!["Cardiology".to_string()]);
+    }
+
+    #[test]
+    fn restore_non_positive_id_error() {
+        let professional = Professional::restore(
+            0,
+            "Gregory House".to_string(),
+            "CRM".to_string(),
+            "123456".to_string(),
+            "SP".to_string(),
+            vec![],
+            true,
+        );
+
+        assert_eq!(professional, Err(ProfessionalEntityError::InvalidId(0)));
+    }
+
+    #[test]
+    fn has_specialty_ignores_case() {
+        let professional = Professional::new(
+            "Gregory House".to_string(),
+            "CRM".to_string(),
+            "CRM 123456".to_string(),
+            "SP".to_string(),
+            vec!["Cardiology".to_string()],
+        )
+        .unwrap();
+
+        assert!(professional.has_specialty("cardiology"));
+        assert!(!professional.has_specialty("Dermatology"));
+    }
+}
@@ -0,0 +1,111 @@
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use diesel::prelude::{Insertable, Queryable};
+
+use crate::{
+    domain::{
+        errors::professional_entity_error::ProfessionalEntityError,
+        value_objects::{id::ID, time_slot::TimeSlot},
+    },
+    schema::professional_schedules,
+};
+
+/// A weekly working-hours template: every `weekday` (ISO numbering, 1 is
+/// Monday) the professional attends from `starts_at` to `ends_at`.
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = professional_schedules)]
+pub struct ProfessionalSchedule {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub professional_id: i32,
+    pub weekday: i16,
+    pub starts_at: NaiveTime,
+    pub ends_at: NaiveTime,
+}
+
+impl ProfessionalSchedule {
+    pub fn new(
+        professional_id: i32,
+        weekday: i16,
+        starts_at: NaiveTime,
+        ends_at: NaiveTime,
+    ) -> Result<Self, ProfessionalEntityError> {
+        if professional_id <= 0 {
+            return Err(ProfessionalEntityError::InvalidId(professional_id));
+        }
+
+        if !(1..=7).contains(&weekday) {
+            return Err(ProfessionalEntityError::InvalidWeekday(weekday));
+        }
+
+        if starts_at >= ends_at {
+            return Err(ProfessionalEntityError::InvalidTimeRange(format!(
+                "{starts_at} is not before {ends_at}"
+            )));
+        }
+
+        Ok(Self {
+            id: ID::New,
+            professional_id,
+            weekday,
+            starts_at,
+            ends_at,
+        })
+    }
+
+    /// The working window this template yields on `date`, if it applies to
+    /// that day of the week.
+    pub fn window_on(&self, date: NaiveDate) -> Option<TimeSlot> {
+        if date.weekday().number_from_monday() as i16 != self.weekday {
+            return None;
+        }
+
+        Some(TimeSlot::new(
+            date.and_time(self.starts_at),
+            date.and_time(self.ends_at),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, NaiveTime};
+
+    use crate::domain::{
+        entities::professional_schedule::ProfessionalSchedule,
+        errors::professional_entity_error::ProfessionalEntityError,
+    };
+
+    fn time(value: &str) -> NaiveTime {
+        value.parse::<NaiveTime>().unwrap()
+    }
+
+    #[test]
+    fn new_invalid_weekday_error() {
+        let schedule = ProfessionalSchedule::new(1, 8, time("08:00:00"), time("12:00:00"));
+
+        assert_eq!(schedule, Err(ProfessionalEntityError::InvalidWeekday(8)));
+    }
+
+    #[test]
+    fn new_invalid_time_range_error() {
+        let schedule = ProfessionalSchedule::new(1, 1, time("12:00:00"), time("08:00:00"));
+
+        assert!(matches!(
+            schedule,
+            Err(ProfessionalEntityError::InvalidTimeRange(_))
+        ));
+    }
+
+    #[test]
+    fn window_on() {
+        // 2025-06-09 is a Monday.
+        let monday = NaiveDate::from_ymd_opt(2025, 6, 9).unwrap();
+        let schedule = ProfessionalSchedule::new(1, 1, time("08:00:00"), time("12:00:00")).unwrap();
+
+        let window = schedule.window_on(monday).unwrap();
+
+        assert_eq!(window.starts_at, monday.and_time(time("08:00:00")));
+        assert_eq!(window.ends_at, monday.and_time(time("12:00:00")));
+        assert_eq!(schedule.window_on(monday.succ_opt().unwrap()), None);
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::{Insertable, Queryable};
+
+use crate::{
+    domain::{
+        errors::professional_entity_error::ProfessionalEntityError,
+        value_objects::{id::ID, time_slot::TimeSlot},
+    },
+    schema::schedule_exceptions,
+};
+
+/// A period in which the professional does not attend even though it falls
+/// inside their weekly schedule (holidays, vacations, training...).
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = schedule_exceptions)]
+pub struct ScheduleException {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub professional_id: i32,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    pub reason: Option<String>,
+}
+
+impl ScheduleException {
+    pub fn new(
+        professional_id: i32,
+        starts_at: NaiveDateTime,
+        ends_at: NaiveDateTime,
+        reason: Option<String>,
+    ) -> Result<Self, ProfessionalEntityError> {
+        if professional_id <= 0 {
+            return Err(ProfessionalEntityError::InvalidId(professional_id));
+        }
+
+        if starts_at >= ends_at {
+            return Err(ProfessionalEntityError::InvalidTimeRange(format!(
+                "{starts_at} is not before {ends_at}"
+            )));
+        }
+
+        Ok(Self {
+            id: ID::New,
+            professional_id,
+            starts_at,
+            ends_at,
+            reason,
+        })
+    }
+
+    pub fn time_slot(&self) -> TimeSlot {
+        TimeSlot::new(self.starts_at, self.ends_at)
+    }
+}
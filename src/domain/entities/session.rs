@@ -0,0 +1,100 @@
+use chrono::{Duration, NaiveDateTime};
+use diesel::prelude::{AsChangeset, Insertable, Queryable};
+
+use crate::{domain::value_objects::id::ID, schema::sessions};
+
+/// How long a refresh token stays usable. Every refresh extends the session
+/// by this much again.
+pub const REFRESH_TOKEN_TTL_DAYS: i64 = 7;
+
+/// A login. Access tokens name the session they belong to, so revoking it
+/// cuts off every token issued under it.
+#[derive(AsChangeset, Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = sessions)]
+pub struct Session {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub admin_id: i32,
+    pub refresh_token_hash: String,
+    pub created_at: NaiveDateTime,
+    pub refreshed_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
+    /// The hash `rotate` replaced. Its token was handed out by this session,
+    /// so presenting it again means it leaked.
+    pub previous_refresh_token_hash: Option<String>,
+}
+
+impl Session {
+    pub fn start(admin_id: i32, refresh_token_hash: String) -> Self {
+        let now = chrono::Local::now().naive_utc();
+
+        Self {
+            id: ID::New,
+            admin_id,
+            refresh_token_hash,
+            created_at: now,
+            refreshed_at: now,
+            expires_at: now + Duration::days(REFRESH_TOKEN_TTL_DAYS),
+            revoked_at: None,
+            previous_refresh_token_hash: None,
+        }
+    }
+
+    pub fn is_active(&self, now: NaiveDateTime) -> bool {
+        self.revoked_at.is_none() && now < self.expires_at
+    }
+
+    /// Replaces the refresh token; the previous one stops working but is
+    /// remembered to detect its reuse.
+    pub fn rotate(&mut self, refresh_token_hash: String) {
+        let now = chrono::Local::now().naive_utc();
+
+        self.previous_refresh_token_hash = Some(std::mem::replace(
+            &mut self.refresh_token_hash,
+            refresh_token_hash,
+        ));
+        self.refreshed_at = now;
+        self.expires_at = now + Duration::days(REFRESH_TOKEN_TTL_DAYS);
+    }
+
+    pub fn revoke(&mut self) {
+        if self.revoked_at.is_none() {
+            self.revoked_at = Some(chrono::Local::now().naive_utc());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Duration;
+
+    use super::Session;
+
+    #[test]
+    fn is_active() {
+        let mut session = Session::start(3, "hash".to_string());
+        let now = session.created_at;
+
+        assert!(session.is_active(now));
+        assert!(!session.is_active(session.expires_at));
+
+        session.revoke();
+        assert!(!session.is_active(now));
+    }
+
+    #[test]
+    fn rotate() {
+        let mut session = Session::start(3, "first".to_string());
+        session.expires_at = session.created_at + Duration::minutes(1);
+
+        session.rotate("second".to_string());
+
+        assert_eq!(session.refresh_token_hash, "second");
+        assert_eq!(
+            session.previous_refresh_token_hash,
+            Some("first".to_string())
+        );
+        assert!(session.expires_at > session.created_at + Duration::days(6));
+    }
+}
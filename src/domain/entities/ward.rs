@@ -0,0 +1,173 @@
+use diesel::prelude::{Insertable, Queryable};
+
+use crate::{
+    domain::{
+        errors::admission_entity_error::AdmissionEntityError,
+        value_objects::{bed_status::BedStatus, id::ID},
+    },
+    presentation::dtos::ward_dto::{AddBedDTO, AddRoomDTO, CreateWardDTO},
+    schema::{beds, rooms, wards},
+};
+
+const MAX_WARD_NAME_LENGTH: usize = 100;
+const MAX_LABEL_LENGTH: usize = 20;
+
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = wards)]
+pub struct Ward {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = rooms)]
+pub struct Room {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub ward_id: i32,
+    pub number: String,
+}
+
+#[derive(Clone, Debug, Insertable, PartialEq, Queryable)]
+#[diesel(table_name = beds)]
+pub struct Bed {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub room_id: i32,
+    pub label: String,
+    #[diesel(serialize_as = String, deserialize_as = String)]
+    pub status: BedStatus,
+}
+
+impl Ward {
+    pub fn new(input: CreateWardDTO) -> Result<Self, AdmissionEntityError> {
+        Ok(Self {
+            id: ID::New,
+            name: normalize_name(input.name, MAX_WARD_NAME_LENGTH)?,
+            description: input.description,
+        })
+    }
+}
+
+impl Room {
+    pub fn new(ward_id: i32, input: AddRoomDTO) -> Result<Self, AdmissionEntityError> {
+        Ok(Self {
+            id: ID::New,
+            ward_id,
+            number: normalize_name(input.number, MAX_LABEL_LENGTH)?,
+        })
+    }
+}
+
+impl Bed {
+    pub fn new(input: AddBedDTO) -> Result<Self, AdmissionEntityError> {
+        Ok(Self {
+            id: ID::New,
+            room_id: input.room_id,
+            label: normalize_name(input.label, MAX_LABEL_LENGTH)?,
+            status: BedStatus::Free,
+        })
+    }
+
+    /// Fails unless the bed can take a patient right now.
+    pub fn ensure_free(&self) -> Result<i32, AdmissionEntityError> {
+        let bed_id: Option<i32> = self.id.clone().into();
+        let bed_id = bed_id.ok_or(AdmissionEntityError::InvalidId(0))?;
+
+        if self.status != BedStatus::Free {
+            return Err(AdmissionEntityError::BedNotAvailable(
+                bed_id,
+                self.status.to_string(),
+            ));
+        }
+
+        Ok(bed_id)
+    }
+
+    /// Housekeeping changes. A bed only becomes or stops being occupied
+    /// through admissions, transfers and discharges.
+    pub fn change_status(&mut self, status: BedStatus) -> Result<(), AdmissionEntityError> {
+        if self.status == status {
+            return Ok(());
+        }
+
+        if self.status == BedStatus::Occupied || status == BedStatus::Occupied {
+            return Err(AdmissionEntityError::InvalidBedStatusChange(
+                self.status.to_string(),
+                status.to_string(),
+            ));
+        }
+
+        self.status = status;
+
+        Ok(())
+    }
+}
+
+fn normalize_name(name: String, max_length: usize) -> Result<String, AdmissionEntityError> {
+    let normalized = name.trim().to_string();
+
+    if normalized.is_empty() || normalized.len() > max_length {
+        return Err(AdmissionEntityError::InvalidName(name));
+    }
+
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{
+        entities::ward::Bed,
+        errors::admission_entity_error::AdmissionEntityError,
+        value_objects::{bed_status::BedStatus, id::ID},
+    };
+
+    fn make_bed(status: BedStatus) -> Bed {
+        Bed {
+            id: ID::Existing(4),
+            room_id: 2,
+            label: "A".to_string(),
+            status,
+        }
+    }
+
+    #[test]
+    fn ensure_free_cleaning_bed_error() {
+        assert_eq!(
+            make_bed(BedStatus::Cleaning).ensure_free(),
+            Err(AdmissionEntityError::BedNotAvailable(
+                4,
+                "cleaning".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn change_status_housekeeping() {
+        let mut bed = make_bed(BedStatus::Cleaning);
+
+        bed.change_status(BedStatus::Free).unwrap();
+
+        assert_eq!(bed.status, BedStatus::Free);
+    }
+
+    #[test]
+    fn change_status_occupied_error() {
+        assert_eq!(
+            make_bed(BedStatus::Free).change_status(BedStatus::Occupied),
+            Err(AdmissionEntityError::InvalidBedStatusChange(
+                "free".to_string(),
+                "occupied".to_string()
+            ))
+        );
+        assert_eq!(
+            make_bed(BedStatus::Occupied).change_status(BedStatus::Blocked),
+            Err(AdmissionEntityError::InvalidBedStatusChange(
+                "occupied".to_string(),
+                "blocked".to_string()
+            ))
+        );
+    }
+}
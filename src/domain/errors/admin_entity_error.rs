@@ -0,0 +1,34 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum AdminEntityError {
+    InvalidName,
+    InvalidEmail(String),
+    InvalidRole(String),
+    WeakPassword(String),
+    PasswordReused,
+}
+
+impl fmt::Display for AdminEntityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdminEntityError::InvalidName => {
+                write!(f, "The name of a user cannot be empty")
+            }
+            AdminEntityError::InvalidEmail(email) => {
+                write!(f, "An invalid e-mail was given for a user: {email}")
+            }
+            AdminEntityError::InvalidRole(role) => {
+                write!(f, "An invalid role was given for a user: {role}")
+            }
+            AdminEntityError::WeakPassword(rule) => {
+                write!(f, "The password is too weak: {rule}")
+            }
+            AdminEntityError::PasswordReused => {
+                write!(f, "The new password must differ from the current one")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AdminEntityError {}
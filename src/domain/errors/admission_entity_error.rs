@@ -0,0 +1,61 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum AdmissionEntityError {
+    InvalidId(i32),
+    InvalidName(String),
+    InvalidReason(String),
+    InvalidBedStatus(String),
+    InvalidBedStatusChange(String, String),
+    BedNotAvailable(i32, String),
+    SameBed(i32),
+    AlreadyDischarged(i32),
+}
+
+impl fmt::Display for AdmissionEntityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdmissionEntityError::InvalidId(id) => {
+                write!(f, "An invalid ID was given for an admission: {id}")
+            }
+            AdmissionEntityError::InvalidName(name) => {
+                write!(f, "An invalid ward, room or bed name was given: {name:?}")
+            }
+            AdmissionEntityError::InvalidReason(reason) => {
+                write!(f, "An invalid admission reason was given: {reason:?}")
+            }
+            AdmissionEntityError::InvalidBedStatus(status) => {
+                write!(f, "An invalid bed status was given: {status}")
+            }
+            AdmissionEntityError::InvalidBedStatusChange(from, to) => {
+                write!(f, "A bed cannot go from {from} to {to}")
+            }
+            AdmissionEntityError::BedNotAvailable(bed_id, status) => {
+                write!(f, "The bed {bed_id} is {status} and cannot take a patient")
+            }
+            AdmissionEntityError::SameBed(bed_id) => {
+                write!(f, "The patient is already in the bed {bed_id}")
+            }
+            AdmissionEntityError::AlreadyDischarged(admission_id) => {
+                write!(f, "The admission {admission_id} was already discharged")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AdmissionEntityError {}
+
+#[cfg(test)]
+mod test {
+    use super::AdmissionEntityError;
+
+    #[test]
+    fn display_bed_not_available() {
+        let err = AdmissionEntityError::BedNotAvailable(4, "cleaning".to_string());
+
+        assert_eq!(
+            err.to_string(),
+            "The bed 4 is cleaning and cannot take a patient"
+        );
+    }
+}
@@ -3,7 +3,12 @@ use std::fmt;
 #[derive(Debug)]
 pub enum AppointmentEntityError {
     InvalidPatientId(i32),
+    InvalidProfessionalId(i32),
     InvalidAppointmentAt(String),
+    InvalidDuration(i64),
+    InvalidStatus(String),
+    InvalidStatusTransition(String, String),
+    NotReschedulable(String),
 }
 
 impl fmt::Display for AppointmentEntityError {
@@ -12,9 +17,24 @@ impl fmt::Display for AppointmentEntityError {
             AppointmentEntityError::InvalidPatientId(patient_id) => {
                 write!(f, "Invalid patient ID: {patient_id}")
             }
+            AppointmentEntityError::InvalidProfessionalId(professional_id) => {
+                write!(f, "Invalid professional ID: {professional_id}")
+            }
             AppointmentEntityError::InvalidAppointmentAt(msg) => {
                 write!(f, "Invalid appointment at: {msg}")
             }
+            AppointmentEntityError::InvalidDuration(minutes) => {
+                write!(f, "Invalid appointment duration in minutes: {minutes}")
+            }
+            AppointmentEntityError::InvalidStatus(status) => {
+                write!(f, "Invalid appointment status: {status}")
+            }
+            AppointmentEntityError::InvalidStatusTransition(from, to) => {
+                write!(f, "An appointment cannot go from {from} to {to}")
+            }
+            AppointmentEntityError::NotReschedulable(status) => {
+                write!(f, "An appointment cannot be rescheduled while {status}")
+            }
         }
     }
 }
@@ -0,0 +1,36 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum EncounterEntityError {
+    InvalidId(i32),
+    MissingChiefComplaint,
+    MissingAmendmentReason,
+    UnrecordableAppointment(String),
+}
+
+impl fmt::Display for EncounterEntityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncounterEntityError::InvalidId(encounter_id) => {
+                write!(
+                    f,
+                    "An invalid ID was given for an encounter: {encounter_id}"
+                )
+            }
+            EncounterEntityError::MissingChiefComplaint => {
+                write!(f, "The chief complaint of an encounter cannot be empty")
+            }
+            EncounterEntityError::MissingAmendmentReason => {
+                write!(f, "An amendment must state its reason")
+            }
+            EncounterEntityError::UnrecordableAppointment(msg) => {
+                write!(
+                    f,
+                    "An encounter cannot be recorded for the appointment: {msg}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncounterEntityError {}
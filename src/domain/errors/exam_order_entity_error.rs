@@ -0,0 +1,49 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum ExamOrderEntityError {
+    InvalidId(i32),
+    InvalidKind(String),
+    InvalidStatus(String),
+    InvalidStatusTransition(String, String),
+    InvalidExamName(String),
+    InvalidResult(String),
+    InvalidResultFile(String),
+    UnrelatedAppointment(String),
+}
+
+impl fmt::Display for ExamOrderEntityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExamOrderEntityError::InvalidId(exam_order_id) => {
+                write!(
+                    f,
+                    "An invalid ID was given for an exam order: {exam_order_id}"
+                )
+            }
+            ExamOrderEntityError::InvalidKind(kind) => {
+                write!(f, "An invalid exam kind was given: {kind}")
+            }
+            ExamOrderEntityError::InvalidStatus(status) => {
+                write!(f, "An invalid exam order status was given: {status}")
+            }
+            ExamOrderEntityError::InvalidStatusTransition(from, to) => {
+                write!(f, "An exam order cannot go from {from} to {to}")
+            }
+            ExamOrderEntityError::InvalidExamName(exam_name) => {
+                write!(f, "An invalid exam name was given: {exam_name:?}")
+            }
+            ExamOrderEntityError::InvalidResult(msg) => {
+                write!(f, "An invalid exam result was given: {msg}")
+            }
+            ExamOrderEntityError::InvalidResultFile(msg) => {
+                write!(f, "An invalid exam result file was given: {msg}")
+            }
+            ExamOrderEntityError::UnrelatedAppointment(msg) => {
+                write!(f, "The exam order cannot be tied to the appointment: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExamOrderEntityError {}
@@ -1,3 +1,10 @@
+pub mod admin_entity_error;
+pub mod admission_entity_error;
 pub mod appointment_entity_error;
+pub mod encounter_entity_error;
+pub mod exam_order_entity_error;
 pub mod patient_entity_error;
+pub mod prescription_entity_error;
+pub mod professional_entity_error;
 pub mod repository_error;
+pub mod storage_error;
@@ -3,6 +3,24 @@ use std::fmt;
 #[derive(Debug, PartialEq)]
 pub enum PatientEntityError {
     InvalidId(i32),
+    InvalidCpf(String),
+    InvalidCns(String),
+    InvalidCep(String),
+    InvalidEmail(String),
+    InvalidPhone(String),
+    InvalidSex(String),
+    InvalidUf(String),
+    InvalidBirthDate(String),
+    InvalidConsentPurpose(String),
+    InvalidConsentChannel(String),
+    InvalidTermVersion(String),
+    ConsentAlreadyRevoked(String),
+    InvalidIcd10(String),
+    InvalidAllergySeverity(String),
+    InvalidSubstance(String),
+    InvalidProblem(String),
+    InvalidDocumentCategory(String),
+    InvalidDocument(String),
 }
 
 impl fmt::Display for PatientEntityError {
@@ -11,6 +29,66 @@ impl fmt::Display for PatientEntityError {
             PatientEntityError::InvalidId(patient_id) => {
                 write!(f, "An invalid ID was given for a patient: {patient_id}")
             }
+            PatientEntityError::InvalidCpf(cpf) => {
+                write!(f, "An invalid CPF was given for a patient: {cpf}")
+            }
+            PatientEntityError::InvalidCns(cns) => {
+                write!(f, "An invalid CNS was given for a patient: {cns}")
+            }
+            PatientEntityError::InvalidCep(cep) => {
+                write!(f, "An invalid CEP was given for a patient: {cep}")
+            }
+            PatientEntityError::InvalidEmail(email) => {
+                write!(f, "An invalid e-mail was given for a patient: {email}")
+            }
+            PatientEntityError::InvalidPhone(phone) => {
+                write!(
+                    f,
+                    "An invalid phone number was given for a patient: {phone}"
+                )
+            }
+            PatientEntityError::InvalidSex(sex) => {
+                write!(f, "An invalid sex was given for a patient: {sex}")
+            }
+            PatientEntityError::InvalidUf(uf) => {
+                write!(f, "An invalid UF was given for a patient: {uf}")
+            }
+            PatientEntityError::InvalidBirthDate(birth_date) => {
+                write!(
+                    f,
+                    "An invalid birth date was given for a patient: {birth_date}"
+                )
+            }
+            PatientEntityError::InvalidConsentPurpose(purpose) => {
+                write!(f, "An invalid consent purpose was given: {purpose}")
+            }
+            PatientEntityError::InvalidConsentChannel(channel) => {
+                write!(f, "An invalid consent channel was given: {channel}")
+            }
+            PatientEntityError::InvalidTermVersion(term_version) => {
+                write!(f, "An invalid term version was given: {term_version}")
+            }
+            PatientEntityError::ConsentAlreadyRevoked(purpose) => {
+                write!(f, "The consent for {purpose} is already revoked")
+            }
+            PatientEntityError::InvalidIcd10(code) => {
+                write!(f, "An invalid ICD-10 code was given: {code}")
+            }
+            PatientEntityError::InvalidAllergySeverity(severity) => {
+                write!(f, "An invalid allergy severity was given: {severity}")
+            }
+            PatientEntityError::InvalidSubstance(substance) => {
+                write!(f, "An invalid allergy substance was given: {substance}")
+            }
+            PatientEntityError::InvalidProblem(msg) => {
+                write!(f, "An invalid problem was given: {msg}")
+            }
+            PatientEntityError::InvalidDocumentCategory(category) => {
+                write!(f, "An invalid document category was given: {category}")
+            }
+            PatientEntityError::InvalidDocument(msg) => {
+                write!(f, "An invalid document was given: {msg}")
+            }
         }
     }
 }
@@ -32,4 +110,16 @@ mod test {
             format!("An invalid ID was given for a patient: {patient_id}")
         );
     }
+
+    #[test]
+    fn display_invalid_cpf() {
+        let cpf = "12345678900";
+        let err = PatientEntityError::InvalidCpf(cpf.to_string());
+        let err = err.to_string();
+
+        assert_eq!(
+            err,
+            format!("An invalid CPF was given for a patient: {cpf}")
+        );
+    }
 }
@@ -0,0 +1,36 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum PrescriptionEntityError {
+    InvalidId(i32),
+    MissingItems,
+    InvalidItem(String),
+    UnrelatedAppointment(String),
+}
+
+impl fmt::Display for PrescriptionEntityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrescriptionEntityError::InvalidId(prescription_id) => {
+                write!(
+                    f,
+                    "An invalid ID was given for a prescription: {prescription_id}"
+                )
+            }
+            PrescriptionEntityError::MissingItems => {
+                write!(f, "A prescription must have at least one item")
+            }
+            PrescriptionEntityError::InvalidItem(msg) => {
+                write!(f, "An invalid prescription item was given: {msg}")
+            }
+            PrescriptionEntityError::UnrelatedAppointment(msg) => {
+                write!(
+                    f,
+                    "The prescription cannot be tied to the appointment: {msg}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PrescriptionEntityError {}
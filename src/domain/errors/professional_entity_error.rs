@@ -0,0 +1,52 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum ProfessionalEntityError {
+    InvalidId(i32),
+    InvalidName(String),
+    InvalidSpecialties(String),
+    InvalidCouncil(String),
+    InvalidLicenseNumber(String),
+    InvalidCouncilState(String),
+    InvalidWeekday(i16),
+    InvalidTimeRange(String),
+}
+
+impl fmt::Display for ProfessionalEntityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfessionalEntityError::InvalidId(professional_id) => {
+                write!(
+                    f,
+                    "An invalid ID was given for a professional: {professional_id}"
+                )
+            }
+            ProfessionalEntityError::InvalidName(name) => {
+                write!(f, "An invalid name was given for a professional: '{name}'")
+            }
+            ProfessionalEntityError::InvalidSpecialties(msg) => {
+                write!(f, "Invalid specialties were given: {msg}")
+            }
+            ProfessionalEntityError::InvalidCouncil(council) => {
+                write!(f, "An invalid council was given: {council}")
+            }
+            ProfessionalEntityError::InvalidLicenseNumber(license_number) => {
+                write!(f, "An invalid license number was given: {license_number}")
+            }
+            ProfessionalEntityError::InvalidCouncilState(council_state) => {
+                write!(f, "An invalid council state was given: {council_state}")
+            }
+            ProfessionalEntityError::InvalidWeekday(weekday) => {
+                write!(
+                    f,
+                    "An invalid weekday was given, expected 1 (Monday) to 7 (Sunday): {weekday}"
+                )
+            }
+            ProfessionalEntityError::InvalidTimeRange(msg) => {
+                write!(f, "An invalid time range was given: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProfessionalEntityError {}
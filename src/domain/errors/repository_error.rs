@@ -3,6 +3,7 @@ use std::fmt;
 #[derive(Clone, Debug)]
 pub enum RepositoryError {
     DatabaseError(String),
+    Conflict(String),
 }
 
 impl fmt::Display for RepositoryError {
@@ -11,6 +12,9 @@ impl fmt::Display for RepositoryError {
             RepositoryError::DatabaseError(msg) => {
                 write!(f, "A database error occurred: {msg}")
             }
+            RepositoryError::Conflict(msg) => {
+                write!(f, "A conflicting record already exists: {msg}")
+            }
         }
     }
 }
@@ -29,4 +33,16 @@ mod test {
 
         assert_eq!(err, "A database error occurred: ".to_owned() + error_msg);
     }
+
+    #[test]
+    fn display_conflict() {
+        let error_msg = "duplicate key value violates unique constraint";
+        let err = RepositoryError::Conflict(error_msg.to_string());
+        let err = err.to_string();
+
+        assert_eq!(
+            err,
+            "A conflicting record already exists: ".to_owned() + error_msg
+        );
+    }
 }
@@ -0,0 +1,32 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StorageError {
+    InvalidKey(String),
+    NotFound(String),
+    Io(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::InvalidKey(key) => {
+                write!(f, "An invalid storage key was given: {key}")
+            }
+            StorageError::NotFound(key) => {
+                write!(f, "No file is stored under the following key: {key}")
+            }
+            StorageError::Io(msg) => {
+                write!(f, "A storage error occurred: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+impl From<std::io::Error> for StorageError {
+    fn from(value: std::io::Error) -> Self {
+        StorageError::Io(value.to_string())
+    }
+}
@@ -1,8 +1,15 @@
 use async_trait::async_trait;
+use mockall::automock;
 
 use crate::domain::{entities::admin::Admin, errors::repository_error::RepositoryError};
 
+#[automock]
 #[async_trait]
 pub trait AdminRepository {
     async fn find_by_email(&self, email: String) -> Result<Option<Admin>, RepositoryError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Admin>, RepositoryError>;
+    /// Every account, active or not, ordered by name.
+    async fn find_all(&self) -> Result<Vec<Admin>, RepositoryError>;
+    async fn save(&self, admin: &Admin) -> Result<i32, RepositoryError>;
+    async fn update(&self, admin: &Admin) -> Result<Admin, RepositoryError>;
 }
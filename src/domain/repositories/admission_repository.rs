@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::admission::{Admission, BedOccupancy},
+    errors::repository_error::RepositoryError,
+};
+
+/// Admissions come along with their bed occupancies, oldest first. Every
+/// write also keeps the status of the beds involved in step, and fails with
+/// a conflict if a bed that must be free is not.
+#[automock]
+#[async_trait]
+pub trait AdmissionRepository {
+    async fn admit(
+        &self,
+        admission: &Admission,
+        occupancy: &BedOccupancy,
+    ) -> Result<(Admission, Vec<BedOccupancy>), RepositoryError>;
+    /// Closes the current occupancy of the admission and opens `occupancy`.
+    async fn transfer(
+        &self,
+        occupancy: &BedOccupancy,
+    ) -> Result<(Admission, Vec<BedOccupancy>), RepositoryError>;
+    async fn discharge(
+        &self,
+        admission: &Admission,
+    ) -> Result<(Admission, Vec<BedOccupancy>), RepositoryError>;
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<(Admission, Vec<BedOccupancy>)>, RepositoryError>;
+    async fn find_by_patient_id(
+        &self,
+        patient_id: i32,
+    ) -> Result<Vec<(Admission, Vec<BedOccupancy>)>, RepositoryError>;
+    async fn find_open(&self) -> Result<Vec<(Admission, Vec<BedOccupancy>)>, RepositoryError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{entities::allergy::Allergy, errors::repository_error::RepositoryError};
+
+#[automock]
+#[async_trait]
+pub trait AllergyRepository {
+    async fn save(&self, allergy: &Allergy) -> Result<Allergy, RepositoryError>;
+    async fn update(&self, allergy: &Allergy) -> Result<Allergy, RepositoryError>;
+    async fn find_by_id(
+        &self,
+        patient_id: i32,
+        id: i32,
+    ) -> Result<Option<Allergy>, RepositoryError>;
+    async fn find_by_patient_id(&self, patient_id: i32) -> Result<Vec<Allergy>, RepositoryError>;
+    async fn delete(&self, patient_id: i32, id: i32) -> Result<bool, RepositoryError>;
+}
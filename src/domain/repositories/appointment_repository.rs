@@ -1,18 +1,41 @@
 use async_trait::async_trait;
 use chrono::NaiveDateTime;
+use mockall::automock;
 
 use crate::domain::{
-    entities::appointment::Appointment, errors::repository_error::RepositoryError,
+    entities::appointment::Appointment,
+    errors::repository_error::RepositoryError,
+    value_objects::{
+        appointment_status::AppointmentStatus,
+        pagination::{Page, Pagination, SortOrder},
+    },
 };
 
+/// Criteria for the agenda search; `None` fields do not restrict the result.
+/// `from`/`to` bound the start time as a half-open range.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AppointmentFilter {
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    pub specialty: Option<String>,
+    pub professional_id: Option<i32>,
+    pub status: Option<AppointmentStatus>,
+    pub patient_id: Option<i32>,
+    pub sort: SortOrder,
+}
+
+#[automock]
 #[async_trait]
 pub trait AppointmentRepository {
-    async fn exists_by_patient_id_and_appointment_at(
+    async fn exists_overlapping_by_patient_id(
         &self,
         patient_id: i32,
-        appointment_at: NaiveDateTime,
+        starts_at: NaiveDateTime,
+        ends_at: NaiveDateTime,
+        excluding_id: Option<i32>,
     ) -> Result<bool, RepositoryError>;
     async fn save(&self, appointment: &Appointment) -> Result<Appointment, RepositoryError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Appointment>, RepositoryError>;
     async fn find_by_patient_id_and_appointment_at(
         &self,
         patient_id: i32,
@@ -23,4 +46,15 @@ pub trait AppointmentRepository {
         &self,
         patient_id: i32,
     ) -> Result<Vec<Appointment>, RepositoryError>;
+    async fn find_by_professional_id_between(
+        &self,
+        professional_id: i32,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<Appointment>, RepositoryError>;
+    async fn search(
+        &self,
+        filter: AppointmentFilter,
+        pagination: Pagination,
+    ) -> Result<Page<Appointment>, RepositoryError>;
 }
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{entities::audit_event::AuditEvent, errors::repository_error::RepositoryError};
+
+#[automock]
+#[async_trait]
+pub trait AuditRepository {
+    async fn save(&self, event: &AuditEvent) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::consent::Consent, errors::repository_error::RepositoryError,
+    value_objects::consent_purpose::ConsentPurpose,
+};
+
+#[automock]
+#[async_trait]
+pub trait ConsentRepository {
+    /// Stores a newly granted consent, revoking the patient's active consent
+    /// for the same purpose, if any.
+    async fn save(&self, consent: &Consent) -> Result<Consent, RepositoryError>;
+    async fn update(&self, consent: &Consent) -> Result<Consent, RepositoryError>;
+    async fn find_active_by_patient_id_and_purpose(
+        &self,
+        patient_id: i32,
+        purpose: ConsentPurpose,
+    ) -> Result<Option<Consent>, RepositoryError>;
+    async fn find_by_patient_id(&self, patient_id: i32) -> Result<Vec<Consent>, RepositoryError>;
+}
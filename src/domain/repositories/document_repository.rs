@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{entities::document::Document, errors::repository_error::RepositoryError};
+
+#[automock]
+#[async_trait]
+pub trait DocumentRepository {
+    async fn save(&self, document: &Document) -> Result<Document, RepositoryError>;
+    async fn find_by_id(
+        &self,
+        patient_id: i32,
+        id: i32,
+    ) -> Result<Option<Document>, RepositoryError>;
+    async fn find_by_patient_id(&self, patient_id: i32) -> Result<Vec<Document>, RepositoryError>;
+    async fn delete(&self, patient_id: i32, id: i32) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{entities::encounter::Encounter, errors::repository_error::RepositoryError};
+
+/// Encounters are append-only, so there is no way to update or delete them.
+#[automock]
+#[async_trait]
+pub trait EncounterRepository {
+    async fn save(&self, encounter: &Encounter) -> Result<Encounter, RepositoryError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Encounter>, RepositoryError>;
+    async fn exists_by_appointment_id(&self, appointment_id: i32) -> Result<bool, RepositoryError>;
+    /// Amendments of the encounter, oldest first.
+    async fn find_amendments(&self, id: i32) -> Result<Vec<Encounter>, RepositoryError>;
+    /// Every encounter of the patient, amendments included, in the order they
+    /// were recorded.
+    async fn find_by_patient_id(&self, patient_id: i32) -> Result<Vec<Encounter>, RepositoryError>;
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::exam_order::{ExamOrder, ExamResult},
+    errors::repository_error::RepositoryError,
+};
+
+#[automock]
+#[async_trait]
+pub trait ExamOrderRepository {
+    async fn save(&self, exam_order: &ExamOrder) -> Result<ExamOrder, RepositoryError>;
+    async fn update(&self, exam_order: &ExamOrder) -> Result<ExamOrder, RepositoryError>;
+    /// The order along with its results, in the order they were recorded.
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<(ExamOrder, Vec<ExamResult>)>, RepositoryError>;
+    /// Every exam order of the patient, newest first, with their results.
+    async fn find_by_patient_id(
+        &self,
+        patient_id: i32,
+    ) -> Result<Vec<(ExamOrder, Vec<ExamResult>)>, RepositoryError>;
+    /// Updates the order and adds the results in a single transaction,
+    /// returning every result of the order.
+    async fn save_results(
+        &self,
+        exam_order: &ExamOrder,
+        results: &[ExamResult],
+    ) -> Result<(ExamOrder, Vec<ExamResult>), RepositoryError>;
+    /// Updates the order and stores the contents of its result file in a
+    /// single transaction, replacing any previous file.
+    async fn save_result_file(
+        &self,
+        exam_order: &ExamOrder,
+        content: Vec<u8>,
+    ) -> Result<ExamOrder, RepositoryError>;
+    async fn find_result_file(&self, id: i32) -> Result<Option<Vec<u8>>, RepositoryError>;
+}
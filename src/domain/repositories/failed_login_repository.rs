@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::{
+    entities::failed_login::{FailedLogin, RecordedAttempt},
+    errors::repository_error::RepositoryError,
+};
+
+#[automock]
+#[async_trait]
+pub trait FailedLoginRepository {
+    /// Stores the attempt and returns the failures recorded since `since`
+    /// before it. Attempts for the same e-mail or address are serialized,
+    /// so concurrent guesses each see the ones that came before.
+    async fn record_attempt(
+        &self,
+        attempt: &FailedLogin,
+        since: NaiveDateTime,
+    ) -> Result<RecordedAttempt, RepositoryError>;
+    async fn delete(&self, id: i32) -> Result<(), RepositoryError>;
+    /// Forgets the failures of an e-mail once it logs in successfully.
+    async fn delete_by_email(&self, email: String) -> Result<usize, RepositoryError>;
+    /// Drops attempts too old to count towards any throttle.
+    async fn delete_older_than(&self, before: NaiveDateTime) -> Result<usize, RepositoryError>;
+}
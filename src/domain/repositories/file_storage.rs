@@ -0,0 +1,22 @@
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use futures::Stream;
+use mockall::automock;
+
+use crate::domain::errors::storage_error::StorageError;
+
+/// The contents of a stored file, read in chunks.
+pub type FileStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>, StorageError>> + Send>>;
+
+/// Where uploaded files live. Keys are relative, `/`-separated paths chosen
+/// by the caller.
+#[automock]
+#[async_trait]
+pub trait FileStorage {
+    /// Stores `content` under `key`, replacing whatever was there.
+    async fn put(&self, key: &str, content: Vec<u8>) -> Result<(), StorageError>;
+    async fn get(&self, key: &str) -> Result<FileStream, StorageError>;
+    /// Deleting a missing file is not an error.
+    async fn delete(&self, key: &str) -> Result<(), StorageError>;
+}
@@ -1,3 +1,18 @@
 pub mod admin_repository;
+pub mod admission_repository;
+pub mod allergy_repository;
 pub mod appointment_repository;
+pub mod audit_repository;
+pub mod consent_repository;
+pub mod document_repository;
+pub mod encounter_repository;
+pub mod exam_order_repository;
+pub mod failed_login_repository;
+pub mod file_storage;
 pub mod patient_repository;
+pub mod prescription_repository;
+pub mod problem_repository;
+pub mod professional_repository;
+pub mod schedule_repository;
+pub mod session_repository;
+pub mod ward_repository;
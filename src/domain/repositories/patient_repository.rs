@@ -1,14 +1,70 @@
-use crate::domain::{entities::patient::Patient, errors::repository_error::RepositoryError};
+use crate::domain::{
+    entities::patient::Patient,
+    errors::repository_error::RepositoryError,
+    value_objects::{
+        cpf::Cpf,
+        pagination::{Page, Pagination},
+    },
+};
 use async_trait::async_trait;
 use mockall::automock;
 
+/// What was moved from a duplicate patient to the surviving one. Allergies
+/// and active problems the survivor already has stay with the duplicate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PatientMergeReport {
+    pub surviving_patient_id: i32,
+    pub duplicate_patient_id: i32,
+    pub moved_appointment_ids: Vec<i32>,
+    pub moved_consent_ids: Vec<i32>,
+    pub moved_encounter_ids: Vec<i32>,
+    pub moved_allergy_ids: Vec<i32>,
+    pub moved_problem_ids: Vec<i32>,
+    pub moved_prescription_ids: Vec<i32>,
+    pub moved_exam_order_ids: Vec<i32>,
+    pub moved_document_ids: Vec<i32>,
+    pub moved_admission_ids: Vec<i32>,
+    /// Active consents of the duplicate that the survivor already held for
+    /// the same purpose. They are revoked before being moved.
+    pub revoked_consent_ids: Vec<i32>,
+}
+
 #[automock]
 #[async_trait]
 pub trait PatientRepository {
-    async fn exists_by_cpf(&self, cpf: &str) -> Result<bool, RepositoryError>;
+    async fn exists_by_cpf(&self, cpf: &Cpf) -> Result<bool, RepositoryError>;
     async fn save(&self, patient: &Patient) -> Result<i32, RepositoryError>;
     async fn find_by_id(&self, id: i32) -> Result<Option<Patient>, RepositoryError>;
-    async fn find_by_cpf(&self, cpf: String) -> Result<Option<Patient>, RepositoryError>;
+    async fn find_by_cpf(&self, cpf: Cpf) -> Result<Option<Patient>, RepositoryError>;
     async fn update(&self, patient: &Patient) -> Result<Patient, RepositoryError>;
-    async fn delete_by_cpf(&self, cpf: String) -> Result<(), RepositoryError>;
+    /// Soft-deletes the patient: the row is kept, with `deleted_at` set, and
+    /// every other query of this repository stops returning it.
+    async fn delete_by_cpf(&self, cpf: Cpf) -> Result<(), RepositoryError>;
+    async fn find_by_cpf_including_deleted(
+        &self,
+        cpf: Cpf,
+    ) -> Result<Option<Patient>, RepositoryError>;
+    /// Irreversibly scrubs the personal data of the patient and the free-text
+    /// fields of their appointments, encounters, prescriptions and exam
+    /// orders, keeping only what statistics need (sex, birth year and UF).
+    /// Their documents and exam result files are deleted and the patient is
+    /// soft-deleted as well. Returns the storage keys of the deleted
+    /// documents, whose files are left for the caller to remove.
+    async fn anonymize(&self, id: i32) -> Result<Vec<String>, RepositoryError>;
+    /// Moves every child record of the duplicate patient to the surviving
+    /// one and soft-deletes the duplicate, marking it as merged into the
+    /// survivor. Runs in a single transaction.
+    async fn merge(
+        &self,
+        surviving_id: i32,
+        duplicate_id: i32,
+        merged_by: String,
+    ) -> Result<PatientMergeReport, RepositoryError>;
+    /// Lists patients ordered by name. `name` matches any part of the name,
+    /// ignoring case and accents.
+    async fn search(
+        &self,
+        name: Option<String>,
+        pagination: Pagination,
+    ) -> Result<Page<Patient>, RepositoryError>;
 }
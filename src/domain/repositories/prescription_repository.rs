@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::prescription::{Prescription, PrescriptionItem},
+    errors::repository_error::RepositoryError,
+};
+
+/// Prescriptions are issued once and never edited.
+#[automock]
+#[async_trait]
+pub trait PrescriptionRepository {
+    /// Saves the prescription and its items in a single transaction.
+    async fn save(
+        &self,
+        prescription: &Prescription,
+        items: &[PrescriptionItem],
+    ) -> Result<(Prescription, Vec<PrescriptionItem>), RepositoryError>;
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<(Prescription, Vec<PrescriptionItem>)>, RepositoryError>;
+    /// Every prescription of the patient, newest first.
+    async fn find_by_patient_id(
+        &self,
+        patient_id: i32,
+    ) -> Result<Vec<(Prescription, Vec<PrescriptionItem>)>, RepositoryError>;
+}
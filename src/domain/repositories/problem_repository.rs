@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{entities::problem::Problem, errors::repository_error::RepositoryError};
+
+#[automock]
+#[async_trait]
+pub trait ProblemRepository {
+    async fn save(&self, problem: &Problem) -> Result<Problem, RepositoryError>;
+    async fn update(&self, problem: &Problem) -> Result<Problem, RepositoryError>;
+    async fn find_by_id(
+        &self,
+        patient_id: i32,
+        id: i32,
+    ) -> Result<Option<Problem>, RepositoryError>;
+    /// Active problems first, then the resolved ones.
+    async fn find_by_patient_id(&self, patient_id: i32) -> Result<Vec<Problem>, RepositoryError>;
+    async fn delete(&self, patient_id: i32, id: i32) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::professional::Professional, errors::repository_error::RepositoryError,
+};
+
+#[automock]
+#[async_trait]
+pub trait ProfessionalRepository {
+    async fn exists_by_license(
+        &self,
+        council: &str,
+        license_number: &str,
+        council_state: &str,
+    ) -> Result<bool, RepositoryError>;
+    async fn save(&self, professional: &Professional) -> Result<i32, RepositoryError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Professional>, RepositoryError>;
+    async fn update(&self, professional: &Professional) -> Result<Professional, RepositoryError>;
+}
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::{
+    entities::{
+        professional_schedule::ProfessionalSchedule, schedule_exception::ScheduleException,
+    },
+    errors::repository_error::RepositoryError,
+};
+
+#[automock]
+#[async_trait]
+pub trait ScheduleRepository {
+    async fn save_schedule(
+        &self,
+        schedule: &ProfessionalSchedule,
+    ) -> Result<ProfessionalSchedule, RepositoryError>;
+    async fn find_schedules_by_professional_id(
+        &self,
+        professional_id: i32,
+    ) -> Result<Vec<ProfessionalSchedule>, RepositoryError>;
+    async fn delete_schedule(
+        &self,
+        professional_id: i32,
+        schedule_id: i32,
+    ) -> Result<bool, RepositoryError>;
+    async fn save_exception(
+        &self,
+        exception: &ScheduleException,
+    ) -> Result<ScheduleException, RepositoryError>;
+    async fn find_exceptions_by_professional_id_between(
+        &self,
+        professional_id: i32,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<ScheduleException>, RepositoryError>;
+    async fn delete_exception(
+        &self,
+        professional_id: i32,
+        exception_id: i32,
+    ) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{entities::session::Session, errors::repository_error::RepositoryError};
+
+/// Also consulted by the token extractor on every request, so it must be
+/// shareable across threads as a trait object.
+#[automock]
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn save(&self, session: &Session) -> Result<i32, RepositoryError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Session>, RepositoryError>;
+    /// Stores the rotated `session` only if it is not revoked and still holds
+    /// `previous_hash`, so a refresh token can be used once. Returns `None`
+    /// when another refresh got there first.
+    async fn rotate(
+        &self,
+        session: &Session,
+        previous_hash: String,
+    ) -> Result<Option<Session>, RepositoryError>;
+    async fn revoke(&self, id: i32) -> Result<(), RepositoryError>;
+    /// Revokes every open session of the account, returning how many.
+    async fn revoke_all_by_admin_id(&self, admin_id: i32) -> Result<usize, RepositoryError>;
+}
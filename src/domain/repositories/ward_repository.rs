@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::ward::{Bed, Room, Ward},
+    errors::repository_error::RepositoryError,
+    value_objects::bed_status::BedStatus,
+};
+
+#[automock]
+#[async_trait]
+pub trait WardRepository {
+    async fn save_ward(&self, ward: &Ward) -> Result<Ward, RepositoryError>;
+    async fn find_ward_by_id(&self, id: i32) -> Result<Option<Ward>, RepositoryError>;
+    async fn find_wards(&self) -> Result<Vec<Ward>, RepositoryError>;
+    async fn save_room(&self, room: &Room) -> Result<Room, RepositoryError>;
+    async fn find_room_by_id(&self, id: i32) -> Result<Option<Room>, RepositoryError>;
+    async fn find_rooms(&self) -> Result<Vec<Room>, RepositoryError>;
+    async fn save_bed(&self, bed: &Bed) -> Result<Bed, RepositoryError>;
+    async fn find_bed_by_id(&self, id: i32) -> Result<Option<Bed>, RepositoryError>;
+    async fn find_beds(&self) -> Result<Vec<Bed>, RepositoryError>;
+    /// Writes the status of `bed` only if it is still `from`, returning
+    /// `None` when someone changed it in the meantime.
+    async fn update_bed_status(
+        &self,
+        bed: &Bed,
+        from: BedStatus,
+    ) -> Result<Option<Bed>, RepositoryError>;
+}
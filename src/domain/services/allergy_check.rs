@@ -0,0 +1,162 @@
+use crate::domain::{
+    entities::{allergy::Allergy, prescription::PrescriptionItem},
+    value_objects::allergy_severity::AllergySeverity,
+};
+
+/// A prescribed drug that matches one of the patient's recorded allergies.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllergyWarning {
+    pub allergy_id: Option<i32>,
+    pub substance: String,
+    pub severity: AllergySeverity,
+    pub drug: String,
+}
+
+impl AllergyWarning {
+    /// Severe allergies block the prescription unless the prescriber states
+    /// why they are going ahead anyway.
+    pub fn is_blocking(&self) -> bool {
+        self.severity == AllergySeverity::Severe
+    }
+}
+
+/// Checks every item against every allergy. A drug matches an allergy when
+/// the words of either name appear in sequence in the other one, ignoring
+/// case and accents, so "Dipirona" matches "Dipirona sódica". Drug classes
+/// are not expanded: an allergy to "penicillin" does not flag "amoxicillin".
+pub fn allergy_warnings(items: &[PrescriptionItem], allergies: &[Allergy]) -> Vec<AllergyWarning> {
+    let mut warnings = Vec::new();
+
+    for item in items {
+        let drug_words = words(&item.drug);
+
+        for allergy in allergies {
+            let substance_words = words(&allergy.substance);
+
+            if contains_words(&drug_words, &substance_words)
+                || contains_words(&substance_words, &drug_words)
+            {
+                warnings.push(AllergyWarning {
+                    allergy_id: allergy.id.clone().into(),
+                    substance: allergy.substance.clone(),
+                    severity: allergy.severity,
+                    drug: item.drug.clone(),
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+fn contains_words(haystack: &[String], needle: &[String]) -> bool {
+    !needle.is_empty()
+        && haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .map(unaccent)
+        .collect::<String>()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn unaccent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ç' => 'c',
+        'ñ' => 'n',
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        domain::{
+            entities::{allergy::Allergy, prescription::PrescriptionItem},
+            services::allergy_check::allergy_warnings,
+            value_objects::{allergy_severity::AllergySeverity, id::ID},
+        },
+        presentation::dtos::{
+            allergy_dto::CreateAllergyDTO, prescription_dto::PrescriptionItemDTO,
+        },
+    };
+
+    #[test]
+    fn allergy_warnings_ignores_case_and_accents() {
+        let warnings = allergy_warnings(
+            &[
+                make_fake_item("DIPIRONA SODICA"),
+                make_fake_item("Paracetamol"),
+            ],
+            &[make_fake_allergy(
+                3,
+                "dipirona sódica",
+                AllergySeverity::Severe,
+            )],
+        );
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].allergy_id, Some(3));
+        assert_eq!(warnings[0].drug, "DIPIRONA SODICA");
+        assert!(warnings[0].is_blocking());
+    }
+
+    #[test]
+    fn allergy_warnings_matches_whole_words_only() {
+        let warnings = allergy_warnings(
+            &[
+                make_fake_item("Amoxicillin + clavulanate"),
+                make_fake_item("Sulfamethoxazole"),
+            ],
+            &[
+                make_fake_allergy(3, "Amoxicillin", AllergySeverity::Mild),
+                make_fake_allergy(4, "Sulfa", AllergySeverity::Moderate),
+            ],
+        );
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].substance, "Amoxicillin");
+        assert!(!warnings[0].is_blocking());
+    }
+
+    fn make_fake_item(drug: &str) -> PrescriptionItem {
+        PrescriptionItem::new(
+            1,
+            PrescriptionItemDTO {
+                drug: drug.to_string(),
+                dose: "500 mg".to_string(),
+                route: "oral".to_string(),
+                frequency: "every 6 hours".to_string(),
+                duration: None,
+            },
+        )
+        .unwrap()
+    }
+
+    fn make_fake_allergy(id: i32, substance: &str, severity: AllergySeverity) -> Allergy {
+        let mut allergy = Allergy::new(
+            7,
+            CreateAllergyDTO {
+                substance: substance.to_string(),
+                reaction: None,
+                severity,
+            },
+        )
+        .unwrap();
+        allergy.id = ID::Existing(id);
+
+        allergy
+    }
+}
@@ -0,0 +1,236 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+
+use crate::domain::{
+    entities::{
+        professional_schedule::ProfessionalSchedule, schedule_exception::ScheduleException,
+    },
+    value_objects::time_slot::TimeSlot,
+};
+
+pub const DEFAULT_SLOT_DURATION_MINUTES: i64 = 30;
+pub const MIN_SLOT_DURATION_MINUTES: i64 = 5;
+pub const MAX_SLOT_DURATION_MINUTES: i64 = 480;
+
+/// Opening hours of the clinic, Monday to Saturday. Appointments without a
+/// professional have no schedule of their own and must fit inside them.
+pub const CLINIC_OPENS_AT: NaiveTime = NaiveTime::from_hms_opt(7, 0, 0).unwrap();
+pub const CLINIC_CLOSES_AT: NaiveTime = NaiveTime::from_hms_opt(19, 0, 0).unwrap();
+
+/// Splits the professional's working windows between `from` and `to`
+/// (inclusive) into consecutive slots of `duration`, dropping the ones that
+/// hit a schedule exception or an already busy period.
+pub fn available_slots(
+    schedules: &[ProfessionalSchedule],
+    exceptions: &[ScheduleException],
+    busy: &[TimeSlot],
+    from: NaiveDate,
+    to: NaiveDate,
+    duration: Duration,
+) -> Vec<TimeSlot> {
+    let mut slots = Vec::new();
+
+    if duration <= Duration::zero() {
+        return slots;
+    }
+
+    for date in from.iter_days().take_while(|date| *date <= to) {
+        let mut windows: Vec<TimeSlot> = schedules
+            .iter()
+            .filter_map(|schedule| schedule.window_on(date))
+            .collect();
+        windows.sort_by_key(|window| window.starts_at);
+
+        for window in windows {
+            let mut starts_at = window.starts_at;
+
+            while starts_at + duration <= window.ends_at {
+                let slot = TimeSlot::new(starts_at, starts_at + duration);
+
+                if is_free(exceptions, busy, &slot) {
+                    slots.push(slot);
+                }
+
+                starts_at += duration;
+            }
+        }
+    }
+
+    slots
+}
+
+/// Whether `slot` lies entirely inside one of the professional's working
+/// windows and is not blocked by an exception or a busy period.
+pub fn is_available(
+    schedules: &[ProfessionalSchedule],
+    exceptions: &[ScheduleException],
+    busy: &[TimeSlot],
+    slot: &TimeSlot,
+) -> bool {
+    let within_schedule = schedules.iter().any(|schedule| {
+        schedule
+            .window_on(slot.starts_at.date())
+            .is_some_and(|window| window.contains(slot))
+    });
+
+    within_schedule && is_free(exceptions, busy, slot)
+}
+
+/// Whether `slot` starts and ends on the same opening day of the clinic,
+/// inside its opening hours.
+pub fn within_clinic_hours(slot: &TimeSlot) -> bool {
+    slot.starts_at.weekday() != Weekday::Sun
+        && slot.starts_at.date() == slot.ends_at.date()
+        && slot.starts_at.time() >= CLINIC_OPENS_AT
+        && slot.ends_at.time() <= CLINIC_CLOSES_AT
+}
+
+fn is_free(exceptions: &[ScheduleException], busy: &[TimeSlot], slot: &TimeSlot) -> bool {
+    !exceptions
+        .iter()
+        .any(|exception| exception.time_slot().overlaps(slot))
+        && !busy.iter().any(|busy_slot| busy_slot.overlaps(slot))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+    use crate::domain::{
+        entities::{
+            professional_schedule::ProfessionalSchedule, schedule_exception::ScheduleException,
+        },
+        value_objects::time_slot::TimeSlot,
+    };
+
+    use super::{available_slots, is_available, within_clinic_hours};
+
+    fn at(value: &str) -> NaiveDateTime {
+        value.parse::<NaiveDateTime>().unwrap()
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse::<NaiveDate>().unwrap()
+    }
+
+    fn monday_morning() -> ProfessionalSchedule {
+        ProfessionalSchedule::new(
+            1,
+            1,
+            "08:00:00".parse::<NaiveTime>().unwrap(),
+            "10:00:00".parse::<NaiveTime>().unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn available_slots_splits_windows() {
+        let slots = available_slots(
+            &[monday_morning()],
+            &[],
+            &[],
+            date("2025-06-09"),
+            date("2025-06-15"),
+            Duration::minutes(30),
+        );
+
+        assert_eq!(slots.len(), 4);
+        assert_eq!(slots[0].starts_at, at("2025-06-09T08:00:00"));
+        assert_eq!(slots[3].ends_at, at("2025-06-09T10:00:00"));
+    }
+
+    #[test]
+    fn available_slots_skips_busy_and_exceptions() {
+        let busy = [TimeSlot::new(
+            at("2025-06-09T08:00:00"),
+            at("2025-06-09T08:30:00"),
+        )];
+        let exceptions = [ScheduleException::new(
+            1,
+            at("2025-06-09T09:15:00"),
+            at("2025-06-09T09:20:00"),
+            Some("Meeting".to_string()),
+        )
+        .unwrap()];
+
+        let slots = available_slots(
+            &[monday_morning()],
+            &exceptions,
+            &busy,
+            date("2025-06-09"),
+            date("2025-06-09"),
+            Duration::minutes(30),
+        );
+
+        assert_eq!(
+            slots,
+            vec![
+                TimeSlot::new(at("2025-06-09T08:30:00"), at("2025-06-09T09:00:00")),
+                TimeSlot::new(at("2025-06-09T09:30:00"), at("2025-06-09T10:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn available_slots_drops_partial_slot_at_window_end() {
+        let slots = available_slots(
+            &[monday_morning()],
+            &[],
+            &[],
+            date("2025-06-09"),
+            date("2025-06-09"),
+            Duration::minutes(45),
+        );
+
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[1].ends_at, at("2025-06-09T09:30:00"));
+    }
+
+    #[test]
+    fn is_available_outside_schedule() {
+        let slot = TimeSlot::new(at("2025-06-09T03:00:00"), at("2025-06-09T03:30:00"));
+
+        assert!(!is_available(&[monday_morning()], &[], &[], &slot));
+    }
+
+    #[test]
+    fn is_available_unaligned_slot_inside_window() {
+        let slot = TimeSlot::new(at("2025-06-09T08:05:00"), at("2025-06-09T08:35:00"));
+
+        assert!(is_available(&[monday_morning()], &[], &[], &slot));
+    }
+
+    #[test]
+    fn is_available_overlapping_busy_slot() {
+        let slot = TimeSlot::new(at("2025-06-09T08:05:00"), at("2025-06-09T08:35:00"));
+        let busy = [TimeSlot::new(
+            at("2025-06-09T08:30:00"),
+            at("2025-06-09T09:00:00"),
+        )];
+
+        assert!(!is_available(&[monday_morning()], &[], &busy, &slot));
+    }
+
+    #[test]
+    fn within_clinic_hours_ok() {
+        assert!(within_clinic_hours(&TimeSlot::new(
+            at("2025-06-14T07:00:00"),
+            at("2025-06-14T19:00:00"),
+        )));
+    }
+
+    #[test]
+    fn within_clinic_hours_rejects_night_sunday_and_overnight() {
+        assert!(!within_clinic_hours(&TimeSlot::new(
+            at("2025-06-10T03:00:00"),
+            at("2025-06-10T03:30:00"),
+        )));
+        assert!(!within_clinic_hours(&TimeSlot::new(
+            at("2025-06-15T10:00:00"),
+            at("2025-06-15T10:30:00"),
+        )));
+        assert!(!within_clinic_hours(&TimeSlot::new(
+            at("2025-06-10T18:30:00"),
+            at("2025-06-11T08:00:00"),
+        )));
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::{
+        admission::{Admission, BedOccupancy},
+        patient::Patient,
+        ward::{Bed, Room, Ward},
+    },
+    value_objects::id::ID,
+};
+
+pub struct BedOccupant {
+    pub admission_id: i32,
+    pub patient_id: i32,
+    pub patient_name: String,
+    pub since: NaiveDateTime,
+}
+
+pub struct BedOverview {
+    pub bed: Bed,
+    pub room_number: String,
+    pub occupant: Option<BedOccupant>,
+}
+
+pub struct WardOccupancy {
+    pub ward: Ward,
+    pub beds: Vec<BedOverview>,
+}
+
+/// Lays out every bed under its ward, ordered by room number and bed label,
+/// along with who is in it according to the open admissions. Wards come in
+/// name order.
+pub fn occupancy_overview(
+    mut wards: Vec<Ward>,
+    rooms: &[Room],
+    beds: Vec<Bed>,
+    open_admissions: &[(Admission, Vec<BedOccupancy>)],
+    patients: &[Patient],
+) -> Vec<WardOccupancy> {
+    let rooms: HashMap<i32, &Room> = rooms
+        .iter()
+        .filter_map(|room| match room.id {
+            ID::Existing(room_id) => Some((room_id, room)),
+            ID::New => None,
+        })
+        .collect();
+
+    let patient_names: HashMap<i32, &str> = patients
+        .iter()
+        .filter_map(|patient| match patient.id {
+            ID::Existing(patient_id) => Some((patient_id, patient.name.as_str())),
+            ID::New => None,
+        })
+        .collect();
+
+    let mut occupants: HashMap<i32, BedOccupant> = HashMap::new();
+
+    for (admission, occupancies) in open_admissions {
+        if admission.is_discharged() {
+            continue;
+        }
+
+        if let ID::Existing(admission_id) = admission.id
+            && let Some(occupancy) = occupancies
+                .iter()
+                .find(|occupancy| occupancy.ended_at.is_none())
+        {
+            occupants.insert(
+                occupancy.bed_id,
+                BedOccupant {
+                    admission_id,
+                    patient_id: admission.patient_id,
+                    patient_name: patient_names
+                        .get(&admission.patient_id)
+                        .map(|name| name.to_string())
+                        .unwrap_or_default(),
+                    since: occupancy.started_at,
+                },
+            );
+        }
+    }
+
+    let mut overviews: HashMap<i32, Vec<BedOverview>> = HashMap::new();
+
+    for bed in beds {
+        let Some(room) = rooms.get(&bed.room_id) else {
+            continue;
+        };
+
+        let occupant = match bed.id {
+            ID::Existing(bed_id) => occupants.remove(&bed_id),
+            ID::New => None,
+        };
+
+        overviews
+            .entry(room.ward_id)
+            .or_default()
+            .push(BedOverview {
+                room_number: room.number.clone(),
+                occupant,
+                bed,
+            });
+    }
+
+    wards.sort_by(|a, b| a.name.cmp(&b.name));
+
+    wards
+        .into_iter()
+        .map(|ward| {
+            let mut beds = match ward.id {
+                ID::Existing(ward_id) => overviews.remove(&ward_id).unwrap_or_default(),
+                ID::New => vec![],
+            };
+            beds.sort_by(|a, b| {
+                (&a.room_number, &a.bed.label).cmp(&(&b.room_number, &b.bed.label))
+            });
+
+            WardOccupancy { ward, beds }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDateTime;
+
+    use crate::domain::{
+        entities::{
+            admission::{Admission, BedOccupancy},
+            patient::Patient,
+            ward::{Bed, Room, Ward},
+        },
+        services::bed_occupancy::occupancy_overview,
+        value_objects::{bed_status::BedStatus, cpf::Cpf, id::ID},
+    };
+
+    fn make_bed(bed_id: i32, room_id: i32, label: &str, status: BedStatus) -> Bed {
+        Bed {
+            id: ID::Existing(bed_id),
+            room_id,
+            label: label.to_string(),
+            status,
+        }
+    }
+
+    #[test]
+    fn occupancy_overview_groups_beds_by_ward() {
+        let since = "2025-09-22T08:00:00".parse::<NaiveDateTime>().unwrap();
+
+        let wards = vec![
+            Ward {
+                id: ID::Existing(2),
+                name: "Pediatrics".to_string(),
+                description: None,
+            },
+            Ward {
+                id: ID::Existing(1),
+                name: "ICU".to_string(),
+                description: None,
+            },
+        ];
+        let rooms = vec![
+            Room {
+                id: ID::Existing(10),
+                ward_id: 1,
+                number: "102".to_string(),
+            },
+            Room {
+                id: ID::Existing(11),
+                ward_id: 1,
+                number: "101".to_string(),
+            },
+        ];
+        let beds = vec![
+            make_bed(100, 10, "A", BedStatus::Occupied),
+            make_bed(101, 11, "B", BedStatus::Cleaning),
+            make_bed(102, 11, "A", BedStatus::Free),
+        ];
+        let admissions = vec![(
+            Admission {
+                id: ID::Existing(9),
+                patient_id: 7,
+                professional_id: None,
+                reason: "Pneumonia".to_string(),
+                admitted_by: "nurse@example.com".to_string(),
+                admitted_at: since,
+                discharged_at: None,
+                discharged_by: None,
+                discharge_summary: None,
+            },
+            vec![
+                BedOccupancy {
+                    id: ID::Existing(1),
+                    admission_id: 9,
+                    bed_id: 101,
+                    started_by: "nurse@example.com".to_string(),
+                    started_at: since,
+                    ended_at: Some(since),
+                },
+                BedOccupancy {
+                    id: ID::Existing(2),
+                    admission_id: 9,
+                    bed_id: 100,
+                    started_by: "nurse@example.com".to_string(),
+                    started_at: since,
+                    ended_at: None,
+                },
+            ],
+        )];
+        let patients = vec![
+            Patient::restore(7, "Andrew".to_string(), Cpf::parse("12345678909").unwrap()).unwrap(),
+        ];
+
+        let overview = occupancy_overview(wards, &rooms, beds, &admissions, &patients);
+
+        assert_eq!(overview.len(), 2);
+        assert_eq!(overview[0].ward.name, "ICU");
+        assert!(overview[1].beds.is_empty());
+
+        let icu: Vec<(&str, &str, Option<&str>)> = overview[0]
+            .beds
+            .iter()
+            .map(|bed| {
+                (
+                    bed.room_number.as_str(),
+                    bed.bed.label.as_str(),
+                    bed.occupant
+                        .as_ref()
+                        .map(|occupant| occupant.patient_name.as_str()),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            icu,
+            vec![
+                ("101", "A", None),
+                ("101", "B", None),
+                ("102", "A", Some("Andrew")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod allergy_check;
+pub mod availability;
+pub mod bed_occupancy;
@@ -0,0 +1,48 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AllergySeverity {
+    Mild,
+    Moderate,
+    Severe,
+}
+
+impl AllergySeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AllergySeverity::Mild => "mild",
+            AllergySeverity::Moderate => "moderate",
+            AllergySeverity::Severe => "severe",
+        }
+    }
+}
+
+impl TryFrom<String> for AllergySeverity {
+    type Error = PatientEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "mild" => Ok(AllergySeverity::Mild),
+            "moderate" => Ok(AllergySeverity::Moderate),
+            "severe" => Ok(AllergySeverity::Severe),
+            _ => Err(PatientEntityError::InvalidAllergySeverity(value)),
+        }
+    }
+}
+
+impl From<AllergySeverity> for String {
+    fn from(value: AllergySeverity) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for AllergySeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
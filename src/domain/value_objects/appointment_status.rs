@@ -0,0 +1,119 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::appointment_entity_error::AppointmentEntityError;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppointmentStatus {
+    Scheduled,
+    Confirmed,
+    CheckedIn,
+    InProgress,
+    Completed,
+    NoShow,
+    Canceled,
+}
+
+impl AppointmentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AppointmentStatus::Scheduled => "scheduled",
+            AppointmentStatus::Confirmed => "confirmed",
+            AppointmentStatus::CheckedIn => "checked_in",
+            AppointmentStatus::InProgress => "in_progress",
+            AppointmentStatus::Completed => "completed",
+            AppointmentStatus::NoShow => "no_show",
+            AppointmentStatus::Canceled => "canceled",
+        }
+    }
+
+    pub fn can_transition_to(&self, next: AppointmentStatus) -> bool {
+        use AppointmentStatus::*;
+
+        matches!(
+            (self, next),
+            (Scheduled, Confirmed | CheckedIn | NoShow | Canceled)
+                | (Confirmed, CheckedIn | NoShow | Canceled)
+                | (CheckedIn, InProgress | Completed | Canceled)
+                | (InProgress, Completed)
+        )
+    }
+
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            AppointmentStatus::Completed | AppointmentStatus::NoShow | AppointmentStatus::Canceled
+        )
+    }
+}
+
+impl TryFrom<String> for AppointmentStatus {
+    type Error = AppointmentEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "scheduled" => Ok(AppointmentStatus::Scheduled),
+            "confirmed" => Ok(AppointmentStatus::Confirmed),
+            "checked_in" => Ok(AppointmentStatus::CheckedIn),
+            "in_progress" => Ok(AppointmentStatus::InProgress),
+            "completed" => Ok(AppointmentStatus::Completed),
+            "no_show" => Ok(AppointmentStatus::NoShow),
+            "canceled" => Ok(AppointmentStatus::Canceled),
+            _ => Err(AppointmentEntityError::InvalidStatus(value)),
+        }
+    }
+}
+
+impl From<AppointmentStatus> for String {
+    fn from(value: AppointmentStatus) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for AppointmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AppointmentStatus;
+
+    #[test]
+    fn try_from_string_round_trip() {
+        for status in [
+            AppointmentStatus::Scheduled,
+            AppointmentStatus::Confirmed,
+            AppointmentStatus::CheckedIn,
+            AppointmentStatus::InProgress,
+            AppointmentStatus::Completed,
+            AppointmentStatus::NoShow,
+            AppointmentStatus::Canceled,
+        ] {
+            let value: String = status.into();
+
+            assert_eq!(AppointmentStatus::try_from(value).unwrap(), status);
+        }
+    }
+
+    #[test]
+    fn try_from_unknown_string_error() {
+        assert!(AppointmentStatus::try_from("rescheduled".to_string()).is_err());
+    }
+
+    #[test]
+    fn can_transition_to() {
+        assert!(AppointmentStatus::Scheduled.can_transition_to(AppointmentStatus::Confirmed));
+        assert!(AppointmentStatus::Confirmed.can_transition_to(AppointmentStatus::CheckedIn));
+        assert!(AppointmentStatus::CheckedIn.can_transition_to(AppointmentStatus::InProgress));
+        assert!(AppointmentStatus::InProgress.can_transition_to(AppointmentStatus::Completed));
+
+        assert!(!AppointmentStatus::Scheduled.can_transition_to(AppointmentStatus::Completed));
+        assert!(!AppointmentStatus::InProgress.can_transition_to(AppointmentStatus::Canceled));
+        assert!(!AppointmentStatus::Canceled.can_transition_to(AppointmentStatus::Confirmed));
+        assert!(!AppointmentStatus::Completed.can_transition_to(AppointmentStatus::NoShow));
+    }
+}
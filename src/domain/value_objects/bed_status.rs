@@ -0,0 +1,51 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::admission_entity_error::AdmissionEntityError;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BedStatus {
+    Free,
+    Occupied,
+    Cleaning,
+    Blocked,
+}
+
+impl BedStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BedStatus::Free => "free",
+            BedStatus::Occupied => "occupied",
+            BedStatus::Cleaning => "cleaning",
+            BedStatus::Blocked => "blocked",
+        }
+    }
+}
+
+impl TryFrom<String> for BedStatus {
+    type Error = AdmissionEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "free" => Ok(BedStatus::Free),
+            "occupied" => Ok(BedStatus::Occupied),
+            "cleaning" => Ok(BedStatus::Cleaning),
+            "blocked" => Ok(BedStatus::Blocked),
+            _ => Err(AdmissionEntityError::InvalidBedStatus(value)),
+        }
+    }
+}
+
+impl From<BedStatus> for String {
+    fn from(value: BedStatus) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for BedStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
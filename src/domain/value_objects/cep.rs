@@ -0,0 +1,98 @@
+use std::fmt;
+
+use diesel::{
+    deserialize::{FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::Pg,
+    serialize::ToSql,
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+const CEP_LENGTH: usize = 8;
+
+/// A Brazilian postal code, held as its eight digits without the hyphen.
+#[derive(AsExpression, Clone, Debug, Deserialize, Eq, FromSqlRow, Hash, PartialEq, Serialize)]
+#[diesel(sql_type = Varchar)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cep(String);
+
+impl Cep {
+    pub fn parse(value: &str) -> Result<Self, PatientEntityError> {
+        let digits: String = value
+            .chars()
+            .filter(|c| !matches!(c, '.' | '-' | ' '))
+            .collect();
+
+        if digits.len() != CEP_LENGTH || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(PatientEntityError::InvalidCep(value.to_string()));
+        }
+
+        Ok(Self(digits))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Cep {
+    type Error = PatientEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<Cep> for String {
+    fn from(value: Cep) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Cep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromSql<Varchar, Pg> for Cep {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> diesel::deserialize::Result<Self> {
+        let cep = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(Cep::parse(&cep)?)
+    }
+}
+
+impl ToSql<Varchar, Pg> for Cep {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, Pg>,
+    ) -> diesel::serialize::Result {
+        <str as ToSql<Varchar, Pg>>::to_sql(&self.0, out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{
+        errors::patient_entity_error::PatientEntityError, value_objects::cep::Cep,
+    };
+
+    #[test]
+    fn parse_ok() {
+        assert_eq!(Cep::parse("01310-100").unwrap().as_str(), "01310100");
+    }
+
+    #[test]
+    fn parse_invalid_cep_error() {
+        assert_eq!(
+            Cep::parse("1310-100"),
+            Err(PatientEntityError::InvalidCep("1310-100".to_string()))
+        );
+        assert!(Cep::parse("0131O100").is_err());
+    }
+}
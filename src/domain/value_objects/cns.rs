@@ -0,0 +1,127 @@
+use std::fmt;
+
+use diesel::{
+    deserialize::{FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::Pg,
+    serialize::ToSql,
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+const CNS_LENGTH: usize = 15;
+
+/// A Cartão Nacional de Saúde number: fifteen digits, starting with 1 or 2
+/// (definitive) or 7, 8 or 9 (provisional), whose digits weighted 15 down to
+/// 1 add up to a multiple of 11.
+#[derive(AsExpression, Clone, Debug, Deserialize, Eq, FromSqlRow, Hash, PartialEq, Serialize)]
+#[diesel(sql_type = Varchar)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cns(String);
+
+impl Cns {
+    pub fn parse(value: &str) -> Result<Self, PatientEntityError> {
+        let digits: String = value.chars().filter(|c| !matches!(c, ' ' | '.')).collect();
+
+        if digits.len() != CNS_LENGTH || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(PatientEntityError::InvalidCns(value.to_string()));
+        }
+
+        if !matches!(digits.as_bytes()[0], b'1' | b'2' | b'7' | b'8' | b'9') {
+            return Err(PatientEntityError::InvalidCns(value.to_string()));
+        }
+
+        let sum: u32 = digits
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .enumerate()
+            .map(|(i, n)| n * (CNS_LENGTH - i) as u32)
+            .sum();
+
+        if !sum.is_multiple_of(11) {
+            return Err(PatientEntityError::InvalidCns(value.to_string()));
+        }
+
+        Ok(Self(digits))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Cns {
+    type Error = PatientEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<Cns> for String {
+    fn from(value: Cns) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Cns {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromSql<Varchar, Pg> for Cns {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> diesel::deserialize::Result<Self> {
+        let cns = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(Cns::parse(&cns)?)
+    }
+}
+
+impl ToSql<Varchar, Pg> for Cns {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, Pg>,
+    ) -> diesel::serialize::Result {
+        <str as ToSql<Varchar, Pg>>::to_sql(&self.0, out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{
+        errors::patient_entity_error::PatientEntityError, value_objects::cns::Cns,
+    };
+
+    #[test]
+    fn parse_ok() {
+        assert_eq!(
+            Cns::parse("100 0000 0000 0007").unwrap().as_str(),
+            "100000000000007"
+        );
+        assert!(Cns::parse("700000000000005").is_ok());
+    }
+
+    #[test]
+    fn parse_wrong_check_error() {
+        assert_eq!(
+            Cns::parse("100000000000008"),
+            Err(PatientEntityError::InvalidCns(
+                "100000000000008".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_prefix_error() {
+        assert!(Cns::parse("300000000000050").is_err());
+    }
+
+    #[test]
+    fn parse_invalid_length_error() {
+        assert!(Cns::parse("10000000000000").is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+/// How the patient expressed the consent.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentChannel {
+    InPerson,
+    Online,
+    Phone,
+    Paper,
+}
+
+impl ConsentChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConsentChannel::InPerson => "in_person",
+            ConsentChannel::Online => "online",
+            ConsentChannel::Phone => "phone",
+            ConsentChannel::Paper => "paper",
+        }
+    }
+}
+
+impl TryFrom<String> for ConsentChannel {
+    type Error = PatientEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "in_person" => Ok(ConsentChannel::InPerson),
+            "online" => Ok(ConsentChannel::Online),
+            "phone" => Ok(ConsentChannel::Phone),
+            "paper" => Ok(ConsentChannel::Paper),
+            _ => Err(PatientEntityError::InvalidConsentChannel(value)),
+        }
+    }
+}
+
+impl From<ConsentChannel> for String {
+    fn from(value: ConsentChannel) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for ConsentChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
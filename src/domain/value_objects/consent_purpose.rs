@@ -0,0 +1,82 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+/// What a patient may agree to, each one granted and revoked on its own.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentPurpose {
+    DataProcessing,
+    Telemedicine,
+    InsurerSharing,
+    SmsContact,
+}
+
+impl ConsentPurpose {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConsentPurpose::DataProcessing => "data_processing",
+            ConsentPurpose::Telemedicine => "telemedicine",
+            ConsentPurpose::InsurerSharing => "insurer_sharing",
+            ConsentPurpose::SmsContact => "sms_contact",
+        }
+    }
+}
+
+impl TryFrom<String> for ConsentPurpose {
+    type Error = PatientEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "data_processing" => Ok(ConsentPurpose::DataProcessing),
+            "telemedicine" => Ok(ConsentPurpose::Telemedicine),
+            "insurer_sharing" => Ok(ConsentPurpose::InsurerSharing),
+            "sms_contact" => Ok(ConsentPurpose::SmsContact),
+            _ => Err(PatientEntityError::InvalidConsentPurpose(value)),
+        }
+    }
+}
+
+impl From<ConsentPurpose> for String {
+    fn from(value: ConsentPurpose) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for ConsentPurpose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{
+        errors::patient_entity_error::PatientEntityError,
+        value_objects::consent_purpose::ConsentPurpose,
+    };
+
+    #[test]
+    fn round_trips_through_string() {
+        for purpose in [
+            ConsentPurpose::DataProcessing,
+            ConsentPurpose::Telemedicine,
+            ConsentPurpose::InsurerSharing,
+            ConsentPurpose::SmsContact,
+        ] {
+            assert_eq!(ConsentPurpose::try_from(String::from(purpose)), Ok(purpose));
+        }
+    }
+
+    #[test]
+    fn try_from_invalid_purpose_error() {
+        assert_eq!(
+            ConsentPurpose::try_from("marketing".to_string()),
+            Err(PatientEntityError::InvalidConsentPurpose(
+                "marketing".to_string()
+            ))
+        );
+    }
+}
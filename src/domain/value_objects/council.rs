@@ -0,0 +1,29 @@
+/// The professional councils whose licenses are accepted: medicine, nursing,
+/// dentistry, pharmacy, physiotherapy, nutrition, psychology, speech therapy
+/// and biomedicine. Each keeps its own numbering, so the same number may be
+/// issued by two of them in the same state.
+pub const COUNCILS: [&str; 9] = [
+    "CRM", "COREN", "CRO", "CRF", "CREFITO", "CRN", "CRP", "CRFA", "CRBM",
+];
+
+pub fn is_valid_council(value: &str) -> bool {
+    COUNCILS.contains(&value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_valid_council;
+
+    #[test]
+    fn is_valid_council_ok() {
+        assert!(is_valid_council("CRM"));
+        assert!(is_valid_council("COREN"));
+    }
+
+    #[test]
+    fn is_valid_council_rejects_unknown_and_lowercase() {
+        assert!(!is_valid_council("XYZ"));
+        assert!(!is_valid_council("crm"));
+        assert!(!is_valid_council(""));
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt;
+
+use diesel::{
+    deserialize::{FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::Pg,
+    serialize::ToSql,
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+const CPF_LENGTH: usize = 11;
+
+/// A Brazilian CPF. One built by [`Cpf::parse`] is normalized to eleven
+/// digits with valid mod-11 check digits; one rebuilt from storage by
+/// [`Cpf::restore`] may be a legacy value that is neither, see
+/// [`Cpf::is_valid`].
+#[derive(AsExpression, Clone, Debug, Deserialize, Eq, FromSqlRow, Hash, PartialEq, Serialize)]
+#[diesel(sql_type = Varchar)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cpf(String);
+
+impl Cpf {
+    pub fn parse(value: &str) -> Result<Self, PatientEntityError> {
+        let digits: String = value
+            .chars()
+            .filter(|c| !matches!(c, '.' | '-' | ' '))
+            .collect();
+
+        if digits.len() != CPF_LENGTH || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(PatientEntityError::InvalidCpf(value.to_string()));
+        }
+
+        let numbers: Vec<u32> = digits.chars().filter_map(|c| c.to_digit(10)).collect();
+
+        if numbers.iter().all(|n| *n == numbers[0]) {
+            return Err(PatientEntityError::InvalidCpf(value.to_string()));
+        }
+
+        if check_digit(&numbers[..9]) != numbers[9] || check_digit(&numbers[..10]) != numbers[10] {
+            return Err(PatientEntityError::InvalidCpf(value.to_string()));
+        }
+
+        Ok(Self(digits))
+    }
+
+    /// Rebuilds a stored CPF. Rows written before validation existed may
+    /// hold values that do not parse; those are kept verbatim so the patient
+    /// can still be loaded, listed and merged.
+    pub fn restore(value: String) -> Self {
+        Self::parse(&value).unwrap_or(Self(value))
+    }
+
+    /// False only for a legacy value kept by [`Cpf::restore`].
+    pub fn is_valid(&self) -> bool {
+        Self::parse(&self.0).is_ok()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn check_digit(numbers: &[u32]) -> u32 {
+    let weight = numbers.len() as u32 + 1;
+    let sum: u32 = numbers
+        .iter()
+        .enumerate()
+        .map(|(i, n)| n * (weight - i as u32))
+        .sum();
+
+    (sum * 10) % 11 % 10
+}
+
+impl TryFrom<String> for Cpf {
+    type Error = PatientEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl FromSql<Varchar, Pg> for Cpf {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> diesel::deserialize::Result<Self> {
+        let cpf = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(Cpf::restore(cpf))
+    }
+}
+
+impl ToSql<Varchar, Pg> for Cpf {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, Pg>,
+    ) -> diesel::serialize::Result {
+        <str as ToSql<Varchar, Pg>>::to_sql(self.as_str(), out)
+    }
+}
+
+impl From<Cpf> for String {
+    fn from(value: Cpf) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Cpf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{
+        Deserialize,
+        de::{
+            IntoDeserializer,
+            value::{Error, StrDeserializer},
+        },
+    };
+
+    use crate::domain::{
+        errors::patient_entity_error::PatientEntityError, value_objects::cpf::Cpf,
+    };
+
+    #[test]
+    fn parse_ok() {
+        let cpf = Cpf::parse("52998224725").unwrap();
+
+        assert_eq!(cpf.as_str(), "52998224725");
+    }
+
+    #[test]
+    fn parse_strips_punctuation() {
+        let cpf = Cpf::parse("529.982.247-25").unwrap();
+
+        assert_eq!(cpf, Cpf::parse("52998224725").unwrap());
+        assert_eq!(cpf.to_string(), "52998224725");
+    }
+
+    #[test]
+    fn parse_invalid_length_error() {
+        let cpf = Cpf::parse("5299822472");
+
+        assert_eq!(
+            cpf,
+            Err(PatientEntityError::InvalidCpf("5299822472".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_non_digit_error() {
+        let cpf = Cpf::parse("5299822472a");
+
+        assert_eq!(
+            cpf,
+            Err(PatientEntityError::InvalidCpf("5299822472a".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_repeated_digits_error() {
+        for digit in 0..10 {
+            let value = digit.to_string().repeat(11);
+
+            assert_eq!(
+                Cpf::parse(&value),
+                Err(PatientEntityError::InvalidCpf(value))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_wrong_check_digits_error() {
+        assert!(Cpf::parse("52998224715").is_err());
+        assert!(Cpf::parse("52998224726").is_err());
+    }
+
+    #[test]
+    fn deserialize_normalizes() -> Result<(), Error> {
+        let deserializer: StrDeserializer<Error> = "111.444.777-35".into_deserializer();
+
+        let cpf = Cpf::deserialize(deserializer)?;
+
+        assert_eq!(cpf.as_str(), "11144477735");
+
+        Ok(())
+    }
+
+    #[test]
+    fn restore_keeps_legacy_value() {
+        let cpf = Cpf::restore("52998224715".to_string());
+
+        assert_eq!(cpf.as_str(), "52998224715");
+        assert!(!cpf.is_valid());
+    }
+
+    #[test]
+    fn restore_valid_value() {
+        let cpf = Cpf::restore("11144477735".to_string());
+
+        assert_eq!(cpf, Cpf::parse("111.444.777-35").unwrap());
+        assert!(cpf.is_valid());
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentCategory {
+    Referral,
+    IdDocument,
+    ExamReport,
+    Other,
+}
+
+impl DocumentCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentCategory::Referral => "referral",
+            DocumentCategory::IdDocument => "id_document",
+            DocumentCategory::ExamReport => "exam_report",
+            DocumentCategory::Other => "other",
+        }
+    }
+}
+
+impl TryFrom<String> for DocumentCategory {
+    type Error = PatientEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "referral" => Ok(DocumentCategory::Referral),
+            "id_document" => Ok(DocumentCategory::IdDocument),
+            "exam_report" => Ok(DocumentCategory::ExamReport),
+            "other" => Ok(DocumentCategory::Other),
+            _ => Err(PatientEntityError::InvalidDocumentCategory(value)),
+        }
+    }
+}
+
+impl From<DocumentCategory> for String {
+    fn from(value: DocumentCategory) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for DocumentCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
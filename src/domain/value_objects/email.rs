@@ -0,0 +1,116 @@
+use std::fmt;
+
+use diesel::{
+    deserialize::{FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::Pg,
+    serialize::ToSql,
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+pub const EMAIL_MAX_LENGTH: usize = 150;
+
+/// A lowercased e-mail address. Only the shape is checked (a single `@`
+/// with a dotted domain); deliverability is not.
+#[derive(AsExpression, Clone, Debug, Deserialize, Eq, FromSqlRow, Hash, PartialEq, Serialize)]
+#[diesel(sql_type = Varchar)]
+#[serde(try_from = "String", into = "String")]
+pub struct Email(String);
+
+impl Email {
+    pub fn parse(value: &str) -> Result<Self, PatientEntityError> {
+        let email = value.trim().to_lowercase();
+
+        let is_valid = email.len() <= EMAIL_MAX_LENGTH
+            && !email.chars().any(char::is_whitespace)
+            && match email.split_once('@') {
+                Some((local, domain)) => {
+                    !local.is_empty()
+                        && !domain.contains('@')
+                        && domain.split('.').count() > 1
+                        && domain.split('.').all(|label| !label.is_empty())
+                }
+                None => false,
+            };
+
+        if !is_valid {
+            return Err(PatientEntityError::InvalidEmail(value.to_string()));
+        }
+
+        Ok(Self(email))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = PatientEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<Email> for String {
+    fn from(value: Email) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Email {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromSql<Varchar, Pg> for Email {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> diesel::deserialize::Result<Self> {
+        let email = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(Email::parse(&email)?)
+    }
+}
+
+impl ToSql<Varchar, Pg> for Email {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, Pg>,
+    ) -> diesel::serialize::Result {
+        <str as ToSql<Varchar, Pg>>::to_sql(&self.0, out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{
+        errors::patient_entity_error::PatientEntityError, value_objects::email::Email,
+    };
+
+    #[test]
+    fn parse_ok() {
+        assert_eq!(
+            Email::parse(" Andrew@Example.com.br ").unwrap().as_str(),
+            "andrew@example.com.br"
+        );
+    }
+
+    #[test]
+    fn parse_invalid_email_error() {
+        assert_eq!(
+            Email::parse("andrew@localhost"),
+            Err(PatientEntityError::InvalidEmail(
+                "andrew@localhost".to_string()
+            ))
+        );
+        assert!(Email::parse("andrew.example.com").is_err());
+        assert!(Email::parse("@example.com").is_err());
+        assert!(Email::parse("an drew@example.com").is_err());
+        assert!(Email::parse("andrew@example..com").is_err());
+    }
+}
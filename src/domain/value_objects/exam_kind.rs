@@ -0,0 +1,45 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::exam_order_entity_error::ExamOrderEntityError;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExamKind {
+    Lab,
+    Imaging,
+}
+
+impl ExamKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExamKind::Lab => "lab",
+            ExamKind::Imaging => "imaging",
+        }
+    }
+}
+
+impl TryFrom<String> for ExamKind {
+    type Error = ExamOrderEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "lab" => Ok(ExamKind::Lab),
+            "imaging" => Ok(ExamKind::Imaging),
+            _ => Err(ExamOrderEntityError::InvalidKind(value)),
+        }
+    }
+}
+
+impl From<ExamKind> for String {
+    fn from(value: ExamKind) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for ExamKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::exam_order_entity_error::ExamOrderEntityError;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExamOrderStatus {
+    Requested,
+    Collected,
+    Resulted,
+    Reviewed,
+}
+
+impl ExamOrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExamOrderStatus::Requested => "requested",
+            ExamOrderStatus::Collected => "collected",
+            ExamOrderStatus::Resulted => "resulted",
+            ExamOrderStatus::Reviewed => "reviewed",
+        }
+    }
+
+    pub fn can_transition_to(&self, next: ExamOrderStatus) -> bool {
+        use ExamOrderStatus::*;
+
+        matches!(
+            (self, next),
+            (Requested, Collected) | (Collected, Resulted) | (Resulted, Reviewed)
+        )
+    }
+}
+
+impl TryFrom<String> for ExamOrderStatus {
+    type Error = ExamOrderEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "requested" => Ok(ExamOrderStatus::Requested),
+            "collected" => Ok(ExamOrderStatus::Collected),
+            "resulted" => Ok(ExamOrderStatus::Resulted),
+            "reviewed" => Ok(ExamOrderStatus::Reviewed),
+            _ => Err(ExamOrderEntityError::InvalidStatus(value)),
+        }
+    }
+}
+
+impl From<ExamOrderStatus> for String {
+    fn from(value: ExamOrderStatus) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for ExamOrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+/// An ICD-10 (CID-10) code, held uppercased with the dot before the
+/// subcategory, e.g. `J45.0`. Both `j450` and `J45.0` are accepted.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Icd10(String);
+
+impl Icd10 {
+    pub fn parse(value: &str) -> Result<Self, PatientEntityError> {
+        let code: String = value
+            .chars()
+            .filter(|c| !matches!(c, '.' | ' '))
+            .collect::<String>()
+            .to_uppercase();
+
+        let mut chars = code.chars();
+        let is_valid = (code.len() == 3 || code.len() == 4)
+            && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+            && chars.all(|c| c.is_ascii_digit());
+
+        if !is_valid {
+            return Err(PatientEntityError::InvalidIcd10(value.to_string()));
+        }
+
+        if code.len() == 4 {
+            return Ok(Self(format!("{}.{}", &code[..3], &code[3..])));
+        }
+
+        Ok(Self(code))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Icd10 {
+    type Error = PatientEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<Icd10> for String {
+    fn from(value: Icd10) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Icd10 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{
+        errors::patient_entity_error::PatientEntityError, value_objects::icd10::Icd10,
+    };
+
+    #[test]
+    fn parse_normalizes() {
+        assert_eq!(Icd10::parse("j450").unwrap().as_str(), "J45.0");
+        assert_eq!(Icd10::parse("E11.9").unwrap().as_str(), "E11.9");
+        assert_eq!(Icd10::parse("i10").unwrap().as_str(), "I10");
+    }
+
+    #[test]
+    fn parse_invalid_code_error() {
+        for code in ["", "J4", "45.0", "J45.01", "JJ5.0"] {
+            assert_eq!(
+                Icd10::parse(code),
+                Err(PatientEntityError::InvalidIcd10(code.to_string()))
+            );
+        }
+    }
+}
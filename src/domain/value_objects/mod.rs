@@ -1 +1,22 @@
+pub mod allergy_severity;
+pub mod appointment_status;
+pub mod bed_status;
+pub mod cep;
+pub mod cns;
+pub mod consent_channel;
+pub mod consent_purpose;
+pub mod council;
+pub mod cpf;
+pub mod document_category;
+pub mod email;
+pub mod exam_kind;
+pub mod exam_order_status;
+pub mod icd10;
 pub mod id;
+pub mod pagination;
+pub mod password;
+pub mod phone;
+pub mod role;
+pub mod sex;
+pub mod time_slot;
+pub mod uf;
@@ -0,0 +1,89 @@
+use serde::Deserialize;
+
+pub const DEFAULT_PAGE_LIMIT: i64 = 20;
+pub const MAX_PAGE_LIMIT: i64 = 100;
+/// Far past any real listing, and low enough that the offset cannot overflow.
+pub const MAX_PAGE: i64 = 1_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pagination {
+    pub page: i64,
+    pub limit: i64,
+}
+
+impl Pagination {
+    /// Builds a 1-based pagination, falling back to the first page and
+    /// clamping the page to `1..=MAX_PAGE` and the limit to
+    /// `1..=MAX_PAGE_LIMIT`.
+    pub fn new(page: Option<i64>, limit: Option<i64>) -> Self {
+        Self {
+            page: page.unwrap_or(1).clamp(1, MAX_PAGE),
+            limit: limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT),
+        }
+    }
+
+    pub fn offset(&self) -> i64 {
+        (self.page - 1) * self.limit
+    }
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub pagination: Pagination,
+}
+
+impl<T> Page<T> {
+    pub fn total_pages(&self) -> i64 {
+        (self.total + self.pagination.limit - 1) / self.pagination.limit
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::value_objects::pagination::{MAX_PAGE, MAX_PAGE_LIMIT, Page, Pagination};
+
+    #[test]
+    fn new_clamps_values() {
+        let pagination = Pagination::new(Some(0), Some(1000));
+
+        assert_eq!(pagination.page, 1);
+        assert_eq!(pagination.limit, MAX_PAGE_LIMIT);
+        assert_eq!(pagination.offset(), 0);
+    }
+
+    #[test]
+    fn new_clamps_huge_page() {
+        let pagination = Pagination::new(Some(i64::MAX), Some(MAX_PAGE_LIMIT));
+
+        assert_eq!(pagination.page, MAX_PAGE);
+        assert_eq!(pagination.offset(), (MAX_PAGE - 1) * MAX_PAGE_LIMIT);
+    }
+
+    #[test]
+    fn total_pages_rounds_up() {
+        let page: Page<i32> = Page {
+            items: vec![],
+            total: 41,
+            pagination: Pagination::new(Some(3), Some(20)),
+        };
+
+        assert_eq!(page.pagination.offset(), 40);
+        assert_eq!(page.total_pages(), 3);
+    }
+}
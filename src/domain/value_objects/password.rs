@@ -0,0 +1,91 @@
+use std::fmt;
+
+use crate::domain::errors::admin_entity_error::AdminEntityError;
+
+pub const PASSWORD_MIN_LENGTH: usize = 12;
+/// bcrypt ignores everything past the 72nd byte.
+pub const PASSWORD_MAX_BYTES: usize = 72;
+
+/// A plain-text password that meets the strength rules: at least
+/// [`PASSWORD_MIN_LENGTH`] characters mixing lowercase and uppercase letters,
+/// digits and symbols. It only lives until it is hashed.
+pub struct Password(String);
+
+impl Password {
+    pub fn parse(value: &str) -> Result<Self, AdminEntityError> {
+        let weak = |rule: &str| Err(AdminEntityError::WeakPassword(rule.to_string()));
+
+        if value.chars().count() < PASSWORD_MIN_LENGTH {
+            return weak(&format!(
+                "it must have at least {PASSWORD_MIN_LENGTH} characters"
+            ));
+        }
+        if value.len() > PASSWORD_MAX_BYTES {
+            return weak(&format!("it must have at most {PASSWORD_MAX_BYTES} bytes"));
+        }
+        if !value.chars().any(char::is_lowercase) || !value.chars().any(char::is_uppercase) {
+            return weak("it must mix lowercase and uppercase letters");
+        }
+        if !value.chars().any(|c| c.is_ascii_digit()) {
+            return weak("it must have a digit");
+        }
+        if value.chars().all(char::is_alphanumeric) {
+            return weak("it must have a symbol");
+        }
+
+        Ok(Self(value.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Password {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Password(***)")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::errors::admin_entity_error::AdminEntityError;
+
+    use super::Password;
+
+    #[test]
+    fn parse_strong_password() {
+        let password = Password::parse("Correct-Horse-42").unwrap();
+
+        assert_eq!(password.as_str(), "Correct-Horse-42");
+        assert_eq!(format!("{password:?}"), "Password(***)");
+    }
+
+    #[test]
+    fn parse_weak_passwords() {
+        for (value, rule) in [
+            ("Short-1", "it must have at least 12 characters"),
+            (
+                "all-lowercase-42",
+                "it must mix lowercase and uppercase letters",
+            ),
+            ("No-Digits-Here", "it must have a digit"),
+            ("NoSymbols12345", "it must have a symbol"),
+        ] {
+            assert_eq!(
+                Password::parse(value).unwrap_err(),
+                AdminEntityError::WeakPassword(rule.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn parse_too_long_password() {
+        let value = format!("Aa1-{}", "x".repeat(69));
+
+        assert_eq!(
+            Password::parse(&value).unwrap_err(),
+            AdminEntityError::WeakPassword("it must have at most 72 bytes".to_string())
+        );
+    }
+}
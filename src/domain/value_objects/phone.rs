@@ -0,0 +1,47 @@
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+/// Normalizes a Brazilian phone number to its digits: a two-digit area code
+/// followed by an 8-digit landline or a 9-digit mobile number. A leading
+/// `+55` country code is dropped.
+pub fn normalize_phone(value: &str) -> Result<String, PatientEntityError> {
+    let digits: String = value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '(' | ')' | '+' | '.'))
+        .collect();
+
+    let digits = match digits.strip_prefix("55") {
+        Some(national) if digits.len() > 11 => national.to_string(),
+        _ => digits,
+    };
+
+    if !matches!(digits.len(), 10 | 11)
+        || !digits.chars().all(|c| c.is_ascii_digit())
+        || digits.starts_with('0')
+    {
+        return Err(PatientEntityError::InvalidPhone(value.to_string()));
+    }
+
+    Ok(digits)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::{
+        errors::patient_entity_error::PatientEntityError, value_objects::phone::normalize_phone,
+    };
+
+    #[test]
+    fn normalize_phone_ok() {
+        assert_eq!(normalize_phone("(11) 98765-4321").unwrap(), "11987654321");
+        assert_eq!(normalize_phone("+55 11 3456-7890").unwrap(), "1134567890");
+    }
+
+    #[test]
+    fn normalize_phone_invalid_phone_error() {
+        assert_eq!(
+            normalize_phone("98765-4321"),
+            Err(PatientEntityError::InvalidPhone("98765-4321".to_string()))
+        );
+        assert!(normalize_phone("(11) 9876A-4321").is_err());
+    }
+}
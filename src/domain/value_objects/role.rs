@@ -0,0 +1,54 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::admin_entity_error::AdminEntityError;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Admin,
+    Physician,
+    Nurse,
+    Receptionist,
+    Auditor,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Physician => "physician",
+            Role::Nurse => "nurse",
+            Role::Receptionist => "receptionist",
+            Role::Auditor => "auditor",
+        }
+    }
+}
+
+impl TryFrom<String> for Role {
+    type Error = AdminEntityError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "admin" => Ok(Role::Admin),
+            "physician" => Ok(Role::Physician),
+            "nurse" => Ok(Role::Nurse),
+            "receptionist" => Ok(Role::Receptionist),
+            "auditor" => Ok(Role::Auditor),
+            _ => Err(AdminEntityError::InvalidRole(value)),
+        }
+    }
+}
+
+impl From<Role> for String {
+    fn from(value: Role) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+
+use diesel::{
+    deserialize::{FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::Pg,
+    serialize::ToSql,
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::patient_entity_error::PatientEntityError;
+
+/// Sex recorded at birth, as used by SUS forms. Gender identity is kept
+/// apart, as free text, on the patient.
+#[derive(AsExpression, Clone, Copy, Debug, Deserialize, Eq, FromSqlRow, PartialEq, Serialize)]
+#[diesel(sql_type = Varchar)]
+#[serde(rename_all = "snake_case")]
+pub enum Sex {
+    Female,
+    Male,
+    Intersex,
+}
+
+impl Sex {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Sex::Female => "female",
+            Sex::Male => "male",
+            Sex::Intersex => "intersex",
+        }
+    }
+}
+
+impl TryFrom<&str> for Sex {
+    type Error = PatientEntityError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "female" => Ok(Sex::Female),
+            "male" => Ok(Sex::Male),
+            "intersex" => Ok(Sex::Intersex),
+            _ => Err(PatientEntityError::InvalidSex(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Sex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromSql<Varchar, Pg> for Sex {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> diesel::deserialize::Result<Self> {
+        let sex = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(Sex::try_from(sex.as_str())?)
+    }
+}
+
+impl ToSql<Varchar, Pg> for Sex {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, Pg>,
+    ) -> diesel::serialize::Result {
+        <str as ToSql<Varchar, Pg>>::to_sql(self.as_str(), out)
+    }
+}
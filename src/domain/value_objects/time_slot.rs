@@ -0,0 +1,55 @@
+use chrono::NaiveDateTime;
+
+/// A half-open `[starts_at, ends_at)` interval of time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeSlot {
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+}
+
+impl TimeSlot {
+    pub fn new(starts_at: NaiveDateTime, ends_at: NaiveDateTime) -> Self {
+        Self { starts_at, ends_at }
+    }
+
+    pub fn overlaps(&self, other: &TimeSlot) -> bool {
+        self.starts_at < other.ends_at && other.starts_at < self.ends_at
+    }
+
+    pub fn contains(&self, other: &TimeSlot) -> bool {
+        self.starts_at <= other.starts_at && other.ends_at <= self.ends_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDateTime;
+
+    use super::TimeSlot;
+
+    fn slot(starts_at: &str, ends_at: &str) -> TimeSlot {
+        TimeSlot::new(
+            starts_at.parse::<NaiveDateTime>().unwrap(),
+            ends_at.parse::<NaiveDateTime>().unwrap(),
+        )
+    }
+
+    #[test]
+    fn overlaps() {
+        let a = slot("2025-06-10T10:00:00", "2025-06-10T10:30:00");
+
+        assert!(a.overlaps(&slot("2025-06-10T10:05:00", "2025-06-10T10:35:00")));
+        assert!(a.overlaps(&slot("2025-06-10T09:00:00", "2025-06-10T11:00:00")));
+        assert!(!a.overlaps(&slot("2025-06-10T10:30:00", "2025-06-10T11:00:00")));
+        assert!(!a.overlaps(&slot("2025-06-10T09:30:00", "2025-06-10T10:00:00")));
+    }
+
+    #[test]
+    fn contains() {
+        let a = slot("2025-06-10T08:00:00", "2025-06-10T12:00:00");
+
+        assert!(a.contains(&slot("2025-06-10T08:00:00", "2025-06-10T08:30:00")));
+        assert!(a.contains(&slot("2025-06-10T11:30:00", "2025-06-10T12:00:00")));
+        assert!(!a.contains(&slot("2025-06-10T11:45:00", "2025-06-10T12:15:00")));
+    }
+}
@@ -0,0 +1,27 @@
+/// The 27 Brazilian federative units (26 states plus the Federal District).
+pub const UFS: [&str; 27] = [
+    "AC", "AL", "AM", "AP", "BA", "CE", "DF", "ES", "GO", "MA", "MG", "MS", "MT", "PA", "PB", "PE",
+    "PI", "PR", "RJ", "RN", "RO", "RR", "RS", "SC", "SE", "SP", "TO",
+];
+
+pub fn is_valid_uf(value: &str) -> bool {
+    UFS.contains(&value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_valid_uf;
+
+    #[test]
+    fn is_valid_uf_ok() {
+        assert!(is_valid_uf("SP"));
+        assert!(is_valid_uf("DF"));
+    }
+
+    #[test]
+    fn is_valid_uf_rejects_unknown_and_lowercase() {
+        assert!(!is_valid_uf("XX"));
+        assert!(!is_valid_uf("sp"));
+        assert!(!is_valid_uf(""));
+    }
+}
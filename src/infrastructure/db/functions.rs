@@ -0,0 +1,28 @@
+use diesel::{define_sql_function, sql_types::Text};
+
+define_sql_function! {
+    /// Accent-stripping wrapper created by the patient name search migration.
+    fn immutable_unaccent(x: Text) -> Text;
+}
+
+define_sql_function! {
+    fn lower(x: Text) -> Text;
+}
+
+/// Escapes `LIKE` wildcards so user input is matched literally.
+pub fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::infrastructure::db::functions::escape_like;
+
+    #[test]
+    fn escape_like_escapes_wildcards() {
+        assert_eq!(escape_like("50%_off\\"), "50\\%\\_off\\\\");
+    }
+}
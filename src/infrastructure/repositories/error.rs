@@ -1,10 +1,24 @@
+use diesel::result::{DatabaseErrorKind, Error};
 use log::error;
 
 use crate::domain::errors::repository_error::RepositoryError;
 
-impl From<diesel::result::Error> for RepositoryError {
-    fn from(value: diesel::result::Error) -> Self {
-        error!("Database error: {}", value);
-        RepositoryError::DatabaseError(value.to_string())
+impl From<Error> for RepositoryError {
+    fn from(value: Error) -> Self {
+        match &value {
+            // Exclusion constraint violations (SQLSTATE 23P01) have no dedicated
+            // kind in diesel, but they are the only "unknown" errors carrying a
+            // constraint name.
+            Error::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            | Error::DatabaseError(DatabaseErrorKind::Unknown, info)
+                if info.constraint_name().is_some() =>
+            {
+                RepositoryError::Conflict(value.to_string())
+            }
+            _ => {
+                error!("Database error: {}", value);
+                RepositoryError::DatabaseError(value.to_string())
+            }
+        }
     }
 }
@@ -1,4 +1,18 @@
 pub mod error;
 pub mod postgres_admin_repository;
+pub mod postgres_admission_repository;
+pub mod postgres_allergy_repository;
 pub mod postgres_appointment_repository;
+pub mod postgres_audit_repository;
+pub mod postgres_consent_repository;
+pub mod postgres_document_repository;
+pub mod postgres_encounter_repository;
+pub mod postgres_exam_order_repository;
+pub mod postgres_failed_login_repository;
 pub mod postgres_patient_repository;
+pub mod postgres_prescription_repository;
+pub mod postgres_problem_repository;
+pub mod postgres_professional_repository;
+pub mod postgres_schedule_repository;
+pub mod postgres_session_repository;
+pub mod postgres_ward_repository;
@@ -1,10 +1,13 @@
 use crate::{
     domain::{
         entities::admin::Admin, errors::repository_error::RepositoryError,
-        repositories::admin_repository::AdminRepository,
+        repositories::admin_repository::AdminRepository, value_objects::id::ID,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::{
+        self,
+        admins::dsl::{admins, email, id, name},
     },
-    infrastructure::db::connection::{DBPool, establish_connection},
-    schema::admins::dsl::{admins, email},
 };
 use async_trait::async_trait;
 use diesel::prelude::*;
@@ -15,11 +18,8 @@ pub struct PostgresAdminRepository {
 }
 
 impl PostgresAdminRepository {
-    pub fn new() -> Self {
-        let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL is missing");
-        Self {
-            pool: establish_connection(&database_url),
-        }
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
     }
 }
 
@@ -33,4 +33,42 @@ impl AdminRepository for Arc<PostgresAdminRepository> {
 
         Ok(admin)
     }
+
+    async fn find_by_id(&self, input_id: i32) -> Result<Option<Admin>, RepositoryError> {
+        let admin = admins
+            .filter(id.eq(input_id))
+            .first::<Admin>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(admin)
+    }
+
+    async fn find_all(&self) -> Result<Vec<Admin>, RepositoryError> {
+        let all_admins = admins
+            .order((name.asc(), id.asc()))
+            .load::<Admin>(&mut self.pool.get().unwrap())?;
+
+        Ok(all_admins)
+    }
+
+    async fn save(&self, admin: &Admin) -> Result<i32, RepositoryError> {
+        let inserted_admin_id = diesel::insert_into(schema::admins::table)
+            .values(admin.clone())
+            .returning(id)
+            .get_result(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_admin_id)
+    }
+
+    async fn update(&self, admin: &Admin) -> Result<Admin, RepositoryError> {
+        if let ID::Existing(input_id) = admin.id {
+            let updated_admin = diesel::update(admins.filter(id.eq(input_id)))
+                .set(admin.clone())
+                .get_result(&mut self.pool.get().unwrap())?;
+
+            return Ok(updated_admin);
+        }
+
+        Ok(admin.clone())
+    }
 }
@@ -0,0 +1,232 @@
+use crate::{
+    domain::{
+        entities::admission::{Admission, BedOccupancy},
+        errors::repository_error::RepositoryError,
+        repositories::admission_repository::AdmissionRepository,
+        value_objects::bed_status::BedStatus,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::{admissions, bed_occupancies, beds},
+};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{pg::PgConnection, prelude::*};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresAdmissionRepository {
+    pool: DBPool,
+}
+
+impl PostgresAdmissionRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AdmissionRepository for Arc<PostgresAdmissionRepository> {
+    async fn admit(
+        &self,
+        admission: &Admission,
+        occupancy: &BedOccupancy,
+    ) -> Result<(Admission, Vec<BedOccupancy>), RepositoryError> {
+        self.pool
+            .get()
+            .unwrap()
+            .transaction::<_, RepositoryError, _>(|conn| {
+                occupy_bed(conn, occupancy.bed_id)?;
+
+                let inserted_admission = diesel::insert_into(admissions::table)
+                    .values(admission.clone())
+                    .get_result::<Admission>(conn)?;
+
+                let admission_id: Option<i32> = inserted_admission.id.clone().into();
+
+                diesel::insert_into(bed_occupancies::table)
+                    .values(BedOccupancy {
+                        admission_id: admission_id.unwrap_or_default(),
+                        ..occupancy.clone()
+                    })
+                    .execute(conn)?;
+
+                Ok(with_occupancies(conn, vec![inserted_admission])?.remove(0))
+            })
+    }
+
+    async fn transfer(
+        &self,
+        occupancy: &BedOccupancy,
+    ) -> Result<(Admission, Vec<BedOccupancy>), RepositoryError> {
+        self.pool
+            .get()
+            .unwrap()
+            .transaction::<_, RepositoryError, _>(|conn| {
+                // Locked so that a discharge cannot slip in between.
+                let admission = admissions::table
+                    .filter(admissions::id.eq(occupancy.admission_id))
+                    .for_update()
+                    .first::<Admission>(conn)?;
+
+                if admission.is_discharged() {
+                    return Err(RepositoryError::Conflict(format!(
+                        "the admission {} was discharged in the meantime",
+                        occupancy.admission_id
+                    )));
+                }
+
+                occupy_bed(conn, occupancy.bed_id)?;
+                vacate_beds(conn, occupancy.admission_id, occupancy.started_at)?;
+
+                diesel::insert_into(bed_occupancies::table)
+                    .values(occupancy.clone())
+                    .execute(conn)?;
+
+                Ok(with_occupancies(conn, vec![admission])?.remove(0))
+            })
+    }
+
+    async fn discharge(
+        &self,
+        admission: &Admission,
+    ) -> Result<(Admission, Vec<BedOccupancy>), RepositoryError> {
+        let admission_id: Option<i32> = admission.id.clone().into();
+        let admission_id = admission_id.unwrap_or(0);
+
+        self.pool
+            .get()
+            .unwrap()
+            .transaction::<_, RepositoryError, _>(|conn| {
+                let discharged_admission = diesel::update(admissions::table)
+                    .filter(admissions::id.eq(admission_id))
+                    .filter(admissions::discharged_at.is_null())
+                    .set(admission.clone())
+                    .get_result::<Admission>(conn)
+                    .optional()?
+                    .ok_or(RepositoryError::Conflict(format!(
+                        "the admission {admission_id} was discharged in the meantime"
+                    )))?;
+
+                vacate_beds(
+                    conn,
+                    admission_id,
+                    discharged_admission
+                        .discharged_at
+                        .unwrap_or(discharged_admission.admitted_at),
+                )?;
+
+                Ok(with_occupancies(conn, vec![discharged_admission])?.remove(0))
+            })
+    }
+
+    async fn find_by_id(
+        &self,
+        input_id: i32,
+    ) -> Result<Option<(Admission, Vec<BedOccupancy>)>, RepositoryError> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let admission = admissions::table
+            .filter(admissions::id.eq(input_id))
+            .first::<Admission>(conn)
+            .optional()?;
+
+        match admission {
+            Some(admission) => Ok(with_occupancies(conn, vec![admission])?.pop()),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_patient_id(
+        &self,
+        input_patient_id: i32,
+    ) -> Result<Vec<(Admission, Vec<BedOccupancy>)>, RepositoryError> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let found_admissions = admissions::table
+            .filter(admissions::patient_id.eq(input_patient_id))
+            .order((admissions::admitted_at.desc(), admissions::id.desc()))
+            .load::<Admission>(conn)?;
+
+        Ok(with_occupancies(conn, found_admissions)?)
+    }
+
+    async fn find_open(&self) -> Result<Vec<(Admission, Vec<BedOccupancy>)>, RepositoryError> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let found_admissions = admissions::table
+            .filter(admissions::discharged_at.is_null())
+            .order((admissions::admitted_at.asc(), admissions::id.asc()))
+            .load::<Admission>(conn)?;
+
+        Ok(with_occupancies(conn, found_admissions)?)
+    }
+}
+
+/// Takes a free bed. Checking and writing the status in one statement keeps
+/// two admissions from grabbing the same bed.
+fn occupy_bed(conn: &mut PgConnection, bed_id: i32) -> Result<(), RepositoryError> {
+    let updated_rows = diesel::update(beds::table)
+        .filter(beds::id.eq(bed_id))
+        .filter(beds::status.eq(BedStatus::Free.as_str()))
+        .set(beds::status.eq(BedStatus::Occupied.as_str()))
+        .execute(conn)?;
+
+    if updated_rows == 0 {
+        return Err(RepositoryError::Conflict(format!(
+            "the bed {bed_id} is no longer free"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Ends the open occupancy of the admission and sends its bed to cleaning.
+fn vacate_beds(
+    conn: &mut PgConnection,
+    admission_id: i32,
+    ended_at: NaiveDateTime,
+) -> Result<(), diesel::result::Error> {
+    let vacated_bed_ids = diesel::update(bed_occupancies::table)
+        .filter(bed_occupancies::admission_id.eq(admission_id))
+        .filter(bed_occupancies::ended_at.is_null())
+        .set(bed_occupancies::ended_at.eq(ended_at))
+        .returning(bed_occupancies::bed_id)
+        .get_results::<i32>(conn)?;
+
+    diesel::update(beds::table)
+        .filter(beds::id.eq_any(vacated_bed_ids))
+        .set(beds::status.eq(BedStatus::Cleaning.as_str()))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+/// Loads the occupancies of every admission with a single query.
+fn with_occupancies(
+    conn: &mut PgConnection,
+    found_admissions: Vec<Admission>,
+) -> Result<Vec<(Admission, Vec<BedOccupancy>)>, diesel::result::Error> {
+    let admission_ids: Vec<i32> = found_admissions
+        .iter()
+        .filter_map(|admission| admission.id.clone().into())
+        .collect();
+
+    let occupancies = bed_occupancies::table
+        .filter(bed_occupancies::admission_id.eq_any(admission_ids))
+        .order((bed_occupancies::started_at.asc(), bed_occupancies::id.asc()))
+        .load::<BedOccupancy>(conn)?;
+
+    Ok(found_admissions
+        .into_iter()
+        .map(|admission| {
+            let admission_id: Option<i32> = admission.id.clone().into();
+            let admission_occupancies = occupancies
+                .iter()
+                .filter(|occupancy| Some(occupancy.admission_id) == admission_id)
+                .cloned()
+                .collect();
+
+            (admission, admission_occupancies)
+        })
+        .collect())
+}
@@ -0,0 +1,82 @@
+use crate::{
+    domain::{
+        entities::allergy::Allergy, errors::repository_error::RepositoryError,
+        repositories::allergy_repository::AllergyRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::allergies::dsl::{allergies, id, patient_id, substance},
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresAllergyRepository {
+    pool: DBPool,
+}
+
+impl PostgresAllergyRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AllergyRepository for Arc<PostgresAllergyRepository> {
+    async fn save(&self, allergy: &Allergy) -> Result<Allergy, RepositoryError> {
+        let inserted_allergy = diesel::insert_into(allergies)
+            .values(allergy.clone())
+            .get_result::<Allergy>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_allergy)
+    }
+
+    async fn update(&self, allergy: &Allergy) -> Result<Allergy, RepositoryError> {
+        let allergy_id: Option<i32> = allergy.id.clone().into();
+        let allergy_id = allergy_id.unwrap_or(0);
+
+        let updated_allergy = diesel::update(allergies)
+            .filter(id.eq(allergy_id))
+            .set(allergy.clone())
+            .get_result::<Allergy>(&mut self.pool.get().unwrap())?;
+
+        Ok(updated_allergy)
+    }
+
+    async fn find_by_id(
+        &self,
+        input_patient_id: i32,
+        input_id: i32,
+    ) -> Result<Option<Allergy>, RepositoryError> {
+        let allergy = allergies
+            .filter(id.eq(input_id))
+            .filter(patient_id.eq(input_patient_id))
+            .first::<Allergy>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(allergy)
+    }
+
+    async fn find_by_patient_id(
+        &self,
+        input_patient_id: i32,
+    ) -> Result<Vec<Allergy>, RepositoryError> {
+        let found_allergies = allergies
+            .filter(patient_id.eq(input_patient_id))
+            .order((substance.asc(), id.asc()))
+            .load::<Allergy>(&mut self.pool.get().unwrap())?;
+
+        Ok(found_allergies)
+    }
+
+    async fn delete(&self, input_patient_id: i32, input_id: i32) -> Result<bool, RepositoryError> {
+        let deleted_rows = diesel::delete(
+            allergies
+                .filter(id.eq(input_id))
+                .filter(patient_id.eq(input_patient_id)),
+        )
+        .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(deleted_rows > 0)
+    }
+}
@@ -1,15 +1,22 @@
 use crate::schema;
-use crate::schema::appointments::dsl::{appointment_at, appointments, canceled, id, patient_id};
+use crate::schema::appointments::dsl::{
+    appointment_at, appointments, ends_at, id, patient_id, professional_id, specialty, status,
+};
 use crate::{
     domain::{
-        entities::appointment::Appointment, errors::repository_error::RepositoryError,
-        repositories::appointment_repository::AppointmentRepository,
+        entities::appointment::Appointment,
+        errors::repository_error::RepositoryError,
+        repositories::appointment_repository::{AppointmentFilter, AppointmentRepository},
+        value_objects::{
+            appointment_status::AppointmentStatus,
+            pagination::{Page, Pagination, SortOrder},
+        },
     },
-    infrastructure::db::connection::{DBPool, establish_connection},
+    infrastructure::db::connection::DBPool,
 };
 use async_trait::async_trait;
 use chrono::NaiveDateTime;
-use diesel::{dsl::exists, prelude::*, select};
+use diesel::{dsl::exists, pg::Pg, prelude::*, select};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -18,30 +25,56 @@ pub struct PostgresAppointmentRepository {
 }
 
 impl PostgresAppointmentRepository {
-    pub fn new() -> Self {
-        let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL is missing");
-        Self {
-            pool: establish_connection(&database_url),
-        }
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
     }
 }
 
+fn filtered_appointments(filter: &AppointmentFilter) -> schema::appointments::BoxedQuery<'_, Pg> {
+    let mut query = appointments.into_boxed();
+
+    if let Some(from) = filter.from {
+        query = query.filter(appointment_at.ge(from));
+    }
+    if let Some(to) = filter.to {
+        query = query.filter(appointment_at.lt(to));
+    }
+    if let Some(input_specialty) = &filter.specialty {
+        query = query.filter(specialty.ilike(input_specialty));
+    }
+    if let Some(input_professional_id) = filter.professional_id {
+        query = query.filter(professional_id.eq(input_professional_id));
+    }
+    if let Some(input_status) = filter.status {
+        query = query.filter(status.eq(input_status.as_str()));
+    }
+    if let Some(input_patient_id) = filter.patient_id {
+        query = query.filter(patient_id.eq(input_patient_id));
+    }
+
+    query
+}
+
 #[async_trait]
 impl AppointmentRepository for Arc<PostgresAppointmentRepository> {
-    async fn exists_by_patient_id_and_appointment_at(
+    async fn exists_overlapping_by_patient_id(
         &self,
         input_patient_id: i32,
-        input_appointment_at: NaiveDateTime,
+        input_starts_at: NaiveDateTime,
+        input_ends_at: NaiveDateTime,
+        excluding_id: Option<i32>,
     ) -> Result<bool, RepositoryError> {
-        let exists_by_patient_id_and_appointment_at = select(exists(
+        let exists_overlapping_by_patient_id = select(exists(
             appointments
                 .filter(patient_id.eq(input_patient_id))
-                .filter(appointment_at.eq(input_appointment_at))
-                .filter(canceled.eq(false)),
+                .filter(id.ne(excluding_id.unwrap_or(0)))
+                .filter(appointment_at.lt(input_ends_at))
+                .filter(ends_at.gt(input_starts_at))
+                .filter(status.ne(AppointmentStatus::Canceled.as_str())),
         ))
         .get_result(&mut self.pool.get().unwrap())?;
 
-        Ok(exists_by_patient_id_and_appointment_at)
+        Ok(exists_overlapping_by_patient_id)
     }
 
     async fn save(&self, appointment: &Appointment) -> Result<Appointment, RepositoryError> {
@@ -52,6 +85,15 @@ impl AppointmentRepository for Arc<PostgresAppointmentRepository> {
         Ok(inserted_appointment)
     }
 
+    async fn find_by_id(&self, input_id: i32) -> Result<Option<Appointment>, RepositoryError> {
+        let appointment = appointments
+            .filter(id.eq(input_id))
+            .first::<Appointment>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(appointment)
+    }
+
     async fn find_by_patient_id_and_appointment_at(
         &self,
         input_patient_id: i32,
@@ -60,7 +102,7 @@ impl AppointmentRepository for Arc<PostgresAppointmentRepository> {
         let appointment = appointments
             .filter(patient_id.eq(input_patient_id))
             .filter(appointment_at.eq(input_appointment_at))
-            .filter(canceled.eq(false))
+            .filter(status.ne(AppointmentStatus::Canceled.as_str()))
             .first::<Appointment>(&mut self.pool.get().unwrap())
             .optional()?;
 
@@ -85,8 +127,53 @@ impl AppointmentRepository for Arc<PostgresAppointmentRepository> {
     ) -> Result<Vec<Appointment>, RepositoryError> {
         let found_appointments = appointments
             .filter(patient_id.eq(input_patient_id))
+            .order(appointment_at.asc())
             .load::<Appointment>(&mut self.pool.get().unwrap())?;
 
         Ok(found_appointments)
     }
+
+    async fn find_by_professional_id_between(
+        &self,
+        input_professional_id: i32,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<Appointment>, RepositoryError> {
+        let found_appointments = appointments
+            .filter(professional_id.eq(input_professional_id))
+            .filter(appointment_at.lt(to))
+            .filter(ends_at.gt(from))
+            .filter(status.ne(AppointmentStatus::Canceled.as_str()))
+            .order(appointment_at.asc())
+            .load::<Appointment>(&mut self.pool.get().unwrap())?;
+
+        Ok(found_appointments)
+    }
+
+    async fn search(
+        &self,
+        filter: AppointmentFilter,
+        pagination: Pagination,
+    ) -> Result<Page<Appointment>, RepositoryError> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let total = filtered_appointments(&filter).count().get_result(conn)?;
+
+        let query = filtered_appointments(&filter);
+        let query = match filter.sort {
+            SortOrder::Asc => query.order((appointment_at.asc(), id.asc())),
+            SortOrder::Desc => query.order((appointment_at.desc(), id.desc())),
+        };
+
+        let items = query
+            .offset(pagination.offset())
+            .limit(pagination.limit)
+            .load::<Appointment>(conn)?;
+
+        Ok(Page {
+            items,
+            total,
+            pagination,
+        })
+    }
 }
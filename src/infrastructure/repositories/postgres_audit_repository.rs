@@ -0,0 +1,33 @@
+use crate::{
+    domain::{
+        entities::audit_event::AuditEvent, errors::repository_error::RepositoryError,
+        repositories::audit_repository::AuditRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema,
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresAuditRepository {
+    pool: DBPool,
+}
+
+impl PostgresAuditRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditRepository for Arc<PostgresAuditRepository> {
+    async fn save(&self, event: &AuditEvent) -> Result<(), RepositoryError> {
+        diesel::insert_into(schema::audit_events::table)
+            .values(event.clone())
+            .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(())
+    }
+}
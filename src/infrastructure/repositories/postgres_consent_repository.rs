@@ -0,0 +1,93 @@
+use crate::{
+    domain::{
+        entities::consent::Consent, errors::repository_error::RepositoryError,
+        repositories::consent_repository::ConsentRepository,
+        value_objects::consent_purpose::ConsentPurpose,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::consents::dsl::{
+        consents, granted_at, id, patient_id, purpose, revoked_at, revoked_by,
+    },
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresConsentRepository {
+    pool: DBPool,
+}
+
+impl PostgresConsentRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ConsentRepository for Arc<PostgresConsentRepository> {
+    async fn save(&self, consent: &Consent) -> Result<Consent, RepositoryError> {
+        let inserted_consent = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                diesel::update(
+                    consents
+                        .filter(patient_id.eq(consent.patient_id))
+                        .filter(purpose.eq(consent.purpose.as_str()))
+                        .filter(revoked_at.is_null()),
+                )
+                .set((
+                    revoked_at.eq(consent.granted_at),
+                    revoked_by.eq(&consent.granted_by),
+                ))
+                .execute(conn)?;
+
+                diesel::insert_into(consents)
+                    .values(consent.clone())
+                    .get_result::<Consent>(conn)
+            })?;
+
+        Ok(inserted_consent)
+    }
+
+    async fn update(&self, consent: &Consent) -> Result<Consent, RepositoryError> {
+        let consent_id: Option<i32> = consent.id.clone().into();
+        let consent_id = consent_id.unwrap_or(0);
+
+        let updated_consent = diesel::update(consents)
+            .filter(id.eq(consent_id))
+            .set(consent.clone())
+            .get_result::<Consent>(&mut self.pool.get().unwrap())?;
+
+        Ok(updated_consent)
+    }
+
+    async fn find_active_by_patient_id_and_purpose(
+        &self,
+        input_patient_id: i32,
+        input_purpose: ConsentPurpose,
+    ) -> Result<Option<Consent>, RepositoryError> {
+        let consent = consents
+            .filter(patient_id.eq(input_patient_id))
+            .filter(purpose.eq(input_purpose.as_str()))
+            .filter(revoked_at.is_null())
+            .first::<Consent>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(consent)
+    }
+
+    async fn find_by_patient_id(
+        &self,
+        input_patient_id: i32,
+    ) -> Result<Vec<Consent>, RepositoryError> {
+        let found_consents = consents
+            .filter(patient_id.eq(input_patient_id))
+            .order((granted_at.desc(), id.desc()))
+            .load::<Consent>(&mut self.pool.get().unwrap())?;
+
+        Ok(found_consents)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    domain::{
+        entities::document::Document, errors::repository_error::RepositoryError,
+        repositories::document_repository::DocumentRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::documents::dsl::{documents, id, patient_id, uploaded_at},
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresDocumentRepository {
+    pool: DBPool,
+}
+
+impl PostgresDocumentRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DocumentRepository for Arc<PostgresDocumentRepository> {
+    async fn save(&self, document: &Document) -> Result<Document, RepositoryError> {
+        let inserted_document = diesel::insert_into(documents)
+            .values(document.clone())
+            .get_result::<Document>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_document)
+    }
+
+    async fn find_by_id(
+        &self,
+        input_patient_id: i32,
+        input_id: i32,
+    ) -> Result<Option<Document>, RepositoryError> {
+        let document = documents
+            .filter(id.eq(input_id))
+            .filter(patient_id.eq(input_patient_id))
+            .first::<Document>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(document)
+    }
+
+    async fn find_by_patient_id(
+        &self,
+        input_patient_id: i32,
+    ) -> Result<Vec<Document>, RepositoryError> {
+        let found_documents = documents
+            .filter(patient_id.eq(input_patient_id))
+            .order((uploaded_at.desc(), id.desc()))
+            .load::<Document>(&mut self.pool.get().unwrap())?;
+
+        Ok(found_documents)
+    }
+
+    async fn delete(&self, input_patient_id: i32, input_id: i32) -> Result<bool, RepositoryError> {
+        let deleted_rows = diesel::delete(
+            documents
+                .filter(id.eq(input_id))
+                .filter(patient_id.eq(input_patient_id)),
+        )
+        .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(deleted_rows > 0)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    domain::{
+        entities::encounter::Encounter, errors::repository_error::RepositoryError,
+        repositories::encounter_repository::EncounterRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::encounters::dsl::{amends_id, appointment_id, encounters, id, patient_id, recorded_at},
+};
+use async_trait::async_trait;
+use diesel::{dsl::exists, prelude::*, select};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresEncounterRepository {
+    pool: DBPool,
+}
+
+impl PostgresEncounterRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EncounterRepository for Arc<PostgresEncounterRepository> {
+    async fn save(&self, encounter: &Encounter) -> Result<Encounter, RepositoryError> {
+        let inserted_encounter = diesel::insert_into(encounters)
+            .values(encounter.clone())
+            .get_result::<Encounter>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_encounter)
+    }
+
+    async fn find_by_id(&self, input_id: i32) -> Result<Option<Encounter>, RepositoryError> {
+        let encounter = encounters
+            .filter(id.eq(input_id))
+            .first::<Encounter>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(encounter)
+    }
+
+    async fn exists_by_appointment_id(
+        &self,
+        input_appointment_id: i32,
+    ) -> Result<bool, RepositoryError> {
+        let exists_by_appointment_id = select(exists(
+            encounters
+                .filter(appointment_id.eq(input_appointment_id))
+                .filter(amends_id.is_null()),
+        ))
+        .get_result(&mut self.pool.get().unwrap())?;
+
+        Ok(exists_by_appointment_id)
+    }
+
+    async fn find_amendments(&self, input_id: i32) -> Result<Vec<Encounter>, RepositoryError> {
+        let amendments = encounters
+            .filter(amends_id.eq(input_id))
+            .order((recorded_at.asc(), id.asc()))
+            .load::<Encounter>(&mut self.pool.get().unwrap())?;
+
+        Ok(amendments)
+    }
+
+    async fn find_by_patient_id(
+        &self,
+        input_patient_id: i32,
+    ) -> Result<Vec<Encounter>, RepositoryError> {
+        let found_encounters = encounters
+            .filter(patient_id.eq(input_patient_id))
+            .order((recorded_at.asc(), id.asc()))
+            .load::<Encounter>(&mut self.pool.get().unwrap())?;
+
+        Ok(found_encounters)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    domain::{
+        entities::exam_order::{ExamOrder, ExamResult},
+        errors::repository_error::RepositoryError,
+        repositories::exam_order_repository::ExamOrderRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::{exam_orders, exam_result_files, exam_results},
+};
+use async_trait::async_trait;
+use diesel::{pg::PgConnection, prelude::*, upsert::excluded};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresExamOrderRepository {
+    pool: DBPool,
+}
+
+impl PostgresExamOrderRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExamOrderRepository for Arc<PostgresExamOrderRepository> {
+    async fn save(&self, exam_order: &ExamOrder) -> Result<ExamOrder, RepositoryError> {
+        let inserted_exam_order = diesel::insert_into(exam_orders::table)
+            .values(exam_order.clone())
+            .get_result::<ExamOrder>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_exam_order)
+    }
+
+    async fn update(&self, exam_order: &ExamOrder) -> Result<ExamOrder, RepositoryError> {
+        Ok(update_exam_order(
+            &mut self.pool.get().unwrap(),
+            exam_order,
+        )?)
+    }
+
+    async fn find_by_id(
+        &self,
+        input_id: i32,
+    ) -> Result<Option<(ExamOrder, Vec<ExamResult>)>, RepositoryError> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let exam_order = exam_orders::table
+            .filter(exam_orders::id.eq(input_id))
+            .first::<ExamOrder>(conn)
+            .optional()?;
+
+        match exam_order {
+            Some(exam_order) => Ok(with_results(conn, vec![exam_order])?.pop()),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_patient_id(
+        &self,
+        input_patient_id: i32,
+    ) -> Result<Vec<(ExamOrder, Vec<ExamResult>)>, RepositoryError> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let found_exam_orders = exam_orders::table
+            .filter(exam_orders::patient_id.eq(input_patient_id))
+            .order((exam_orders::requested_at.desc(), exam_orders::id.desc()))
+            .load::<ExamOrder>(conn)?;
+
+        Ok(with_results(conn, found_exam_orders)?)
+    }
+
+    async fn save_results(
+        &self,
+        exam_order: &ExamOrder,
+        results: &[ExamResult],
+    ) -> Result<(ExamOrder, Vec<ExamResult>), RepositoryError> {
+        let saved = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let updated_exam_order = update_exam_order(conn, exam_order)?;
+
+                diesel::insert_into(exam_results::table)
+                    .values(results.to_vec())
+                    .execute(conn)?;
+
+                let exam_order_id: Option<i32> = updated_exam_order.id.clone().into();
+                let saved_results = exam_results::table
+                    .filter(exam_results::exam_order_id.eq(exam_order_id.unwrap_or_default()))
+                    .order((exam_results::recorded_at.asc(), exam_results::id.asc()))
+                    .load::<ExamResult>(conn)?;
+
+                Ok((updated_exam_order, saved_results))
+            })?;
+
+        Ok(saved)
+    }
+
+    async fn save_result_file(
+        &self,
+        exam_order: &ExamOrder,
+        content: Vec<u8>,
+    ) -> Result<ExamOrder, RepositoryError> {
+        let updated_exam_order = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let updated_exam_order = update_exam_order(conn, exam_order)?;
+                let exam_order_id: Option<i32> = updated_exam_order.id.clone().into();
+
+                diesel::insert_into(exam_result_files::table)
+                    .values((
+                        exam_result_files::exam_order_id.eq(exam_order_id.unwrap_or_default()),
+                        exam_result_files::content.eq(content),
+                    ))
+                    .on_conflict(exam_result_files::exam_order_id)
+                    .do_update()
+                    .set(exam_result_files::content.eq(excluded(exam_result_files::content)))
+                    .execute(conn)?;
+
+                Ok(updated_exam_order)
+            })?;
+
+        Ok(updated_exam_order)
+    }
+
+    async fn find_result_file(&self, input_id: i32) -> Result<Option<Vec<u8>>, RepositoryError> {
+        let content = exam_result_files::table
+            .filter(exam_result_files::exam_order_id.eq(input_id))
+            .select(exam_result_files::content)
+            .first::<Vec<u8>>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(content)
+    }
+}
+
+fn update_exam_order(
+    conn: &mut PgConnection,
+    exam_order: &ExamOrder,
+) -> Result<ExamOrder, diesel::result::Error> {
+    let exam_order_id: Option<i32> = exam_order.id.clone().into();
+
+    diesel::update(exam_orders::table)
+        .filter(exam_orders::id.eq(exam_order_id.unwrap_or(0)))
+        .set(exam_order.clone())
+        .get_result::<ExamOrder>(conn)
+}
+
+/// Loads the results of every order with a single query.
+fn with_results(
+    conn: &mut PgConnection,
+    found_exam_orders: Vec<ExamOrder>,
+) -> Result<Vec<(ExamOrder, Vec<ExamResult>)>, diesel::result::Error> {
+    let exam_order_ids: Vec<i32> = found_exam_orders
+        .iter()
+        .filter_map(|exam_order| exam_order.id.clone().into())
+        .collect();
+
+    let results = exam_results::table
+        .filter(exam_results::exam_order_id.eq_any(exam_order_ids))
+        .order((exam_results::recorded_at.asc(), exam_results::id.asc()))
+        .load::<ExamResult>(conn)?;
+
+    Ok(found_exam_orders
+        .into_iter()
+        .map(|exam_order| {
+            let exam_order_id: Option<i32> = exam_order.id.clone().into();
+            let exam_order_results = results
+                .iter()
+                .filter(|result| Some(result.exam_order_id) == exam_order_id)
+                .cloned()
+                .collect();
+
+            (exam_order, exam_order_results)
+        })
+        .collect())
+}
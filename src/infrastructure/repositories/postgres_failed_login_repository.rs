@@ -0,0 +1,104 @@
+use crate::{
+    domain::{
+        entities::failed_login::{FailedLogin, RecordedAttempt},
+        errors::repository_error::RepositoryError,
+        repositories::failed_login_repository::FailedLoginRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::{
+        self,
+        failed_logins::dsl::{attempted_at, email, failed_logins, id, ip_address},
+    },
+};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::*,
+    sql_types::{Integer, Text},
+};
+use std::sync::Arc;
+
+// Advisory lock classes, so e-mail and address keys can never collide.
+const EMAIL_LOCK_CLASS: i32 = 1;
+const IP_ADDRESS_LOCK_CLASS: i32 = 2;
+
+pub struct PostgresFailedLoginRepository {
+    pool: DBPool,
+}
+
+impl PostgresFailedLoginRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FailedLoginRepository for Arc<PostgresFailedLoginRepository> {
+    async fn record_attempt(
+        &self,
+        attempt: &FailedLogin,
+        since: NaiveDateTime,
+    ) -> Result<RecordedAttempt, RepositoryError> {
+        let recorded_attempt = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                // Always e-mail first, then address, so two attempts can
+                // never wait on each other.
+                diesel::sql_query("SELECT pg_advisory_xact_lock($1, hashtext($2))")
+                    .bind::<Integer, _>(EMAIL_LOCK_CLASS)
+                    .bind::<Text, _>(&attempt.email)
+                    .execute(conn)?;
+                diesel::sql_query("SELECT pg_advisory_xact_lock($1, hashtext($2))")
+                    .bind::<Integer, _>(IP_ADDRESS_LOCK_CLASS)
+                    .bind::<Text, _>(&attempt.ip_address)
+                    .execute(conn)?;
+
+                let account_failures = failed_logins
+                    .filter(email.eq(&attempt.email))
+                    .filter(attempted_at.ge(since))
+                    .select(attempted_at)
+                    .load::<NaiveDateTime>(conn)?;
+                let address_failures = failed_logins
+                    .filter(ip_address.eq(&attempt.ip_address))
+                    .filter(attempted_at.ge(since))
+                    .select(attempted_at)
+                    .load::<NaiveDateTime>(conn)?;
+
+                let inserted_failed_login_id = diesel::insert_into(schema::failed_logins::table)
+                    .values(attempt.clone())
+                    .returning(id)
+                    .get_result(conn)?;
+
+                Ok(RecordedAttempt {
+                    id: inserted_failed_login_id,
+                    account_failures,
+                    address_failures,
+                })
+            })?;
+
+        Ok(recorded_attempt)
+    }
+
+    async fn delete(&self, input_id: i32) -> Result<(), RepositoryError> {
+        diesel::delete(failed_logins.filter(id.eq(input_id)))
+            .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(())
+    }
+
+    async fn delete_by_email(&self, input_email: String) -> Result<usize, RepositoryError> {
+        let deleted = diesel::delete(failed_logins.filter(email.eq(input_email)))
+            .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(deleted)
+    }
+
+    async fn delete_older_than(&self, before: NaiveDateTime) -> Result<usize, RepositoryError> {
+        let deleted = diesel::delete(failed_logins.filter(attempted_at.lt(before)))
+            .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(deleted)
+    }
+}
@@ -1,37 +1,68 @@
 use crate::{
     domain::{
-        entities::patient::Patient, errors::repository_error::RepositoryError,
-        repositories::patient_repository::PatientRepository, value_objects::id::ID,
+        entities::patient::Patient,
+        errors::repository_error::RepositoryError,
+        repositories::patient_repository::{PatientMergeReport, PatientRepository},
+        value_objects::{
+            cpf::Cpf,
+            id::ID,
+            pagination::{Page, Pagination},
+        },
+    },
+    infrastructure::db::{
+        connection::DBPool,
+        functions::{escape_like, immutable_unaccent, lower},
     },
-    infrastructure::db::connection::{DBPool, establish_connection},
     schema::{
         self,
-        patients::dsl::{cpf, id, name, patients},
+        patients::dsl::{
+            address_complement, address_number, anonymized_at, birth_date, cep, city, cns, cpf,
+            deleted_at, email, gender_identity, id, merged_at, merged_into_id, mother_name, name,
+            neighborhood, patients, phones, social_name, street,
+        },
     },
 };
 use async_trait::async_trait;
-use diesel::{dsl::exists, prelude::*, select};
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use diesel::{dsl::exists, pg::Pg, prelude::*, select};
 use std::sync::Arc;
 
+/// Replaces required free text that anonymization cannot clear.
+const ANONYMIZED_TEXT: &str = "Anonymized";
+
 #[derive(Clone)]
 pub struct PostgresPatientRepository {
     pool: DBPool,
 }
 
 impl PostgresPatientRepository {
-    pub fn new() -> Self {
-        let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL is missing");
-        Self {
-            pool: establish_connection(&database_url),
-        }
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn patients_named_like(input_name: &Option<String>) -> schema::patients::BoxedQuery<'_, Pg> {
+    let mut query = patients.filter(deleted_at.is_null()).into_boxed();
+
+    if let Some(input_name) = input_name {
+        let pattern = format!("%{}%", escape_like(input_name.trim()));
+
+        query =
+            query.filter(lower(immutable_unaccent(name)).like(lower(immutable_unaccent(pattern))));
     }
+
+    query
 }
 
 #[async_trait]
 impl PatientRepository for Arc<PostgresPatientRepository> {
-    async fn exists_by_cpf(&self, input_cpf: &str) -> Result<bool, RepositoryError> {
-        let exists_by_cpf = select(exists(patients.filter(cpf.eq(input_cpf))))
-            .get_result(&mut self.pool.get().unwrap())?;
+    async fn exists_by_cpf(&self, input_cpf: &Cpf) -> Result<bool, RepositoryError> {
+        let exists_by_cpf = select(exists(
+            patients
+                .filter(cpf.eq(input_cpf.as_str()))
+                .filter(deleted_at.is_null()),
+        ))
+        .get_result(&mut self.pool.get().unwrap())?;
 
         Ok(exists_by_cpf)
     }
@@ -48,15 +79,17 @@ impl PatientRepository for Arc<PostgresPatientRepository> {
     async fn find_by_id(&self, input_id: i32) -> Result<Option<Patient>, RepositoryError> {
         let patient = patients
             .filter(id.eq(input_id))
+            .filter(deleted_at.is_null())
             .first::<Patient>(&mut self.pool.get().unwrap())
             .optional()?;
 
         Ok(patient)
     }
 
-    async fn find_by_cpf(&self, input_cpf: String) -> Result<Option<Patient>, RepositoryError> {
+    async fn find_by_cpf(&self, input_cpf: Cpf) -> Result<Option<Patient>, RepositoryError> {
         let patient = patients
-            .filter(cpf.eq(input_cpf))
+            .filter(cpf.eq(input_cpf.as_str()))
+            .filter(deleted_at.is_null())
             .first::<Patient>(&mut self.pool.get().unwrap())
             .optional()?;
 
@@ -65,9 +98,13 @@ impl PatientRepository for Arc<PostgresPatientRepository> {
 
     async fn update(&self, patient: &Patient) -> Result<Patient, RepositoryError> {
         if let ID::Existing(input_id) = patient.id {
-            let updated_patient = diesel::update(patients.filter(id.eq(input_id)))
-                .set(name.eq(patient.name.clone()))
-                .get_result(&mut self.pool.get().unwrap())?;
+            let updated_patient = diesel::update(
+                patients
+                    .filter(id.eq(input_id))
+                    .filter(deleted_at.is_null()),
+            )
+            .set(patient.clone())
+            .get_result(&mut self.pool.get().unwrap())?;
 
             return Ok(updated_patient);
         }
@@ -75,10 +112,317 @@ impl PatientRepository for Arc<PostgresPatientRepository> {
         Ok(patient.clone())
     }
 
-    async fn delete_by_cpf(&self, input_cpf: String) -> Result<(), RepositoryError> {
-        diesel::delete(patients.filter(cpf.eq(input_cpf)))
-            .execute(&mut self.pool.get().unwrap())?;
+    async fn delete_by_cpf(&self, input_cpf: Cpf) -> Result<(), RepositoryError> {
+        diesel::update(
+            patients
+                .filter(cpf.eq(input_cpf.as_str()))
+                .filter(deleted_at.is_null()),
+        )
+        .set(deleted_at.eq(chrono::Local::now().naive_utc()))
+        .execute(&mut self.pool.get().unwrap())?;
 
         Ok(())
     }
+
+    async fn find_by_cpf_including_deleted(
+        &self,
+        input_cpf: Cpf,
+    ) -> Result<Option<Patient>, RepositoryError> {
+        let patient = patients
+            .filter(cpf.eq(input_cpf.as_str()))
+            .order((deleted_at.desc().nulls_first(), id.desc()))
+            .first::<Patient>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(patient)
+    }
+
+    async fn anonymize(&self, input_id: i32) -> Result<Vec<String>, RepositoryError> {
+        let now = chrono::Local::now().naive_utc();
+
+        let storage_keys = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let (found_birth_date, found_deleted_at) = patients
+                    .filter(id.eq(input_id))
+                    .select((birth_date, deleted_at))
+                    .first::<(Option<NaiveDate>, Option<NaiveDateTime>)>(conn)?;
+
+                diesel::update(patients.filter(id.eq(input_id)))
+                    .set((
+                        name.eq("Anonymized patient"),
+                        // Not a valid CPF on purpose, so it can never collide
+                        // with or be mistaken for a real one.
+                        cpf.eq(format!("A{input_id:010}")),
+                        social_name.eq(None::<String>),
+                        birth_date.eq(found_birth_date
+                            .and_then(|date| NaiveDate::from_ymd_opt(date.year(), 1, 1))),
+                        gender_identity.eq(None::<String>),
+                        mother_name.eq(None::<String>),
+                        cns.eq(None::<String>),
+                        email.eq(None::<String>),
+                        phones.eq(Vec::<String>::new()),
+                    ))
+                    .execute(conn)?;
+
+                diesel::update(patients.filter(id.eq(input_id)))
+                    .set((
+                        cep.eq(None::<String>),
+                        street.eq(None::<String>),
+                        address_number.eq(None::<String>),
+                        address_complement.eq(None::<String>),
+                        neighborhood.eq(None::<String>),
+                        city.eq(None::<String>),
+                        anonymized_at.eq(now),
+                        deleted_at.eq(found_deleted_at.unwrap_or(now)),
+                    ))
+                    .execute(conn)?;
+
+                diesel::update(
+                    schema::appointments::table
+                        .filter(schema::appointments::patient_id.eq(input_id)),
+                )
+                .set((
+                    schema::appointments::notes.eq(None::<String>),
+                    schema::appointments::cancellation_reason.eq(None::<String>),
+                    schema::appointments::reschedule_reason.eq(None::<String>),
+                ))
+                .execute(conn)?;
+
+                // The append-only trigger lets the notes of an anonymized
+                // patient lose their free text, and nothing else.
+                diesel::update(
+                    schema::encounters::table.filter(schema::encounters::patient_id.eq(input_id)),
+                )
+                .set((
+                    schema::encounters::chief_complaint.eq(ANONYMIZED_TEXT),
+                    schema::encounters::history.eq(None::<String>),
+                    schema::encounters::physical_exam.eq(None::<String>),
+                    schema::encounters::assessment.eq(None::<String>),
+                    schema::encounters::plan.eq(None::<String>),
+                ))
+                .execute(conn)?;
+
+                // Amendments must keep a reason.
+                diesel::update(
+                    schema::encounters::table
+                        .filter(schema::encounters::patient_id.eq(input_id))
+                        .filter(schema::encounters::amendment_reason.is_not_null()),
+                )
+                .set(schema::encounters::amendment_reason.eq(ANONYMIZED_TEXT))
+                .execute(conn)?;
+
+                diesel::update(
+                    schema::prescriptions::table
+                        .filter(schema::prescriptions::patient_id.eq(input_id)),
+                )
+                .set((
+                    schema::prescriptions::notes.eq(None::<String>),
+                    schema::prescriptions::allergy_override_reason.eq(None::<String>),
+                ))
+                .execute(conn)?;
+
+                let exam_order_ids = diesel::update(
+                    schema::exam_orders::table.filter(schema::exam_orders::patient_id.eq(input_id)),
+                )
+                .set((
+                    schema::exam_orders::clinical_indication.eq(None::<String>),
+                    schema::exam_orders::result_file_name.eq(None::<String>),
+                    schema::exam_orders::result_file_content_type.eq(None::<String>),
+                    schema::exam_orders::result_file_size.eq(None::<i32>),
+                ))
+                .returning(schema::exam_orders::id)
+                .get_results::<i32>(conn)?;
+
+                diesel::delete(
+                    schema::exam_result_files::table
+                        .filter(schema::exam_result_files::exam_order_id.eq_any(exam_order_ids)),
+                )
+                .execute(conn)?;
+
+                diesel::delete(
+                    schema::documents::table.filter(schema::documents::patient_id.eq(input_id)),
+                )
+                .returning(schema::documents::storage_key)
+                .get_results::<String>(conn)
+            })?;
+
+        Ok(storage_keys)
+    }
+
+    async fn merge(
+        &self,
+        surviving_id: i32,
+        duplicate_id: i32,
+        merged_by: String,
+    ) -> Result<PatientMergeReport, RepositoryError> {
+        let now = chrono::Local::now().naive_utc();
+
+        let report = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let moved_appointment_ids = diesel::update(
+                    schema::appointments::table
+                        .filter(schema::appointments::patient_id.eq(duplicate_id)),
+                )
+                .set(schema::appointments::patient_id.eq(surviving_id))
+                .returning(schema::appointments::id)
+                .get_results::<i32>(conn)?;
+
+                // Only one consent per purpose may be active, so the
+                // survivor's ones win over the duplicate's.
+                let surviving_purposes = schema::consents::table
+                    .filter(schema::consents::patient_id.eq(surviving_id))
+                    .filter(schema::consents::revoked_at.is_null())
+                    .select(schema::consents::purpose)
+                    .load::<String>(conn)?;
+
+                let revoked_consent_ids = diesel::update(
+                    schema::consents::table
+                        .filter(schema::consents::patient_id.eq(duplicate_id))
+                        .filter(schema::consents::revoked_at.is_null())
+                        .filter(schema::consents::purpose.eq_any(surviving_purposes)),
+                )
+                .set((
+                    schema::consents::revoked_at.eq(now),
+                    schema::consents::revoked_by.eq(&merged_by),
+                ))
+                .returning(schema::consents::id)
+                .get_results::<i32>(conn)?;
+
+                let moved_consent_ids = diesel::update(
+                    schema::consents::table.filter(schema::consents::patient_id.eq(duplicate_id)),
+                )
+                .set(schema::consents::patient_id.eq(surviving_id))
+                .returning(schema::consents::id)
+                .get_results::<i32>(conn)?;
+
+                let moved_encounter_ids = diesel::update(
+                    schema::encounters::table
+                        .filter(schema::encounters::patient_id.eq(duplicate_id)),
+                )
+                .set(schema::encounters::patient_id.eq(surviving_id))
+                .returning(schema::encounters::id)
+                .get_results::<i32>(conn)?;
+
+                let surviving_substances = schema::allergies::table
+                    .filter(schema::allergies::patient_id.eq(surviving_id))
+                    .select(lower(schema::allergies::substance))
+                    .load::<String>(conn)?;
+
+                let moved_allergy_ids = diesel::update(
+                    schema::allergies::table
+                        .filter(schema::allergies::patient_id.eq(duplicate_id))
+                        .filter(lower(schema::allergies::substance).ne_all(surviving_substances)),
+                )
+                .set(schema::allergies::patient_id.eq(surviving_id))
+                .returning(schema::allergies::id)
+                .get_results::<i32>(conn)?;
+
+                let surviving_codes = schema::problems::table
+                    .filter(schema::problems::patient_id.eq(surviving_id))
+                    .filter(schema::problems::resolved_at.is_null())
+                    .select(schema::problems::icd10_code)
+                    .load::<String>(conn)?;
+
+                let moved_problem_ids = diesel::update(
+                    schema::problems::table
+                        .filter(schema::problems::patient_id.eq(duplicate_id))
+                        .filter(
+                            schema::problems::resolved_at
+                                .is_not_null()
+                                .or(schema::problems::icd10_code.ne_all(surviving_codes)),
+                        ),
+                )
+                .set(schema::problems::patient_id.eq(surviving_id))
+                .returning(schema::problems::id)
+                .get_results::<i32>(conn)?;
+
+                let moved_prescription_ids = diesel::update(
+                    schema::prescriptions::table
+                        .filter(schema::prescriptions::patient_id.eq(duplicate_id)),
+                )
+                .set(schema::prescriptions::patient_id.eq(surviving_id))
+                .returning(schema::prescriptions::id)
+                .get_results::<i32>(conn)?;
+
+                let moved_exam_order_ids = diesel::update(
+                    schema::exam_orders::table
+                        .filter(schema::exam_orders::patient_id.eq(duplicate_id)),
+                )
+                .set(schema::exam_orders::patient_id.eq(surviving_id))
+                .returning(schema::exam_orders::id)
+                .get_results::<i32>(conn)?;
+
+                let moved_document_ids = diesel::update(
+                    schema::documents::table.filter(schema::documents::patient_id.eq(duplicate_id)),
+                )
+                .set(schema::documents::patient_id.eq(surviving_id))
+                .returning(schema::documents::id)
+                .get_results::<i32>(conn)?;
+
+                let moved_admission_ids = diesel::update(
+                    schema::admissions::table
+                        .filter(schema::admissions::patient_id.eq(duplicate_id)),
+                )
+                .set(schema::admissions::patient_id.eq(surviving_id))
+                .returning(schema::admissions::id)
+                .get_results::<i32>(conn)?;
+
+                diesel::update(
+                    patients
+                        .filter(id.eq(duplicate_id))
+                        .filter(deleted_at.is_null()),
+                )
+                .set((
+                    merged_into_id.eq(surviving_id),
+                    merged_at.eq(now),
+                    deleted_at.eq(now),
+                ))
+                .execute(conn)?;
+
+                Ok(PatientMergeReport {
+                    surviving_patient_id: surviving_id,
+                    duplicate_patient_id: duplicate_id,
+                    moved_appointment_ids,
+                    moved_consent_ids,
+                    moved_encounter_ids,
+                    moved_allergy_ids,
+                    moved_problem_ids,
+                    moved_prescription_ids,
+                    moved_exam_order_ids,
+                    moved_document_ids,
+                    moved_admission_ids,
+                    revoked_consent_ids,
+                })
+            })?;
+
+        Ok(report)
+    }
+
+    async fn search(
+        &self,
+        input_name: Option<String>,
+        pagination: Pagination,
+    ) -> Result<Page<Patient>, RepositoryError> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let total = patients_named_like(&input_name).count().get_result(conn)?;
+
+        let items = patients_named_like(&input_name)
+            .order((name.asc(), id.asc()))
+            .offset(pagination.offset())
+            .limit(pagination.limit)
+            .load::<Patient>(conn)?;
+
+        Ok(Page {
+            items,
+            total,
+            pagination,
+        })
+    }
 }
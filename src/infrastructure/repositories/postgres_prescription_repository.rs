@@ -0,0 +1,125 @@
+use crate::{
+    domain::{
+        entities::prescription::{Prescription, PrescriptionItem},
+        errors::repository_error::RepositoryError,
+        repositories::prescription_repository::PrescriptionRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::{prescription_items, prescriptions},
+};
+use async_trait::async_trait;
+use diesel::{pg::PgConnection, prelude::*};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresPrescriptionRepository {
+    pool: DBPool,
+}
+
+impl PostgresPrescriptionRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PrescriptionRepository for Arc<PostgresPrescriptionRepository> {
+    async fn save(
+        &self,
+        prescription: &Prescription,
+        items: &[PrescriptionItem],
+    ) -> Result<(Prescription, Vec<PrescriptionItem>), RepositoryError> {
+        let saved = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let inserted_prescription = diesel::insert_into(prescriptions::table)
+                    .values(prescription.clone())
+                    .get_result::<Prescription>(conn)?;
+
+                let prescription_id: Option<i32> = inserted_prescription.id.clone().into();
+                let prescription_id = prescription_id.unwrap_or_default();
+
+                let inserted_items = diesel::insert_into(prescription_items::table)
+                    .values(
+                        items
+                            .iter()
+                            .map(|item| PrescriptionItem {
+                                prescription_id,
+                                ..item.clone()
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .get_results::<PrescriptionItem>(conn)?;
+
+                Ok((inserted_prescription, inserted_items))
+            })?;
+
+        Ok(saved)
+    }
+
+    async fn find_by_id(
+        &self,
+        input_id: i32,
+    ) -> Result<Option<(Prescription, Vec<PrescriptionItem>)>, RepositoryError> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let prescription = prescriptions::table
+            .filter(prescriptions::id.eq(input_id))
+            .first::<Prescription>(conn)
+            .optional()?;
+
+        match prescription {
+            Some(prescription) => Ok(with_items(conn, vec![prescription])?.pop()),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_patient_id(
+        &self,
+        input_patient_id: i32,
+    ) -> Result<Vec<(Prescription, Vec<PrescriptionItem>)>, RepositoryError> {
+        let conn = &mut self.pool.get().unwrap();
+
+        let found_prescriptions = prescriptions::table
+            .filter(prescriptions::patient_id.eq(input_patient_id))
+            .order((prescriptions::issued_at.desc(), prescriptions::id.desc()))
+            .load::<Prescription>(conn)?;
+
+        Ok(with_items(conn, found_prescriptions)?)
+    }
+}
+
+/// Loads the items of every prescription with a single query.
+fn with_items(
+    conn: &mut PgConnection,
+    found_prescriptions: Vec<Prescription>,
+) -> Result<Vec<(Prescription, Vec<PrescriptionItem>)>, diesel::result::Error> {
+    let prescription_ids: Vec<i32> = found_prescriptions
+        .iter()
+        .filter_map(|prescription| prescription.id.clone().into())
+        .collect();
+
+    let items = prescription_items::table
+        .filter(prescription_items::prescription_id.eq_any(prescription_ids))
+        .order((
+            prescription_items::prescription_id.asc(),
+            prescription_items::position.asc(),
+        ))
+        .load::<PrescriptionItem>(conn)?;
+
+    Ok(found_prescriptions
+        .into_iter()
+        .map(|prescription| {
+            let prescription_id: Option<i32> = prescription.id.clone().into();
+            let prescription_items = items
+                .iter()
+                .filter(|item| Some(item.prescription_id) == prescription_id)
+                .cloned()
+                .collect();
+
+            (prescription, prescription_items)
+        })
+        .collect())
+}
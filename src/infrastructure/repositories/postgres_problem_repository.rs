@@ -0,0 +1,86 @@
+use crate::{
+    domain::{
+        entities::problem::Problem, errors::repository_error::RepositoryError,
+        repositories::problem_repository::ProblemRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::problems::dsl::{id, onset_date, patient_id, problems, resolved_at},
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresProblemRepository {
+    pool: DBPool,
+}
+
+impl PostgresProblemRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProblemRepository for Arc<PostgresProblemRepository> {
+    async fn save(&self, problem: &Problem) -> Result<Problem, RepositoryError> {
+        let inserted_problem = diesel::insert_into(problems)
+            .values(problem.clone())
+            .get_result::<Problem>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_problem)
+    }
+
+    async fn update(&self, problem: &Problem) -> Result<Problem, RepositoryError> {
+        let problem_id: Option<i32> = problem.id.clone().into();
+        let problem_id = problem_id.unwrap_or(0);
+
+        let updated_problem = diesel::update(problems)
+            .filter(id.eq(problem_id))
+            .set(problem.clone())
+            .get_result::<Problem>(&mut self.pool.get().unwrap())?;
+
+        Ok(updated_problem)
+    }
+
+    async fn find_by_id(
+        &self,
+        input_patient_id: i32,
+        input_id: i32,
+    ) -> Result<Option<Problem>, RepositoryError> {
+        let problem = problems
+            .filter(id.eq(input_id))
+            .filter(patient_id.eq(input_patient_id))
+            .first::<Problem>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(problem)
+    }
+
+    async fn find_by_patient_id(
+        &self,
+        input_patient_id: i32,
+    ) -> Result<Vec<Problem>, RepositoryError> {
+        let found_problems = problems
+            .filter(patient_id.eq(input_patient_id))
+            .order((
+                resolved_at.desc().nulls_first(),
+                onset_date.desc().nulls_last(),
+                id.desc(),
+            ))
+            .load::<Problem>(&mut self.pool.get().unwrap())?;
+
+        Ok(found_problems)
+    }
+
+    async fn delete(&self, input_patient_id: i32, input_id: i32) -> Result<bool, RepositoryError> {
+        let deleted_rows = diesel::delete(
+            problems
+                .filter(id.eq(input_id))
+                .filter(patient_id.eq(input_patient_id)),
+        )
+        .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(deleted_rows > 0)
+    }
+}
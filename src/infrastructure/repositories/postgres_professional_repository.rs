@@ -0,0 +1,75 @@
+use crate::{
+    domain::{
+        entities::professional::Professional, errors::repository_error::RepositoryError,
+        repositories::professional_repository::ProfessionalRepository, value_objects::id::ID,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::{
+        self,
+        professionals::dsl::{council, council_state, id, license_number, professionals},
+    },
+};
+use async_trait::async_trait;
+use diesel::{dsl::exists, prelude::*, select};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresProfessionalRepository {
+    pool: DBPool,
+}
+
+impl PostgresProfessionalRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProfessionalRepository for Arc<PostgresProfessionalRepository> {
+    async fn exists_by_license(
+        &self,
+        input_council: &str,
+        input_license_number: &str,
+        input_council_state: &str,
+    ) -> Result<bool, RepositoryError> {
+        let exists_by_license = select(exists(
+            professionals
+                .filter(council.eq(input_council))
+                .filter(license_number.eq(input_license_number))
+                .filter(council_state.eq(input_council_state)),
+        ))
+        .get_result(&mut self.pool.get().unwrap())?;
+
+        Ok(exists_by_license)
+    }
+
+    async fn save(&self, professional: &Professional) -> Result<i32, RepositoryError> {
+        let inserted_professional_id = diesel::insert_into(schema::professionals::table)
+            .values(professional.clone())
+            .returning(id)
+            .get_result(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_professional_id)
+    }
+
+    async fn find_by_id(&self, input_id: i32) -> Result<Option<Professional>, RepositoryError> {
+        let professional = professionals
+            .filter(id.eq(input_id))
+            .first::<Professional>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(professional)
+    }
+
+    async fn update(&self, professional: &Professional) -> Result<Professional, RepositoryError> {
+        if let ID::Existing(input_id) = professional.id {
+            let updated_professional = diesel::update(professionals.filter(id.eq(input_id)))
+                .set(professional.clone())
+                .get_result(&mut self.pool.get().unwrap())?;
+
+            return Ok(updated_professional);
+        }
+
+        Ok(professional.clone())
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    domain::{
+        entities::{
+            professional_schedule::ProfessionalSchedule, schedule_exception::ScheduleException,
+        },
+        errors::repository_error::RepositoryError,
+        repositories::schedule_repository::ScheduleRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::{professional_schedules, schedule_exceptions},
+};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresScheduleRepository {
+    pool: DBPool,
+}
+
+impl PostgresScheduleRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ScheduleRepository for Arc<PostgresScheduleRepository> {
+    async fn save_schedule(
+        &self,
+        schedule: &ProfessionalSchedule,
+    ) -> Result<ProfessionalSchedule, RepositoryError> {
+        let inserted_schedule = diesel::insert_into(professional_schedules::table)
+            .values(schedule.clone())
+            .get_result::<ProfessionalSchedule>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_schedule)
+    }
+
+    async fn find_schedules_by_professional_id(
+        &self,
+        input_professional_id: i32,
+    ) -> Result<Vec<ProfessionalSchedule>, RepositoryError> {
+        let schedules = professional_schedules::table
+            .filter(professional_schedules::professional_id.eq(input_professional_id))
+            .order((
+                professional_schedules::weekday.asc(),
+                professional_schedules::starts_at.asc(),
+            ))
+            .load::<ProfessionalSchedule>(&mut self.pool.get().unwrap())?;
+
+        Ok(schedules)
+    }
+
+    async fn delete_schedule(
+        &self,
+        input_professional_id: i32,
+        schedule_id: i32,
+    ) -> Result<bool, RepositoryError> {
+        let deleted_rows = diesel::delete(
+            professional_schedules::table
+                .filter(professional_schedules::id.eq(schedule_id))
+                .filter(professional_schedules::professional_id.eq(input_professional_id)),
+        )
+        .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(deleted_rows > 0)
+    }
+
+    async fn save_exception(
+        &self,
+        exception: &ScheduleException,
+    ) -> Result<ScheduleException, RepositoryError> {
+        let inserted_exception = diesel::insert_into(schedule_exceptions::table)
+            .values(exception.clone())
+            .get_result::<ScheduleException>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_exception)
+    }
+
+    async fn find_exceptions_by_professional_id_between(
+        &self,
+        input_professional_id: i32,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<ScheduleException>, RepositoryError> {
+        let exceptions = schedule_exceptions::table
+            .filter(schedule_exceptions::professional_id.eq(input_professional_id))
+            .filter(schedule_exceptions::starts_at.lt(to))
+            .filter(schedule_exceptions::ends_at.gt(from))
+            .order(schedule_exceptions::starts_at.asc())
+            .load::<ScheduleException>(&mut self.pool.get().unwrap())?;
+
+        Ok(exceptions)
+    }
+
+    async fn delete_exception(
+        &self,
+        input_professional_id: i32,
+        exception_id: i32,
+    ) -> Result<bool, RepositoryError> {
+        let deleted_rows = diesel::delete(
+            schedule_exceptions::table
+                .filter(schedule_exceptions::id.eq(exception_id))
+                .filter(schedule_exceptions::professional_id.eq(input_professional_id)),
+        )
+        .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(deleted_rows > 0)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    domain::{
+        entities::session::Session, errors::repository_error::RepositoryError,
+        repositories::session_repository::SessionRepository, value_objects::id::ID,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::{
+        self,
+        sessions::dsl::{admin_id, id, refresh_token_hash, revoked_at, sessions},
+    },
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+pub struct PostgresSessionRepository {
+    pool: DBPool,
+}
+
+impl PostgresSessionRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for Arc<PostgresSessionRepository> {
+    async fn save(&self, session: &Session) -> Result<i32, RepositoryError> {
+        let inserted_session_id = diesel::insert_into(schema::sessions::table)
+            .values(session.clone())
+            .returning(id)
+            .get_result(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_session_id)
+    }
+
+    async fn find_by_id(&self, input_id: i32) -> Result<Option<Session>, RepositoryError> {
+        let session = sessions
+            .filter(id.eq(input_id))
+            .first::<Session>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(session)
+    }
+
+    async fn rotate(
+        &self,
+        session: &Session,
+        previous_hash: String,
+    ) -> Result<Option<Session>, RepositoryError> {
+        let ID::Existing(session_id) = session.id else {
+            return Ok(None);
+        };
+
+        let rotated_session = diesel::update(
+            sessions
+                .filter(id.eq(session_id))
+                .filter(refresh_token_hash.eq(previous_hash))
+                .filter(revoked_at.is_null()),
+        )
+        .set(session.clone())
+        .get_result::<Session>(&mut self.pool.get().unwrap())
+        .optional()?;
+
+        Ok(rotated_session)
+    }
+
+    async fn revoke(&self, input_id: i32) -> Result<(), RepositoryError> {
+        diesel::update(
+            sessions
+                .filter(id.eq(input_id))
+                .filter(revoked_at.is_null()),
+        )
+        .set(revoked_at.eq(chrono::Local::now().naive_utc()))
+        .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_by_admin_id(&self, input_admin_id: i32) -> Result<usize, RepositoryError> {
+        let revoked = diesel::update(
+            sessions
+                .filter(admin_id.eq(input_admin_id))
+                .filter(revoked_at.is_null()),
+        )
+        .set(revoked_at.eq(chrono::Local::now().naive_utc()))
+        .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(revoked)
+    }
+}
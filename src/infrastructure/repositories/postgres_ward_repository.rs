@@ -0,0 +1,119 @@
+use crate::{
+    domain::{
+        entities::ward::{Bed, Room, Ward},
+        errors::repository_error::RepositoryError,
+        repositories::ward_repository::WardRepository,
+        value_objects::bed_status::BedStatus,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::{beds, rooms, wards},
+};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresWardRepository {
+    pool: DBPool,
+}
+
+impl PostgresWardRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WardRepository for Arc<PostgresWardRepository> {
+    async fn save_ward(&self, ward: &Ward) -> Result<Ward, RepositoryError> {
+        let inserted_ward = diesel::insert_into(wards::table)
+            .values(ward.clone())
+            .get_result::<Ward>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_ward)
+    }
+
+    async fn find_ward_by_id(&self, input_id: i32) -> Result<Option<Ward>, RepositoryError> {
+        let ward = wards::table
+            .filter(wards::id.eq(input_id))
+            .first::<Ward>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(ward)
+    }
+
+    async fn find_wards(&self) -> Result<Vec<Ward>, RepositoryError> {
+        let found_wards = wards::table
+            .order((wards::name.asc(), wards::id.asc()))
+            .load::<Ward>(&mut self.pool.get().unwrap())?;
+
+        Ok(found_wards)
+    }
+
+    async fn save_room(&self, room: &Room) -> Result<Room, RepositoryError> {
+        let inserted_room = diesel::insert_into(rooms::table)
+            .values(room.clone())
+            .get_result::<Room>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_room)
+    }
+
+    async fn find_room_by_id(&self, input_id: i32) -> Result<Option<Room>, RepositoryError> {
+        let room = rooms::table
+            .filter(rooms::id.eq(input_id))
+            .first::<Room>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(room)
+    }
+
+    async fn find_rooms(&self) -> Result<Vec<Room>, RepositoryError> {
+        let found_rooms = rooms::table
+            .order((rooms::number.asc(), rooms::id.asc()))
+            .load::<Room>(&mut self.pool.get().unwrap())?;
+
+        Ok(found_rooms)
+    }
+
+    async fn save_bed(&self, bed: &Bed) -> Result<Bed, RepositoryError> {
+        let inserted_bed = diesel::insert_into(beds::table)
+            .values(bed.clone())
+            .get_result::<Bed>(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_bed)
+    }
+
+    async fn find_bed_by_id(&self, input_id: i32) -> Result<Option<Bed>, RepositoryError> {
+        let bed = beds::table
+            .filter(beds::id.eq(input_id))
+            .first::<Bed>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(bed)
+    }
+
+    async fn find_beds(&self) -> Result<Vec<Bed>, RepositoryError> {
+        let found_beds = beds::table
+            .order((beds::label.asc(), beds::id.asc()))
+            .load::<Bed>(&mut self.pool.get().unwrap())?;
+
+        Ok(found_beds)
+    }
+
+    async fn update_bed_status(
+        &self,
+        bed: &Bed,
+        from: BedStatus,
+    ) -> Result<Option<Bed>, RepositoryError> {
+        let bed_id: Option<i32> = bed.id.clone().into();
+
+        let updated_bed = diesel::update(beds::table)
+            .filter(beds::id.eq(bed_id.unwrap_or(0)))
+            .filter(beds::status.eq(from.as_str()))
+            .set(beds::status.eq(bed.status.as_str()))
+            .get_result::<Bed>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(updated_bed)
+    }
+}
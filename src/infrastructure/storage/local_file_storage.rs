@@ -0,0 +1,166 @@
+use std::{
+    io::ErrorKind,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use tokio::{fs, io::AsyncReadExt};
+
+use crate::domain::{
+    errors::storage_error::StorageError,
+    repositories::file_storage::{FileStorage, FileStream},
+};
+
+const DEFAULT_ROOT: &str = "storage/documents";
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Stores files under a directory of the local filesystem, one file per key.
+#[derive(Clone)]
+pub struct LocalFileStorage {
+    root: PathBuf,
+}
+
+impl LocalFileStorage {
+    pub fn new() -> Self {
+        Self::with_root(std::env::var("DOCUMENTS_DIR").unwrap_or(DEFAULT_ROOT.to_string()))
+    }
+
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Only plain relative paths are accepted, so a key can never point
+    /// outside of the root directory.
+    fn path_for(&self, key: &str) -> Result<PathBuf, StorageError> {
+        let relative = Path::new(key);
+
+        if key.is_empty()
+            || !relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(StorageError::InvalidKey(key.to_string()));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+impl Default for LocalFileStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl FileStorage for Arc<LocalFileStorage> {
+    async fn put(&self, key: &str, content: Vec<u8>) -> Result<(), StorageError> {
+        let path = self.path_for(key)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        // Readers never see a partially written file.
+        let mut partial_path = path.clone().into_os_string();
+        partial_path.push(".part");
+
+        fs::write(&partial_path, content).await?;
+        fs::rename(&partial_path, &path).await?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<FileStream, StorageError> {
+        let path = self.path_for(key)?;
+
+        let file = match fs::File::open(&path).await {
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                return Err(StorageError::NotFound(key.to_string()));
+            }
+            file => file?,
+        };
+
+        let chunks = futures::stream::try_unfold(file, |mut file| async move {
+            let mut chunk = vec![0; CHUNK_SIZE];
+            let read = file.read(&mut chunk).await?;
+
+            if read == 0 {
+                return Ok::<_, StorageError>(None);
+            }
+
+            chunk.truncate(read);
+
+            Ok(Some((chunk, file)))
+        });
+
+        Ok(Box::pin(chunks))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        match fs::remove_file(self.path_for(key)?).await {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use futures::TryStreamExt;
+
+    use crate::domain::{
+        errors::storage_error::StorageError, repositories::file_storage::FileStorage,
+    };
+
+    use super::LocalFileStorage;
+
+    fn make_storage(name: &str) -> Arc<LocalFileStorage> {
+        let root =
+            std::env::temp_dir().join(format!("local-file-storage-{name}-{}", std::process::id()));
+
+        Arc::new(LocalFileStorage::with_root(root))
+    }
+
+    #[tokio::test]
+    async fn put_get_and_delete() {
+        let storage = make_storage("round-trip");
+        let content: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+
+        storage.put("7/report", content.clone()).await.unwrap();
+
+        let chunks: Vec<Vec<u8>> = storage
+            .get("7/report")
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks.concat(), content);
+
+        storage.delete("7/report").await.unwrap();
+        storage.delete("7/report").await.unwrap();
+
+        assert_eq!(
+            storage.get("7/report").await.err(),
+            Some(StorageError::NotFound("7/report".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn put_escaping_key_error() {
+        let storage = make_storage("escaping-key");
+
+        for key in ["../outside", "/etc/passwd", "7/../../outside", ""] {
+            assert_eq!(
+                storage.put(key, b"%PDF-".to_vec()).await,
+                Err(StorageError::InvalidKey(key.to_string()))
+            );
+        }
+    }
+}
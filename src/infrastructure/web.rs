@@ -1,14 +1,40 @@
 use std::sync::Arc;
 
 use actix_web::{App, HttpServer, middleware::Logger, web};
-use log::info;
+use log::{info, warn};
 
 use crate::{
-    infrastructure::repositories::{
-        postgres_appointment_repository::PostgresAppointmentRepository,
-        postgres_patient_repository::PostgresPatientRepository,
+    application::{
+        security::jwt::{jwt, keys::JwtConfig},
+        use_cases::remove_default_admin::{DEFAULT_ADMIN_EMAIL, RemoveDefaultAdminUseCase},
+    },
+    domain::repositories::session_repository::SessionRepository,
+    infrastructure::{
+        db::connection::establish_connection,
+        repositories::{
+            postgres_admission_repository::PostgresAdmissionRepository,
+            postgres_allergy_repository::PostgresAllergyRepository,
+            postgres_appointment_repository::PostgresAppointmentRepository,
+            postgres_audit_repository::PostgresAuditRepository,
+            postgres_consent_repository::PostgresConsentRepository,
+            postgres_document_repository::PostgresDocumentRepository,
+            postgres_encounter_repository::PostgresEncounterRepository,
+            postgres_exam_order_repository::PostgresExamOrderRepository,
+            postgres_failed_login_repository::PostgresFailedLoginRepository,
+            postgres_patient_repository::PostgresPatientRepository,
+            postgres_prescription_repository::PostgresPrescriptionRepository,
+            postgres_problem_repository::PostgresProblemRepository,
+            postgres_professional_repository::PostgresProfessionalRepository,
+            postgres_schedule_repository::PostgresScheduleRepository,
+            postgres_session_repository::PostgresSessionRepository,
+            postgres_ward_repository::PostgresWardRepository,
+        },
+        storage::local_file_storage::LocalFileStorage,
+    },
+    presentation::{
+        errors::json_http_error::json_error_handler,
+        extractors::client_ip_extractor::TrustedProxies, routes,
     },
-    presentation::routes,
 };
 
 use super::repositories::postgres_admin_repository::PostgresAdminRepository;
@@ -17,28 +43,109 @@ pub struct AppState {
     pub patient_repo: Arc<PostgresPatientRepository>,
     pub appointment_repo: Arc<PostgresAppointmentRepository>,
     pub admin_repo: Arc<PostgresAdminRepository>,
+    pub professional_repo: Arc<PostgresProfessionalRepository>,
+    pub schedule_repo: Arc<PostgresScheduleRepository>,
+    pub audit_repo: Arc<PostgresAuditRepository>,
+    pub consent_repo: Arc<PostgresConsentRepository>,
+    pub encounter_repo: Arc<PostgresEncounterRepository>,
+    pub allergy_repo: Arc<PostgresAllergyRepository>,
+    pub problem_repo: Arc<PostgresProblemRepository>,
+    pub prescription_repo: Arc<PostgresPrescriptionRepository>,
+    pub exam_order_repo: Arc<PostgresExamOrderRepository>,
+    pub document_repo: Arc<PostgresDocumentRepository>,
+    pub file_storage: Arc<LocalFileStorage>,
+    pub ward_repo: Arc<PostgresWardRepository>,
+    pub admission_repo: Arc<PostgresAdmissionRepository>,
+    pub session_repo: Arc<PostgresSessionRepository>,
+    pub failed_login_repo: Arc<PostgresFailedLoginRepository>,
 }
 
 pub async fn run() -> std::io::Result<()> {
-    let patient_repo = Arc::new(PostgresPatientRepository::new());
-    let appointment_repo = Arc::new(PostgresAppointmentRepository::new());
-    let admin_repo = Arc::new(PostgresAdminRepository::new());
+    jwt::init(JwtConfig::from_env().map_err(|err| std::io::Error::other(err.to_string()))?);
+    let trusted_proxies = web::Data::new(
+        TrustedProxies::from_env()
+            .map_err(|err| std::io::Error::other(format!("TRUSTED_PROXIES: {err}")))?,
+    );
+
+    // One pool for every repository, so the connections it opens stay within
+    // what the database allows.
+    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL is missing");
+    let pool = establish_connection(&database_url);
+
+    let patient_repo = Arc::new(PostgresPatientRepository::new(pool.clone()));
+    let appointment_repo = Arc::new(PostgresAppointmentRepository::new(pool.clone()));
+    let admin_repo = Arc::new(PostgresAdminRepository::new(pool.clone()));
+    let professional_repo = Arc::new(PostgresProfessionalRepository::new(pool.clone()));
+    let schedule_repo = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+    let audit_repo = Arc::new(PostgresAuditRepository::new(pool.clone()));
+    let consent_repo = Arc::new(PostgresConsentRepository::new(pool.clone()));
+    let encounter_repo = Arc::new(PostgresEncounterRepository::new(pool.clone()));
+    let allergy_repo = Arc::new(PostgresAllergyRepository::new(pool.clone()));
+    let problem_repo = Arc::new(PostgresProblemRepository::new(pool.clone()));
+    let prescription_repo = Arc::new(PostgresPrescriptionRepository::new(pool.clone()));
+    let exam_order_repo = Arc::new(PostgresExamOrderRepository::new(pool.clone()));
+    let document_repo = Arc::new(PostgresDocumentRepository::new(pool.clone()));
+    let file_storage = Arc::new(LocalFileStorage::new());
+    let ward_repo = Arc::new(PostgresWardRepository::new(pool.clone()));
+    let admission_repo = Arc::new(PostgresAdmissionRepository::new(pool.clone()));
+    let session_repo = Arc::new(PostgresSessionRepository::new(pool.clone()));
+    let failed_login_repo = Arc::new(PostgresFailedLoginRepository::new(pool.clone()));
+    // The token extractors look sessions up without knowing about AppState.
+    let session_store: Arc<dyn SessionRepository> = Arc::new(session_repo.clone());
 
     let app_data = web::Data::new(AppState {
         patient_repo,
         appointment_repo,
         admin_repo,
+        professional_repo,
+        schedule_repo,
+        audit_repo,
+        consent_repo,
+        encounter_repo,
+        allergy_repo,
+        problem_repo,
+        prescription_repo,
+        exam_order_repo,
+        document_repo,
+        file_storage,
+        ward_repo,
+        admission_repo,
+        session_repo,
+        failed_login_repo,
     });
 
+    if std::env::var("APP_ENV").is_ok_and(|app_env| app_env == "production") {
+        let removed = RemoveDefaultAdminUseCase::new(
+            app_data.admin_repo.clone(),
+            app_data.audit_repo.clone(),
+        )
+        .execute()
+        .await
+        .map_err(|err| std::io::Error::other(err.to_string()))?;
+
+        if removed {
+            warn!("The seeded account {DEFAULT_ADMIN_EMAIL} was deactivated");
+        }
+    }
+
     info!("Starting...");
 
     HttpServer::new(move || {
         App::new()
             .app_data(app_data.clone())
+            .app_data(web::Data::from(session_store.clone()))
+            .app_data(trusted_proxies.clone())
+            .app_data(web::JsonConfig::default().error_handler(json_error_handler))
             .wrap(Logger::default())
             .configure(routes::patient_routes::patient_routes)
             .configure(routes::appointment_routes::appointment_routes)
             .configure(routes::admin_routes::admin_routes)
+            .configure(routes::professional_routes::professional_routes)
+            .configure(routes::encounter_routes::encounter_routes)
+            .configure(routes::prescription_routes::prescription_routes)
+            .configure(routes::exam_order_routes::exam_order_routes)
+            .configure(routes::ward_routes::ward_routes)
+            .configure(routes::admission_routes::admission_routes)
     })
     .bind("0.0.0.0:4000")
     .unwrap()
@@ -1,7 +1,96 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::security::refresh_token::TokenPair,
+    domain::{
+        entities::admin::Admin,
+        value_objects::{id::ID, role::Role},
+    },
+};
 
 #[derive(Deserialize)]
 pub struct LoginDTO {
     pub email: String,
     pub password: String,
 }
+
+#[derive(Deserialize)]
+pub struct RefreshDTO {
+    pub refresh_token: String,
+}
+
+#[derive(Serialize)]
+pub struct TokenPairDTO {
+    pub access_token: String,
+    pub token_type: &'static str,
+    /// Seconds until the access token expires.
+    pub expires_in: i64,
+    pub refresh_token: String,
+}
+
+impl From<TokenPair> for TokenPairDTO {
+    fn from(value: TokenPair) -> Self {
+        Self {
+            access_token: value.access_token,
+            token_type: "Bearer",
+            expires_in: value.expires_in,
+            refresh_token: value.refresh_token,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CreateUserDTO {
+    pub name: String,
+    pub email: String,
+    pub role: Role,
+    /// Temporary; the user must change it on first login.
+    pub password: String,
+}
+
+#[derive(Deserialize)]
+pub struct ChangePasswordDTO {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[derive(Deserialize)]
+pub struct ResetPasswordDTO {
+    pub temporary_password: String,
+}
+
+#[derive(Deserialize)]
+pub struct LinkProfessionalDTO {
+    /// `null` unlinks the account.
+    pub professional_id: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub struct LoadedUserDTO {
+    pub id: i32,
+    pub name: String,
+    pub email: String,
+    pub role: Role,
+    pub active: bool,
+    pub must_change_password: bool,
+    pub password_changed_at: String,
+    pub professional_id: Option<i32>,
+}
+
+impl From<Admin> for Option<LoadedUserDTO> {
+    fn from(value: Admin) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedUserDTO {
+                id,
+                name: value.name,
+                email: value.email,
+                role: value.role,
+                active: value.active,
+                must_change_password: value.must_change_password,
+                password_changed_at: value.password_changed_at.to_string(),
+                professional_id: value.professional_id,
+            }),
+            ID::New => None,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::admission::{Admission, BedOccupancy, current_bed_id},
+    value_objects::{cpf::Cpf, id::ID},
+};
+
+#[derive(Deserialize)]
+pub struct AdmitPatientDTO {
+    pub patient_cpf: Cpf,
+    pub bed_id: i32,
+    pub professional_id: Option<i32>,
+    pub reason: String,
+}
+
+#[derive(Deserialize)]
+pub struct TransferPatientDTO {
+    pub bed_id: i32,
+}
+
+#[derive(Default, Deserialize)]
+pub struct DischargePatientDTO {
+    pub summary: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct LoadedBedOccupancyDTO {
+    pub id: i32,
+    pub bed_id: i32,
+    pub started_by: String,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+}
+
+impl From<BedOccupancy> for Option<LoadedBedOccupancyDTO> {
+    fn from(value: BedOccupancy) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedBedOccupancyDTO {
+                id,
+                bed_id: value.bed_id,
+                started_by: value.started_by,
+                started_at: value.started_at.to_string(),
+                ended_at: value.ended_at.map(|ended_at| ended_at.to_string()),
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LoadedAdmissionDTO {
+    pub id: i32,
+    pub patient_id: i32,
+    pub professional_id: Option<i32>,
+    pub reason: String,
+    pub admitted_by: String,
+    pub admitted_at: String,
+    pub discharged_at: Option<String>,
+    pub discharged_by: Option<String>,
+    pub discharge_summary: Option<String>,
+    pub current_bed_id: Option<i32>,
+    /// Every bed the patient went through, oldest first.
+    pub occupancies: Vec<LoadedBedOccupancyDTO>,
+}
+
+impl LoadedAdmissionDTO {
+    pub fn new(admission: Admission, occupancies: Vec<BedOccupancy>) -> Option<Self> {
+        match admission.id {
+            ID::Existing(id) => Some(Self {
+                id,
+                patient_id: admission.patient_id,
+                professional_id: admission.professional_id,
+                reason: admission.reason,
+                admitted_by: admission.admitted_by,
+                admitted_at: admission.admitted_at.to_string(),
+                discharged_at: admission
+                    .discharged_at
+                    .map(|discharged_at| discharged_at.to_string()),
+                discharged_by: admission.discharged_by,
+                discharge_summary: admission.discharge_summary,
+                current_bed_id: current_bed_id(&occupancies),
+                occupancies: occupancies
+                    .into_iter()
+                    .filter_map(|occupancy| occupancy.into())
+                    .collect(),
+            }),
+            ID::New => None,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::allergy::Allergy,
+    value_objects::{allergy_severity::AllergySeverity, id::ID},
+};
+
+#[derive(Deserialize)]
+pub struct CreateAllergyDTO {
+    pub substance: String,
+    pub reaction: Option<String>,
+    pub severity: AllergySeverity,
+}
+
+#[derive(Default, Deserialize)]
+pub struct UpdateAllergyDTO {
+    pub substance: Option<String>,
+    pub reaction: Option<String>,
+    pub severity: Option<AllergySeverity>,
+}
+
+#[derive(Deserialize)]
+pub struct AllergyPath {
+    pub allergy_id: i32,
+}
+
+#[derive(Serialize)]
+pub struct LoadedAllergyDTO {
+    pub id: i32,
+    pub patient_id: i32,
+    pub substance: String,
+    pub reaction: Option<String>,
+    pub severity: AllergySeverity,
+    pub recorded_at: String,
+}
+
+impl From<Allergy> for Option<LoadedAllergyDTO> {
+    fn from(value: Allergy) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedAllergyDTO {
+                id,
+                patient_id: value.patient_id,
+                substance: value.substance,
+                reaction: value.reaction,
+                severity: value.severity,
+                recorded_at: value.recorded_at.to_string(),
+            }),
+            ID::New => None,
+        }
+    }
+}
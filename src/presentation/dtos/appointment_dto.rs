@@ -1,39 +1,76 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::{entities::appointment::Appointment, value_objects::id::ID};
+use crate::domain::{
+    entities::appointment::Appointment,
+    value_objects::{
+        appointment_status::AppointmentStatus, cpf::Cpf, id::ID, pagination::SortOrder,
+    },
+};
 
 #[derive(Deserialize)]
 pub struct BookAppointmentDTO {
-    pub patient_cpf: String,
+    pub patient_cpf: Cpf,
+    pub professional_id: Option<i32>,
     pub appointment_at: String,
+    pub duration_minutes: Option<i64>,
     pub specialty: String,
     pub notes: Option<String>,
+    #[serde(default)]
+    pub telemedicine: bool,
 }
 
 #[derive(Serialize)]
 pub struct LoadedAppointmentDTO {
     pub id: i32,
     pub patient_id: i32,
+    pub professional_id: Option<i32>,
     pub appointment_at: String,
+    pub ends_at: String,
     pub specialty: String,
     pub notes: Option<String>,
+    pub telemedicine: bool,
+    pub status: AppointmentStatus,
+    pub confirmed_at: Option<String>,
+    pub checked_in_at: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub no_show_at: Option<String>,
     pub canceled: bool,
     pub canceled_at: Option<String>,
     pub cancellation_reason: Option<String>,
+    pub previous_appointment_at: Option<String>,
+    pub rescheduled_at: Option<String>,
+    pub rescheduled_by: Option<String>,
+    pub reschedule_reason: Option<String>,
 }
 
 impl From<Appointment> for Option<LoadedAppointmentDTO> {
     fn from(value: Appointment) -> Self {
+        let canceled = value.is_canceled();
+
         match value.id {
             ID::Existing(id) => Self::Some(LoadedAppointmentDTO {
                 id,
                 patient_id: value.patient_id,
+                professional_id: value.professional_id,
                 appointment_at: value.appointment_at.to_string(),
+                ends_at: value.ends_at.to_string(),
                 specialty: value.specialty,
                 notes: value.notes,
-                canceled: value.canceled,
+                telemedicine: value.telemedicine,
+                status: value.status,
+                confirmed_at: value.confirmed_at.map(|dt| dt.to_string()),
+                checked_in_at: value.checked_in_at.map(|dt| dt.to_string()),
+                started_at: value.started_at.map(|dt| dt.to_string()),
+                completed_at: value.completed_at.map(|dt| dt.to_string()),
+                no_show_at: value.no_show_at.map(|dt| dt.to_string()),
+                canceled,
                 canceled_at: value.canceled_at.map(|dt| dt.to_string()),
                 cancellation_reason: value.cancellation_reason,
+                previous_appointment_at: value.previous_appointment_at.map(|dt| dt.to_string()),
+                rescheduled_at: value.rescheduled_at.map(|dt| dt.to_string()),
+                rescheduled_by: value.rescheduled_by,
+                reschedule_reason: value.reschedule_reason,
             }),
             ID::New => None,
         }
@@ -69,7 +106,38 @@ impl From<Vec<Appointment>> for LoadedAppointmentsDTO {
 
 #[derive(Deserialize)]
 pub struct CancelAppointmentDTO {
-    pub patient_cpf: String,
+    pub patient_cpf: Cpf,
     pub appointment_at: String,
     pub cancellation_reason: Option<String>,
 }
+
+#[derive(Deserialize)]
+pub struct CancelAppointmentByIdDTO {
+    pub cancellation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct AppointmentLookupQueryDTO {
+    pub patient_cpf: Cpf,
+    pub appointment_at: String,
+}
+
+#[derive(Deserialize)]
+pub struct RescheduleAppointmentDTO {
+    pub appointment_at: String,
+    pub duration_minutes: Option<i64>,
+    pub reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct SearchAppointmentsQueryDTO {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub specialty: Option<String>,
+    pub professional_id: Option<i32>,
+    pub status: Option<AppointmentStatus>,
+    pub patient_cpf: Option<Cpf>,
+    pub sort: Option<SortOrder>,
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::consent::Consent,
+    value_objects::{consent_channel::ConsentChannel, consent_purpose::ConsentPurpose, id::ID},
+};
+
+#[derive(Deserialize)]
+pub struct GrantConsentDTO {
+    pub purpose: ConsentPurpose,
+    pub term_version: String,
+    pub channel: ConsentChannel,
+}
+
+#[derive(Deserialize)]
+pub struct ConsentPurposePath {
+    pub purpose: ConsentPurpose,
+}
+
+#[derive(Serialize)]
+pub struct LoadedConsentDTO {
+    pub id: i32,
+    pub patient_id: i32,
+    pub purpose: ConsentPurpose,
+    pub term_version: String,
+    pub channel: ConsentChannel,
+    pub active: bool,
+    pub granted_at: String,
+    pub granted_by: String,
+    pub revoked_at: Option<String>,
+    pub revoked_by: Option<String>,
+}
+
+impl From<Consent> for Option<LoadedConsentDTO> {
+    fn from(value: Consent) -> Self {
+        let active = value.is_active();
+
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedConsentDTO {
+                id,
+                patient_id: value.patient_id,
+                purpose: value.purpose,
+                term_version: value.term_version,
+                channel: value.channel,
+                active,
+                granted_at: value.granted_at.to_string(),
+                granted_by: value.granted_by,
+                revoked_at: value.revoked_at.map(|dt| dt.to_string()),
+                revoked_by: value.revoked_by,
+            }),
+            ID::New => None,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::document::Document,
+    value_objects::{document_category::DocumentCategory, id::ID},
+};
+
+/// A buffered multipart upload, see
+/// [`DocumentUpload`](crate::presentation::extractors::document_extractor::DocumentUpload).
+pub struct UploadDocumentDTO {
+    pub category: DocumentCategory,
+    pub file_name: String,
+    pub content_type: String,
+    pub description: Option<String>,
+    pub content: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+pub struct DocumentPath {
+    pub document_id: i32,
+}
+
+#[derive(Serialize)]
+pub struct LoadedDocumentDTO {
+    pub id: i32,
+    pub patient_id: i32,
+    pub category: DocumentCategory,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub description: Option<String>,
+    pub uploaded_by: String,
+    pub uploaded_at: String,
+}
+
+impl From<Document> for Option<LoadedDocumentDTO> {
+    fn from(value: Document) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedDocumentDTO {
+                id,
+                patient_id: value.patient_id,
+                category: value.category,
+                file_name: value.file_name,
+                content_type: value.content_type,
+                size_bytes: value.size_bytes,
+                sha256: value.sha256,
+                description: value.description,
+                uploaded_by: value.uploaded_by,
+                uploaded_at: value.uploaded_at.to_string(),
+            }),
+            ID::New => None,
+        }
+    }
+}
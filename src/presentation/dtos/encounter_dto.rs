@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{entities::encounter::Encounter, value_objects::id::ID};
+
+/// The structured sections of a clinical note.
+#[derive(Clone, Default, Deserialize)]
+pub struct EncounterSectionsDTO {
+    pub chief_complaint: String,
+    pub history: Option<String>,
+    pub physical_exam: Option<String>,
+    pub assessment: Option<String>,
+    pub plan: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct AmendEncounterDTO {
+    #[serde(flatten)]
+    pub sections: EncounterSectionsDTO,
+    pub amendment_reason: String,
+}
+
+#[derive(Serialize)]
+pub struct LoadedEncounterDTO {
+    pub id: i32,
+    pub appointment_id: i32,
+    pub patient_id: i32,
+    pub professional_id: i32,
+    pub chief_complaint: String,
+    pub history: Option<String>,
+    pub physical_exam: Option<String>,
+    pub assessment: Option<String>,
+    pub plan: Option<String>,
+    pub amends_id: Option<i32>,
+    pub amendment_reason: Option<String>,
+    pub recorded_by: String,
+    pub recorded_at: String,
+    pub author_id: i32,
+}
+
+impl From<Encounter> for Option<LoadedEncounterDTO> {
+    fn from(value: Encounter) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedEncounterDTO {
+                id,
+                appointment_id: value.appointment_id,
+                patient_id: value.patient_id,
+                professional_id: value.professional_id,
+                chief_complaint: value.chief_complaint,
+                history: value.history,
+                physical_exam: value.physical_exam,
+                assessment: value.assessment,
+                plan: value.plan,
+                amends_id: value.amends_id,
+                amendment_reason: value.amendment_reason,
+                recorded_by: value.recorded_by,
+                recorded_at: value.recorded_at.to_string(),
+                author_id: value.author_id,
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+/// A note with every amendment made to it, oldest first.
+#[derive(Serialize)]
+pub struct LoadedEncounterWithAmendmentsDTO {
+    #[serde(flatten)]
+    pub encounter: LoadedEncounterDTO,
+    pub amendments: Vec<LoadedEncounterDTO>,
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::exam_order::{ExamOrder, ExamResult},
+    value_objects::{cpf::Cpf, exam_kind::ExamKind, exam_order_status::ExamOrderStatus, id::ID},
+};
+
+#[derive(Deserialize)]
+pub struct OrderExamDTO {
+    pub patient_cpf: Cpf,
+    pub appointment_id: Option<i32>,
+    pub kind: ExamKind,
+    pub exam_name: String,
+    pub clinical_indication: Option<String>,
+}
+
+/// A single measured value. `abnormal` overrides the flag computed from the
+/// reference range, for results a range cannot judge.
+#[derive(Clone, Default, Deserialize)]
+pub struct ExamResultDTO {
+    pub analyte: String,
+    pub value: String,
+    pub unit: Option<String>,
+    pub reference_low: Option<f64>,
+    pub reference_high: Option<f64>,
+    pub reference_text: Option<String>,
+    pub abnormal: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct RecordExamResultsDTO {
+    pub results: Vec<ExamResultDTO>,
+}
+
+#[derive(Deserialize)]
+pub struct ExamResultFileQueryDTO {
+    pub file_name: String,
+}
+
+#[derive(Serialize)]
+pub struct LoadedExamResultDTO {
+    pub id: i32,
+    pub analyte: String,
+    pub value: String,
+    pub unit: Option<String>,
+    pub reference_low: Option<f64>,
+    pub reference_high: Option<f64>,
+    pub reference_text: Option<String>,
+    pub abnormal: bool,
+    pub recorded_at: String,
+}
+
+impl From<ExamResult> for Option<LoadedExamResultDTO> {
+    fn from(value: ExamResult) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedExamResultDTO {
+                id,
+                analyte: value.analyte,
+                value: value.value,
+                unit: value.unit,
+                reference_low: value.reference_low,
+                reference_high: value.reference_high,
+                reference_text: value.reference_text,
+                abnormal: value.abnormal,
+                recorded_at: value.recorded_at.to_string(),
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ExamResultFileDTO {
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i32,
+}
+
+#[derive(Serialize)]
+pub struct LoadedExamOrderDTO {
+    pub id: i32,
+    pub patient_id: i32,
+    pub professional_id: i32,
+    pub appointment_id: Option<i32>,
+    pub kind: ExamKind,
+    pub exam_name: String,
+    pub clinical_indication: Option<String>,
+    pub status: ExamOrderStatus,
+    pub requested_by: String,
+    pub requested_at: String,
+    pub collected_at: Option<String>,
+    pub resulted_at: Option<String>,
+    pub reviewed_at: Option<String>,
+    pub reviewed_by: Option<String>,
+    pub results: Vec<LoadedExamResultDTO>,
+    pub has_abnormal_results: bool,
+    pub result_file: Option<ExamResultFileDTO>,
+}
+
+impl LoadedExamOrderDTO {
+    /// `None` when the order was not saved yet.
+    pub fn new(exam_order: ExamOrder, results: Vec<ExamResult>) -> Option<Self> {
+        let ID::Existing(id) = exam_order.id else {
+            return None;
+        };
+
+        let result_file = match (
+            exam_order.result_file_name,
+            exam_order.result_file_content_type,
+            exam_order.result_file_size,
+        ) {
+            (Some(file_name), Some(content_type), Some(size)) => Some(ExamResultFileDTO {
+                file_name,
+                content_type,
+                size,
+            }),
+            _ => None,
+        };
+
+        Some(Self {
+            id,
+            patient_id: exam_order.patient_id,
+            professional_id: exam_order.professional_id,
+            appointment_id: exam_order.appointment_id,
+            kind: exam_order.kind,
+            exam_name: exam_order.exam_name,
+            clinical_indication: exam_order.clinical_indication,
+            status: exam_order.status,
+            requested_by: exam_order.requested_by,
+            requested_at: exam_order.requested_at.to_string(),
+            collected_at: exam_order.collected_at.map(|at| at.to_string()),
+            resulted_at: exam_order.resulted_at.map(|at| at.to_string()),
+            reviewed_at: exam_order.reviewed_at.map(|at| at.to_string()),
+            reviewed_by: exam_order.reviewed_by,
+            has_abnormal_results: results.iter().any(|result| result.abnormal),
+            results: results
+                .into_iter()
+                .filter_map(|result| result.into())
+                .collect(),
+            result_file,
+        })
+    }
+}
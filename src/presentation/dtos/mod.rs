@@ -1,3 +1,16 @@
 pub mod admin_dto;
+pub mod admission_dto;
+pub mod allergy_dto;
 pub mod appointment_dto;
+pub mod consent_dto;
+pub mod document_dto;
+pub mod encounter_dto;
+pub mod exam_order_dto;
+pub mod page_dto;
 pub mod patient_dto;
+pub mod patient_export_dto;
+pub mod prescription_dto;
+pub mod problem_dto;
+pub mod professional_dto;
+pub mod schedule_dto;
+pub mod ward_dto;
@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+use crate::domain::value_objects::pagination::Page;
+
+#[derive(Serialize)]
+pub struct PagedDTO<T> {
+    pub items: Vec<T>,
+    pub page: i64,
+    pub limit: i64,
+    pub total: i64,
+    pub total_pages: i64,
+}
+
+impl<T, U: Into<Option<T>>> From<Page<U>> for PagedDTO<T> {
+    fn from(value: Page<U>) -> Self {
+        let total_pages = value.total_pages();
+
+        Self {
+            items: value
+                .items
+                .into_iter()
+                .filter_map(|item| item.into())
+                .collect(),
+            page: value.pagination.page,
+            limit: value.pagination.limit,
+            total: value.total,
+            total_pages,
+        }
+    }
+}
@@ -1,11 +1,37 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::{entities::patient::Patient, value_objects::id::ID};
+use crate::domain::{
+    entities::patient::Patient,
+    repositories::patient_repository::PatientMergeReport,
+    value_objects::{cep::Cep, cns::Cns, cpf::Cpf, email::Email, id::ID, sex::Sex},
+};
+
+/// Optional demographic data shared by the create and update payloads.
+#[derive(Clone, Default, Deserialize)]
+pub struct PatientProfileDTO {
+    pub social_name: Option<String>,
+    pub birth_date: Option<String>,
+    pub sex: Option<Sex>,
+    pub gender_identity: Option<String>,
+    pub mother_name: Option<String>,
+    pub cns: Option<Cns>,
+    pub email: Option<Email>,
+    pub phones: Option<Vec<String>>,
+    pub cep: Option<Cep>,
+    pub street: Option<String>,
+    pub address_number: Option<String>,
+    pub address_complement: Option<String>,
+    pub neighborhood: Option<String>,
+    pub city: Option<String>,
+    pub uf: Option<String>,
+}
 
 #[derive(Clone, Deserialize)]
 pub struct CreatePatientDTO {
     pub name: String,
-    pub cpf: String,
+    pub cpf: Cpf,
+    #[serde(flatten)]
+    pub profile: PatientProfileDTO,
 }
 
 #[derive(Serialize)]
@@ -13,6 +39,26 @@ pub struct LoadedPatientDTO {
     pub id: i32,
     pub name: String,
     pub cpf: String,
+    pub social_name: Option<String>,
+    pub birth_date: Option<String>,
+    pub sex: Option<Sex>,
+    pub gender_identity: Option<String>,
+    pub mother_name: Option<String>,
+    pub cns: Option<Cns>,
+    pub email: Option<Email>,
+    pub phones: Vec<String>,
+    pub address: LoadedAddressDTO,
+}
+
+#[derive(Serialize)]
+pub struct LoadedAddressDTO {
+    pub cep: Option<Cep>,
+    pub street: Option<String>,
+    pub number: Option<String>,
+    pub complement: Option<String>,
+    pub neighborhood: Option<String>,
+    pub city: Option<String>,
+    pub uf: Option<String>,
 }
 
 impl From<Patient> for Option<LoadedPatientDTO> {
@@ -21,7 +67,24 @@ impl From<Patient> for Option<LoadedPatientDTO> {
             ID::Existing(id) => Self::Some(LoadedPatientDTO {
                 id,
                 name: value.name,
-                cpf: value.cpf,
+                cpf: value.cpf.into(),
+                social_name: value.social_name,
+                birth_date: value.birth_date.map(|date| date.to_string()),
+                sex: value.sex,
+                gender_identity: value.gender_identity,
+                mother_name: value.mother_name,
+                cns: value.cns,
+                email: value.email,
+                phones: value.phones,
+                address: LoadedAddressDTO {
+                    cep: value.cep,
+                    street: value.street,
+                    number: value.address_number,
+                    complement: value.address_complement,
+                    neighborhood: value.neighborhood,
+                    city: value.city,
+                    uf: value.uf,
+                },
             }),
             ID::New => None,
         }
@@ -31,12 +94,65 @@ impl From<Patient> for Option<LoadedPatientDTO> {
 #[derive(Deserialize)]
 pub struct UpdatePatientDTO {
     pub name: Option<String>,
+    #[serde(flatten)]
+    pub profile: PatientProfileDTO,
+}
+
+#[derive(Deserialize)]
+pub struct MergePatientsDTO {
+    pub surviving_patient_id: i32,
+    pub duplicate_patient_id: i32,
+}
+
+#[derive(Serialize)]
+pub struct PatientMergeReportDTO {
+    pub surviving_patient_id: i32,
+    pub duplicate_patient_id: i32,
+    pub moved_appointment_ids: Vec<i32>,
+    pub moved_consent_ids: Vec<i32>,
+    pub moved_encounter_ids: Vec<i32>,
+    pub moved_allergy_ids: Vec<i32>,
+    pub moved_problem_ids: Vec<i32>,
+    pub moved_prescription_ids: Vec<i32>,
+    pub moved_exam_order_ids: Vec<i32>,
+    pub moved_document_ids: Vec<i32>,
+    pub moved_admission_ids: Vec<i32>,
+    pub revoked_consent_ids: Vec<i32>,
+}
+
+impl From<PatientMergeReport> for PatientMergeReportDTO {
+    fn from(value: PatientMergeReport) -> Self {
+        Self {
+            surviving_patient_id: value.surviving_patient_id,
+            duplicate_patient_id: value.duplicate_patient_id,
+            moved_appointment_ids: value.moved_appointment_ids,
+            moved_consent_ids: value.moved_consent_ids,
+            moved_encounter_ids: value.moved_encounter_ids,
+            moved_allergy_ids: value.moved_allergy_ids,
+            moved_problem_ids: value.moved_problem_ids,
+            moved_prescription_ids: value.moved_prescription_ids,
+            moved_exam_order_ids: value.moved_exam_order_ids,
+            moved_document_ids: value.moved_document_ids,
+            moved_admission_ids: value.moved_admission_ids,
+            revoked_consent_ids: value.revoked_consent_ids,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ListPatientsQueryDTO {
+    pub name: Option<String>,
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
 }
 
 #[cfg(test)]
 mod test {
     use crate::{
-        domain::{entities::patient::Patient, value_objects::id::ID},
+        domain::{
+            entities::patient::Patient,
+            value_objects::{cpf::Cpf, id::ID},
+        },
         presentation::dtos::patient_dto::LoadedPatientDTO,
     };
 
@@ -44,9 +160,9 @@ mod test {
     fn from_patient_into_optional_loaded_patient_dto() {
         let id = 42;
         let name = "Andrew";
-        let cpf = "00011122233";
+        let cpf = "00011122285";
 
-        let new_patient = Patient::new(name.to_string(), cpf.to_string());
+        let new_patient = Patient::new(name.to_string(), Cpf::parse(cpf).unwrap());
 
         let loaded_patient_dto: Option<LoadedPatientDTO> = new_patient.clone().into();
 
@@ -0,0 +1,90 @@
+use serde::Serialize;
+
+use crate::{
+    application::use_cases::export_patient_data::PatientDataExport,
+    presentation::dtos::{
+        admission_dto::LoadedAdmissionDTO, allergy_dto::LoadedAllergyDTO,
+        appointment_dto::LoadedAppointmentDTO, consent_dto::LoadedConsentDTO,
+        document_dto::LoadedDocumentDTO, encounter_dto::LoadedEncounterDTO,
+        exam_order_dto::LoadedExamOrderDTO, patient_dto::LoadedPatientDTO,
+        prescription_dto::LoadedPrescriptionDTO, problem_dto::LoadedProblemDTO,
+        professional_dto::LoadedProfessionalDTO,
+    },
+};
+
+#[derive(Serialize)]
+pub struct PatientExportDTO {
+    pub exported_at: String,
+    pub patient: Option<LoadedPatientDTO>,
+    pub appointments: Vec<LoadedAppointmentDTO>,
+    pub professionals: Vec<LoadedProfessionalDTO>,
+    pub consents: Vec<LoadedConsentDTO>,
+    pub encounters: Vec<LoadedEncounterDTO>,
+    pub allergies: Vec<LoadedAllergyDTO>,
+    pub problems: Vec<LoadedProblemDTO>,
+    pub prescriptions: Vec<LoadedPrescriptionDTO>,
+    pub exam_orders: Vec<LoadedExamOrderDTO>,
+    pub documents: Vec<LoadedDocumentDTO>,
+    pub admissions: Vec<LoadedAdmissionDTO>,
+}
+
+impl From<PatientDataExport> for PatientExportDTO {
+    fn from(value: PatientDataExport) -> Self {
+        Self {
+            exported_at: value.exported_at.to_string(),
+            patient: value.patient.into(),
+            appointments: value
+                .appointments
+                .into_iter()
+                .filter_map(|appointment| appointment.into())
+                .collect(),
+            professionals: value
+                .professionals
+                .into_iter()
+                .filter_map(|professional| professional.into())
+                .collect(),
+            consents: value
+                .consents
+                .into_iter()
+                .filter_map(|consent| consent.into())
+                .collect(),
+            encounters: value
+                .encounters
+                .into_iter()
+                .filter_map(|encounter| encounter.into())
+                .collect(),
+            allergies: value
+                .allergies
+                .into_iter()
+                .filter_map(|allergy| allergy.into())
+                .collect(),
+            problems: value
+                .problems
+                .into_iter()
+                .filter_map(|problem| problem.into())
+                .collect(),
+            prescriptions: value
+                .prescriptions
+                .into_iter()
+                .filter_map(|(prescription, items)| LoadedPrescriptionDTO::new(prescription, items))
+                .collect(),
+            exam_orders: value
+                .exam_orders
+                .into_iter()
+                .filter_map(|(exam_order, results)| LoadedExamOrderDTO::new(exam_order, results))
+                .collect(),
+            documents: value
+                .documents
+                .into_iter()
+                .filter_map(|document| document.into())
+                .collect(),
+            admissions: value
+                .admissions
+                .into_iter()
+                .filter_map(|(admission, occupancies)| {
+                    LoadedAdmissionDTO::new(admission, occupancies)
+                })
+                .collect(),
+        }
+    }
+}
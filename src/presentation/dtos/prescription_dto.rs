@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::use_cases::{
+        find_prescription_by_id::PrescriptionDocument, issue_prescription::IssuedPrescription,
+    },
+    domain::{
+        entities::prescription::{Prescription, PrescriptionItem},
+        services::allergy_check::AllergyWarning,
+        value_objects::{allergy_severity::AllergySeverity, id::ID},
+    },
+};
+
+#[derive(Clone, Default, Deserialize)]
+pub struct PrescriptionItemDTO {
+    pub drug: String,
+    pub dose: String,
+    pub route: String,
+    pub frequency: String,
+    pub duration: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct IssuePrescriptionDTO {
+    pub appointment_id: Option<i32>,
+    pub items: Vec<PrescriptionItemDTO>,
+    pub notes: Option<String>,
+    /// Required to go ahead when a drug matches a severe allergy.
+    pub allergy_override_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CheckPrescriptionAllergiesDTO {
+    pub items: Vec<PrescriptionItemDTO>,
+}
+
+#[derive(Serialize)]
+pub struct AllergyWarningDTO {
+    pub allergy_id: Option<i32>,
+    pub substance: String,
+    pub severity: AllergySeverity,
+    pub drug: String,
+    /// Whether the warning blocks the prescription unless overridden.
+    pub blocking: bool,
+}
+
+impl From<AllergyWarning> for AllergyWarningDTO {
+    fn from(value: AllergyWarning) -> Self {
+        Self {
+            blocking: value.is_blocking(),
+            allergy_id: value.allergy_id,
+            substance: value.substance,
+            severity: value.severity,
+            drug: value.drug,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LoadedPrescriptionItemDTO {
+    pub id: i32,
+    pub position: i32,
+    pub drug: String,
+    pub dose: String,
+    pub route: String,
+    pub frequency: String,
+    pub duration: Option<String>,
+}
+
+impl From<PrescriptionItem> for Option<LoadedPrescriptionItemDTO> {
+    fn from(value: PrescriptionItem) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedPrescriptionItemDTO {
+                id,
+                position: value.position,
+                drug: value.drug,
+                dose: value.dose,
+                route: value.route,
+                frequency: value.frequency,
+                duration: value.duration,
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LoadedPrescriptionDTO {
+    pub id: i32,
+    pub patient_id: i32,
+    pub professional_id: i32,
+    pub appointment_id: Option<i32>,
+    pub items: Vec<LoadedPrescriptionItemDTO>,
+    pub notes: Option<String>,
+    pub allergy_override_reason: Option<String>,
+    pub issued_by: String,
+    pub issued_at: String,
+}
+
+impl LoadedPrescriptionDTO {
+    /// `None` when the prescription was not saved yet.
+    pub fn new(prescription: Prescription, items: Vec<PrescriptionItem>) -> Option<Self> {
+        match prescription.id {
+            ID::Existing(id) => Some(Self {
+                id,
+                patient_id: prescription.patient_id,
+                professional_id: prescription.professional_id,
+                appointment_id: prescription.appointment_id,
+                items: items.into_iter().filter_map(|item| item.into()).collect(),
+                notes: prescription.notes,
+                allergy_override_reason: prescription.allergy_override_reason,
+                issued_by: prescription.issued_by,
+                issued_at: prescription.issued_at.to_string(),
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct IssuedPrescriptionDTO {
+    #[serde(flatten)]
+    pub prescription: Option<LoadedPrescriptionDTO>,
+    pub allergy_warnings: Vec<AllergyWarningDTO>,
+}
+
+impl From<IssuedPrescription> for IssuedPrescriptionDTO {
+    fn from(value: IssuedPrescription) -> Self {
+        Self {
+            prescription: LoadedPrescriptionDTO::new(value.prescription, value.items),
+            allergy_warnings: value.warnings.into_iter().map(|w| w.into()).collect(),
+        }
+    }
+}
+
+/// A prescription together with who it is for and who signed it.
+#[derive(Serialize)]
+pub struct PrescriptionDocumentDTO {
+    #[serde(flatten)]
+    pub prescription: Option<LoadedPrescriptionDTO>,
+    pub patient_name: String,
+    pub patient_cpf: String,
+    pub professional_name: String,
+    pub professional_license: String,
+}
+
+impl From<PrescriptionDocument> for PrescriptionDocumentDTO {
+    fn from(value: PrescriptionDocument) -> Self {
+        Self {
+            patient_name: value.patient.social_name.unwrap_or(value.patient.name),
+            patient_cpf: value.patient.cpf.to_string(),
+            professional_name: value.professional.name,
+            professional_license: format!(
+                "{} {}/{}",
+                value.professional.council,
+                value.professional.license_number,
+                value.professional.council_state
+            ),
+            prescription: LoadedPrescriptionDTO::new(value.prescription, value.items),
+        }
+    }
+}
+
+impl PrescriptionDocumentDTO {
+    /// Plain-text rendering meant to be printed and signed.
+    pub fn to_printable_text(&self) -> String {
+        let Some(prescription) = &self.prescription else {
+            return String::new();
+        };
+
+        let mut lines = vec![
+            format!("PRESCRIPTION #{}", prescription.id),
+            format!("Issued at: {}", prescription.issued_at),
+            String::new(),
+            format!("Patient: {}", self.patient_name),
+            format!("CPF: {}", self.patient_cpf),
+            String::new(),
+        ];
+
+        for item in &prescription.items {
+            lines.push(format!("{}. {} {}", item.position, item.drug, item.dose));
+            lines.push(match &item.duration {
+                Some(duration) => format!("   {}, {}, for {duration}", item.route, item.frequency),
+                None => format!("   {}, {}", item.route, item.frequency),
+            });
+        }
+
+        if let Some(notes) = &prescription.notes {
+            lines.push(String::new());
+            lines.push(format!("Notes: {notes}"));
+        }
+
+        lines.extend([
+            String::new(),
+            "______________________________".to_string(),
+            self.professional_name.clone(),
+            self.professional_license.clone(),
+        ]);
+
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LoadedPrescriptionDTO, LoadedPrescriptionItemDTO, PrescriptionDocumentDTO};
+
+    #[test]
+    fn to_printable_text() {
+        let document = PrescriptionDocumentDTO {
+            prescription: Some(LoadedPrescriptionDTO {
+                id: 9,
+                patient_id: 7,
+                professional_id: 42,
+                appointment_id: None,
+                items: vec![LoadedPrescriptionItemDTO {
+                    id: 1,
+                    position: 1,
+                    drug: "Amoxicillin".to_string(),
+                    dose: "500 mg".to_string(),
+                    route: "oral".to_string(),
+                    frequency: "every 8 hours".to_string(),
+                    duration: Some("7 days".to_string()),
+                }],
+                notes: None,
+                allergy_override_reason: None,
+                issued_by: "admin@example.com".to_string(),
+                issued_at: "2025-06-09 08:30:00".to_string(),
+            }),
+            patient_name: "Andrew".to_string(),
+            patient_cpf: "12345678909".to_string(),
+            professional_name: "Gregory House".to_string(),
+            professional_license: "CRM 123456/SP".to_string(),
+        };
+
+        assert_eq!(
+            document.to_printable_text(),
+            "PRESCRIPTION #9\nIssued at: 2025-06-09 08:30:00\n\nPatient: Andrew\nCPF: 12345678909\n\n1. Amoxicillin 500 mg\n   oral, every 8 hours, for 7 days\n\n______________________________\nGregory House\nCRM 123456/SP\n"
+        );
+    }
+}
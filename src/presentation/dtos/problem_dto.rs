@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::problem::Problem,
+    value_objects::{icd10::Icd10, id::ID},
+};
+
+#[derive(Deserialize)]
+pub struct CreateProblemDTO {
+    pub icd10_code: Icd10,
+    pub description: String,
+    pub onset_date: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+pub struct UpdateProblemDTO {
+    pub description: Option<String>,
+    pub onset_date: Option<String>,
+    pub resolved_at: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ProblemPath {
+    pub problem_id: i32,
+}
+
+#[derive(Serialize)]
+pub struct LoadedProblemDTO {
+    pub id: i32,
+    pub patient_id: i32,
+    pub icd10_code: Icd10,
+    pub description: String,
+    pub onset_date: Option<String>,
+    pub resolved_at: Option<String>,
+    pub active: bool,
+    pub notes: Option<String>,
+    pub recorded_at: String,
+}
+
+impl From<Problem> for Option<LoadedProblemDTO> {
+    fn from(value: Problem) -> Self {
+        let active = value.is_active();
+
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedProblemDTO {
+                id,
+                patient_id: value.patient_id,
+                icd10_code: value.icd10_code,
+                description: value.description,
+                onset_date: value.onset_date.map(|date| date.to_string()),
+                resolved_at: value.resolved_at.map(|date| date.to_string()),
+                active,
+                notes: value.notes,
+                recorded_at: value.recorded_at.to_string(),
+            }),
+            ID::New => None,
+        }
+    }
+}
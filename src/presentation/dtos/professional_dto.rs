@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{entities::professional::Professional, value_objects::id::ID};
+
+#[derive(Clone, Deserialize)]
+pub struct CreateProfessionalDTO {
+    pub name: String,
+    pub council: String,
+    pub license_number: String,
+    pub council_state: String,
+    #[serde(default)]
+    pub specialties: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct LoadedProfessionalDTO {
+    pub id: i32,
+    pub name: String,
+    pub council: String,
+    pub license_number: String,
+    pub council_state: String,
+    pub specialties: Vec<String>,
+    pub active: bool,
+}
+
+impl From<Professional> for Option<LoadedProfessionalDTO> {
+    fn from(value: Professional) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedProfessionalDTO {
+                id,
+                name: value.name,
+                council: value.council,
+                license_number: value.license_number,
+                council_state: value.council_state,
+                specialties: value.specialties,
+                active: value.active,
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct UpdateProfessionalDTO {
+    pub name: Option<String>,
+    pub specialties: Option<Vec<String>>,
+    pub active: Option<bool>,
+}
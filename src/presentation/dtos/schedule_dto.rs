@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::{
+        professional_schedule::ProfessionalSchedule, schedule_exception::ScheduleException,
+    },
+    value_objects::{id::ID, time_slot::TimeSlot},
+};
+
+#[derive(Clone, Deserialize)]
+pub struct CreateProfessionalScheduleDTO {
+    pub weekday: i16,
+    pub starts_at: String,
+    pub ends_at: String,
+}
+
+#[derive(Serialize)]
+pub struct LoadedProfessionalScheduleDTO {
+    pub id: i32,
+    pub professional_id: i32,
+    pub weekday: i16,
+    pub starts_at: String,
+    pub ends_at: String,
+}
+
+impl From<ProfessionalSchedule> for Option<LoadedProfessionalScheduleDTO> {
+    fn from(value: ProfessionalSchedule) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedProfessionalScheduleDTO {
+                id,
+                professional_id: value.professional_id,
+                weekday: value.weekday,
+                starts_at: value.starts_at.to_string(),
+                ends_at: value.ends_at.to_string(),
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+pub struct CreateScheduleExceptionDTO {
+    pub starts_at: String,
+    pub ends_at: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct LoadedScheduleExceptionDTO {
+    pub id: i32,
+    pub professional_id: i32,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub reason: Option<String>,
+}
+
+impl From<ScheduleException> for Option<LoadedScheduleExceptionDTO> {
+    fn from(value: ScheduleException) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedScheduleExceptionDTO {
+                id,
+                professional_id: value.professional_id,
+                starts_at: value.starts_at.to_string(),
+                ends_at: value.ends_at.to_string(),
+                reason: value.reason,
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct DateRangeQueryDTO {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Deserialize)]
+pub struct AvailableSlotsQueryDTO {
+    pub from: String,
+    pub to: String,
+    pub duration: Option<i64>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct AvailableSlotDTO {
+    pub starts_at: String,
+    pub ends_at: String,
+}
+
+impl From<TimeSlot> for AvailableSlotDTO {
+    fn from(value: TimeSlot) -> Self {
+        Self {
+            starts_at: value.starts_at.to_string(),
+            ends_at: value.ends_at.to_string(),
+        }
+    }
+}
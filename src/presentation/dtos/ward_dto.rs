@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::ward::{Bed, Room, Ward},
+    services::bed_occupancy::{BedOverview, WardOccupancy},
+    value_objects::{bed_status::BedStatus, id::ID},
+};
+
+#[derive(Deserialize)]
+pub struct CreateWardDTO {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct AddRoomDTO {
+    pub number: String,
+}
+
+#[derive(Deserialize)]
+pub struct AddBedDTO {
+    pub room_id: i32,
+    pub label: String,
+}
+
+/// Only `free`, `cleaning` and `blocked` can be set by hand; beds become
+/// `occupied` through admissions and transfers.
+#[derive(Deserialize)]
+pub struct ChangeBedStatusDTO {
+    pub status: BedStatus,
+}
+
+#[derive(Serialize)]
+pub struct LoadedWardDTO {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl From<Ward> for Option<LoadedWardDTO> {
+    fn from(value: Ward) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedWardDTO {
+                id,
+                name: value.name,
+                description: value.description,
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LoadedRoomDTO {
+    pub id: i32,
+    pub ward_id: i32,
+    pub number: String,
+}
+
+impl From<Room> for Option<LoadedRoomDTO> {
+    fn from(value: Room) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedRoomDTO {
+                id,
+                ward_id: value.ward_id,
+                number: value.number,
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LoadedBedDTO {
+    pub id: i32,
+    pub room_id: i32,
+    pub label: String,
+    pub status: BedStatus,
+}
+
+impl From<Bed> for Option<LoadedBedDTO> {
+    fn from(value: Bed) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedBedDTO {
+                id,
+                room_id: value.room_id,
+                label: value.label,
+                status: value.status,
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct BedOccupantDTO {
+    pub admission_id: i32,
+    pub patient_id: i32,
+    pub patient_name: String,
+    pub since: String,
+}
+
+#[derive(Serialize)]
+pub struct BedOverviewDTO {
+    pub bed_id: i32,
+    pub room_number: String,
+    pub label: String,
+    pub status: BedStatus,
+    pub occupant: Option<BedOccupantDTO>,
+}
+
+impl From<BedOverview> for Option<BedOverviewDTO> {
+    fn from(value: BedOverview) -> Self {
+        match value.bed.id {
+            ID::Existing(bed_id) => Self::Some(BedOverviewDTO {
+                bed_id,
+                room_number: value.room_number,
+                label: value.bed.label,
+                status: value.bed.status,
+                occupant: value.occupant.map(|occupant| BedOccupantDTO {
+                    admission_id: occupant.admission_id,
+                    patient_id: occupant.patient_id,
+                    patient_name: occupant.patient_name,
+                    since: occupant.since.to_string(),
+                }),
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct WardOccupancyDTO {
+    pub ward_id: i32,
+    pub name: String,
+    pub total_beds: usize,
+    pub free_beds: usize,
+    pub occupied_beds: usize,
+    pub cleaning_beds: usize,
+    pub blocked_beds: usize,
+    pub beds: Vec<BedOverviewDTO>,
+}
+
+impl From<WardOccupancy> for Option<WardOccupancyDTO> {
+    fn from(value: WardOccupancy) -> Self {
+        let count = |status: BedStatus| {
+            value
+                .beds
+                .iter()
+                .filter(|overview| overview.bed.status == status)
+                .count()
+        };
+
+        match value.ward.id {
+            ID::Existing(ward_id) => Self::Some(WardOccupancyDTO {
+                ward_id,
+                total_beds: value.beds.len(),
+                free_beds: count(BedStatus::Free),
+                occupied_beds: count(BedStatus::Occupied),
+                cleaning_beds: count(BedStatus::Cleaning),
+                blocked_beds: count(BedStatus::Blocked),
+                name: value.ward.name,
+                beds: value
+                    .beds
+                    .into_iter()
+                    .filter_map(|overview| overview.into())
+                    .collect(),
+            }),
+            ID::New => None,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt;
+
+use actix_web::{HttpResponse, ResponseError, body::BoxBody, http::header::RETRY_AFTER};
+
+use crate::application::errors::admin_application_error::AdminApplicationError;
+
+#[derive(Debug, PartialEq)]
+pub enum AdminHttpError {
+    Constraint(String),
+    Internal(String),
+    NotFound(String),
+    Unauthorized(String),
+    TooManyRequests(String, i64),
+}
+
+impl fmt::Display for AdminHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdminHttpError::Constraint(msg) => {
+                write!(f, "A constraint error occurred for the user: {msg}")
+            }
+            AdminHttpError::Internal(msg) => {
+                write!(f, "An internal error occurred for the user: {msg}")
+            }
+            AdminHttpError::NotFound(msg) => {
+                write!(f, "The user could not be found: {msg}")
+            }
+            AdminHttpError::Unauthorized(msg) => {
+                write!(f, "{msg}")
+            }
+            AdminHttpError::TooManyRequests(msg, _) => {
+                write!(f, "{msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AdminHttpError {}
+
+impl From<AdminApplicationError> for AdminHttpError {
+    fn from(value: AdminApplicationError) -> Self {
+        match value {
+            AdminApplicationError::Conflict(msg) => Self::Constraint(msg),
+            AdminApplicationError::Constraint(msg) => Self::Constraint(msg),
+            AdminApplicationError::Unexpected(msg) => Self::Internal(msg),
+            err @ (AdminApplicationError::NotFound(_) | AdminApplicationError::NotFoundById(_)) => {
+                Self::NotFound(err.to_string())
+            }
+            err @ (AdminApplicationError::LoginFailed(_)
+            | AdminApplicationError::InvalidCredentials
+            | AdminApplicationError::InvalidRefreshToken) => Self::Unauthorized(err.to_string()),
+            err @ AdminApplicationError::TooManyAttempts(retry_after) => {
+                Self::TooManyRequests(err.to_string(), retry_after)
+            }
+            err @ (AdminApplicationError::IncorrectPassword
+            | AdminApplicationError::SelfDeactivation) => Self::Constraint(err.to_string()),
+        }
+    }
+}
+
+impl ResponseError for AdminHttpError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        match self {
+            AdminHttpError::Constraint(_) => {
+                HttpResponse::UnprocessableEntity().json(self.to_string())
+            }
+            AdminHttpError::Internal(_) => {
+                HttpResponse::InternalServerError().json(self.to_string())
+            }
+            AdminHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
+            AdminHttpError::Unauthorized(_) => HttpResponse::Unauthorized().json(self.to_string()),
+            AdminHttpError::TooManyRequests(_, retry_after) => HttpResponse::TooManyRequests()
+                .insert_header((RETRY_AFTER, retry_after.to_string()))
+                .json(self.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{ResponseError, http::StatusCode};
+
+    use crate::application::errors::admin_application_error::AdminApplicationError;
+
+    use super::AdminHttpError;
+
+    #[test]
+    fn from_admin_application_not_found_by_id_error() {
+        let err: AdminHttpError = AdminApplicationError::NotFoundById(3).into();
+
+        assert_eq!(
+            err,
+            AdminHttpError::NotFound("A user with the following ID was not found: 3".to_string())
+        );
+        assert_eq!(err.error_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn from_admin_application_incorrect_password_error() {
+        let err: AdminHttpError = AdminApplicationError::IncorrectPassword.into();
+
+        assert_eq!(
+            err.error_response().status(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
+    #[test]
+    fn from_admin_application_too_many_attempts_error() {
+        let err: AdminHttpError = AdminApplicationError::TooManyAttempts(30).into();
+
+        let response = err.error_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get("retry-after").unwrap(), "30");
+    }
+}
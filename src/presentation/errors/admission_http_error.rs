@@ -0,0 +1,106 @@
+use std::fmt;
+
+use actix_web::{HttpResponse, ResponseError, body::BoxBody};
+
+use crate::application::errors::admission_application_error::AdmissionApplicationError;
+
+#[derive(Debug, PartialEq)]
+pub enum AdmissionHttpError {
+    Constraint(String),
+    Internal(String),
+    NotFound(String),
+}
+
+impl fmt::Display for AdmissionHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdmissionHttpError::Constraint(msg) => {
+                write!(f, "A constraint error occurred for the admission: {msg}")
+            }
+            AdmissionHttpError::Internal(msg) => {
+                write!(f, "An internal error occurred for the admission: {msg}")
+            }
+            AdmissionHttpError::NotFound(msg) => {
+                write!(f, "The admission could not be found: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AdmissionHttpError {}
+
+impl From<AdmissionApplicationError> for AdmissionHttpError {
+    fn from(value: AdmissionApplicationError) -> Self {
+        match value {
+            AdmissionApplicationError::Conflict(msg) => Self::Constraint(msg),
+            AdmissionApplicationError::Constraint(msg) => Self::Constraint(msg),
+            AdmissionApplicationError::Unexpected(msg) => Self::Internal(msg),
+            err @ AdmissionApplicationError::AlreadyAdmitted(_) => {
+                Self::Constraint(err.to_string())
+            }
+            err @ (AdmissionApplicationError::NotFound(_)
+            | AdmissionApplicationError::PatientNotFound(_)
+            | AdmissionApplicationError::ProfessionalNotFound(_)
+            | AdmissionApplicationError::WardNotFound(_)
+            | AdmissionApplicationError::RoomNotFound(_)
+            | AdmissionApplicationError::BedNotFound(_)) => Self::NotFound(err.to_string()),
+        }
+    }
+}
+
+impl ResponseError for AdmissionHttpError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        match self {
+            AdmissionHttpError::Constraint(_) => {
+                HttpResponse::UnprocessableEntity().json(self.to_string())
+            }
+            AdmissionHttpError::Internal(_) => {
+                HttpResponse::InternalServerError().json(self.to_string())
+            }
+            AdmissionHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{ResponseError, http::StatusCode};
+
+    use crate::application::errors::admission_application_error::AdmissionApplicationError;
+
+    use super::AdmissionHttpError;
+
+    #[test]
+    fn from_admission_application_bed_not_found_error() {
+        let err: AdmissionHttpError = AdmissionApplicationError::BedNotFound(3).into();
+
+        assert_eq!(
+            err,
+            AdmissionHttpError::NotFound(
+                "A bed with the following ID was not found: 3".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn error_response_status() {
+        let cases = [
+            (
+                AdmissionHttpError::Constraint("invalid".to_string()),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                AdmissionHttpError::Internal("Database error".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                AdmissionHttpError::NotFound("42".to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+        ];
+
+        for (err, status) in cases {
+            assert_eq!(err.error_response().status(), status);
+        }
+    }
+}
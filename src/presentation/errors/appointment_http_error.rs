@@ -10,6 +10,8 @@ pub enum AppointmentHttpError {
     Internal(String),
     NotFound(String),
     PatientNotFound(String),
+    ProfessionalNotFound(i32),
+    UnavailableSlot(String),
 }
 
 impl fmt::Display for AppointmentHttpError {
@@ -27,6 +29,12 @@ impl fmt::Display for AppointmentHttpError {
             AppointmentHttpError::PatientNotFound(patient_cpf) => {
                 write!(f, "The patient could not be found: {patient_cpf}")
             }
+            AppointmentHttpError::ProfessionalNotFound(professional_id) => {
+                write!(f, "The professional could not be found: {professional_id}")
+            }
+            AppointmentHttpError::UnavailableSlot(msg) => {
+                write!(f, "The requested time is not available: {msg}")
+            }
         }
     }
 }
@@ -39,9 +47,15 @@ impl From<AppointmentApplicationError> for AppointmentHttpError {
             AppointmentApplicationError::PatientNotFound(patient_cpf) => {
                 AppointmentHttpError::PatientNotFound(patient_cpf)
             }
+            AppointmentApplicationError::ProfessionalNotFound(professional_id) => {
+                AppointmentHttpError::ProfessionalNotFound(professional_id)
+            }
             AppointmentApplicationError::Constraint(msg) => AppointmentHttpError::Constraint(msg),
             AppointmentApplicationError::Unexpected(msg) => AppointmentHttpError::Internal(msg),
             AppointmentApplicationError::NotFound(msg) => AppointmentHttpError::NotFound(msg),
+            AppointmentApplicationError::UnavailableSlot(msg) => {
+                AppointmentHttpError::UnavailableSlot(msg)
+            }
         }
     }
 }
@@ -56,7 +70,11 @@ impl ResponseError for AppointmentHttpError {
                 HttpResponse::InternalServerError().json(self.to_string())
             }
             AppointmentHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
-            AppointmentHttpError::PatientNotFound(_) => {
+            AppointmentHttpError::UnavailableSlot(_) => {
+                HttpResponse::Conflict().json(self.to_string())
+            }
+            AppointmentHttpError::PatientNotFound(_)
+            | AppointmentHttpError::ProfessionalNotFound(_) => {
                 HttpResponse::NotFound().json(self.to_string())
             }
         }
@@ -0,0 +1,100 @@
+use std::fmt;
+
+use actix_web::{HttpResponse, ResponseError, body::BoxBody};
+
+use crate::application::errors::encounter_application_error::EncounterApplicationError;
+
+#[derive(Debug, PartialEq)]
+pub enum EncounterHttpError {
+    Constraint(String),
+    Internal(String),
+    NotFound(String),
+}
+
+impl fmt::Display for EncounterHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncounterHttpError::Constraint(msg) => {
+                write!(f, "A constraint error occurred for the encounter: {msg}")
+            }
+            EncounterHttpError::Internal(msg) => {
+                write!(f, "An internal error occurred for the encounter: {msg}")
+            }
+            EncounterHttpError::NotFound(msg) => {
+                write!(f, "The encounter could not be found: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncounterHttpError {}
+
+impl From<EncounterApplicationError> for EncounterHttpError {
+    fn from(value: EncounterApplicationError) -> Self {
+        match value {
+            EncounterApplicationError::Conflict(msg) => Self::Constraint(msg),
+            EncounterApplicationError::Constraint(msg) => Self::Constraint(msg),
+            EncounterApplicationError::Unexpected(msg) => Self::Internal(msg),
+            err @ (EncounterApplicationError::NotFound(_)
+            | EncounterApplicationError::AppointmentNotFound(_)
+            | EncounterApplicationError::PatientNotFound(_)) => Self::NotFound(err.to_string()),
+        }
+    }
+}
+
+impl ResponseError for EncounterHttpError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        match self {
+            EncounterHttpError::Constraint(_) => {
+                HttpResponse::UnprocessableEntity().json(self.to_string())
+            }
+            EncounterHttpError::Internal(_) => {
+                HttpResponse::InternalServerError().json(self.to_string())
+            }
+            EncounterHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{ResponseError, http::StatusCode};
+
+    use crate::application::errors::encounter_application_error::EncounterApplicationError;
+
+    use super::EncounterHttpError;
+
+    #[test]
+    fn from_encounter_application_appointment_not_found_error() {
+        let err: EncounterHttpError = EncounterApplicationError::AppointmentNotFound(3).into();
+
+        assert_eq!(
+            err,
+            EncounterHttpError::NotFound(
+                "An appointment with the following ID was not found: 3".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn error_response_status() {
+        let cases = [
+            (
+                EncounterHttpError::Constraint("invalid".to_string()),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                EncounterHttpError::Internal("Database error".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                EncounterHttpError::NotFound("42".to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+        ];
+
+        for (err, status) in cases {
+            assert_eq!(err.error_response().status(), status);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+
+use actix_web::{HttpResponse, ResponseError, body::BoxBody};
+
+use crate::application::errors::exam_order_application_error::ExamOrderApplicationError;
+
+#[derive(Debug, PartialEq)]
+pub enum ExamOrderHttpError {
+    Constraint(String),
+    Internal(String),
+    NotFound(String),
+}
+
+impl fmt::Display for ExamOrderHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExamOrderHttpError::Constraint(msg) => {
+                write!(f, "A constraint error occurred for the exam order: {msg}")
+            }
+            ExamOrderHttpError::Internal(msg) => {
+                write!(f, "An internal error occurred for the exam order: {msg}")
+            }
+            ExamOrderHttpError::NotFound(msg) => {
+                write!(f, "The exam order could not be found: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExamOrderHttpError {}
+
+impl From<ExamOrderApplicationError> for ExamOrderHttpError {
+    fn from(value: ExamOrderApplicationError) -> Self {
+        match value {
+            ExamOrderApplicationError::Conflict(msg) => Self::Constraint(msg),
+            ExamOrderApplicationError::Constraint(msg) => Self::Constraint(msg),
+            ExamOrderApplicationError::Unexpected(msg) => Self::Internal(msg),
+            err @ (ExamOrderApplicationError::NotFound(_)
+            | ExamOrderApplicationError::AppointmentNotFound(_)
+            | ExamOrderApplicationError::PatientNotFound(_)
+            | ExamOrderApplicationError::ResultFileNotFound(_)) => Self::NotFound(err.to_string()),
+        }
+    }
+}
+
+impl ResponseError for ExamOrderHttpError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        match self {
+            ExamOrderHttpError::Constraint(_) => {
+                HttpResponse::UnprocessableEntity().json(self.to_string())
+            }
+            ExamOrderHttpError::Internal(_) => {
+                HttpResponse::InternalServerError().json(self.to_string())
+            }
+            ExamOrderHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{ResponseError, http::StatusCode};
+
+    use crate::application::errors::exam_order_application_error::ExamOrderApplicationError;
+
+    use super::ExamOrderHttpError;
+
+    #[test]
+    fn from_exam_order_application_result_file_not_found_error() {
+        let err: ExamOrderHttpError = ExamOrderApplicationError::ResultFileNotFound(3).into();
+
+        assert_eq!(
+            err,
+            ExamOrderHttpError::NotFound(
+                "The exam order with the following ID has no result file: 3".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn error_response_status() {
+        let cases = [
+            (
+                ExamOrderHttpError::Constraint("invalid".to_string()),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                ExamOrderHttpError::Internal("Database error".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                ExamOrderHttpError::NotFound("42".to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+        ];
+
+        for (err, status) in cases {
+            assert_eq!(err.error_response().status(), status);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use actix_web::{
+    HttpRequest, HttpResponse,
+    error::{Error, InternalError, JsonPayloadError},
+};
+
+/// Answers request bodies that are well-formed JSON but carry invalid values
+/// (e.g. a CPF with wrong check digits) with 422 instead of actix's default 400.
+pub fn json_error_handler(err: JsonPayloadError, _req: &HttpRequest) -> Error {
+    let response = match &err {
+        JsonPayloadError::Deserialize(json_err) if json_err.is_data() => {
+            HttpResponse::UnprocessableEntity().json(err.to_string())
+        }
+        _ => HttpResponse::BadRequest().json(err.to_string()),
+    };
+
+    InternalError::from_response(err, response).into()
+}
@@ -1,2 +1,9 @@
+pub mod admin_http_error;
+pub mod admission_http_error;
 pub mod appointment_http_error;
+pub mod encounter_http_error;
+pub mod exam_order_http_error;
+pub mod json_http_error;
 pub mod patient_http_error;
+pub mod prescription_http_error;
+pub mod professional_http_error;
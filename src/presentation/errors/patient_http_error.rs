@@ -9,6 +9,7 @@ pub enum PatientHttpError {
     Constraint(String),
     Internal(String),
     NotFound(String),
+    InvalidCpf(String),
 }
 
 impl fmt::Display for PatientHttpError {
@@ -23,6 +24,9 @@ impl fmt::Display for PatientHttpError {
             PatientHttpError::NotFound(msg) => {
                 write!(f, "The patient could not be found: {msg}")
             }
+            PatientHttpError::InvalidCpf(msg) => {
+                write!(f, "The patient CPF is invalid: {msg}")
+            }
         }
     }
 }
@@ -35,6 +39,13 @@ impl From<PatientApplicationError> for PatientHttpError {
             PatientApplicationError::Conflict(msg) => Self::Constraint(msg),
             PatientApplicationError::Unexpected(msg) => Self::Internal(msg),
             PatientApplicationError::NotFound(msg) => Self::NotFound(msg),
+            err @ PatientApplicationError::NotFoundById(_) => Self::NotFound(err.to_string()),
+            PatientApplicationError::InvalidCpf(msg) => Self::InvalidCpf(msg),
+            PatientApplicationError::Constraint(msg) => Self::Constraint(msg),
+            PatientApplicationError::ConsentNotFound(msg) => Self::NotFound(msg),
+            err @ (PatientApplicationError::AllergyNotFound(_)
+            | PatientApplicationError::ProblemNotFound(_)
+            | PatientApplicationError::DocumentNotFound(_)) => Self::NotFound(err.to_string()),
         }
     }
 }
@@ -49,6 +60,9 @@ impl ResponseError for PatientHttpError {
                 HttpResponse::InternalServerError().json(self.to_string())
             }
             PatientHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
+            PatientHttpError::InvalidCpf(_) => {
+                HttpResponse::UnprocessableEntity().json(self.to_string())
+            }
         }
     }
 }
@@ -112,6 +126,15 @@ mod test {
         assert_eq!(err, PatientHttpError::NotFound(err_msg.to_string()));
     }
 
+    #[test]
+    fn from_patient_application_invalid_cpf_error() {
+        let cpf = "12345678900";
+        let application_err = PatientApplicationError::InvalidCpf(cpf.to_string());
+        let err: PatientHttpError = application_err.into();
+
+        assert_eq!(err, PatientHttpError::InvalidCpf(cpf.to_string()));
+    }
+
     #[test]
     fn constraint_error_response() -> Result<(), Box<dyn std::error::Error>> {
         let err = PatientHttpError::Constraint("Constraint X violated".to_string());
@@ -159,4 +182,20 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn invalid_cpf_error_response() -> Result<(), Box<dyn std::error::Error>> {
+        let err = PatientHttpError::InvalidCpf("12345678900".to_string());
+
+        let result = err.error_response();
+
+        let result_status = result.status();
+        let result_body = result.into_body().try_into_bytes().unwrap();
+        let result_body = std::str::from_utf8(&result_body)?;
+
+        assert_eq!(result_status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(result_body.replace("\"", ""), err.to_string());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,110 @@
+use std::fmt;
+
+use actix_web::{HttpResponse, ResponseError, body::BoxBody};
+
+use crate::application::errors::prescription_application_error::PrescriptionApplicationError;
+
+#[derive(Debug, PartialEq)]
+pub enum PrescriptionHttpError {
+    Constraint(String),
+    Internal(String),
+    NotFound(String),
+}
+
+impl fmt::Display for PrescriptionHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrescriptionHttpError::Constraint(msg) => {
+                write!(f, "A constraint error occurred for the prescription: {msg}")
+            }
+            PrescriptionHttpError::Internal(msg) => {
+                write!(f, "An internal error occurred for the prescription: {msg}")
+            }
+            PrescriptionHttpError::NotFound(msg) => {
+                write!(f, "The prescription could not be found: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PrescriptionHttpError {}
+
+impl From<PrescriptionApplicationError> for PrescriptionHttpError {
+    fn from(value: PrescriptionApplicationError) -> Self {
+        match value {
+            PrescriptionApplicationError::Conflict(msg) => Self::Constraint(msg),
+            PrescriptionApplicationError::Constraint(msg) => Self::Constraint(msg),
+            err @ PrescriptionApplicationError::BlockedByAllergies(_) => {
+                Self::Constraint(err.to_string())
+            }
+            PrescriptionApplicationError::Unexpected(msg) => Self::Internal(msg),
+            err @ (PrescriptionApplicationError::NotFound(_)
+            | PrescriptionApplicationError::AppointmentNotFound(_)
+            | PrescriptionApplicationError::PatientNotFound(_)
+            | PrescriptionApplicationError::ProfessionalNotFound(_)) => {
+                Self::NotFound(err.to_string())
+            }
+        }
+    }
+}
+
+impl ResponseError for PrescriptionHttpError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        match self {
+            PrescriptionHttpError::Constraint(_) => {
+                HttpResponse::UnprocessableEntity().json(self.to_string())
+            }
+            PrescriptionHttpError::Internal(_) => {
+                HttpResponse::InternalServerError().json(self.to_string())
+            }
+            PrescriptionHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{ResponseError, http::StatusCode};
+
+    use crate::application::errors::prescription_application_error::PrescriptionApplicationError;
+
+    use super::PrescriptionHttpError;
+
+    #[test]
+    fn from_prescription_application_blocked_by_allergies_error() {
+        let err: PrescriptionHttpError = PrescriptionApplicationError::BlockedByAllergies(vec![
+            "Penicillin".to_string(),
+            "Dipyrone".to_string(),
+        ])
+        .into();
+
+        assert_eq!(
+            err,
+            PrescriptionHttpError::Constraint(
+                "The patient has severe allergies to: Penicillin, Dipyrone. State an allergy override reason to prescribe anyway".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn error_response_status() {
+        let cases = [
+            (
+                PrescriptionHttpError::Constraint("invalid".to_string()),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                PrescriptionHttpError::Internal("Database error".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                PrescriptionHttpError::NotFound("42".to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+        ];
+
+        for (err, status) in cases {
+            assert_eq!(err.error_response().status(), status);
+        }
+    }
+}
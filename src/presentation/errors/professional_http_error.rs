@@ -0,0 +1,99 @@
+use std::fmt;
+
+use actix_web::{HttpResponse, ResponseError, body::BoxBody};
+
+use crate::application::errors::professional_application_error::ProfessionalApplicationError;
+
+#[derive(Debug, PartialEq)]
+pub enum ProfessionalHttpError {
+    Constraint(String),
+    Internal(String),
+    NotFound(String),
+}
+
+impl fmt::Display for ProfessionalHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfessionalHttpError::Constraint(msg) => {
+                write!(f, "A constraint error occurred for the professional: {msg}")
+            }
+            ProfessionalHttpError::Internal(msg) => {
+                write!(f, "An internal error occurred for the professional: {msg}")
+            }
+            ProfessionalHttpError::NotFound(msg) => {
+                write!(f, "The professional could not be found: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProfessionalHttpError {}
+
+impl From<ProfessionalApplicationError> for ProfessionalHttpError {
+    fn from(value: ProfessionalApplicationError) -> Self {
+        match value {
+            ProfessionalApplicationError::Conflict(msg) => Self::Constraint(msg),
+            ProfessionalApplicationError::Constraint(msg) => Self::Constraint(msg),
+            ProfessionalApplicationError::Unexpected(msg) => Self::Internal(msg),
+            err @ ProfessionalApplicationError::NotFound(_) => Self::NotFound(err.to_string()),
+            ProfessionalApplicationError::ScheduleNotFound(msg) => Self::NotFound(msg),
+        }
+    }
+}
+
+impl ResponseError for ProfessionalHttpError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        match self {
+            ProfessionalHttpError::Constraint(_) => {
+                HttpResponse::UnprocessableEntity().json(self.to_string())
+            }
+            ProfessionalHttpError::Internal(_) => {
+                HttpResponse::InternalServerError().json(self.to_string())
+            }
+            ProfessionalHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{ResponseError, http::StatusCode};
+
+    use crate::application::errors::professional_application_error::ProfessionalApplicationError;
+
+    use super::ProfessionalHttpError;
+
+    #[test]
+    fn from_professional_application_not_found_error() {
+        let err: ProfessionalHttpError = ProfessionalApplicationError::NotFound(42).into();
+
+        assert_eq!(
+            err,
+            ProfessionalHttpError::NotFound(
+                "A professional with the following ID was not found: 42".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn error_response_status() {
+        let cases = [
+            (
+                ProfessionalHttpError::Constraint("invalid".to_string()),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                ProfessionalHttpError::Internal("Database error".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                ProfessionalHttpError::NotFound("42".to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+        ];
+
+        for (err, status) in cases {
+            assert_eq!(err.error_response().status(), status);
+        }
+    }
+}
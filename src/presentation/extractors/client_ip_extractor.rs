@@ -0,0 +1,158 @@
+use std::net::{AddrParseError, IpAddr};
+
+use actix_web::{FromRequest, http::header::HeaderName, web};
+use futures::future::{Ready, ready};
+
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+
+/// Reverse proxies whose `X-Forwarded-For` header is believed, read from the
+/// comma-separated `TRUSTED_PROXIES` environment variable. None by default.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrustedProxies(Vec<IpAddr>);
+
+impl TrustedProxies {
+    pub fn new(proxies: Vec<IpAddr>) -> Self {
+        Self(proxies)
+    }
+
+    pub fn from_env() -> Result<Self, AddrParseError> {
+        let Ok(proxies) = std::env::var("TRUSTED_PROXIES") else {
+            return Ok(Self::default());
+        };
+
+        proxies
+            .split(',')
+            .map(str::trim)
+            .filter(|proxy| !proxy.is_empty())
+            .map(str::parse::<IpAddr>)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+
+    fn trusts(&self, addr: &IpAddr) -> bool {
+        self.0.contains(addr)
+    }
+}
+
+/// The address a request came from, as far as it can be trusted. A direct
+/// peer is taken as is. When the peer is a trusted proxy, `X-Forwarded-For`
+/// is walked from the right past the other trusted proxies; everything to
+/// the left of the first untrusted hop was written by the client and is
+/// ignored.
+pub struct ClientIp(pub String);
+
+impl ClientIp {
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl FromRequest for ClientIp {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        _payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        let proxies = req
+            .app_data::<web::Data<TrustedProxies>>()
+            .map(|proxies| proxies.get_ref().clone())
+            .unwrap_or_default();
+        let forwarded_for: Vec<&str> = req
+            .headers()
+            .get_all(X_FORWARDED_FOR)
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .collect();
+
+        let client_ip = resolve(
+            req.peer_addr().map(|addr| addr.ip()),
+            &forwarded_for,
+            &proxies,
+        );
+
+        ready(Ok(ClientIp(
+            client_ip.map_or("unknown".to_string(), |ip| ip.to_string()),
+        )))
+    }
+}
+
+fn resolve(
+    peer: Option<IpAddr>,
+    forwarded_for: &[&str],
+    proxies: &TrustedProxies,
+) -> Option<IpAddr> {
+    let mut client = peer?;
+
+    for hop in forwarded_for.iter().rev() {
+        if !proxies.trusts(&client) {
+            break;
+        }
+
+        match hop.parse::<IpAddr>() {
+            Ok(hop) => client = hop,
+            Err(_) => break,
+        }
+    }
+
+    Some(client)
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::{IpAddr, SocketAddr};
+
+    use actix_web::{FromRequest, test::TestRequest, web};
+
+    use super::{ClientIp, TrustedProxies};
+
+    fn proxy() -> IpAddr {
+        "10.0.0.1".parse().unwrap()
+    }
+
+    async fn client_ip(req: TestRequest) -> String {
+        let (req, mut payload) = req.peer_addr(SocketAddr::new(proxy(), 443)).to_http_parts();
+
+        ClientIp::from_request(&req, &mut payload)
+            .await
+            .unwrap()
+            .into_inner()
+    }
+
+    #[tokio::test]
+    async fn from_request_untrusted_peer_ignores_header() {
+        let ip =
+            client_ip(TestRequest::default().insert_header(("X-Forwarded-For", "203.0.113.9")))
+                .await;
+
+        assert_eq!(ip, "10.0.0.1");
+    }
+
+    #[tokio::test]
+    async fn from_request_trusted_proxy() {
+        let ip = client_ip(
+            TestRequest::default()
+                .app_data(web::Data::new(TrustedProxies::new(vec![proxy()])))
+                .insert_header(("X-Forwarded-For", "198.51.100.1, 203.0.113.9")),
+        )
+        .await;
+
+        assert_eq!(ip, "203.0.113.9");
+    }
+
+    #[tokio::test]
+    async fn from_request_spoofed_entries_are_ignored() {
+        let proxies = TrustedProxies::new(vec![proxy(), "10.0.0.2".parse().unwrap()]);
+
+        let ip = client_ip(
+            TestRequest::default()
+                .app_data(web::Data::new(proxies))
+                .insert_header(("X-Forwarded-For", "garbage, 203.0.113.9, 10.0.0.2")),
+        )
+        .await;
+
+        assert_eq!(ip, "203.0.113.9");
+    }
+}
@@ -0,0 +1,68 @@
+use actix_web::FromRequest;
+use futures::future::{Ready, ready};
+
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::value_objects::cpf::Cpf, presentation::errors::patient_http_error::PatientHttpError,
+};
+
+/// Extracts and validates the `{cpf}` segment of the request path.
+pub struct CpfPath(pub Cpf);
+
+impl CpfPath {
+    pub fn into_inner(self) -> Cpf {
+        self.0
+    }
+}
+
+impl FromRequest for CpfPath {
+    type Error = PatientHttpError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        _payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        let cpf = req.match_info().get("cpf").unwrap_or_default();
+
+        ready(
+            Cpf::parse(cpf)
+                .map(CpfPath)
+                .map_err(|err| PatientApplicationError::from(err).into()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{FromRequest, test::TestRequest};
+
+    use crate::presentation::errors::patient_http_error::PatientHttpError;
+
+    use super::CpfPath;
+
+    #[tokio::test]
+    async fn from_request_ok() {
+        let (req, mut payload) = TestRequest::default()
+            .param("cpf", "529.982.247-25")
+            .to_http_parts();
+
+        let cpf = CpfPath::from_request(&req, &mut payload).await.unwrap();
+
+        assert_eq!(cpf.into_inner().as_str(), "52998224725");
+    }
+
+    #[tokio::test]
+    async fn from_request_invalid_cpf_error() {
+        let (req, mut payload) = TestRequest::default()
+            .param("cpf", "12345678900")
+            .to_http_parts();
+
+        let result = CpfPath::from_request(&req, &mut payload).await;
+
+        assert_eq!(
+            result.err(),
+            Some(PatientHttpError::InvalidCpf("12345678900".to_string()))
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use actix_multipart::{Field, Multipart};
+use actix_web::FromRequest;
+use futures::{TryStreamExt, future::LocalBoxFuture};
+
+use crate::{
+    application::errors::patient_application_error::PatientApplicationError,
+    domain::{
+        entities::document::MAX_DOCUMENT_SIZE, errors::patient_entity_error::PatientEntityError,
+        value_objects::document_category::DocumentCategory,
+    },
+    presentation::{
+        dtos::document_dto::UploadDocumentDTO, errors::patient_http_error::PatientHttpError,
+    },
+};
+
+const MAX_TEXT_FIELD_SIZE: usize = 4 * 1024;
+
+/// Reads a `multipart/form-data` upload with a `file` part plus `category`
+/// and optional `description` text parts. Reading stops as soon as a part
+/// grows past its limit.
+pub struct DocumentUpload(pub UploadDocumentDTO);
+
+impl DocumentUpload {
+    pub fn into_inner(self) -> UploadDocumentDTO {
+        self.0
+    }
+}
+
+impl FromRequest for DocumentUpload {
+    type Error = PatientHttpError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        let multipart = Multipart::new(req.headers(), payload.take());
+
+        Box::pin(async move { read_upload(multipart).await.map_err(|err| err.into()) })
+    }
+}
+
+async fn read_upload(mut multipart: Multipart) -> Result<DocumentUpload, PatientApplicationError> {
+    let mut category = None;
+    let mut description = None;
+    let mut file = None;
+
+    while let Some(mut field) = multipart.try_next().await.map_err(invalid_upload)? {
+        match field.name().unwrap_or_default() {
+            "file" => {
+                let file_name = field
+                    .content_disposition()
+                    .and_then(|disposition| disposition.get_filename())
+                    .unwrap_or_default()
+                    .to_string();
+                let content_type = field
+                    .content_type()
+                    .map(|mime| mime.essence_str().to_string())
+                    .unwrap_or_default();
+                let content = read_field(&mut field, MAX_DOCUMENT_SIZE).await?;
+
+                file = Some((file_name, content_type, content));
+            }
+            "category" => {
+                category = Some(DocumentCategory::try_from(
+                    read_text_field(&mut field).await?,
+                )?);
+            }
+            "description" => description = Some(read_text_field(&mut field).await?),
+            _ => {
+                read_field(&mut field, MAX_TEXT_FIELD_SIZE).await?;
+            }
+        }
+    }
+
+    let (file_name, content_type, content) = file.ok_or(PatientEntityError::InvalidDocument(
+        "the file part is missing".to_string(),
+    ))?;
+    let category = category.ok_or(PatientEntityError::InvalidDocument(
+        "the category part is missing".to_string(),
+    ))?;
+
+    Ok(DocumentUpload(UploadDocumentDTO {
+        category,
+        file_name,
+        content_type,
+        description,
+        content,
+    }))
+}
+
+async fn read_field(field: &mut Field, limit: usize) -> Result<Vec<u8>, PatientApplicationError> {
+    let mut content = Vec::new();
+
+    while let Some(chunk) = field.try_next().await.map_err(invalid_upload)? {
+        if content.len() + chunk.len() > limit {
+            return Err(PatientEntityError::InvalidDocument(format!(
+                "the {} part must have at most {limit} bytes",
+                field.name().unwrap_or_default()
+            ))
+            .into());
+        }
+
+        content.extend_from_slice(&chunk);
+    }
+
+    Ok(content)
+}
+
+async fn read_text_field(field: &mut Field) -> Result<String, PatientApplicationError> {
+    let content = read_field(field, MAX_TEXT_FIELD_SIZE).await?;
+
+    String::from_utf8(content).map_err(invalid_upload)
+}
+
+fn invalid_upload(err: impl ToString) -> PatientApplicationError {
+    PatientEntityError::InvalidDocument(err.to_string()).into()
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{FromRequest, test::TestRequest};
+
+    use crate::{
+        domain::value_objects::document_category::DocumentCategory,
+        presentation::errors::patient_http_error::PatientHttpError,
+    };
+
+    use super::DocumentUpload;
+
+    const BOUNDARY: &str = "X-BOUNDARY";
+
+    /// Builds a `multipart/form-data` request with the given text parts and,
+    /// if given, a `referral.pdf` file part.
+    fn make_request(text_parts: &[(&str, &str)], file: Option<&[u8]>) -> TestRequest {
+        let mut body = Vec::new();
+
+        for (name, value) in text_parts {
+            body.extend_from_slice(
+                format!(
+                    "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+                )
+                .as_bytes(),
+            );
+        }
+
+        if let Some(content) = file {
+            body.extend_from_slice(
+                format!(
+                    "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"referral.pdf\"\r\nContent-Type: application/pdf\r\n\r\n"
+                )
+                .as_bytes(),
+            );
+            body.extend_from_slice(content);
+            body.extend_from_slice(b"\r\n");
+        }
+
+        body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+
+        TestRequest::post()
+            .insert_header((
+                "Content-Type",
+                format!("multipart/form-data; boundary={BOUNDARY}"),
+            ))
+            .set_payload(body)
+    }
+
+    #[tokio::test]
+    async fn from_request_ok() {
+        let (req, mut payload) = make_request(
+            &[
+                ("category", "referral"),
+                ("description", "Cardiology referral"),
+            ],
+            Some(b"%PDF-1.7"),
+        )
+        .to_http_parts();
+
+        let upload = DocumentUpload::from_request(&req, &mut payload)
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(upload.category, DocumentCategory::Referral);
+        assert_eq!(upload.file_name, "referral.pdf");
+        assert_eq!(upload.content_type, "application/pdf");
+        assert_eq!(upload.description, Some("Cardiology referral".to_string()));
+        assert_eq!(upload.content, b"%PDF-1.7");
+    }
+
+    #[tokio::test]
+    async fn from_request_missing_file_error() {
+        let (req, mut payload) = make_request(&[("category", "other")], None).to_http_parts();
+
+        let result = DocumentUpload::from_request(&req, &mut payload).await;
+
+        assert_eq!(
+            result.err(),
+            Some(PatientHttpError::Constraint(
+                "An invalid document was given: the file part is missing".to_string()
+            ))
+        );
+    }
+}
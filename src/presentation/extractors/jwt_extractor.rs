@@ -1,32 +1,145 @@
-use actix_web::FromRequest;
-use futures::future::{Ready, ready};
+use actix_web::{FromRequest, web};
+use futures::future::LocalBoxFuture;
+use log::error;
 
-use crate::application::security::jwt::jwt::validate_jwt;
+use crate::{
+    application::security::jwt::jwt::validate_jwt,
+    domain::{repositories::session_repository::SessionRepository, value_objects::role::Role},
+};
 
+/// Any user holding a valid token, whatever their role, including one that
+/// must change their password first. The session the token was issued under
+/// must still be open, so logging out or revoking sessions takes effect
+/// before the token expires.
 pub struct AuthenticatedAdmin {
     pub email: String,
+    pub role: Role,
+    pub must_change_password: bool,
+    pub session_id: i32,
 }
 
 impl FromRequest for AuthenticatedAdmin {
     type Error = actix_web::Error;
-    type Future = Ready<Result<Self, Self::Error>>;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
 
     fn from_request(
         req: &actix_web::HttpRequest,
         _payload: &mut actix_web::dev::Payload,
     ) -> Self::Future {
-        if let Some(auth_header) = req.headers().get("Authorization") {
-            if let Ok(auth_str) = auth_header.to_str() {
-                if let Some(token) = auth_str.strip_prefix("Bearer ") {
-                    if let Ok(token_data) = validate_jwt(token.to_string()) {
-                        return ready(Ok(AuthenticatedAdmin {
-                            email: token_data.claims.sub,
-                        }));
-                    }
-                }
+        let claims = req
+            .headers()
+            .get("Authorization")
+            .and_then(|auth_header| auth_header.to_str().ok())
+            .and_then(|auth_str| auth_str.strip_prefix("Bearer "))
+            .and_then(|token| validate_jwt(token.to_string()).ok())
+            .map(|token_data| token_data.claims);
+        let sessions = req.app_data::<web::Data<dyn SessionRepository>>().cloned();
+
+        Box::pin(async move {
+            let claims =
+                claims.ok_or_else(|| actix_web::error::ErrorUnauthorized("Unauthorized"))?;
+
+            let sessions = sessions.ok_or_else(|| {
+                error!("No session repository is registered as app data");
+                actix_web::error::ErrorInternalServerError("Internal Server Error")
+            })?;
+
+            let session = sessions.find_by_id(claims.sid).await.map_err(|err| {
+                error!("Could not check the session {}: {err}", claims.sid);
+                actix_web::error::ErrorInternalServerError("Internal Server Error")
+            })?;
+
+            if !session.is_some_and(|session| session.is_active(chrono::Local::now().naive_utc())) {
+                return Err(actix_web::error::ErrorUnauthorized("Unauthorized"));
             }
-        }
 
-        ready(Err(actix_web::error::ErrorUnauthorized("Unauthorized")))
+            Ok(AuthenticatedAdmin {
+                email: claims.sub,
+                role: claims.role,
+                must_change_password: claims.must_change_password,
+                session_id: claims.sid,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use actix_web::{FromRequest, http::StatusCode, test::TestRequest, web};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::security::jwt::jwt::create_jwt,
+        domain::{
+            entities::{admin::Admin, session::Session},
+            repositories::session_repository::{MockSessionRepository, SessionRepository},
+            value_objects::role::Role,
+        },
+    };
+
+    use super::AuthenticatedAdmin;
+
+    fn make_request(session: Option<Session>) -> TestRequest {
+        let admin = Admin::new(
+            "Remy Hadley".to_string(),
+            "hadley@sghss.com",
+            Role::Physician,
+            "hash".to_string(),
+        )
+        .unwrap();
+        let token = create_jwt(&admin, 12).unwrap();
+
+        let mut mock_session_repo = MockSessionRepository::new();
+        mock_session_repo
+            .expect_find_by_id()
+            .with(eq(12))
+            .times(1)
+            .return_const(Ok(session));
+        let sessions: Arc<dyn SessionRepository> = Arc::new(mock_session_repo);
+
+        TestRequest::default()
+            .app_data(web::Data::from(sessions))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+    }
+
+    #[tokio::test]
+    async fn from_request_open_session() {
+        let (req, mut payload) =
+            make_request(Some(Session::start(3, "hash".to_string()))).to_http_parts();
+
+        let user = AuthenticatedAdmin::from_request(&req, &mut payload)
+            .await
+            .unwrap();
+
+        assert_eq!(user.email, "hadley@sghss.com");
+        assert_eq!(user.session_id, 12);
+    }
+
+    #[tokio::test]
+    async fn from_request_revoked_session() {
+        let mut session = Session::start(3, "hash".to_string());
+        session.revoke();
+        let (req, mut payload) = make_request(Some(session)).to_http_parts();
+
+        let err = AuthenticatedAdmin::from_request(&req, &mut payload)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(err.error_response().status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn from_request_unknown_session() {
+        let (req, mut payload) = make_request(None).to_http_parts();
+
+        let err = AuthenticatedAdmin::from_request(&req, &mut payload)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(err.error_response().status(), StatusCode::UNAUTHORIZED);
     }
 }
@@ -1 +1,5 @@
+pub mod client_ip_extractor;
+pub mod cpf_extractor;
+pub mod document_extractor;
 pub mod jwt_extractor;
+pub mod role_extractor;
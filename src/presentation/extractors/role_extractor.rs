@@ -0,0 +1,200 @@
+use std::marker::PhantomData;
+
+use actix_web::FromRequest;
+use futures::future::LocalBoxFuture;
+
+use crate::{
+    domain::value_objects::role::Role, presentation::extractors::jwt_extractor::AuthenticatedAdmin,
+};
+
+/// The roles allowed through a [`RequireRole`] extractor.
+pub trait RolePolicy {
+    const ROLES: &'static [Role];
+}
+
+/// Account and facility management.
+pub struct Administrators;
+
+impl RolePolicy for Administrators {
+    const ROLES: &'static [Role] = &[Role::Admin];
+}
+
+/// Everyone who works with patients: registration, scheduling, documents and
+/// bed management.
+pub struct Staff;
+
+impl RolePolicy for Staff {
+    const ROLES: &'static [Role] = &[
+        Role::Admin,
+        Role::Physician,
+        Role::Nurse,
+        Role::Receptionist,
+    ];
+}
+
+/// The clinical record: encounters, allergies, problems, prescriptions and
+/// exams.
+pub struct Clinicians;
+
+impl RolePolicy for Clinicians {
+    const ROLES: &'static [Role] = &[Role::Physician, Role::Nurse];
+}
+
+/// Acts only a physician may take, such as prescribing or ordering exams.
+pub struct Physicians;
+
+impl RolePolicy for Physicians {
+    const ROLES: &'static [Role] = &[Role::Physician];
+}
+
+/// Data protection requests, such as a patient's data export.
+pub struct Compliance;
+
+impl RolePolicy for Compliance {
+    const ROLES: &'static [Role] = &[Role::Admin, Role::Auditor];
+}
+
+/// A user holding a valid token whose role is allowed by `P`. A missing or
+/// invalid token is rejected with 401; a role outside `P`, or a pending
+/// password change, with 403.
+pub struct RequireRole<P: RolePolicy> {
+    pub email: String,
+    pub role: Role,
+    policy: PhantomData<P>,
+}
+
+impl<P: RolePolicy> FromRequest for RequireRole<P> {
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        let user = AuthenticatedAdmin::from_request(req, payload);
+
+        Box::pin(async move {
+            let user = user.await?;
+
+            if user.must_change_password {
+                return Err(actix_web::error::ErrorForbidden("Password change required"));
+            }
+
+            if !P::ROLES.contains(&user.role) {
+                return Err(actix_web::error::ErrorForbidden("Forbidden"));
+            }
+
+            Ok(RequireRole {
+                email: user.email,
+                role: user.role,
+                policy: PhantomData,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use actix_web::{FromRequest, http::StatusCode, test::TestRequest, web};
+
+    use crate::{
+        application::security::jwt::jwt::create_jwt,
+        domain::{
+            entities::{admin::Admin, session::Session},
+            repositories::session_repository::{MockSessionRepository, SessionRepository},
+            value_objects::role::Role,
+        },
+    };
+
+    use super::{Clinicians, RequireRole};
+
+    fn make_token(email: &str, role: Role, must_change_password: bool) -> Option<String> {
+        let admin = Admin {
+            must_change_password,
+            ..Admin::new("Staff".to_string(), email, role, "hash".to_string()).unwrap()
+        };
+
+        create_jwt(&admin, 12)
+    }
+
+    /// A request whose session store knows the session 12 as open.
+    fn make_request(token: Option<String>) -> TestRequest {
+        let mut mock_session_repo = MockSessionRepository::new();
+        mock_session_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(Session::start(3, "hash".to_string()))));
+        let sessions: Arc<dyn SessionRepository> = Arc::new(mock_session_repo);
+
+        let request = TestRequest::default().app_data(web::Data::from(sessions));
+        match token {
+            Some(token) => request.insert_header(("Authorization", format!("Bearer {token}"))),
+            None => request,
+        }
+    }
+
+    #[tokio::test]
+    async fn from_request_allowed_role() {
+        let token = make_token("house@example.com", Role::Physician, false);
+        let (req, mut payload) = make_request(token).to_http_parts();
+
+        let user = RequireRole::<Clinicians>::from_request(&req, &mut payload)
+            .await
+            .unwrap();
+
+        assert_eq!(user.email, "house@example.com");
+        assert_eq!(user.role, Role::Physician);
+    }
+
+    #[tokio::test]
+    async fn from_request_forbidden_role() {
+        let token = make_token("front@example.com", Role::Receptionist, false);
+        let (req, mut payload) = make_request(token).to_http_parts();
+
+        let err = RequireRole::<Clinicians>::from_request(&req, &mut payload)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(err.error_response().status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn from_request_password_change_required() {
+        let token = make_token("house@example.com", Role::Physician, true);
+        let (req, mut payload) = make_request(token).to_http_parts();
+
+        let err = RequireRole::<Clinicians>::from_request(&req, &mut payload)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(err.error_response().status(), StatusCode::FORBIDDEN);
+        assert_eq!(err.to_string(), "Password change required");
+    }
+
+    #[tokio::test]
+    async fn from_request_missing_token() {
+        let (req, mut payload) = make_request(None).to_http_parts();
+
+        let err = RequireRole::<Clinicians>::from_request(&req, &mut payload)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(err.error_response().status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn from_request_invalid_token() {
+        let (req, mut payload) = make_request(Some("not-a-token".to_string())).to_http_parts();
+
+        let err = RequireRole::<Clinicians>::from_request(&req, &mut payload)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(err.error_response().status(), StatusCode::UNAUTHORIZED);
+    }
+}
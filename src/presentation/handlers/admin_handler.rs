@@ -1,20 +1,253 @@
-use actix_web::{HttpResponse, post, web};
+use actix_web::{HttpResponse, ResponseError, delete, get, patch, post, put, web};
 
 use crate::{
-    application::use_cases::login::LoginUseCase, infrastructure::web::AppState,
-    presentation::dtos::admin_dto::LoginDTO,
+    application::{
+        security::jwt::jwt,
+        use_cases::{
+            change_password::ChangePasswordUseCase, create_user::CreateUserUseCase,
+            deactivate_user::DeactivateUserUseCase,
+            link_user_professional::LinkUserProfessionalUseCase, list_users::ListUsersUseCase,
+            login::LoginUseCase, logout::LogoutUseCase, reactivate_user::ReactivateUserUseCase,
+            refresh_session::RefreshSessionUseCase, reset_password::ResetPasswordUseCase,
+            revoke_sessions::RevokeSessionsUseCase,
+        },
+    },
+    infrastructure::web::AppState,
+    presentation::{
+        dtos::admin_dto::{
+            ChangePasswordDTO, CreateUserDTO, LinkProfessionalDTO, LoadedUserDTO, LoginDTO,
+            RefreshDTO, ResetPasswordDTO, TokenPairDTO,
+        },
+        errors::admin_http_error::AdminHttpError,
+        extractors::{
+            client_ip_extractor::ClientIp,
+            jwt_extractor::AuthenticatedAdmin,
+            role_extractor::{Administrators, RequireRole},
+        },
+    },
 };
 
-#[post("/login")]
+/// Failures are throttled per client address; set `TRUSTED_PROXIES` when
+/// running behind a reverse proxy, or every client shares its address.
+#[post("/api/v1/login")]
 pub async fn login_handler(
+    client_ip: ClientIp,
     app_state: web::Data<AppState>,
     input: web::Json<LoginDTO>,
 ) -> HttpResponse {
-    match LoginUseCase::new(app_state.admin_repo.clone())
-        .execute(input.into_inner())
+    match LoginUseCase::new(
+        app_state.admin_repo.clone(),
+        app_state.session_repo.clone(),
+        app_state.failed_login_repo.clone(),
+        app_state.audit_repo.clone(),
+    )
+    .execute(input.into_inner(), client_ip.into_inner())
+    .await
+    {
+        Ok(tokens) => HttpResponse::Ok().json(TokenPairDTO::from(tokens)),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+/// The public keys access tokens can be verified with.
+#[get("/.well-known/jwks.json")]
+pub async fn jwks_handler() -> HttpResponse {
+    HttpResponse::Ok().json(jwt::config().public_keys())
+}
+
+#[post("/api/v1/refresh")]
+pub async fn refresh_handler(
+    app_state: web::Data<AppState>,
+    input: web::Json<RefreshDTO>,
+) -> HttpResponse {
+    match RefreshSessionUseCase::new(app_state.admin_repo.clone(), app_state.session_repo.clone())
+        .execute(&input.refresh_token)
+        .await
+    {
+        Ok(tokens) => HttpResponse::Ok().json(TokenPairDTO::from(tokens)),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/api/v1/logout")]
+pub async fn logout_handler(
+    user: AuthenticatedAdmin,
+    app_state: web::Data<AppState>,
+) -> HttpResponse {
+    match LogoutUseCase::new(app_state.session_repo.clone())
+        .execute(user.session_id)
+        .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+#[post("")]
+pub async fn create_user_handler(
+    user: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    input: web::Json<CreateUserDTO>,
+) -> HttpResponse {
+    match CreateUserUseCase::new(app_state.admin_repo.clone(), app_state.audit_repo.clone())
+        .execute(input.into_inner(), user.email)
+        .await
+    {
+        Ok(admin) => HttpResponse::Ok().json(Option::<LoadedUserDTO>::from(admin)),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+#[get("")]
+pub async fn list_users_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+) -> HttpResponse {
+    match ListUsersUseCase::new(app_state.admin_repo.clone())
+        .execute()
+        .await
+    {
+        Ok(admins) => {
+            let loaded_users: Vec<LoadedUserDTO> = admins
+                .into_iter()
+                .filter_map(|admin| admin.into())
+                .collect();
+            HttpResponse::Ok().json(loaded_users)
+        }
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+/// Open to every role, including accounts that must change their password.
+/// Answers with a new token pair; the caller's other sessions are logged out.
+#[put("/me/password")]
+pub async fn change_password_handler(
+    user: AuthenticatedAdmin,
+    app_state: web::Data<AppState>,
+    input: web::Json<ChangePasswordDTO>,
+) -> HttpResponse {
+    match ChangePasswordUseCase::new(
+        app_state.admin_repo.clone(),
+        app_state.session_repo.clone(),
+        app_state.audit_repo.clone(),
+    )
+    .execute(user.email, input.into_inner())
+    .await
+    {
+        Ok(tokens) => HttpResponse::Ok().json(TokenPairDTO::from(tokens)),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+/// Logs the caller out on every device.
+#[delete("/me/sessions")]
+pub async fn revoke_own_sessions_handler(
+    user: AuthenticatedAdmin,
+    app_state: web::Data<AppState>,
+) -> HttpResponse {
+    match RevokeSessionsUseCase::new(
+        app_state.admin_repo.clone(),
+        app_state.session_repo.clone(),
+        app_state.audit_repo.clone(),
+    )
+    .execute_own(user.email)
+    .await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+#[patch("/{id}/deactivation")]
+pub async fn deactivate_user_handler(
+    user: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    match DeactivateUserUseCase::new(
+        app_state.admin_repo.clone(),
+        app_state.session_repo.clone(),
+        app_state.audit_repo.clone(),
+    )
+    .execute(path.into_inner(), user.email)
+    .await
+    {
+        Ok(admin) => HttpResponse::Ok().json(Option::<LoadedUserDTO>::from(admin)),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+/// Links the account to the professional it records clinical notes as.
+#[put("/{id}/professional")]
+pub async fn link_user_professional_handler(
+    user: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<LinkProfessionalDTO>,
+) -> HttpResponse {
+    match LinkUserProfessionalUseCase::new(
+        app_state.admin_repo.clone(),
+        app_state.professional_repo.clone(),
+        app_state.audit_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner(), user.email)
+    .await
+    {
+        Ok(admin) => HttpResponse::Ok().json(Option::<LoadedUserDTO>::from(admin)),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+#[patch("/{id}/reactivation")]
+pub async fn reactivate_user_handler(
+    user: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    match ReactivateUserUseCase::new(app_state.admin_repo.clone(), app_state.audit_repo.clone())
+        .execute(path.into_inner(), user.email)
         .await
     {
-        Ok(token) => HttpResponse::Ok().json(token),
-        Err(err) => HttpResponse::Unauthorized().json(err.to_string()),
+        Ok(admin) => HttpResponse::Ok().json(Option::<LoadedUserDTO>::from(admin)),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{id}/password-reset")]
+pub async fn reset_password_handler(
+    user: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<ResetPasswordDTO>,
+) -> HttpResponse {
+    match ResetPasswordUseCase::new(
+        app_state.admin_repo.clone(),
+        app_state.session_repo.clone(),
+        app_state.audit_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner(), user.email)
+    .await
+    {
+        Ok(admin) => HttpResponse::Ok().json(Option::<LoadedUserDTO>::from(admin)),
+        Err(err) => AdminHttpError::from(err).error_response(),
+    }
+}
+
+#[delete("/{id}/sessions")]
+pub async fn revoke_sessions_handler(
+    user: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    match RevokeSessionsUseCase::new(
+        app_state.admin_repo.clone(),
+        app_state.session_repo.clone(),
+        app_state.audit_repo.clone(),
+    )
+    .execute(path.into_inner(), user.email)
+    .await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => AdminHttpError::from(err).error_response(),
     }
 }
@@ -0,0 +1,93 @@
+use crate::{
+    application::use_cases::{
+        admit_patient::AdmitPatientUseCase, discharge_patient::DischargePatientUseCase,
+        find_admission_by_id::FindAdmissionByIdUseCase, transfer_patient::TransferPatientUseCase,
+    },
+    infrastructure::web::AppState,
+    presentation::{
+        dtos::admission_dto::{
+            AdmitPatientDTO, DischargePatientDTO, LoadedAdmissionDTO, TransferPatientDTO,
+        },
+        errors::admission_http_error::AdmissionHttpError,
+        extractors::role_extractor::{Clinicians, RequireRole, Staff},
+    },
+};
+use actix_web::{HttpResponse, ResponseError, get, post, web};
+
+#[post("")]
+pub async fn admit_patient_handler(
+    user: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    input: web::Json<AdmitPatientDTO>,
+) -> HttpResponse {
+    match AdmitPatientUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.professional_repo.clone(),
+        app_state.ward_repo.clone(),
+        app_state.admission_repo.clone(),
+    )
+    .execute(input.into_inner(), user.email)
+    .await
+    {
+        Ok((admission, occupancies)) => {
+            HttpResponse::Ok().json(LoadedAdmissionDTO::new(admission, occupancies))
+        }
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{id}")]
+pub async fn find_admission_by_id_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    match FindAdmissionByIdUseCase::new(app_state.admission_repo.clone())
+        .execute(path.into_inner())
+        .await
+    {
+        Ok((admission, occupancies)) => {
+            HttpResponse::Ok().json(LoadedAdmissionDTO::new(admission, occupancies))
+        }
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{id}/transfer")]
+pub async fn transfer_patient_handler(
+    user: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<TransferPatientDTO>,
+) -> HttpResponse {
+    match TransferPatientUseCase::new(
+        app_state.ward_repo.clone(),
+        app_state.admission_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner(), user.email)
+    .await
+    {
+        Ok((admission, occupancies)) => {
+            HttpResponse::Ok().json(LoadedAdmissionDTO::new(admission, occupancies))
+        }
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{id}/discharge")]
+pub async fn discharge_patient_handler(
+    user: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<DischargePatientDTO>,
+) -> HttpResponse {
+    match DischargePatientUseCase::new(app_state.admission_repo.clone())
+        .execute(path.into_inner(), input.into_inner(), user.email)
+        .await
+    {
+        Ok((admission, occupancies)) => {
+            HttpResponse::Ok().json(LoadedAdmissionDTO::new(admission, occupancies))
+        }
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
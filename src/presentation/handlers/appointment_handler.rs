@@ -1,25 +1,45 @@
 use crate::{
     application::use_cases::{
         book_appointment::BookAppointmentUseCase, cancel_appointment::CancelAppointmentUseCase,
+        change_appointment_status::ChangeAppointmentStatusUseCase,
+        find_appointment_by_id::FindAppointmentByIdUseCase,
+        find_appointment_by_patient_cpf_and_time::FindAppointmentByPatientCpfAndTimeUseCase,
+        record_encounter::RecordEncounterUseCase,
+        reschedule_appointment::RescheduleAppointmentUseCase,
+        search_appointments::SearchAppointmentsUseCase,
     },
+    domain::value_objects::appointment_status::AppointmentStatus,
     infrastructure::web::AppState,
     presentation::{
-        dtos::appointment_dto::{BookAppointmentDTO, CancelAppointmentDTO, LoadedAppointmentDTO},
-        errors::appointment_http_error::AppointmentHttpError,
-        extractors::jwt_extractor::AuthenticatedAdmin,
+        dtos::{
+            appointment_dto::{
+                AppointmentLookupQueryDTO, BookAppointmentDTO, CancelAppointmentByIdDTO,
+                CancelAppointmentDTO, LoadedAppointmentDTO, RescheduleAppointmentDTO,
+                SearchAppointmentsQueryDTO,
+            },
+            encounter_dto::{EncounterSectionsDTO, LoadedEncounterDTO},
+            page_dto::PagedDTO,
+        },
+        errors::{
+            appointment_http_error::AppointmentHttpError, encounter_http_error::EncounterHttpError,
+        },
+        extractors::role_extractor::{Clinicians, RequireRole, Staff},
     },
 };
-use actix_web::{HttpResponse, ResponseError, patch, post, web};
+use actix_web::{HttpResponse, ResponseError, get, patch, post, web};
 
 #[post("")]
 pub async fn book_appointment_handler(
-    _: AuthenticatedAdmin,
+    _: RequireRole<Staff>,
     app_state: web::Data<AppState>,
     input: web::Json<BookAppointmentDTO>,
 ) -> HttpResponse {
     match BookAppointmentUseCase::new(
         app_state.appointment_repo.clone(),
         app_state.patient_repo.clone(),
+        app_state.professional_repo.clone(),
+        app_state.schedule_repo.clone(),
+        app_state.consent_repo.clone(),
     )
     .execute(input.into_inner())
     .await
@@ -32,9 +52,69 @@ pub async fn book_appointment_handler(
     }
 }
 
+#[get("")]
+pub async fn search_appointments_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    query: web::Query<SearchAppointmentsQueryDTO>,
+) -> HttpResponse {
+    match SearchAppointmentsUseCase::new(
+        app_state.appointment_repo.clone(),
+        app_state.patient_repo.clone(),
+    )
+    .execute(query.into_inner())
+    .await
+    {
+        Ok(page) => {
+            let paged_appointments: PagedDTO<LoadedAppointmentDTO> = page.into();
+            HttpResponse::Ok().json(paged_appointments)
+        }
+        Err(err) => AppointmentHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/lookup")]
+pub async fn find_appointment_by_patient_cpf_and_time_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    query: web::Query<AppointmentLookupQueryDTO>,
+) -> HttpResponse {
+    match FindAppointmentByPatientCpfAndTimeUseCase::new(
+        app_state.appointment_repo.clone(),
+        app_state.patient_repo.clone(),
+    )
+    .execute(query.into_inner())
+    .await
+    {
+        Ok(appointment) => {
+            let loaded_appointment: Option<LoadedAppointmentDTO> = appointment.into();
+            HttpResponse::Ok().json(loaded_appointment)
+        }
+        Err(err) => AppointmentHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{id}")]
+pub async fn find_appointment_by_id_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    match FindAppointmentByIdUseCase::new(app_state.appointment_repo.clone())
+        .execute(path.into_inner())
+        .await
+    {
+        Ok(appointment) => {
+            let loaded_appointment: Option<LoadedAppointmentDTO> = appointment.into();
+            HttpResponse::Ok().json(loaded_appointment)
+        }
+        Err(err) => AppointmentHttpError::from(err).error_response(),
+    }
+}
+
 #[patch("/cancellation")]
 pub async fn cancel_appointment_handler(
-    _: AuthenticatedAdmin,
+    _: RequireRole<Staff>,
     app_state: web::Data<AppState>,
     input: web::Json<CancelAppointmentDTO>,
 ) -> HttpResponse {
@@ -52,3 +132,133 @@ pub async fn cancel_appointment_handler(
         Err(err) => AppointmentHttpError::from(err).error_response(),
     }
 }
+
+#[patch("/{id}/cancellation")]
+pub async fn cancel_appointment_by_id_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<CancelAppointmentByIdDTO>,
+) -> HttpResponse {
+    match CancelAppointmentUseCase::new(
+        app_state.appointment_repo.clone(),
+        app_state.patient_repo.clone(),
+    )
+    .execute_by_id(path.into_inner(), input.into_inner())
+    .await
+    {
+        Ok(appointment) => {
+            let loaded_appointment: Option<LoadedAppointmentDTO> = appointment.into();
+            HttpResponse::Ok().json(loaded_appointment)
+        }
+        Err(err) => AppointmentHttpError::from(err).error_response(),
+    }
+}
+
+#[patch("/{id}/confirmation")]
+pub async fn confirm_appointment_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    change_appointment_status(app_state, path.into_inner(), AppointmentStatus::Confirmed).await
+}
+
+#[patch("/{id}/check-in")]
+pub async fn check_in_appointment_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    change_appointment_status(app_state, path.into_inner(), AppointmentStatus::CheckedIn).await
+}
+
+#[patch("/{id}/start")]
+pub async fn start_appointment_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    change_appointment_status(app_state, path.into_inner(), AppointmentStatus::InProgress).await
+}
+
+#[patch("/{id}/completion")]
+pub async fn complete_appointment_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    change_appointment_status(app_state, path.into_inner(), AppointmentStatus::Completed).await
+}
+
+#[patch("/{id}/no-show")]
+pub async fn no_show_appointment_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    change_appointment_status(app_state, path.into_inner(), AppointmentStatus::NoShow).await
+}
+
+#[patch("/{id}/reschedule")]
+pub async fn reschedule_appointment_handler(
+    user: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<RescheduleAppointmentDTO>,
+) -> HttpResponse {
+    match RescheduleAppointmentUseCase::new(
+        app_state.appointment_repo.clone(),
+        app_state.schedule_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner(), user.email)
+    .await
+    {
+        Ok(appointment) => {
+            let loaded_appointment: Option<LoadedAppointmentDTO> = appointment.into();
+            HttpResponse::Ok().json(loaded_appointment)
+        }
+        Err(err) => AppointmentHttpError::from(err).error_response(),
+    }
+}
+
+async fn change_appointment_status(
+    app_state: web::Data<AppState>,
+    id: i32,
+    status: AppointmentStatus,
+) -> HttpResponse {
+    match ChangeAppointmentStatusUseCase::new(app_state.appointment_repo.clone())
+        .execute(id, status)
+        .await
+    {
+        Ok(appointment) => {
+            let loaded_appointment: Option<LoadedAppointmentDTO> = appointment.into();
+            HttpResponse::Ok().json(loaded_appointment)
+        }
+        Err(err) => AppointmentHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{id}/encounter")]
+pub async fn record_encounter_handler(
+    user: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<EncounterSectionsDTO>,
+) -> HttpResponse {
+    match RecordEncounterUseCase::new(
+        app_state.appointment_repo.clone(),
+        app_state.encounter_repo.clone(),
+        app_state.admin_repo.clone(),
+        app_state.professional_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner(), user.email)
+    .await
+    {
+        Ok(encounter) => {
+            let loaded_encounter: Option<LoadedEncounterDTO> = encounter.into();
+            HttpResponse::Ok().json(loaded_encounter)
+        }
+        Err(err) => EncounterHttpError::from(err).error_response(),
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{
+    application::use_cases::{
+        amend_encounter::AmendEncounterUseCase, find_encounter_by_id::FindEncounterByIdUseCase,
+    },
+    infrastructure::web::AppState,
+    presentation::{
+        dtos::encounter_dto::{
+            AmendEncounterDTO, LoadedEncounterDTO, LoadedEncounterWithAmendmentsDTO,
+        },
+        errors::encounter_http_error::EncounterHttpError,
+        extractors::role_extractor::{Clinicians, RequireRole},
+    },
+};
+use actix_web::{HttpResponse, ResponseError, get, post, web};
+
+#[get("/{id}")]
+pub async fn find_encounter_by_id_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    match FindEncounterByIdUseCase::new(app_state.encounter_repo.clone())
+        .execute(path.into_inner())
+        .await
+    {
+        Ok((encounter, amendments)) => {
+            let encounter: Option<LoadedEncounterDTO> = encounter.into();
+
+            HttpResponse::Ok().json(encounter.map(|encounter| {
+                LoadedEncounterWithAmendmentsDTO {
+                    encounter,
+                    amendments: amendments
+                        .into_iter()
+                        .filter_map(|amendment| amendment.into())
+                        .collect(),
+                }
+            }))
+        }
+        Err(err) => EncounterHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{id}/amendments")]
+pub async fn amend_encounter_handler(
+    user: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<AmendEncounterDTO>,
+) -> HttpResponse {
+    match AmendEncounterUseCase::new(
+        app_state.encounter_repo.clone(),
+        app_state.admin_repo.clone(),
+        app_state.professional_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner(), user.email)
+    .await
+    {
+        Ok(amendment) => {
+            let loaded_amendment: Option<LoadedEncounterDTO> = amendment.into();
+            HttpResponse::Ok().json(loaded_amendment)
+        }
+        Err(err) => EncounterHttpError::from(err).error_response(),
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    application::use_cases::{
+        attach_exam_result_file::AttachExamResultFileUseCase,
+        change_exam_order_status::ChangeExamOrderStatusUseCase,
+        find_exam_order_by_id::FindExamOrderByIdUseCase,
+        find_exam_result_file::FindExamResultFileUseCase, order_exam::OrderExamUseCase,
+        record_exam_results::RecordExamResultsUseCase,
+    },
+    domain::value_objects::exam_order_status::ExamOrderStatus,
+    infrastructure::web::AppState,
+    presentation::{
+        dtos::exam_order_dto::{
+            ExamResultFileQueryDTO, LoadedExamOrderDTO, OrderExamDTO, RecordExamResultsDTO,
+        },
+        errors::exam_order_http_error::ExamOrderHttpError,
+        extractors::role_extractor::{Clinicians, Physicians, RequireRole},
+    },
+};
+use actix_web::{
+    HttpMessage, HttpRequest, HttpResponse, ResponseError, get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    patch, post, put, web,
+};
+
+#[post("")]
+pub async fn order_exam_handler(
+    user: RequireRole<Physicians>,
+    app_state: web::Data<AppState>,
+    input: web::Json<OrderExamDTO>,
+) -> HttpResponse {
+    match OrderExamUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.professional_repo.clone(),
+        app_state.appointment_repo.clone(),
+        app_state.exam_order_repo.clone(),
+        app_state.admin_repo.clone(),
+    )
+    .execute(input.into_inner(), user.email)
+    .await
+    {
+        Ok(exam_order) => HttpResponse::Ok().json(LoadedExamOrderDTO::new(exam_order, vec![])),
+        Err(err) => ExamOrderHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{id}")]
+pub async fn find_exam_order_by_id_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    match FindExamOrderByIdUseCase::new(app_state.exam_order_repo.clone())
+        .execute(path.into_inner())
+        .await
+    {
+        Ok((exam_order, results)) => {
+            HttpResponse::Ok().json(LoadedExamOrderDTO::new(exam_order, results))
+        }
+        Err(err) => ExamOrderHttpError::from(err).error_response(),
+    }
+}
+
+#[patch("/{id}/collection")]
+pub async fn collect_exam_handler(
+    user: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    change_exam_order_status(
+        app_state,
+        path.into_inner(),
+        ExamOrderStatus::Collected,
+        user.email,
+    )
+    .await
+}
+
+#[patch("/{id}/review")]
+pub async fn review_exam_handler(
+    user: RequireRole<Physicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    change_exam_order_status(
+        app_state,
+        path.into_inner(),
+        ExamOrderStatus::Reviewed,
+        user.email,
+    )
+    .await
+}
+
+async fn change_exam_order_status(
+    app_state: web::Data<AppState>,
+    id: i32,
+    status: ExamOrderStatus,
+    actor: String,
+) -> HttpResponse {
+    match ChangeExamOrderStatusUseCase::new(app_state.exam_order_repo.clone())
+        .execute(id, status, actor)
+        .await
+    {
+        Ok((exam_order, results)) => {
+            HttpResponse::Ok().json(LoadedExamOrderDTO::new(exam_order, results))
+        }
+        Err(err) => ExamOrderHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{id}/results")]
+pub async fn record_exam_results_handler(
+    user: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<RecordExamResultsDTO>,
+) -> HttpResponse {
+    match RecordExamResultsUseCase::new(app_state.exam_order_repo.clone())
+        .execute(path.into_inner(), input.into_inner().results, user.email)
+        .await
+    {
+        Ok((exam_order, results)) => {
+            HttpResponse::Ok().json(LoadedExamOrderDTO::new(exam_order, results))
+        }
+        Err(err) => ExamOrderHttpError::from(err).error_response(),
+    }
+}
+
+/// The body is the file itself, typed by the Content-Type header.
+#[put("/{id}/result-file")]
+pub async fn attach_exam_result_file_handler(
+    user: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    query: web::Query<ExamResultFileQueryDTO>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> HttpResponse {
+    match AttachExamResultFileUseCase::new(app_state.exam_order_repo.clone())
+        .execute(
+            path.into_inner(),
+            query.into_inner().file_name,
+            req.content_type().to_string(),
+            body.to_vec(),
+            user.email,
+        )
+        .await
+    {
+        Ok((exam_order, results)) => {
+            HttpResponse::Ok().json(LoadedExamOrderDTO::new(exam_order, results))
+        }
+        Err(err) => ExamOrderHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{id}/result-file")]
+pub async fn download_exam_result_file_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    match FindExamResultFileUseCase::new(app_state.exam_order_repo.clone())
+        .execute(path.into_inner())
+        .await
+    {
+        Ok((exam_order, content)) => HttpResponse::Ok()
+            .content_type(exam_order.result_file_content_type.unwrap_or_default())
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename(
+                    exam_order.result_file_name.unwrap_or_default(),
+                )],
+            })
+            .body(content),
+        Err(err) => ExamOrderHttpError::from(err).error_response(),
+    }
+}
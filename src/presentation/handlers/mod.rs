@@ -1,3 +1,9 @@
 pub mod admin_handler;
+pub mod admission_handler;
 pub mod appointment_handler;
+pub mod encounter_handler;
+pub mod exam_order_handler;
 pub mod patient_handler;
+pub mod prescription_handler;
+pub mod professional_handler;
+pub mod ward_handler;
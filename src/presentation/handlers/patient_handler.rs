@@ -1,31 +1,75 @@
 use actix_web::{
-    HttpResponse, ResponseError, delete, get, post, put,
-    web::{self, Path},
+    HttpResponse, ResponseError, delete, get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    patch, post, put, web,
 };
+use futures::TryStreamExt;
 
 use crate::{
     application::use_cases::{
-        delete_patient_by_cpf::DeletePatientByCpfUseCase,
-        find_patient_by_cpf::FindPatientByCpfUseCase,
+        add_allergy::AddAllergyUseCase, add_problem::AddProblemUseCase,
+        anonymize_patient::AnonymizePatientUseCase,
+        check_prescription_allergies::CheckPrescriptionAllergiesUseCase,
+        delete_document::DeleteDocumentUseCase, delete_patient_by_cpf::DeletePatientByCpfUseCase,
+        download_document::DownloadDocumentUseCase, export_patient_data::ExportPatientDataUseCase,
+        find_patient_by_cpf::FindPatientByCpfUseCase, grant_consent::GrantConsentUseCase,
+        issue_prescription::IssuePrescriptionUseCase,
+        list_admissions_by_patient_cpf::ListAdmissionsByPatientCpfUseCase,
+        list_allergies_by_patient_cpf::ListAllergiesByPatientCpfUseCase,
         list_appointments_by_patient_cpf::ListAppointmentsByPatientCpfUseCase,
-        register_patient::RegisterPatientUseCase, update_patient_by_cpf::UpdatePatientByCpfUseCase,
+        list_consents_by_patient_cpf::ListConsentsByPatientCpfUseCase,
+        list_documents_by_patient_cpf::ListDocumentsByPatientCpfUseCase,
+        list_encounters_by_patient_cpf::ListEncountersByPatientCpfUseCase,
+        list_exam_orders_by_patient_cpf::ListExamOrdersByPatientCpfUseCase,
+        list_patients::ListPatientsUseCase,
+        list_prescriptions_by_patient_cpf::ListPrescriptionsByPatientCpfUseCase,
+        list_problems_by_patient_cpf::ListProblemsByPatientCpfUseCase,
+        merge_patients::MergePatientsUseCase, register_patient::RegisterPatientUseCase,
+        remove_allergy::RemoveAllergyUseCase, remove_problem::RemoveProblemUseCase,
+        revoke_consent::RevokeConsentUseCase, update_allergy::UpdateAllergyUseCase,
+        update_patient_by_cpf::UpdatePatientByCpfUseCase, update_problem::UpdateProblemUseCase,
+        upload_document::UploadDocumentUseCase,
     },
     infrastructure::web::AppState,
     presentation::{
         dtos::{
+            admission_dto::LoadedAdmissionDTO,
+            allergy_dto::{AllergyPath, CreateAllergyDTO, LoadedAllergyDTO, UpdateAllergyDTO},
             appointment_dto::LoadedAppointmentsDTO,
-            patient_dto::{CreatePatientDTO, LoadedPatientDTO, UpdatePatientDTO},
+            consent_dto::{ConsentPurposePath, GrantConsentDTO, LoadedConsentDTO},
+            document_dto::{DocumentPath, LoadedDocumentDTO},
+            encounter_dto::LoadedEncounterDTO,
+            exam_order_dto::LoadedExamOrderDTO,
+            page_dto::PagedDTO,
+            patient_dto::{
+                CreatePatientDTO, ListPatientsQueryDTO, LoadedPatientDTO, MergePatientsDTO,
+                PatientMergeReportDTO, UpdatePatientDTO,
+            },
+            patient_export_dto::PatientExportDTO,
+            prescription_dto::{
+                AllergyWarningDTO, CheckPrescriptionAllergiesDTO, IssuePrescriptionDTO,
+                IssuedPrescriptionDTO, LoadedPrescriptionDTO,
+            },
+            problem_dto::{CreateProblemDTO, LoadedProblemDTO, ProblemPath, UpdateProblemDTO},
         },
         errors::{
-            appointment_http_error::AppointmentHttpError, patient_http_error::PatientHttpError,
+            admission_http_error::AdmissionHttpError, appointment_http_error::AppointmentHttpError,
+            encounter_http_error::EncounterHttpError, exam_order_http_error::ExamOrderHttpError,
+            patient_http_error::PatientHttpError, prescription_http_error::PrescriptionHttpError,
+        },
+        extractors::{
+            cpf_extractor::CpfPath,
+            document_extractor::DocumentUpload,
+            role_extractor::{
+                Administrators, Clinicians, Compliance, Physicians, RequireRole, Staff,
+            },
         },
-        extractors::jwt_extractor::AuthenticatedAdmin,
     },
 };
 
 #[post("")]
 pub async fn register_patient_handler(
-    _: AuthenticatedAdmin,
+    _: RequireRole<Staff>,
     app_state: web::Data<AppState>,
     input: web::Json<CreatePatientDTO>,
 ) -> HttpResponse {
@@ -38,11 +82,44 @@ pub async fn register_patient_handler(
     }
 }
 
+#[get("")]
+pub async fn list_patients_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    query: web::Query<ListPatientsQueryDTO>,
+) -> HttpResponse {
+    match ListPatientsUseCase::new(app_state.patient_repo.clone())
+        .execute(query.into_inner())
+        .await
+    {
+        Ok(page) => {
+            let paged_patients: PagedDTO<LoadedPatientDTO> = page.into();
+            HttpResponse::Ok().json(paged_patients)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/merge")]
+pub async fn merge_patients_handler(
+    user: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    input: web::Json<MergePatientsDTO>,
+) -> HttpResponse {
+    match MergePatientsUseCase::new(app_state.patient_repo.clone(), app_state.audit_repo.clone())
+        .execute(input.into_inner(), user.email)
+        .await
+    {
+        Ok(report) => HttpResponse::Ok().json(PatientMergeReportDTO::from(report)),
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
 #[get("/{cpf}")]
 pub async fn find_patient_by_cpf_handler(
-    _: AuthenticatedAdmin,
+    _: RequireRole<Staff>,
     app_state: web::Data<AppState>,
-    path: Path<String>,
+    path: CpfPath,
 ) -> HttpResponse {
     let cpf = path.into_inner();
 
@@ -65,9 +142,9 @@ pub async fn find_patient_by_cpf_handler(
 
 #[put("/{cpf}")]
 pub async fn update_patient_by_cpf_handler(
-    _: AuthenticatedAdmin,
+    _: RequireRole<Staff>,
     app_state: web::Data<AppState>,
-    path: Path<String>,
+    path: CpfPath,
     input: web::Json<UpdatePatientDTO>,
 ) -> HttpResponse {
     match UpdatePatientByCpfUseCase::new(app_state.patient_repo.clone())
@@ -84,9 +161,9 @@ pub async fn update_patient_by_cpf_handler(
 
 #[delete("/{cpf}")]
 pub async fn delete_patient_by_cpf_handler(
-    _: AuthenticatedAdmin,
+    _: RequireRole<Administrators>,
     app_state: web::Data<AppState>,
-    path: Path<String>,
+    path: CpfPath,
 ) -> HttpResponse {
     match DeletePatientByCpfUseCase::new(app_state.patient_repo.clone())
         .execute(path.into_inner())
@@ -97,11 +174,66 @@ pub async fn delete_patient_by_cpf_handler(
     }
 }
 
+#[post("/{cpf}/anonymization")]
+pub async fn anonymize_patient_handler(
+    user: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    match AnonymizePatientUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.audit_repo.clone(),
+        app_state.file_storage.clone(),
+    )
+    .execute(path.into_inner(), user.email)
+    .await
+    {
+        Ok(_) => HttpResponse::Ok().json(()),
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{cpf}/export")]
+pub async fn export_patient_data_handler(
+    _: RequireRole<Compliance>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    let cpf = path.into_inner();
+
+    match ExportPatientDataUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.appointment_repo.clone(),
+        app_state.professional_repo.clone(),
+        app_state.consent_repo.clone(),
+        app_state.encounter_repo.clone(),
+        app_state.allergy_repo.clone(),
+        app_state.problem_repo.clone(),
+        app_state.prescription_repo.clone(),
+        app_state.exam_order_repo.clone(),
+        app_state.document_repo.clone(),
+        app_state.admission_repo.clone(),
+    )
+    .execute(cpf.clone())
+    .await
+    {
+        Ok(export) => HttpResponse::Ok()
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename(format!(
+                    "patient-{cpf}-export.json"
+                ))],
+            })
+            .json(PatientExportDTO::from(export)),
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
 #[get("/{cpf}/appointments")]
 pub async fn list_appointments_by_patient_cpf_handler(
-    _: AuthenticatedAdmin,
+    _: RequireRole<Staff>,
     app_state: web::Data<AppState>,
-    path: Path<String>,
+    path: CpfPath,
 ) -> HttpResponse {
     match ListAppointmentsByPatientCpfUseCase::new(
         app_state.patient_repo.clone(),
@@ -117,3 +249,497 @@ pub async fn list_appointments_by_patient_cpf_handler(
         Err(err) => AppointmentHttpError::from(err).error_response(),
     }
 }
+
+#[post("/{cpf}/consents")]
+pub async fn grant_consent_handler(
+    user: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    input: web::Json<GrantConsentDTO>,
+) -> HttpResponse {
+    match GrantConsentUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.consent_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner(), user.email)
+    .await
+    {
+        Ok(consent) => {
+            let loaded_consent: Option<LoadedConsentDTO> = consent.into();
+            HttpResponse::Ok().json(loaded_consent)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{cpf}/consents")]
+pub async fn list_consents_by_patient_cpf_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    match ListConsentsByPatientCpfUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.consent_repo.clone(),
+    )
+    .execute(path.into_inner())
+    .await
+    {
+        Ok(consents) => {
+            let loaded_consents: Vec<LoadedConsentDTO> = consents
+                .into_iter()
+                .filter_map(|consent| consent.into())
+                .collect();
+            HttpResponse::Ok().json(loaded_consents)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[patch("/{cpf}/consents/{purpose}/revocation")]
+pub async fn revoke_consent_handler(
+    user: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    purpose_path: web::Path<ConsentPurposePath>,
+) -> HttpResponse {
+    match RevokeConsentUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.consent_repo.clone(),
+    )
+    .execute(
+        path.into_inner(),
+        purpose_path.into_inner().purpose,
+        user.email,
+    )
+    .await
+    {
+        Ok(consent) => {
+            let loaded_consent: Option<LoadedConsentDTO> = consent.into();
+            HttpResponse::Ok().json(loaded_consent)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{cpf}/encounters")]
+pub async fn list_encounters_by_patient_cpf_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    match ListEncountersByPatientCpfUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.encounter_repo.clone(),
+    )
+    .execute(path.into_inner())
+    .await
+    {
+        Ok(encounters) => {
+            let loaded_encounters: Vec<LoadedEncounterDTO> = encounters
+                .into_iter()
+                .filter_map(|encounter| encounter.into())
+                .collect();
+            HttpResponse::Ok().json(loaded_encounters)
+        }
+        Err(err) => EncounterHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{cpf}/allergies")]
+pub async fn add_allergy_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    input: web::Json<CreateAllergyDTO>,
+) -> HttpResponse {
+    match AddAllergyUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.allergy_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner())
+    .await
+    {
+        Ok(allergy) => {
+            let loaded_allergy: Option<LoadedAllergyDTO> = allergy.into();
+            HttpResponse::Ok().json(loaded_allergy)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{cpf}/allergies")]
+pub async fn list_allergies_by_patient_cpf_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    match ListAllergiesByPatientCpfUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.allergy_repo.clone(),
+    )
+    .execute(path.into_inner())
+    .await
+    {
+        Ok(allergies) => {
+            let loaded_allergies: Vec<LoadedAllergyDTO> = allergies
+                .into_iter()
+                .filter_map(|allergy| allergy.into())
+                .collect();
+            HttpResponse::Ok().json(loaded_allergies)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[put("/{cpf}/allergies/{allergy_id}")]
+pub async fn update_allergy_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    allergy_path: web::Path<AllergyPath>,
+    input: web::Json<UpdateAllergyDTO>,
+) -> HttpResponse {
+    match UpdateAllergyUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.allergy_repo.clone(),
+    )
+    .execute(
+        path.into_inner(),
+        allergy_path.into_inner().allergy_id,
+        input.into_inner(),
+    )
+    .await
+    {
+        Ok(allergy) => {
+            let loaded_allergy: Option<LoadedAllergyDTO> = allergy.into();
+            HttpResponse::Ok().json(loaded_allergy)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[delete("/{cpf}/allergies/{allergy_id}")]
+pub async fn remove_allergy_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    allergy_path: web::Path<AllergyPath>,
+) -> HttpResponse {
+    match RemoveAllergyUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.allergy_repo.clone(),
+    )
+    .execute(path.into_inner(), allergy_path.into_inner().allergy_id)
+    .await
+    {
+        Ok(_) => HttpResponse::Ok().json(()),
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{cpf}/problems")]
+pub async fn add_problem_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    input: web::Json<CreateProblemDTO>,
+) -> HttpResponse {
+    match AddProblemUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.problem_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner())
+    .await
+    {
+        Ok(problem) => {
+            let loaded_problem: Option<LoadedProblemDTO> = problem.into();
+            HttpResponse::Ok().json(loaded_problem)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{cpf}/problems")]
+pub async fn list_problems_by_patient_cpf_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    match ListProblemsByPatientCpfUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.problem_repo.clone(),
+    )
+    .execute(path.into_inner())
+    .await
+    {
+        Ok(problems) => {
+            let loaded_problems: Vec<LoadedProblemDTO> = problems
+                .into_iter()
+                .filter_map(|problem| problem.into())
+                .collect();
+            HttpResponse::Ok().json(loaded_problems)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[put("/{cpf}/problems/{problem_id}")]
+pub async fn update_problem_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    problem_path: web::Path<ProblemPath>,
+    input: web::Json<UpdateProblemDTO>,
+) -> HttpResponse {
+    match UpdateProblemUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.problem_repo.clone(),
+    )
+    .execute(
+        path.into_inner(),
+        problem_path.into_inner().problem_id,
+        input.into_inner(),
+    )
+    .await
+    {
+        Ok(problem) => {
+            let loaded_problem: Option<LoadedProblemDTO> = problem.into();
+            HttpResponse::Ok().json(loaded_problem)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[delete("/{cpf}/problems/{problem_id}")]
+pub async fn remove_problem_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    problem_path: web::Path<ProblemPath>,
+) -> HttpResponse {
+    match RemoveProblemUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.problem_repo.clone(),
+    )
+    .execute(path.into_inner(), problem_path.into_inner().problem_id)
+    .await
+    {
+        Ok(_) => HttpResponse::Ok().json(()),
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{cpf}/prescriptions")]
+pub async fn issue_prescription_handler(
+    user: RequireRole<Physicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    input: web::Json<IssuePrescriptionDTO>,
+) -> HttpResponse {
+    match IssuePrescriptionUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.professional_repo.clone(),
+        app_state.appointment_repo.clone(),
+        app_state.allergy_repo.clone(),
+        app_state.prescription_repo.clone(),
+        app_state.admin_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner(), user.email)
+    .await
+    {
+        Ok(issued) => HttpResponse::Ok().json(IssuedPrescriptionDTO::from(issued)),
+        Err(err) => PrescriptionHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{cpf}/prescriptions")]
+pub async fn list_prescriptions_by_patient_cpf_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    match ListPrescriptionsByPatientCpfUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.prescription_repo.clone(),
+    )
+    .execute(path.into_inner())
+    .await
+    {
+        Ok(prescriptions) => {
+            let loaded_prescriptions: Vec<LoadedPrescriptionDTO> = prescriptions
+                .into_iter()
+                .filter_map(|(prescription, items)| LoadedPrescriptionDTO::new(prescription, items))
+                .collect();
+            HttpResponse::Ok().json(loaded_prescriptions)
+        }
+        Err(err) => PrescriptionHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{cpf}/prescriptions/allergy-check")]
+pub async fn check_prescription_allergies_handler(
+    _: RequireRole<Physicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    input: web::Json<CheckPrescriptionAllergiesDTO>,
+) -> HttpResponse {
+    match CheckPrescriptionAllergiesUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.allergy_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner().items)
+    .await
+    {
+        Ok(warnings) => {
+            let warnings: Vec<AllergyWarningDTO> =
+                warnings.into_iter().map(|warning| warning.into()).collect();
+            HttpResponse::Ok().json(warnings)
+        }
+        Err(err) => PrescriptionHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{cpf}/exam-orders")]
+pub async fn list_exam_orders_by_patient_cpf_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    match ListExamOrdersByPatientCpfUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.exam_order_repo.clone(),
+    )
+    .execute(path.into_inner())
+    .await
+    {
+        Ok(exam_orders) => {
+            let loaded_exam_orders: Vec<LoadedExamOrderDTO> = exam_orders
+                .into_iter()
+                .filter_map(|(exam_order, results)| LoadedExamOrderDTO::new(exam_order, results))
+                .collect();
+            HttpResponse::Ok().json(loaded_exam_orders)
+        }
+        Err(err) => ExamOrderHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{cpf}/documents")]
+pub async fn upload_document_handler(
+    user: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    upload: DocumentUpload,
+) -> HttpResponse {
+    match UploadDocumentUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.document_repo.clone(),
+        app_state.file_storage.clone(),
+    )
+    .execute(path.into_inner(), upload.into_inner(), user.email)
+    .await
+    {
+        Ok(document) => HttpResponse::Ok().json(Option::<LoadedDocumentDTO>::from(document)),
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{cpf}/documents")]
+pub async fn list_documents_by_patient_cpf_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    match ListDocumentsByPatientCpfUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.document_repo.clone(),
+    )
+    .execute(path.into_inner())
+    .await
+    {
+        Ok(documents) => {
+            let loaded_documents: Vec<LoadedDocumentDTO> = documents
+                .into_iter()
+                .filter_map(|document| document.into())
+                .collect();
+            HttpResponse::Ok().json(loaded_documents)
+        }
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+/// Streams the stored file without buffering it. The checksum goes along in
+/// a `Digest` header so clients can verify what they received.
+#[get("/{cpf}/documents/{document_id}")]
+pub async fn download_document_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    document_path: web::Path<DocumentPath>,
+) -> HttpResponse {
+    match DownloadDocumentUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.document_repo.clone(),
+        app_state.file_storage.clone(),
+    )
+    .execute(path.into_inner(), document_path.into_inner().document_id)
+    .await
+    {
+        Ok((document, content)) => HttpResponse::Ok()
+            .content_type(document.content_type)
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename(document.file_name)],
+            })
+            .insert_header(("Digest", format!("sha-256={}", document.sha256)))
+            .no_chunking(document.size_bytes as u64)
+            .streaming(content.map_ok(web::Bytes::from)),
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[delete("/{cpf}/documents/{document_id}")]
+pub async fn delete_document_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+    document_path: web::Path<DocumentPath>,
+) -> HttpResponse {
+    match DeleteDocumentUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.document_repo.clone(),
+        app_state.file_storage.clone(),
+    )
+    .execute(path.into_inner(), document_path.into_inner().document_id)
+    .await
+    {
+        Ok(_) => HttpResponse::Ok().json(()),
+        Err(err) => PatientHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{cpf}/admissions")]
+pub async fn list_admissions_by_patient_cpf_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: CpfPath,
+) -> HttpResponse {
+    match ListAdmissionsByPatientCpfUseCase::new(
+        app_state.patient_repo.clone(),
+        app_state.admission_repo.clone(),
+    )
+    .execute(path.into_inner())
+    .await
+    {
+        Ok(admissions) => {
+            let loaded_admissions: Vec<LoadedAdmissionDTO> = admissions
+                .into_iter()
+                .filter_map(|(admission, occupancies)| {
+                    LoadedAdmissionDTO::new(admission, occupancies)
+                })
+                .collect();
+            HttpResponse::Ok().json(loaded_admissions)
+        }
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
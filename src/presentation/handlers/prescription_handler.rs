@@ -0,0 +1,60 @@
+use crate::{
+    application::use_cases::find_prescription_by_id::FindPrescriptionByIdUseCase,
+    infrastructure::web::AppState,
+    presentation::{
+        dtos::prescription_dto::PrescriptionDocumentDTO,
+        errors::prescription_http_error::PrescriptionHttpError,
+        extractors::role_extractor::{Clinicians, RequireRole},
+    },
+};
+use actix_web::{
+    HttpResponse, ResponseError, get,
+    http::header::{ContentDisposition, ContentType, DispositionParam, DispositionType},
+    web,
+};
+
+#[get("/{id}")]
+pub async fn find_prescription_by_id_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    match FindPrescriptionByIdUseCase::new(
+        app_state.prescription_repo.clone(),
+        app_state.patient_repo.clone(),
+        app_state.professional_repo.clone(),
+    )
+    .execute(path.into_inner())
+    .await
+    {
+        Ok(document) => HttpResponse::Ok().json(PrescriptionDocumentDTO::from(document)),
+        Err(err) => PrescriptionHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{id}/print")]
+pub async fn print_prescription_handler(
+    _: RequireRole<Clinicians>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+) -> HttpResponse {
+    let id = path.into_inner();
+
+    match FindPrescriptionByIdUseCase::new(
+        app_state.prescription_repo.clone(),
+        app_state.patient_repo.clone(),
+        app_state.professional_repo.clone(),
+    )
+    .execute(id)
+    .await
+    {
+        Ok(document) => HttpResponse::Ok()
+            .content_type(ContentType::plaintext())
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Inline,
+                parameters: vec![DispositionParam::Filename(format!("prescription-{id}.txt"))],
+            })
+            .body(PrescriptionDocumentDTO::from(document).to_printable_text()),
+        Err(err) => PrescriptionHttpError::from(err).error_response(),
+    }
+}
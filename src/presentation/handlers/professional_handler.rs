@@ -0,0 +1,246 @@
+use actix_web::{
+    HttpResponse, ResponseError, delete, get, post, put,
+    web::{self, Path, Query},
+};
+
+use crate::{
+    application::use_cases::{
+        add_professional_schedule::AddProfessionalScheduleUseCase,
+        add_schedule_exception::AddScheduleExceptionUseCase,
+        deactivate_professional_by_id::DeactivateProfessionalByIdUseCase,
+        find_professional_by_id::FindProfessionalByIdUseCase,
+        list_available_slots::ListAvailableSlotsUseCase,
+        list_professional_schedules::ListProfessionalSchedulesUseCase,
+        list_schedule_exceptions::ListScheduleExceptionsUseCase,
+        register_professional::RegisterProfessionalUseCase,
+        remove_professional_schedule::RemoveProfessionalScheduleUseCase,
+        remove_schedule_exception::RemoveScheduleExceptionUseCase,
+        update_professional_by_id::UpdateProfessionalByIdUseCase,
+    },
+    infrastructure::web::AppState,
+    presentation::{
+        dtos::{
+            professional_dto::{
+                CreateProfessionalDTO, LoadedProfessionalDTO, UpdateProfessionalDTO,
+            },
+            schedule_dto::{
+                AvailableSlotDTO, AvailableSlotsQueryDTO, CreateProfessionalScheduleDTO,
+                CreateScheduleExceptionDTO, DateRangeQueryDTO, LoadedProfessionalScheduleDTO,
+                LoadedScheduleExceptionDTO,
+            },
+        },
+        errors::professional_http_error::ProfessionalHttpError,
+        extractors::role_extractor::{Administrators, RequireRole, Staff},
+    },
+};
+
+#[post("")]
+pub async fn register_professional_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    input: web::Json<CreateProfessionalDTO>,
+) -> HttpResponse {
+    match RegisterProfessionalUseCase::new(app_state.professional_repo.clone())
+        .execute(input.into_inner())
+        .await
+    {
+        Ok(id) => HttpResponse::Ok().json(id),
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{id}")]
+pub async fn find_professional_by_id_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: Path<i32>,
+) -> HttpResponse {
+    match FindProfessionalByIdUseCase::new(app_state.professional_repo.clone())
+        .execute(path.into_inner())
+        .await
+    {
+        Ok(professional) => {
+            let loaded_professional: Option<LoadedProfessionalDTO> = professional.into();
+            HttpResponse::Ok().json(loaded_professional)
+        }
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[put("/{id}")]
+pub async fn update_professional_by_id_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: Path<i32>,
+    input: web::Json<UpdateProfessionalDTO>,
+) -> HttpResponse {
+    match UpdateProfessionalByIdUseCase::new(app_state.professional_repo.clone())
+        .execute(path.into_inner(), input.into_inner())
+        .await
+    {
+        Ok(professional) => {
+            let loaded_professional: Option<LoadedProfessionalDTO> = professional.into();
+            HttpResponse::Ok().json(loaded_professional)
+        }
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[delete("/{id}")]
+pub async fn deactivate_professional_by_id_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: Path<i32>,
+) -> HttpResponse {
+    match DeactivateProfessionalByIdUseCase::new(app_state.professional_repo.clone())
+        .execute(path.into_inner())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(()),
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{id}/schedules")]
+pub async fn add_professional_schedule_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: Path<i32>,
+    input: web::Json<CreateProfessionalScheduleDTO>,
+) -> HttpResponse {
+    match AddProfessionalScheduleUseCase::new(
+        app_state.professional_repo.clone(),
+        app_state.schedule_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner())
+    .await
+    {
+        Ok(schedule) => {
+            let loaded_schedule: Option<LoadedProfessionalScheduleDTO> = schedule.into();
+            HttpResponse::Ok().json(loaded_schedule)
+        }
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{id}/schedules")]
+pub async fn list_professional_schedules_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: Path<i32>,
+) -> HttpResponse {
+    match ListProfessionalSchedulesUseCase::new(app_state.schedule_repo.clone())
+        .execute(path.into_inner())
+        .await
+    {
+        Ok(schedules) => {
+            let loaded_schedules: Vec<LoadedProfessionalScheduleDTO> = schedules
+                .into_iter()
+                .filter_map(Option::<LoadedProfessionalScheduleDTO>::from)
+                .collect();
+            HttpResponse::Ok().json(loaded_schedules)
+        }
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[delete("/{id}/schedules/{schedule_id}")]
+pub async fn remove_professional_schedule_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: Path<(i32, i32)>,
+) -> HttpResponse {
+    let (professional_id, schedule_id) = path.into_inner();
+
+    match RemoveProfessionalScheduleUseCase::new(app_state.schedule_repo.clone())
+        .execute(professional_id, schedule_id)
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(()),
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{id}/schedule-exceptions")]
+pub async fn add_schedule_exception_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: Path<i32>,
+    input: web::Json<CreateScheduleExceptionDTO>,
+) -> HttpResponse {
+    match AddScheduleExceptionUseCase::new(
+        app_state.professional_repo.clone(),
+        app_state.schedule_repo.clone(),
+    )
+    .execute(path.into_inner(), input.into_inner())
+    .await
+    {
+        Ok(exception) => {
+            let loaded_exception: Option<LoadedScheduleExceptionDTO> = exception.into();
+            HttpResponse::Ok().json(loaded_exception)
+        }
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{id}/schedule-exceptions")]
+pub async fn list_schedule_exceptions_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: Path<i32>,
+    query: Query<DateRangeQueryDTO>,
+) -> HttpResponse {
+    match ListScheduleExceptionsUseCase::new(app_state.schedule_repo.clone())
+        .execute(path.into_inner(), query.into_inner())
+        .await
+    {
+        Ok(exceptions) => {
+            let loaded_exceptions: Vec<LoadedScheduleExceptionDTO> = exceptions
+                .into_iter()
+                .filter_map(Option::<LoadedScheduleExceptionDTO>::from)
+                .collect();
+            HttpResponse::Ok().json(loaded_exceptions)
+        }
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[delete("/{id}/schedule-exceptions/{exception_id}")]
+pub async fn remove_schedule_exception_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: Path<(i32, i32)>,
+) -> HttpResponse {
+    let (professional_id, exception_id) = path.into_inner();
+
+    match RemoveScheduleExceptionUseCase::new(app_state.schedule_repo.clone())
+        .execute(professional_id, exception_id)
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(()),
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
+
+#[get("/{id}/slots")]
+pub async fn list_available_slots_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: Path<i32>,
+    query: Query<AvailableSlotsQueryDTO>,
+) -> HttpResponse {
+    match ListAvailableSlotsUseCase::new(
+        app_state.professional_repo.clone(),
+        app_state.schedule_repo.clone(),
+        app_state.appointment_repo.clone(),
+    )
+    .execute(path.into_inner(), query.into_inner())
+    .await
+    {
+        Ok(slots) => {
+            let slots: Vec<AvailableSlotDTO> = slots.into_iter().map(Into::into).collect();
+            HttpResponse::Ok().json(slots)
+        }
+        Err(err) => ProfessionalHttpError::from(err).error_response(),
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    application::use_cases::{
+        add_bed::AddBedUseCase, add_room::AddRoomUseCase,
+        change_bed_status::ChangeBedStatusUseCase, create_ward::CreateWardUseCase,
+        get_occupancy_overview::GetOccupancyOverviewUseCase,
+    },
+    infrastructure::web::AppState,
+    presentation::{
+        dtos::ward_dto::{
+            AddBedDTO, AddRoomDTO, ChangeBedStatusDTO, CreateWardDTO, LoadedBedDTO, LoadedRoomDTO,
+            LoadedWardDTO, WardOccupancyDTO,
+        },
+        errors::admission_http_error::AdmissionHttpError,
+        extractors::role_extractor::{Administrators, RequireRole, Staff},
+    },
+};
+use actix_web::{HttpResponse, ResponseError, get, patch, post, web};
+
+#[post("")]
+pub async fn create_ward_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    input: web::Json<CreateWardDTO>,
+) -> HttpResponse {
+    match CreateWardUseCase::new(app_state.ward_repo.clone())
+        .execute(input.into_inner())
+        .await
+    {
+        Ok(ward) => HttpResponse::Ok().json(Option::<LoadedWardDTO>::from(ward)),
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
+
+/// Every bed of every ward, with its status and current occupant.
+#[get("/occupancy")]
+pub async fn get_occupancy_overview_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+) -> HttpResponse {
+    match GetOccupancyOverviewUseCase::new(
+        app_state.ward_repo.clone(),
+        app_state.admission_repo.clone(),
+        app_state.patient_repo.clone(),
+    )
+    .execute()
+    .await
+    {
+        Ok(overview) => {
+            let overview: Vec<WardOccupancyDTO> = overview
+                .into_iter()
+                .filter_map(|ward_occupancy| ward_occupancy.into())
+                .collect();
+            HttpResponse::Ok().json(overview)
+        }
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
+
+#[post("/{id}/rooms")]
+pub async fn add_room_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<AddRoomDTO>,
+) -> HttpResponse {
+    match AddRoomUseCase::new(app_state.ward_repo.clone())
+        .execute(path.into_inner(), input.into_inner())
+        .await
+    {
+        Ok(room) => HttpResponse::Ok().json(Option::<LoadedRoomDTO>::from(room)),
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
+
+#[post("")]
+pub async fn add_bed_handler(
+    _: RequireRole<Administrators>,
+    app_state: web::Data<AppState>,
+    input: web::Json<AddBedDTO>,
+) -> HttpResponse {
+    match AddBedUseCase::new(app_state.ward_repo.clone())
+        .execute(input.into_inner())
+        .await
+    {
+        Ok(bed) => HttpResponse::Ok().json(Option::<LoadedBedDTO>::from(bed)),
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
+
+#[patch("/{id}/status")]
+pub async fn change_bed_status_handler(
+    _: RequireRole<Staff>,
+    app_state: web::Data<AppState>,
+    path: web::Path<i32>,
+    input: web::Json<ChangeBedStatusDTO>,
+) -> HttpResponse {
+    match ChangeBedStatusUseCase::new(app_state.ward_repo.clone())
+        .execute(path.into_inner(), input.into_inner().status)
+        .await
+    {
+        Ok(bed) => HttpResponse::Ok().json(Option::<LoadedBedDTO>::from(bed)),
+        Err(err) => AdmissionHttpError::from(err).error_response(),
+    }
+}
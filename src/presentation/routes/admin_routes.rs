@@ -1,7 +1,28 @@
 use actix_web::web;
 
-use crate::presentation::handlers::admin_handler::login_handler;
+use crate::presentation::handlers::admin_handler::{
+    change_password_handler, create_user_handler, deactivate_user_handler, jwks_handler,
+    link_user_professional_handler, list_users_handler, login_handler, logout_handler,
+    reactivate_user_handler, refresh_handler, reset_password_handler, revoke_own_sessions_handler,
+    revoke_sessions_handler,
+};
 
 pub fn admin_routes(config: &mut web::ServiceConfig) {
-    config.service(web::scope("/api/v1").service(login_handler));
+    config
+        .service(
+            web::scope("/api/v1/users")
+                .service(create_user_handler)
+                .service(list_users_handler)
+                .service(change_password_handler)
+                .service(revoke_own_sessions_handler)
+                .service(deactivate_user_handler)
+                .service(reactivate_user_handler)
+                .service(link_user_professional_handler)
+                .service(reset_password_handler)
+                .service(revoke_sessions_handler),
+        )
+        .service(login_handler)
+        .service(refresh_handler)
+        .service(logout_handler)
+        .service(jwks_handler);
 }
@@ -0,0 +1,16 @@
+use actix_web::web;
+
+use crate::presentation::handlers::admission_handler::{
+    admit_patient_handler, discharge_patient_handler, find_admission_by_id_handler,
+    transfer_patient_handler,
+};
+
+pub fn admission_routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/api/v1/admissions")
+            .service(admit_patient_handler)
+            .service(find_admission_by_id_handler)
+            .service(transfer_patient_handler)
+            .service(discharge_patient_handler),
+    );
+}
@@ -1,13 +1,28 @@
 use actix_web::web;
 
 use crate::presentation::handlers::appointment_handler::{
-    book_appointment_handler, cancel_appointment_handler,
+    book_appointment_handler, cancel_appointment_by_id_handler, cancel_appointment_handler,
+    check_in_appointment_handler, complete_appointment_handler, confirm_appointment_handler,
+    find_appointment_by_id_handler, find_appointment_by_patient_cpf_and_time_handler,
+    no_show_appointment_handler, record_encounter_handler, reschedule_appointment_handler,
+    search_appointments_handler, start_appointment_handler,
 };
 
 pub fn appointment_routes(config: &mut web::ServiceConfig) {
     config.service(
         web::scope("/api/v1/appointments")
             .service(book_appointment_handler)
-            .service(cancel_appointment_handler),
+            .service(search_appointments_handler)
+            .service(find_appointment_by_patient_cpf_and_time_handler)
+            .service(find_appointment_by_id_handler)
+            .service(cancel_appointment_handler)
+            .service(cancel_appointment_by_id_handler)
+            .service(confirm_appointment_handler)
+            .service(check_in_appointment_handler)
+            .service(start_appointment_handler)
+            .service(complete_appointment_handler)
+            .service(no_show_appointment_handler)
+            .service(reschedule_appointment_handler)
+            .service(record_encounter_handler),
     );
 }
@@ -0,0 +1,13 @@
+use actix_web::web;
+
+use crate::presentation::handlers::encounter_handler::{
+    amend_encounter_handler, find_encounter_by_id_handler,
+};
+
+pub fn encounter_routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/api/v1/encounters")
+            .service(find_encounter_by_id_handler)
+            .service(amend_encounter_handler),
+    );
+}
@@ -0,0 +1,24 @@
+use actix_web::web;
+
+use crate::{
+    domain::entities::exam_order::MAX_RESULT_FILE_SIZE,
+    presentation::handlers::exam_order_handler::{
+        attach_exam_result_file_handler, collect_exam_handler, download_exam_result_file_handler,
+        find_exam_order_by_id_handler, order_exam_handler, record_exam_results_handler,
+        review_exam_handler,
+    },
+};
+
+pub fn exam_order_routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/api/v1/exam-orders")
+            .app_data(web::PayloadConfig::new(MAX_RESULT_FILE_SIZE))
+            .service(order_exam_handler)
+            .service(find_exam_order_by_id_handler)
+            .service(collect_exam_handler)
+            .service(record_exam_results_handler)
+            .service(attach_exam_result_file_handler)
+            .service(download_exam_result_file_handler)
+            .service(review_exam_handler),
+    );
+}
@@ -1,3 +1,9 @@
 pub mod admin_routes;
+pub mod admission_routes;
 pub mod appointment_routes;
+pub mod encounter_routes;
+pub mod exam_order_routes;
 pub mod patient_routes;
+pub mod prescription_routes;
+pub mod professional_routes;
+pub mod ward_routes;
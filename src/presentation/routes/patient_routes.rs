@@ -1,18 +1,51 @@
 use actix_web::web;
 
 use crate::presentation::handlers::patient_handler::{
-    delete_patient_by_cpf_handler, find_patient_by_cpf_handler,
-    list_appointments_by_patient_cpf_handler, register_patient_handler,
-    update_patient_by_cpf_handler,
+    add_allergy_handler, add_problem_handler, anonymize_patient_handler,
+    check_prescription_allergies_handler, delete_document_handler, delete_patient_by_cpf_handler,
+    download_document_handler, export_patient_data_handler, find_patient_by_cpf_handler,
+    grant_consent_handler, issue_prescription_handler, list_admissions_by_patient_cpf_handler,
+    list_allergies_by_patient_cpf_handler, list_appointments_by_patient_cpf_handler,
+    list_consents_by_patient_cpf_handler, list_documents_by_patient_cpf_handler,
+    list_encounters_by_patient_cpf_handler, list_exam_orders_by_patient_cpf_handler,
+    list_patients_handler, list_prescriptions_by_patient_cpf_handler,
+    list_problems_by_patient_cpf_handler, merge_patients_handler, register_patient_handler,
+    remove_allergy_handler, remove_problem_handler, revoke_consent_handler, update_allergy_handler,
+    update_patient_by_cpf_handler, update_problem_handler, upload_document_handler,
 };
 
 pub fn patient_routes(config: &mut web::ServiceConfig) {
     config.service(
         web::scope("/api/v1/patients")
             .service(register_patient_handler)
+            .service(list_patients_handler)
+            .service(merge_patients_handler)
             .service(find_patient_by_cpf_handler)
             .service(update_patient_by_cpf_handler)
             .service(delete_patient_by_cpf_handler)
-            .service(list_appointments_by_patient_cpf_handler),
+            .service(anonymize_patient_handler)
+            .service(export_patient_data_handler)
+            .service(list_appointments_by_patient_cpf_handler)
+            .service(grant_consent_handler)
+            .service(list_consents_by_patient_cpf_handler)
+            .service(revoke_consent_handler)
+            .service(list_encounters_by_patient_cpf_handler)
+            .service(add_allergy_handler)
+            .service(list_allergies_by_patient_cpf_handler)
+            .service(update_allergy_handler)
+            .service(remove_allergy_handler)
+            .service(add_problem_handler)
+            .service(list_problems_by_patient_cpf_handler)
+            .service(update_problem_handler)
+            .service(remove_problem_handler)
+            .service(issue_prescription_handler)
+            .service(list_prescriptions_by_patient_cpf_handler)
+            .service(check_prescription_allergies_handler)
+            .service(list_exam_orders_by_patient_cpf_handler)
+            .service(upload_document_handler)
+            .service(list_documents_by_patient_cpf_handler)
+            .service(download_document_handler)
+            .service(delete_document_handler)
+            .service(list_admissions_by_patient_cpf_handler),
     );
 }
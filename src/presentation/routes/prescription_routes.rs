@@ -0,0 +1,13 @@
+use actix_web::web;
+
+use crate::presentation::handlers::prescription_handler::{
+    find_prescription_by_id_handler, print_prescription_handler,
+};
+
+pub fn prescription_routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/api/v1/prescriptions")
+            .service(find_prescription_by_id_handler)
+            .service(print_prescription_handler),
+    );
+}
@@ -0,0 +1,27 @@
+use actix_web::web;
+
+use crate::presentation::handlers::professional_handler::{
+    add_professional_schedule_handler, add_schedule_exception_handler,
+    deactivate_professional_by_id_handler, find_professional_by_id_handler,
+    list_available_slots_handler, list_professional_schedules_handler,
+    list_schedule_exceptions_handler, register_professional_handler,
+    remove_professional_schedule_handler, remove_schedule_exception_handler,
+    update_professional_by_id_handler,
+};
+
+pub fn professional_routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/api/v1/professionals")
+            .service(register_professional_handler)
+            .service(find_professional_by_id_handler)
+            .service(update_professional_by_id_handler)
+            .service(deactivate_professional_by_id_handler)
+            .service(add_professional_schedule_handler)
+            .service(list_professional_schedules_handler)
+            .service(remove_professional_schedule_handler)
+            .service(add_schedule_exception_handler)
+            .service(list_schedule_exceptions_handler)
+            .service(remove_schedule_exception_handler)
+            .service(list_available_slots_handler),
+    );
+}
@@ -0,0 +1,21 @@
+use actix_web::web;
+
+use crate::presentation::handlers::ward_handler::{
+    add_bed_handler, add_room_handler, change_bed_status_handler, create_ward_handler,
+    get_occupancy_overview_handler,
+};
+
+pub fn ward_routes(config: &mut web::ServiceConfig) {
+    config
+        .service(
+            web::scope("/api/v1/wards")
+                .service(create_ward_handler)
+                .service(get_occupancy_overview_handler)
+                .service(add_room_handler),
+        )
+        .service(
+            web::scope("/api/v1/beds")
+                .service(add_bed_handler)
+                .service(change_bed_status_handler),
+        );
+}
@@ -9,6 +9,41 @@ diesel::table! {
         email -> Varchar,
         #[max_length = 255]
         password_hash -> Varchar,
+        #[max_length = 20]
+        role -> Varchar,
+        active -> Bool,
+        must_change_password -> Bool,
+        password_changed_at -> Timestamp,
+        professional_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    admissions (id) {
+        id -> Int4,
+        patient_id -> Int4,
+        professional_id -> Nullable<Int4>,
+        reason -> Text,
+        #[max_length = 150]
+        admitted_by -> Varchar,
+        admitted_at -> Timestamp,
+        discharged_at -> Nullable<Timestamp>,
+        #[max_length = 150]
+        discharged_by -> Nullable<Varchar>,
+        discharge_summary -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    allergies (id) {
+        id -> Int4,
+        patient_id -> Int4,
+        #[max_length = 150]
+        substance -> Varchar,
+        reaction -> Nullable<Text>,
+        #[max_length = 10]
+        severity -> Varchar,
+        recorded_at -> Timestamp,
     }
 }
 
@@ -20,9 +55,188 @@ diesel::table! {
         #[max_length = 100]
         specialty -> Varchar,
         notes -> Nullable<Text>,
-        canceled -> Bool,
         canceled_at -> Nullable<Timestamp>,
         cancellation_reason -> Nullable<Text>,
+        professional_id -> Nullable<Int4>,
+        ends_at -> Timestamp,
+        #[max_length = 20]
+        status -> Varchar,
+        confirmed_at -> Nullable<Timestamp>,
+        checked_in_at -> Nullable<Timestamp>,
+        started_at -> Nullable<Timestamp>,
+        completed_at -> Nullable<Timestamp>,
+        no_show_at -> Nullable<Timestamp>,
+        previous_appointment_at -> Nullable<Timestamp>,
+        rescheduled_at -> Nullable<Timestamp>,
+        #[max_length = 150]
+        rescheduled_by -> Nullable<Varchar>,
+        reschedule_reason -> Nullable<Text>,
+        telemedicine -> Bool,
+    }
+}
+
+diesel::table! {
+    audit_events (id) {
+        id -> Int4,
+        #[max_length = 150]
+        actor -> Varchar,
+        #[max_length = 50]
+        action -> Varchar,
+        #[max_length = 50]
+        entity_type -> Varchar,
+        entity_id -> Nullable<Int4>,
+        details -> Nullable<Text>,
+        occurred_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    bed_occupancies (id) {
+        id -> Int4,
+        admission_id -> Int4,
+        bed_id -> Int4,
+        #[max_length = 150]
+        started_by -> Varchar,
+        started_at -> Timestamp,
+        ended_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    beds (id) {
+        id -> Int4,
+        room_id -> Int4,
+        #[max_length = 20]
+        label -> Varchar,
+        #[max_length = 20]
+        status -> Varchar,
+    }
+}
+
+diesel::table! {
+    consents (id) {
+        id -> Int4,
+        patient_id -> Int4,
+        #[max_length = 30]
+        purpose -> Varchar,
+        #[max_length = 50]
+        term_version -> Varchar,
+        #[max_length = 20]
+        channel -> Varchar,
+        granted_at -> Timestamp,
+        #[max_length = 150]
+        granted_by -> Varchar,
+        revoked_at -> Nullable<Timestamp>,
+        #[max_length = 150]
+        revoked_by -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    documents (id) {
+        id -> Int4,
+        patient_id -> Int4,
+        #[max_length = 20]
+        category -> Varchar,
+        #[max_length = 255]
+        file_name -> Varchar,
+        #[max_length = 100]
+        content_type -> Varchar,
+        size_bytes -> Int8,
+        #[max_length = 64]
+        sha256 -> Bpchar,
+        #[max_length = 255]
+        storage_key -> Varchar,
+        description -> Nullable<Text>,
+        #[max_length = 150]
+        uploaded_by -> Varchar,
+        uploaded_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    encounters (id) {
+        id -> Int4,
+        appointment_id -> Int4,
+        patient_id -> Int4,
+        professional_id -> Int4,
+        chief_complaint -> Text,
+        history -> Nullable<Text>,
+        physical_exam -> Nullable<Text>,
+        assessment -> Nullable<Text>,
+        plan -> Nullable<Text>,
+        amends_id -> Nullable<Int4>,
+        amendment_reason -> Nullable<Text>,
+        #[max_length = 150]
+        recorded_by -> Varchar,
+        recorded_at -> Timestamp,
+        author_id -> Int4,
+    }
+}
+
+diesel::table! {
+    exam_orders (id) {
+        id -> Int4,
+        patient_id -> Int4,
+        professional_id -> Int4,
+        appointment_id -> Nullable<Int4>,
+        #[max_length = 10]
+        kind -> Varchar,
+        #[max_length = 150]
+        exam_name -> Varchar,
+        clinical_indication -> Nullable<Text>,
+        #[max_length = 10]
+        status -> Varchar,
+        #[max_length = 150]
+        requested_by -> Varchar,
+        requested_at -> Timestamp,
+        collected_at -> Nullable<Timestamp>,
+        resulted_at -> Nullable<Timestamp>,
+        reviewed_at -> Nullable<Timestamp>,
+        #[max_length = 150]
+        reviewed_by -> Nullable<Varchar>,
+        #[max_length = 255]
+        result_file_name -> Nullable<Varchar>,
+        #[max_length = 100]
+        result_file_content_type -> Nullable<Varchar>,
+        result_file_size -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    exam_result_files (exam_order_id) {
+        exam_order_id -> Int4,
+        content -> Bytea,
+    }
+}
+
+diesel::table! {
+    exam_results (id) {
+        id -> Int4,
+        exam_order_id -> Int4,
+        #[max_length = 150]
+        analyte -> Varchar,
+        #[max_length = 100]
+        value -> Varchar,
+        #[max_length = 30]
+        unit -> Nullable<Varchar>,
+        reference_low -> Nullable<Float8>,
+        reference_high -> Nullable<Float8>,
+        #[max_length = 100]
+        reference_text -> Nullable<Varchar>,
+        abnormal -> Bool,
+        recorded_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    failed_logins (id) {
+        id -> Int4,
+        #[max_length = 150]
+        email -> Varchar,
+        #[max_length = 45]
+        ip_address -> Varchar,
+        attempted_at -> Timestamp,
     }
 }
 
@@ -33,24 +247,208 @@ diesel::table! {
         name -> Varchar,
         #[max_length = 11]
         cpf -> Varchar,
+        #[max_length = 255]
+        social_name -> Nullable<Varchar>,
+        birth_date -> Nullable<Date>,
+        #[max_length = 10]
+        sex -> Nullable<Varchar>,
+        #[max_length = 50]
+        gender_identity -> Nullable<Varchar>,
+        #[max_length = 255]
+        mother_name -> Nullable<Varchar>,
+        #[max_length = 15]
+        cns -> Nullable<Varchar>,
+        #[max_length = 150]
+        email -> Nullable<Varchar>,
+        phones -> Array<Text>,
+        #[max_length = 8]
+        cep -> Nullable<Varchar>,
+        #[max_length = 255]
+        street -> Nullable<Varchar>,
+        #[max_length = 20]
+        address_number -> Nullable<Varchar>,
+        #[max_length = 100]
+        address_complement -> Nullable<Varchar>,
+        #[max_length = 100]
+        neighborhood -> Nullable<Varchar>,
+        #[max_length = 100]
+        city -> Nullable<Varchar>,
+        #[max_length = 2]
+        uf -> Nullable<Varchar>,
+        deleted_at -> Nullable<Timestamp>,
+        anonymized_at -> Nullable<Timestamp>,
+        merged_into_id -> Nullable<Int4>,
+        merged_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    prescription_items (id) {
+        id -> Int4,
+        prescription_id -> Int4,
+        position -> Int4,
+        #[max_length = 150]
+        drug -> Varchar,
+        #[max_length = 100]
+        dose -> Varchar,
+        #[max_length = 50]
+        route -> Varchar,
+        #[max_length = 100]
+        frequency -> Varchar,
+        #[max_length = 100]
+        duration -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    prescriptions (id) {
+        id -> Int4,
+        patient_id -> Int4,
+        professional_id -> Int4,
+        appointment_id -> Nullable<Int4>,
+        notes -> Nullable<Text>,
+        allergy_override_reason -> Nullable<Text>,
+        #[max_length = 150]
+        issued_by -> Varchar,
+        issued_at -> Timestamp,
     }
 }
 
 diesel::table! {
-    users (id) {
+    problems (id) {
         id -> Int4,
+        patient_id -> Int4,
+        #[max_length = 10]
+        icd10_code -> Varchar,
+        #[max_length = 255]
+        description -> Varchar,
+        onset_date -> Nullable<Date>,
+        resolved_at -> Nullable<Date>,
+        notes -> Nullable<Text>,
+        recorded_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    professional_schedules (id) {
+        id -> Int4,
+        professional_id -> Int4,
+        weekday -> Int2,
+        starts_at -> Time,
+        ends_at -> Time,
+    }
+}
+
+diesel::table! {
+    professionals (id) {
+        id -> Int4,
+        #[max_length = 255]
         name -> Varchar,
-        email -> Varchar,
-        phone -> Varchar,
-        address -> Varchar,
+        #[max_length = 20]
+        license_number -> Varchar,
+        #[max_length = 2]
+        council_state -> Varchar,
+        specialties -> Array<Text>,
+        active -> Bool,
+        #[max_length = 10]
+        council -> Varchar,
+    }
+}
+
+diesel::table! {
+    rooms (id) {
+        id -> Int4,
+        ward_id -> Int4,
+        #[max_length = 20]
+        number -> Varchar,
+    }
+}
+
+diesel::table! {
+    schedule_exceptions (id) {
+        id -> Int4,
+        professional_id -> Int4,
+        starts_at -> Timestamp,
+        ends_at -> Timestamp,
+        reason -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    sessions (id) {
+        id -> Int4,
+        admin_id -> Int4,
+        #[max_length = 64]
+        refresh_token_hash -> Bpchar,
+        created_at -> Timestamp,
+        refreshed_at -> Timestamp,
+        expires_at -> Timestamp,
+        revoked_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        previous_refresh_token_hash -> Nullable<Bpchar>,
+    }
+}
+
+diesel::table! {
+    wards (id) {
+        id -> Int4,
+        #[max_length = 100]
+        name -> Varchar,
+        description -> Nullable<Text>,
     }
 }
 
+diesel::joinable!(admins -> professionals (professional_id));
+diesel::joinable!(admissions -> patients (patient_id));
+diesel::joinable!(admissions -> professionals (professional_id));
+diesel::joinable!(allergies -> patients (patient_id));
 diesel::joinable!(appointments -> patients (patient_id));
+diesel::joinable!(appointments -> professionals (professional_id));
+diesel::joinable!(bed_occupancies -> admissions (admission_id));
+diesel::joinable!(bed_occupancies -> beds (bed_id));
+diesel::joinable!(beds -> rooms (room_id));
+diesel::joinable!(consents -> patients (patient_id));
+diesel::joinable!(documents -> patients (patient_id));
+diesel::joinable!(encounters -> appointments (appointment_id));
+diesel::joinable!(encounters -> patients (patient_id));
+diesel::joinable!(exam_orders -> appointments (appointment_id));
+diesel::joinable!(exam_orders -> patients (patient_id));
+diesel::joinable!(exam_orders -> professionals (professional_id));
+diesel::joinable!(exam_result_files -> exam_orders (exam_order_id));
+diesel::joinable!(exam_results -> exam_orders (exam_order_id));
+diesel::joinable!(prescription_items -> prescriptions (prescription_id));
+diesel::joinable!(prescriptions -> appointments (appointment_id));
+diesel::joinable!(prescriptions -> patients (patient_id));
+diesel::joinable!(prescriptions -> professionals (professional_id));
+diesel::joinable!(problems -> patients (patient_id));
+diesel::joinable!(professional_schedules -> professionals (professional_id));
+diesel::joinable!(rooms -> wards (ward_id));
+diesel::joinable!(schedule_exceptions -> professionals (professional_id));
+diesel::joinable!(sessions -> admins (admin_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     admins,
+    admissions,
+    allergies,
     appointments,
+    audit_events,
+    bed_occupancies,
+    beds,
+    consents,
+    documents,
+    encounters,
+    exam_orders,
+    exam_result_files,
+    exam_results,
+    failed_logins,
     patients,
-    users,
+    prescription_items,
+    prescriptions,
+    problems,
+    professional_schedules,
+    professionals,
+    rooms,
+    schedule_exceptions,
+    sessions,
+    wards,
 );